@@ -11,6 +11,23 @@ fn readme(c: &mut Criterion) {
     });
 }
 
+fn prose(c: &mut Criterion) {
+    let paragraph = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\ntempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam,\nquis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo.\n\n";
+    let doc = paragraph.repeat(1000);
+
+    c.bench_with_input(BenchmarkId::new("prose", "prose"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn attention(c: &mut Criterion) {
+    let doc = "*a* a** **a ".repeat(10_000);
+
+    c.bench_with_input(BenchmarkId::new("attention", "attention"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
 // fn one_and_a_half_mb(c: &mut Criterion) {
 //     let doc = fs::read_to_string("../a-dump-of-markdown/markdown.md").unwrap();
 //     let mut group = c.benchmark_group("giant");
@@ -22,5 +39,5 @@ fn readme(c: &mut Criterion) {
 // }
 // , one_and_a_half_mb
 
-criterion_group!(benches, readme);
+criterion_group!(benches, readme, prose, attention);
 criterion_main!(benches);
@@ -42,5 +42,45 @@ fn main() -> Result<(), String> {
         )?
     );
 
+    // Support custom syntax with directives, by handling them in the syntax
+    // tree:
+    let mut tree = markdown::to_mdast(
+        "Hi, :mention[wooorm]!",
+        &markdown::ParseOptions {
+            constructs: markdown::Constructs {
+                directive_text: true,
+                ..markdown::Constructs::default()
+            },
+            ..markdown::ParseOptions::default()
+        },
+    )?;
+    mentions(&mut tree);
+    println!("{:?}", tree);
+
     Ok(())
 }
+
+/// Turn text directives named `mention` into links.
+fn mentions(node: &mut markdown::mdast::Node) {
+    if let Some(children) = node.children_mut() {
+        for child in children.iter_mut() {
+            if let markdown::mdast::Node::TextDirective(directive) = child {
+                if directive.name == "mention" {
+                    let name = child.to_string();
+                    *child = markdown::mdast::Node::Link(markdown::mdast::Link {
+                        url: format!("https://github.com/{}", name),
+                        title: None,
+                        children: vec![markdown::mdast::Node::Text(markdown::mdast::Text {
+                            value: format!("@{}", name),
+                            position: None,
+                        })],
+                        position: child.position().cloned(),
+                    });
+                    continue;
+                }
+            }
+
+            mentions(child);
+        }
+    }
+}
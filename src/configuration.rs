@@ -1,8 +1,13 @@
+use crate::event::{Kind as EventKind, Name as EventName};
+use crate::unist::Position;
 use crate::util::{
+    constant::TAB_SIZE,
     line_ending::LineEnding,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
+    slug::Slug as HeadingIdSlug,
+    warning::Sink as WarningSink,
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{boxed::Box, fmt, string::String, vec, vec::Vec};
 
 /// Control which constructs are enabled.
 ///
@@ -21,6 +26,9 @@ use alloc::{boxed::Box, fmt, string::String};
 /// // To turn on all of GFM, use the `gfm` method:
 /// let gfm = Constructs::gfm();
 ///
+/// // To only turn on constructs in text, use the `text` method:
+/// let text = Constructs::text();
+///
 /// // Or, mix and match:
 /// let custom = Constructs {
 ///   math_flow: true,
@@ -32,6 +40,15 @@ use alloc::{boxed::Box, fmt, string::String};
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Constructs {
+    /// Abbreviation.
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// > | a HTML b
+    ///       ^^^^
+    /// ```
+    pub abbreviation: bool,
     /// Attention.
     ///
     /// ```markdown
@@ -99,6 +116,47 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Definition list.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    pub definition_list: bool,
+    /// Directive (container).
+    ///
+    /// ```markdown
+    /// > | :::a
+    ///     ^^^^
+    /// > | b
+    ///     ^
+    /// > | :::
+    ///     ^^^
+    /// ```
+    pub directive_container: bool,
+    /// Directive (leaf).
+    ///
+    /// ```markdown
+    /// > | ::a[b]{c}
+    ///     ^^^^^^^^^
+    /// ```
+    pub directive_leaf: bool,
+    /// Directive (text).
+    ///
+    /// ```markdown
+    /// > | a :b[c]{d} e
+    ///       ^^^^^^^^
+    /// ```
+    pub directive_text: bool,
+    /// Emoji (shortcode).
+    ///
+    /// ```markdown
+    /// > | a :tada: b
+    ///       ^^^^^^
+    /// ```
+    pub emoji: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -187,6 +245,13 @@ pub struct Constructs {
     ///     ^^
     /// ```
     pub heading_setext: bool,
+    /// Highlight.
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^^^^
+    /// ```
+    pub highlight: bool,
     /// HTML (flow).
     ///
     /// ```markdown
@@ -201,6 +266,13 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub html_text: bool,
+    /// Inline footnote.
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^^^^
+    /// ```
+    pub inline_footnote: bool,
     /// Label start (image).
     ///
     /// ```markdown
@@ -254,9 +326,10 @@ pub struct Constructs {
     ///     ^^^^^^^^^^^^^^^^^
     /// ```
     ///
-    /// > 👉 **Note**: to support ESM, you *must* pass
-    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] too.
-    /// > Otherwise, ESM is treated as normal markdown.
+    /// > 👉 **Note**: you *can* pass
+    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] to parse ESM
+    /// > according to a certain grammar (typically, a programming language).
+    /// > Otherwise, ESM runs until a blank line.
     pub mdx_esm: bool,
     /// MDX: expression (flow).
     ///
@@ -320,6 +393,24 @@ pub struct Constructs {
     /// > Otherwise, expressions are parsed with a basic algorithm that only
     /// > cares about braces.
     pub mdx_jsx_text: bool,
+    /// Subscript.
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^^^
+    /// ```
+    ///
+    /// > 👉 **Note**: when on, a single tilde forms subscript and no longer
+    /// > forms [GFM strikethrough][Constructs::gfm_strikethrough], while two
+    /// > tildes still do.
+    pub subscript: bool,
+    /// Superscript.
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^^^
+    /// ```
+    pub superscript: bool,
     /// Thematic break.
     ///
     /// ```markdown
@@ -327,6 +418,13 @@ pub struct Constructs {
     ///     ^^^
     /// ```
     pub thematic_break: bool,
+    /// Wikilink.
+    ///
+    /// ```markdown
+    /// > | [[a|b]]
+    ///     ^^^^^^^
+    /// ```
+    pub wikilink: bool,
 }
 
 impl Default for Constructs {
@@ -340,6 +438,7 @@ impl Default for Constructs {
     /// <https://spec.commonmark.org>.
     fn default() -> Self {
         Self {
+            abbreviation: false,
             attention: true,
             autolink: true,
             block_quote: true,
@@ -349,6 +448,11 @@ impl Default for Constructs {
             code_fenced: true,
             code_text: true,
             definition: true,
+            definition_list: false,
+            directive_container: false,
+            directive_leaf: false,
+            directive_text: false,
+            emoji: false,
             frontmatter: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
@@ -360,8 +464,10 @@ impl Default for Constructs {
             hard_break_trailing: true,
             heading_atx: true,
             heading_setext: true,
+            highlight: false,
             html_flow: true,
             html_text: true,
+            inline_footnote: false,
             label_start_image: true,
             label_start_link: true,
             label_end: true,
@@ -373,7 +479,10 @@ impl Default for Constructs {
             mdx_expression_text: false,
             mdx_jsx_flow: false,
             mdx_jsx_text: false,
+            subscript: false,
+            superscript: false,
             thematic_break: true,
+            wikilink: false,
         }
     }
 }
@@ -408,9 +517,10 @@ impl Constructs {
     /// For more information, see the MDX website:
     /// <https://mdxjs.com>.
     ///
-    /// > 👉 **Note**: to support ESM, you *must* pass
-    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] too.
-    /// > Otherwise, ESM is treated as normal markdown.
+    /// > 👉 **Note**: you *can* pass
+    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] to parse ESM
+    /// > according to a certain grammar (typically, a programming language).
+    /// > Otherwise, ESM runs until a blank line.
     /// >
     /// > You *can* pass
     /// > [`mdx_expression_parse`][MdxExpressionParse]
@@ -432,6 +542,164 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Text.
+    ///
+    /// This turns on the `CommonMark` constructs that occur in text (such as
+    /// attention, code (text), and links), and turns off everything else
+    /// (such as block quotes, headings, and lists).
+    /// Useful for markdown that is shown inline, such as titles or comments
+    /// of one line.
+    ///
+    /// > 👉 **Note**: the result is still wrapped in a paragraph.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# *a*",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::text(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p># <em>a</em></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn text() -> Self {
+        Self {
+            block_quote: false,
+            code_indented: false,
+            code_fenced: false,
+            definition: false,
+            heading_atx: false,
+            heading_setext: false,
+            html_flow: false,
+            list_item: false,
+            thematic_break: false,
+            ..Self::default()
+        }
+    }
+}
+
+/// Signature of a function that is given places of HTML shown as text.
+///
+/// Can be passed as `dangerous_html_sink` in [`CompileOptions`][] to be told
+/// where HTML was found that was not allowed.
+/// Gets the position of the HTML (flow or text).
+pub type DangerousHtmlSink = dyn Fn(Position);
+
+/// Signature of a function that is given definitions.
+///
+/// Can be passed as `definition_sink` in [`CompileOptions`][] to be told
+/// about definitions.
+/// Gets the normalized label, the destination, and the title (if any).
+pub type DefinitionSink = dyn Fn(&str, &str, Option<&str>);
+
+/// Signature of a function that compiles events to HTML.
+///
+/// Can be passed as `event_html` in [`CompileOptions`][] to change how
+/// certain things are turned into HTML.
+/// Gets the kind of the event (enter or exit), its name, the markdown of the
+/// thing it belongs to, the HTML in the current buffer before the event, and
+/// the HTML that is made for the event by default, and returns what to use
+/// instead, or `None` to use the default.
+pub type EventHtml = dyn Fn(&EventKind, &EventName, &str, &str, &str) -> Option<EventOutput>;
+
+/// What to use instead of the HTML of an event.
+///
+/// Returned by [`EventHtml`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::EventOutput;
+/// # fn main() {
+///
+/// // Use HTML as-is:
+/// let raw = EventOutput::Raw("<i>".into());
+///
+/// // Use text, which is encoded (so this turns into `a &lt; b`):
+/// let escaped = EventOutput::Escaped("a < b".into());
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventOutput {
+    /// HTML, used as-is.
+    ///
+    /// Dangerous characters (`&`, `<`, `>`, and `"`) in text must be encoded
+    /// already.
+    Raw(String),
+    /// Text, in which dangerous characters are encoded.
+    Escaped(String),
+}
+
+/// Signature of a function that highlights code.
+///
+/// Can be passed as `highlight` in [`CompileOptions`][] to highlight code.
+/// Gets the language (can be empty), the meta (can be empty), and the code,
+/// and returns HTML.
+pub type Highlight = dyn Fn(&str, &str, &str) -> String;
+
+/// Signature of a function that checks whether a link is external.
+///
+/// Can be passed as `link_external` in [`CompileOptions`][] to decide which
+/// links get `link_external_rel` and `link_external_target`.
+/// Gets the URL, and returns whether it is external.
+pub type LinkExternal = dyn Fn(&str) -> bool;
+
+/// Signature of a function that turns the target of a wikilink into a URL.
+///
+/// Can be passed as `wikilink_url` in [`ParseOptions`][] to change where
+/// wikilinks go.
+/// Gets the target (such as `Some page` in `[[Some page|label]]`), and
+/// returns the URL.
+pub type WikilinkUrl = dyn Fn(&str) -> String;
+
+/// Where to add anchor links to headings.
+///
+/// Can be passed as `heading_anchor` in [`CompileOptions`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::HeadingAnchor;
+/// # fn main() {
+///
+/// // Add anchors after the text of headings:
+/// let after = HeadingAnchor::After;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HeadingAnchor {
+    /// No anchors.
+    #[default]
+    None,
+    /// Anchor before the text of a heading.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <h1 id="a"><a href="#a" class="anchor" aria-hidden="true" tabindex="-1">¶</a>a</h1>
+    /// ```
+    Before,
+    /// Anchor after the text of a heading.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <h1 id="a">a<a href="#a" class="anchor" aria-hidden="true" tabindex="-1">¶</a></h1>
+    /// ```
+    After,
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -466,7 +734,7 @@ impl Constructs {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Default)]
+#[derive(Default)]
 pub struct CompileOptions {
     /// Whether to allow (dangerous) HTML.
     ///
@@ -475,10 +743,21 @@ pub struct CompileOptions {
     ///
     /// Pass `true` for trusted content to get actual HTML elements.
     ///
+    /// To not support HTML at all, turn off the
+    /// [`html_flow`][Constructs::html_flow] and
+    /// [`html_text`][Constructs::html_text] constructs instead.
+    /// The difference is in how the rest of the markdown is parsed: with the
+    /// default, HTML is still recognized, so it can interrupt a paragraph or
+    /// contain blank lines, and things in it are not markdown.
+    /// With the constructs off, what looks like HTML is plain text, and like
+    /// all text, its `<`, `>`, `&`, and `"` are escaped.
+    /// Autolinks (such as `<https://example.com>`) are a different construct,
+    /// so they still work.
+    ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
     /// // `markdown-rs` is safe by default:
@@ -501,6 +780,25 @@ pub struct CompileOptions {
     ///     )?,
     ///     "<p>Hi, <i>venus</i>!</p>"
     /// );
+    ///
+    /// // Turn the HTML constructs off to treat HTML as text:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div>\n*a*\n</div>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 html_flow: false,
+    ///                 html_text: false,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>&lt;div&gt;\n<em>a</em>\n&lt;/div&gt;</p>"
+    /// );
     /// # Ok(())
     /// # }
     /// ```
@@ -550,201 +848,476 @@ pub struct CompileOptions {
     /// ```
     pub allow_dangerous_protocol: bool,
 
-    /// Default line ending to use when compiling to HTML, for line endings not
-    /// in `value`.
+    /// Schemes (protocols) to allow in URLs of links, images, and autolinks.
     ///
-    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
-    /// the markdown document over to the compiled HTML.
-    /// In some cases, such as `> a`, CommonMark requires that extra line
-    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    /// The default is `None`, which uses `allow_dangerous_protocol`.
     ///
-    /// To create that line ending, the document is checked for the first line
-    /// ending that is used.
-    /// If there is no line ending, `default_line_ending` is used.
-    /// If that isn’t configured, `\n` is used.
+    /// Pass a list of schemes (such as `vec!["https".into()]`) to allow
+    /// only those, regardless of `allow_dangerous_protocol`.
+    /// Schemes are compared case-insensitively, without `:`.
+    /// Dangerous schemes such as `javascript`, `data`, and `vbscript` can be
+    /// allowed by adding them to the list.
+    /// Relative URLs (such as `./a`, `/b`, or `?c`) and fragments (`#d`) are
+    /// always allowed.
+    /// Note that email autolinks (`<a@b.c>`) use `mailto`.
+    ///
+    /// When a scheme is not allowed, links get `#` as their `href`, images
+    /// get an empty `src`, and autolinks are shown as text.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` uses `\n` by default:
-    /// assert_eq!(
-    ///     to_html("> a"),
-    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
-    /// );
-    ///
-    /// // Define `default_line_ending` to configure the default:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "> a",
+    ///         "[a](https://b.c) [d](http://e.f) <irc://g.h> [i](#j)",
     ///         &Options {
     ///             compile: CompileOptions {
-    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               allowed_schemes: Some(vec!["https".into()]),
     ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    ///     "<p><a href=\"https://b.c\">a</a> <a href=\"#\">d</a> irc://g.h <a href=\"#j\">i</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub default_line_ending: LineEnding,
+    pub allowed_schemes: Option<Vec<String>>,
 
-    /// Textual label to use for the footnotes section.
+    /// URL to resolve relative URLs in links and images against.
     ///
-    /// The default value is `"Footnotes"`.
-    /// Change it when the markdown is not in English.
+    /// The default is `None`, which leaves URLs as they are.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// Pass a URL (such as `https://example.com/docs/`) to resolve relative
+    /// URLs, such as `./a.png` or `/b`, in links and images (both resources
+    /// and references) against it.
+    /// URLs with a protocol (such as `https:` or `mailto:`),
+    /// protocol-relative URLs (`//example.com`), and fragments (`#a`) are not
+    /// changed.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"Footnotes"` is used by default:
+    /// // `markdown-rs` leaves relative URLs as they are by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("[a](./b) ![c](/d.png) [e](#f)"),
+    ///     "<p><a href=\"./b\">a</a> <img src=\"/d.png\" alt=\"c\" /> <a href=\"#f\">e</a></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label` to use something else:
+    /// // Pass `base_url` to resolve them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "[a](./b) ![c](/d.png) [e](#f)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               base_url: Some("https://example.com/docs/".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href=\"https://example.com/docs/b\">a</a> <img src=\"https://example.com/d.png\" alt=\"c\" /> <a href=\"#f\">e</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label: Option<String>,
+    pub base_url: Option<String>,
 
-    /// HTML tag name to use for the footnote label element.
+    /// Prefix to use before the language of code (fenced) in its `class`.
     ///
-    /// The default value is `"h2"`.
-    /// Change it to match your document structure.
+    /// The default is `None`, which uses `"language-"`, as suggested by
+    /// HTML.
+    /// Pass a string such as `"lang-"` for CSS that expects something else,
+    /// or an empty string to use only the language.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// The language is the first word of the info string, which is the same
+    /// regardless of this option.
+    /// Math (flow) and math (text) always use `language-math`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"h2"` is used by default:
+    /// // `markdown-rs` uses `language-` by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("```rust\na\n```"),
+    ///     "<pre><code class=\"language-rust\">a\n</code></pre>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// // Pass `code_language_prefix` to use something else:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "```rust\na\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_tag_name: Some("h1".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               code_language_prefix: Some("".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<pre><code class=\"rust\">a\n</code></pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_tag_name: Option<String>,
+    pub code_language_prefix: Option<String>,
 
-    /// Attributes to use on the footnote label.
+    /// Function to call with HTML that is shown as text.
     ///
-    /// The default value is `"class=\"sr-only\""`.
-    /// Change it to show the label and add other attributes.
-    ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass an empty string.
-    /// You can also add different attributes.
+    /// The default is `None`.
     ///
-    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
-    /// > calls use it with `aria-describedby` to provide an accessible label.
+    /// When `allow_dangerous_html` is `false`, HTML written by the author is
+    /// shown as text instead of as elements.
+    /// Pass a function to be told where that happened, for example to warn
+    /// the author.
+    /// It is called with the position of each piece of HTML (flow and text),
+    /// in document order.
+    /// It is not called when `allow_dangerous_html` is `true`, or for
+    /// comments that are dropped with `strip_comments`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, unist::Position, CompileOptions, Options};
+    /// use std::{cell::RefCell, rc::Rc};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"class=\"sr-only\""` is used by default:
-    /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
-    /// );
+    /// let positions = Rc::new(RefCell::new(vec![]));
+    /// let sink = positions.clone();
     ///
-    /// // Pass `gfm_footnote_label_attributes` to use something else:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "a <i>b</i>",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               dangerous_html_sink: Some(Box::new(move |position: Position| {
+    ///                   sink.borrow_mut().push(format!("{:?}", position));
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a &lt;i&gt;b&lt;/i&gt;</p>"
     /// );
+    ///
+    /// assert_eq!(*positions.borrow(), vec!["1:3-1:6 (2-5)", "1:7-1:11 (6-10)"]);
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_attributes: Option<String>,
+    pub dangerous_html_sink: Option<Box<DangerousHtmlSink>>,
 
-    /// Textual label to describe the backreference back to footnote calls.
+    /// Default line ending to use when compiling to HTML, for line endings not
+    /// in `value`.
     ///
-    /// The default value is `"Back to content"`.
-    /// Change it when the markdown is not in English.
+    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
+    /// the markdown document over to the compiled HTML.
+    /// In some cases, such as `> a`, CommonMark requires that extra line
+    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
     ///
-    /// This label is used in the `aria-label` attribute on each backreference
-    /// (the `↩` links).
-    /// It affects users of assistive technology.
+    /// To create that line ending, the document is checked for the first line
+    /// ending that is used.
+    /// If there is no line ending, `default_line_ending` is used.
+    /// If that isn’t configured, `\n` is used.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `\n` by default:
+    /// assert_eq!(
+    ///     to_html("> a"),
+    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    /// );
+    ///
+    /// // Define `default_line_ending` to configure the default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_line_ending: LineEnding,
+
+    /// Function to call with definitions.
+    ///
+    /// The default is `None`.
+    ///
+    /// Definitions are not shown in HTML.
+    /// Pass a function to be told about them, for example to check the
+    /// destinations or to build an index of references.
+    /// It is called once for each definition, in document order, with the
+    /// normalized label (lowercased, like `identifier` on
+    /// [`Definition`][crate::mdast::Definition] in mdast), the destination,
+    /// and the title.
+    /// Character escapes and character references in the destination and
+    /// title are decoded, and they are not encoded for HTML.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// use std::{cell::RefCell, rc::Rc};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let definitions = Rc::new(RefCell::new(vec![]));
+    /// let sink = definitions.clone();
+    ///
+    /// to_html_with_options(
+    ///     "[Alpha]: <b&amp;c> \"d\"\n[e]: f",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           definition_sink: Some(Box::new(move |label, destination, title| {
+    ///               sink.borrow_mut().push(format!("{} {} {:?}", label, destination, title));
+    ///           })),
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     *definitions.borrow(),
+    ///     vec!["alpha b&c Some(\"d\")", "e f None"]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub definition_sink: Option<Box<DefinitionSink>>,
+
+    /// Function to change how events are turned into HTML.
+    ///
+    /// The default is `None`.
+    ///
+    /// Pass a function to compile certain things differently, without
+    /// writing a whole compiler.
+    /// It is called for each event, after the default HTML for it is
+    /// generated, with its kind (enter or exit), its name, the markdown of the
+    /// thing it belongs to, the HTML in the current buffer before the event,
+    /// and the default HTML of the event.
+    /// When it returns something, that is used instead of the default HTML of
+    /// that event: either HTML as-is
+    /// ([`EventOutput::Raw`][EventOutput::Raw]), or text that is encoded
+    /// ([`EventOutput::Escaped`][EventOutput::Escaped]).
+    /// Return something based on the default HTML to wrap or change it.
+    /// The function is not called in image alt text, as that is plain text.
+    ///
+    /// > 👉 **Note**: the default HTML of an event is only what that event adds.
+    /// > The HTML of some things is made by other events than their enter and
+    /// > exit: links and images are made entirely on exit, the opening tag of
+    /// > ATX headings is made by their first sequence, setext headings are made
+    /// > entirely by their underline sequence, list items are opened by their
+    /// > marker, and the `>` of the opening tags of lists (`<ul`, `<ol`) and
+    /// > fenced code (`<pre><code`) is added by later events.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, EventKind, EventName, EventOutput, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `em` for emphasis by default:
+    /// assert_eq!(
+    ///     to_html("*a*"),
+    ///     "<p><em>a</em></p>"
+    /// );
+    ///
+    /// // Pass `event_html` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "*a*",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               event_html: Some(Box::new(|kind, name, _value, _buffer, _html| match (kind, name) {
+    ///                   (EventKind::Enter, EventName::Emphasis) => Some(EventOutput::Raw("<i>".into())),
+    ///                   (EventKind::Exit, EventName::Emphasis) => Some(EventOutput::Raw("</i>".into())),
+    ///                   _ => None,
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><i>a</i></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub event_html: Option<Box<EventHtml>>,
+
+    /// Textual label to use for the footnotes section.
+    ///
+    /// The default value is `"Footnotes"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"Footnotes"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label: Option<String>,
+
+    /// HTML tag name to use for the footnote label element.
+    ///
+    /// The default value is `"h2"`.
+    /// Change it to match your document structure.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"h2"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_tag_name: Option<String>,
+
+    /// Attributes to use on the footnote label.
+    ///
+    /// The default value is `"class=\"sr-only\""`.
+    /// Change it to show the label and add other attributes.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass an empty string.
+    /// You can also add different attributes.
+    ///
+    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
+    /// > calls use it with `aria-describedby` to provide an accessible label.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"class=\"sr-only\""` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_attributes: Option<String>,
+
+    /// Textual label to describe the backreference back to footnote calls.
+    ///
+    /// The default value is `"Back to content"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is used in the `aria-label` attribute on each backreference
+    /// (the `↩` links).
+    /// It affects users of assistive technology.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
     /// // `"Back to content"` is used by default:
@@ -768,271 +1341,1842 @@ pub struct CompileOptions {
     ///             }
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_back_label: Option<String>,
+
+    /// Prefix to use before the `id` attribute on footnotes to prevent them
+    /// from *clobbering*.
+    ///
+    /// The default is `"user-content-"`.
+    /// Pass `Some("".into())` for trusted markdown and when you are careful
+    /// with polyfilling.
+    /// You could pass a different prefix.
+    ///
+    /// DOM clobbering is this:
+    ///
+    /// ```html
+    /// <p id="x"></p>
+    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
+    /// ```
+    ///
+    /// The above example shows that elements are made available by browsers,
+    /// by their ID, on the `window` object.
+    /// This is a security risk because you might be expecting some other
+    /// variable at that place.
+    /// It can also break polyfills.
+    /// Using a prefix solves these problems.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"user-content-"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_clobber_prefix: Some("".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_clobber_prefix: Option<String>,
+
+    /// Whether to support the GFM tagfilter.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
+    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    ///
+    /// The tagfilter is kinda weird and kinda useless.
+    /// The tag filter is a naïve attempt at XSS protection.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<iframe>"
+    /// );
+    ///
+    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               gfm_tagfilter: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "&lt;iframe>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## References
+    ///
+    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
+    pub gfm_tagfilter: bool,
+
+    /// HTML to use for hard breaks.
+    ///
+    /// The default is `None`, which uses `<br />`.
+    ///
+    /// Pass a string such as `"<br>"` to change how hard breaks are
+    /// written.
+    /// It is used for hard breaks made with trailing spaces or a trailing
+    /// backslash, and for line endings with `soft_break_as_hard`.
+    ///
+    /// > 👉 **Note**: the value is added as-is, also when
+    /// > `allow_dangerous_html` is `false`: it is configuration, not content
+    /// > from the author.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses a self-closing tag by default:
+    /// assert_eq!(to_html("a\\\nb"), "<p>a<br />\nb</p>");
+    ///
+    /// // Pass `hard_break_html` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\\\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               hard_break_html: Some("<br>".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br>\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub hard_break_html: Option<String>,
+
+    /// Where to add anchor links to headings.
+    ///
+    /// Only used when `heading_ids` is on.
+    /// The default is `HeadingAnchor::None`, which does not add anchors.
+    ///
+    /// Pass `HeadingAnchor::Before` or `HeadingAnchor::After` to add a link
+    /// to the heading itself (such as `<a href="#hello">¶</a>`) before or
+    /// after its text, like GitHub does.
+    /// The link goes to the ID of the heading, so there is no anchor for
+    /// headings that get no ID (such as when they are empty).
+    /// See `heading_anchor_class` and `heading_anchor_content` to change how
+    /// anchors look.
+    ///
+    /// Anchors are only added to HTML: they are not part of the text content
+    /// of headings, so they do not affect IDs, or
+    /// [`headings()`][crate::headings].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, HeadingAnchor, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_anchor: HeadingAnchor::Before,
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello\"><a href=\"#hello\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>Hello</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_anchor: HeadingAnchor,
+
+    /// Class to use for heading anchors.
+    ///
+    /// Only used when `heading_anchor` is on.
+    /// The default is `None`, which uses `anchor`, the same as GitHub.
+    ///
+    /// Pass a string to use a different class, or an empty string to not add
+    /// a `class` attribute.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, HeadingAnchor, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_anchor: HeadingAnchor::After,
+    ///               heading_anchor_class: Some("permalink".into()),
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello\">Hello<a href=\"#hello\" class=\"permalink\" aria-hidden=\"true\" tabindex=\"-1\">¶</a></h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_anchor_class: Option<String>,
+
+    /// HTML to use inside heading anchors.
+    ///
+    /// Only used when `heading_anchor` is on.
+    /// The default is `None`, which uses a pilcrow (`¶`).
+    ///
+    /// Pass a string to use something else, such as an SVG icon.
+    ///
+    /// > 👉 **Note**: the value is added as-is, also when
+    /// > `allow_dangerous_html` is `false`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, HeadingAnchor, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_anchor: HeadingAnchor::Before,
+    ///               heading_anchor_content: Some("<span class=\"icon\"></span>".into()),
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello\"><a href=\"#hello\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\"><span class=\"icon\"></span></a>Hello</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_anchor_content: Option<String>,
+
+    /// Function to turn the text content of a heading into an ID.
+    ///
+    /// Only used when `heading_ids` is on.
+    /// The default is `None`, which uses GitHub-style slugs: the text is
+    /// lowercased, spaces are turned into dashes, and everything that is not
+    /// a letter, number, dash, or underscore is dropped.
+    ///
+    /// Pass a function to generate different IDs, such as to add a prefix.
+    /// When the function returns an empty string, no ID is added.
+    /// IDs are made unique afterwards: numeric suffixes are added to IDs that
+    /// were seen before.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               heading_id_slug: Some(Box::new(|value| format!("section-{}", value.len()))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"section-5\">Hello</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_id_slug: Option<Box<HeadingIdSlug>>,
+
+    /// Whether to add IDs to headings.
+    ///
+    /// The default is `false`, which does not add IDs.
+    ///
+    /// Pass `true` to add `id` attributes to headings, generated from their
+    /// text content (see `heading_id_slug`), in the same way as GitHub does.
+    /// Duplicate IDs get numeric suffixes, in document order.
+    ///
+    /// > 👉 **Note**: IDs are not prefixed (GitHub prefixes them with
+    /// > `user-content-`), so they can clobber other IDs or globals on a page.
+    /// > Pass a `heading_id_slug` function to add a prefix when dealing with
+    /// > untrusted content.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add IDs by default:
+    /// assert_eq!(to_html("# Hello, world!"), "<h1>Hello, world!</h1>");
+    ///
+    /// // Pass `heading_ids: true` to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello, world!\n\n## Hello, world!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello-world\">Hello, world!</h1>\n<h2 id=\"hello-world-1\">Hello, world!</h2>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_ids: bool,
+
+    /// Function to highlight code.
+    ///
+    /// The default is `None`, which shows code as (encoded) text.
+    ///
+    /// Pass a function to highlight code (fenced) and code (indented).
+    /// It is called with the language (the first word of the info string),
+    /// the meta (the rest of the info string, such as `{1,3-5}` in
+    /// `` ```js {1,3-5} ``), and the code itself.
+    /// Whitespace between the language and the meta, and around the info
+    /// string, is not included.
+    /// The language and meta are empty when there is no info string or for
+    /// code (indented).
+    /// The `class` on `<code>` still only uses the language.
+    /// What it returns is used verbatim instead of the encoded code, inside
+    /// the `<pre><code>` elements, so it must be safe HTML.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```js {1}\nconst a = 1\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               highlight: Some(Box::new(|language, meta, code| {
+    ///                 format!("<span class=\"{}\" data-meta=\"{}\">{}</span>", language, meta, code.trim_end())
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-js\"><span class=\"js\" data-meta=\"{1}\">const a = 1</span></code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub highlight: Option<Box<Highlight>>,
+
+    /// Attributes to add to images.
+    ///
+    /// The default is an empty list, which adds no attributes.
+    ///
+    /// Pass a list of keys and values to add them to every `<img>` element,
+    /// after `src` and `alt`, and before `title`.
+    /// Values are encoded, keys are used as-is.
+    /// This is useful to add `loading="lazy"` or `decoding="async"`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no extra attributes by default:
+    /// assert_eq!(
+    ///     to_html("![a](b \"c\")"),
+    ///     "<p><img src=\"b\" alt=\"a\" title=\"c\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_attributes` to add some:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b \"c\")",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_attributes: vec![
+    ///                 ("loading".into(), "lazy".into()),
+    ///                 ("decoding".into(), "async".into()),
+    ///               ],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"b\" alt=\"a\" loading=\"lazy\" decoding=\"async\" title=\"c\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_attributes: Vec<(String, String)>,
+
+    /// Function to check whether a link is external.
+    ///
+    /// Only used when `link_external_rel` or `link_external_target` is set.
+    /// The default is `None`, which considers links to URLs with `http:` or
+    /// `https:` protocols and protocol-relative URLs (`//example.com`)
+    /// external.
+    ///
+    /// Pass a function to decide differently, such as to not treat links to
+    /// your own site as external.
+    /// It is called with the URL, as it is used in `href`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com), [b](https://example.org)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_external: Some(Box::new(|url| !url.starts_with("https://example.com"))),
+    ///               link_external_rel: Some("nofollow".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\">a</a>, <a href=\"https://example.org\" rel=\"nofollow\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_external: Option<Box<LinkExternal>>,
+
+    /// Value of the `rel` attribute to add to external links.
+    ///
+    /// The default is `None`, which does not add `rel` attributes.
+    ///
+    /// Pass a value such as `"nofollow noopener"` to add it to links
+    /// (resources, references, autolinks, and GFM autolink literals) that are
+    /// external (see `link_external`).
+    /// Relative links and links to fragments (`#a`) are not external.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no `rel` attributes by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://example.com \"b\"), [c](#d)"),
+    ///     "<p><a href=\"https://example.com\" title=\"b\">a</a>, <a href=\"#d\">c</a></p>"
+    /// );
+    ///
+    /// // Pass `link_external_rel` to add them to external links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com \"b\"), [c](#d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_external_rel: Some("nofollow noopener".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\" title=\"b\" rel=\"nofollow noopener\">a</a>, <a href=\"#d\">c</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_external_rel: Option<String>,
+
+    /// Value of the `target` attribute to add to external links.
+    ///
+    /// The default is `None`, which does not add `target` attributes.
+    ///
+    /// Pass a value such as `"_blank"` to add it to links that are external
+    /// (see `link_external`).
+    /// You likely also want to set `link_external_rel` to include
+    /// `noopener`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<https://example.com>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_external_rel: Some("noopener".into()),
+    ///               link_external_target: Some("_blank".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\" rel=\"noopener\" target=\"_blank\">https://example.com</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_external_target: Option<String>,
+
+    /// Line ending to turn all line endings into when compiling to HTML.
+    ///
+    /// The default is `None`, which keeps each line ending in the markdown
+    /// as it is (see `default_line_ending` for line endings that are not in
+    /// the markdown).
+    /// That way, a document with mixed line endings is compiled to HTML with
+    /// the same line endings.
+    ///
+    /// Pass a line ending, such as `LineEnding::CarriageReturnLineFeed`, to
+    /// use it for every line ending instead, including those added to the
+    /// HTML (in which case `default_line_ending` is not used).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps line endings by default:
+    /// assert_eq!(
+    ///     to_html("> a\r\nb\nc"),
+    ///     "<blockquote>\r\n<p>a\r\nb\nc</p>\r\n</blockquote>"
+    /// );
+    ///
+    /// // Pass `normalize_line_endings` to use one line ending:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a\r\nb\nc",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               normalize_line_endings: Some(LineEnding::LineFeed),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\n<p>a\nb\nc</p>\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub normalize_line_endings: Option<LineEnding>,
+
+    /// Whether to leave out the `<code>` element in code (fenced, indented).
+    ///
+    /// The default is `false`, which uses `<pre><code>`, as `CommonMark`
+    /// does.
+    ///
+    /// Pass `true` to only use `<pre>`, such as for preformatted text that is
+    /// not code.
+    /// The `class` with the language of code (fenced) is then added to the
+    /// `<pre>`.
+    /// Math (flow) always uses `<pre><code>`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `<code>` in `<pre>` by default:
+    /// assert_eq!(
+    ///     to_html("```txt\na\n```"),
+    ///     "<pre><code class=\"language-txt\">a\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `pre_without_code` to leave it out:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```txt\na\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               pre_without_code: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre class=\"language-txt\">a\n</pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub pre_without_code: bool,
+
+    /// Whether to turn line endings in paragraphs into hard breaks.
+    ///
+    /// The default is `false`, which keeps line endings as-is (soft
+    /// breaks): in HTML, they are shown as spaces.
+    /// Hard breaks are then only made with two or more trailing spaces or a
+    /// trailing backslash.
+    ///
+    /// Pass `true` to add a hard break (see `hard_break_html`) before every
+    /// line ending in a paragraph, like GitHub does in comments.
+    /// Line endings that already follow a hard break do not get a second
+    /// one.
+    /// Line endings in code (text), in image descriptions, in link
+    /// destinations and titles, and in HTML (text) when
+    /// `allow_dangerous_html` is `true` are left alone.
+    /// When `allow_dangerous_html` is `false`, HTML is shown as text, so
+    /// line endings in it do get hard breaks.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps soft breaks by default:
+    /// assert_eq!(to_html("a\nb"), "<p>a\nb</p>");
+    ///
+    /// // Pass `soft_break_as_hard: true` to turn them into hard breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               soft_break_as_hard: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub soft_break_as_hard: bool,
+
+    /// Whether to turn straight punctuation into typographic punctuation.
+    ///
+    /// The default is `false`.
+    ///
+    /// Pass `true` to turn `"` and `'` into curly quotes (`“`, `”`, `‘`,
+    /// `’`), `--` into an en dash (`–`), `---` into an em dash (`—`), and
+    /// `...` into an ellipsis (`…`), like `SmartyPants`.
+    /// Whether a quote opens or closes is inferred from the characters
+    /// around it.
+    /// Apostrophes, such as in `it's`, turn into right single quotes (`’`).
+    ///
+    /// Only text is changed: code, HTML, URLs, and escaped characters
+    /// (`\"`) are left alone.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` leaves punctuation as-is by default:
+    /// assert_eq!(
+    ///     to_html("\"It's\" -- `a--b`..."),
+    ///     "<p>&quot;It's&quot; -- <code>a--b</code>...</p>"
+    /// );
+    ///
+    /// // Pass `smart_punctuation: true` to make it typographic:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "\"It's\" -- `a--b`...",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               smart_punctuation: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>“It’s” – <code>a--b</code>…</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub smart_punctuation: bool,
+
+    /// Whether to drop HTML comments.
+    ///
+    /// The default is `false`, which passes comments through (or, without
+    /// `allow_dangerous_html`, shows them as text).
+    ///
+    /// Pass `true` to drop comments in HTML (flow) and HTML (text), while
+    /// keeping other HTML.
+    /// A comment that is not closed runs to the end of the HTML (flow) it is
+    /// in, which is the end of the document or its container.
+    /// Lines that only had comments on them are dropped, and so is HTML (flow)
+    /// that ends up empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes comments through:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a <!--b--> <i>c</i>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a <!--b--> <i>c</i></p>"
+    /// );
+    ///
+    /// // Pass `strip_comments: true` to drop them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a <!--b--> <i>c</i>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               strip_comments: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a  <i>c</i></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strip_comments: bool,
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+}
+
+impl fmt::Debug for CompileOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompileOptions")
+            .field("allow_dangerous_html", &self.allow_dangerous_html)
+            .field("allow_dangerous_protocol", &self.allow_dangerous_protocol)
+            .field("allowed_schemes", &self.allowed_schemes)
+            .field("base_url", &self.base_url)
+            .field("code_language_prefix", &self.code_language_prefix)
+            .field(
+                "dangerous_html_sink",
+                &self.dangerous_html_sink.as_ref().map(|_d| "[Function]"),
+            )
+            .field("default_line_ending", &self.default_line_ending)
+            .field(
+                "definition_sink",
+                &self.definition_sink.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "event_html",
+                &self.event_html.as_ref().map(|_d| "[Function]"),
+            )
+            .field("gfm_footnote_label", &self.gfm_footnote_label)
+            .field(
+                "gfm_footnote_label_tag_name",
+                &self.gfm_footnote_label_tag_name,
+            )
+            .field(
+                "gfm_footnote_label_attributes",
+                &self.gfm_footnote_label_attributes,
+            )
+            .field("gfm_footnote_back_label", &self.gfm_footnote_back_label)
+            .field(
+                "gfm_footnote_clobber_prefix",
+                &self.gfm_footnote_clobber_prefix,
+            )
+            .field("gfm_tagfilter", &self.gfm_tagfilter)
+            .field("hard_break_html", &self.hard_break_html)
+            .field("heading_anchor", &self.heading_anchor)
+            .field("heading_anchor_class", &self.heading_anchor_class)
+            .field("heading_anchor_content", &self.heading_anchor_content)
+            .field(
+                "heading_id_slug",
+                &self.heading_id_slug.as_ref().map(|_d| "[Function]"),
+            )
+            .field("heading_ids", &self.heading_ids)
+            .field("highlight", &self.highlight.as_ref().map(|_d| "[Function]"))
+            .field("image_attributes", &self.image_attributes)
+            .field(
+                "link_external",
+                &self.link_external.as_ref().map(|_d| "[Function]"),
+            )
+            .field("link_external_rel", &self.link_external_rel)
+            .field("link_external_target", &self.link_external_target)
+            .field("normalize_line_endings", &self.normalize_line_endings)
+            .field("pre_without_code", &self.pre_without_code)
+            .field("smart_punctuation", &self.smart_punctuation)
+            .field("soft_break_as_hard", &self.soft_break_as_hard)
+            .field("strip_comments", &self.strip_comments)
+            .finish()
+    }
+}
+
+impl CompileOptions {
+    /// GFM.
+    ///
+    /// GFM stands for **GitHub flavored markdown**.
+    /// On the compilation side, GFM turns on the GFM tag filter.
+    /// The tagfilter is useless, but it’s included here for consistency, and
+    /// this method exists for parity to parse options.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>.
+    pub fn gfm() -> Self {
+        Self {
+            gfm_tagfilter: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// What to do when a list item uses a different marker than the previous
+/// item.
+///
+/// Can be passed as `list_marker_change` in [`ParseOptions`][].
+/// Only markers of the same kind are compared: bullets (`*`, `+`, `-`) with
+/// bullets, and the delimiters of ordered items (`.`, `)`) with each other.
+/// An ordered item after a bullet item (or the other way around) always
+/// starts a new list.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ListMarkerChange;
+/// # fn main() {
+///
+/// // Keep items with different markers in one list:
+/// let join = ListMarkerChange::Join;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ListMarkerChange {
+    /// Start a new list, like `CommonMark`.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <ul>
+    /// <li>a</li>
+    /// </ul>
+    /// <ul>
+    /// <li>b</li>
+    /// </ul>
+    /// ```
+    #[default]
+    Split,
+    /// Start a new list, like `Split`, and also pass a
+    /// [`ListMarkerChange`][crate::WarningKind::ListMarkerChange] warning to
+    /// [`warning_sink`][ParseOptions::warning_sink].
+    Warn,
+    /// Continue the list.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <ul>
+    /// <li>a</li>
+    /// <li>b</li>
+    /// </ul>
+    /// ```
+    Join,
+}
+
+/// Configuration that describes how to parse from markdown.
+///
+/// You can use this:
+///
+/// *   To control what markdown constructs are turned on and off
+/// *   To control some of those constructs
+/// *   To add support for certain programming languages when parsing MDX
+///
+/// In most cases, you will want to use the default trait or `gfm` method.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ParseOptions;
+/// # fn main() {
+///
+/// // Use the default trait to parse markdown according to `CommonMark`:
+/// let commonmark = ParseOptions::default();
+///
+/// // Use the `gfm` method to parse markdown according to GFM:
+/// let gfm = ParseOptions::gfm();
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+    /// Which constructs to enable and disable.
+    ///
+    /// The default is to follow `CommonMark`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("    indented code?"),
+    ///     "<pre><code>indented code?\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `constructs` to choose what to enable and disable:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "    indented code?",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 code_indented: false,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>indented code?</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub constructs: Constructs,
+
+    /// Which characters can be escaped with a backslash.
+    ///
+    /// This option does nothing if `character_escape` is not turned on in
+    /// `constructs`.
+    /// Characters other than ASCII punctuation, letters, and digits are
+    /// ignored.
+    /// Escaping a line ending is a hard break (escape), which is not affected
+    /// by this option.
+    ///
+    /// The default is `None`, which follows `CommonMark`, as all ASCII
+    /// punctuation can be escaped.
+    /// That includes the markers of extensions, such as `~` and `|` in GFM.
+    /// Pass a list of characters to only allow those, such as to make a
+    /// dialect where `\~` is only an escape when strikethrough is turned on,
+    /// or to allow escaping more characters.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports escaping ASCII punctuation by default:
+    /// assert_eq!(
+    ///     to_html("\\* \\~ \\a"),
+    ///     "<p>* ~ \\a</p>"
+    /// );
+    ///
+    /// // Pass `character_escape_characters` to use other characters:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "\\* \\~ \\a",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               character_escape_characters: Some(vec!['*', 'a']),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>* \\~ a</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub character_escape_characters: Option<Vec<char>>,
+
+    /// Extra emoji shortcodes.
+    ///
+    /// The default is `vec![]`, which only supports the shortcodes that
+    /// GitHub supports, such as `:tada:`.
+    ///
+    /// Pass a list of names (without colons) and values to support more.
+    /// They take precedence over the default shortcodes.
+    /// When a name is given more than once, the first one is used.
+    /// A value that contains a slash (`/`), such as `/emoji/ferris.png`, is
+    /// the URL of an image, for custom emoji; other values are text.
+    /// Names are matched exactly, and can only contain ASCII alphanumerics,
+    /// `_`, `+`, and `-`.
+    ///
+    /// This option does nothing if `emoji` is not turned on in
+    /// `constructs`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` with emoji turned on supports GitHub shortcodes:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ":tada: :ferris:",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 emoji: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>🎉 :ferris:</p>"
+    /// );
+    ///
+    /// // Pass `emoji_shortcodes` to support more:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ":tada: :ferris:",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 emoji: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               emoji_shortcodes: vec![("ferris".into(), "/ferris.png".into())],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>🎉 <img src=\"/ferris.png\" alt=\":ferris:\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub emoji_shortcodes: Vec<(String, String)>,
+
+    /// Whether to support TOML frontmatter (with `+++` fences).
+    ///
+    /// This option does nothing if `frontmatter` is not turned on in
+    /// `constructs`.
+    /// This option does not affect YAML frontmatter (with `---` fences).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to only allow YAML frontmatter to form.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports TOML frontmatter if `frontmatter` is on:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "+++\na = \"b\"\n+++",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     ""
+    /// );
+    ///
+    /// // Pass `frontmatter_toml: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "+++\na = \"b\"\n+++",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               frontmatter_toml: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>+++\na = &quot;b&quot;\n+++</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub frontmatter_toml: bool,
+
+    /// Whether to support YAML frontmatter (with `---` fences).
+    ///
+    /// This option does nothing if `frontmatter` is not turned on in
+    /// `constructs`.
+    /// This option does not affect TOML frontmatter (with `+++` fences).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to only allow TOML frontmatter to form.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports YAML frontmatter if `frontmatter` is on:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "---\na: b\n---",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     ""
+    /// );
+    ///
+    /// // Pass `frontmatter_yaml: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "---\na: b\n---",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               frontmatter_yaml: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<hr />\n<h2>a: b</h2>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub frontmatter_yaml: bool,
+
+    /// Whether to support GFM strikethrough with a single tilde
+    ///
+    /// This option does nothing if `gfm_strikethrough` is not turned on in
+    /// `constructs`, or if `subscript` is, as single tildes then form
+    /// subscript.
+    /// This option does not affect strikethrough with double tildes.
+    ///
+    /// The default is `true`, which follows how markdown on `github.com`
+    /// works, as strikethrough with single tildes is supported.
+    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
+    /// strikethrough with single tildes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports single tildes by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_strikethrough_single_tilde: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>~a~</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_strikethrough_single_tilde: bool,
+
+    /// Whether to support closing sequences in heading (atx).
+    ///
+    /// The default is `true`, which follows `CommonMark`: a final sequence
+    /// of `#`s (preceded by whitespace) closes the heading and is not shown.
+    /// Pass `false` to keep it as text instead, for a stricter dialect.
+    /// Either way, `#`s that are not preceded by whitespace (such as in
+    /// `## a #b`) are text.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports closing sequences by default:
+    /// assert_eq!(to_html("## a ##"), "<h2>a</h2>");
+    ///
+    /// // Pass `heading_atx_closing_sequence: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "## a ##",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               heading_atx_closing_sequence: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h2>a ##</h2>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_atx_closing_sequence: bool,
+
+    /// What to do when a list item uses a different marker than the previous
+    /// item.
+    ///
+    /// The default is `ListMarkerChange::Split`, which follows `CommonMark`:
+    /// `- a` followed by `* b` makes two lists, and so does `1. a` followed
+    /// by `2) b`.
+    /// Pass `ListMarkerChange::Warn` to also be told about such changes, or
+    /// `ListMarkerChange::Join` to keep the items in one list instead.
+    /// When joined, the list uses the marker of its first item.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, ListMarkerChange, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` starts a new list when the marker changes by default:
+    /// assert_eq!(
+    ///     to_html("- a\n* b"),
+    ///     "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `ListMarkerChange::Join` to continue the list:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "- a\n* b",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               list_marker_change: ListMarkerChange::Join,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li>a</li>\n<li>b</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub list_marker_change: ListMarkerChange,
+
+    /// Whether to support math (text) with a single dollar
+    ///
+    /// This option does nothing if `math_text` is not turned on in
+    /// `constructs`.
+    /// This option does not affect math (text) with two or more dollars.
+    ///
+    /// The default is `true`, which is more close to how code (text) and
+    /// Pandoc work, as it allows math with a single dollar to form.
+    /// However, single dollars can interfere with “normal” dollars in text.
+    /// Pass `false`, to only allow math (text) to form when two or more
+    /// dollars are used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports single dollars by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    /// );
+    ///
+    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               math_text_single_dollar: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>$a$</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_text_single_dollar: bool,
+
+    /// Function to parse expressions with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within expressions.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
+
+    /// Function to parse ESM with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within ESM blocks, however, the keywords (`export`,
+    /// `import`) are currently hardcoded JavaScript-specific.
+    ///
+    /// > 👉 **Note**: please raise an issue if you’re interested in working on
+    /// > MDX that is aware of, say, Rust, or other programming languages.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+
+    /// Whether to crash on a `<` that does not start JSX.
+    ///
+    /// In MDX, JSX tags that are written wrong crash, with a message about
+    /// where the problem happened, what occurred, and what was expected
+    /// instead.
+    /// But a `<` followed by whitespace (or the end of the file) is not seen
+    /// as a tag, so that `1 < 3` can be written in markdown.
+    ///
+    /// The default is `false`, which follows how MDX works: such a `<` is
+    /// text.
+    /// Pass `true` to crash on it instead, to catch tags where whitespace is
+    /// accidentally used after `<`.
+    /// The `<` can then be written as a character reference (`&lt;`) or
+    /// in an expression (`{'<'}`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports a `<` followed by whitespace in MDX by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "1 < 3",
+    ///         &Options {
+    ///             parse: ParseOptions::mdx(),
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>1 &lt; 3</p>"
+    /// );
+    ///
+    /// // Pass `mdx_jsx_strict: true` to crash on it instead:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "1 < 3",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               mdx_jsx_strict: true,
+    ///               ..ParseOptions::mdx()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )
+    ///     .err()
+    ///     .unwrap()
+    ///     .to_string(),
+    ///     "1:4: Unexpected character U+0020 after `<`, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a less than sign in MDX, use `&lt;` or `{'<'}`)"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mdx_jsx_strict: bool,
+
+    /// Size of tab stops.
+    ///
+    /// Tabs in markdown expand to the next tab stop, which matters for
+    /// indentation.
+    /// The columns of points in positions also account for this.
+    /// Only tab stops change: the indentation needed for constructs is still
+    /// measured in columns, so code (indented) still needs 4 of them.
+    ///
+    /// > 👉 **Note**: changing this breaks `CommonMark` conformance, as
+    /// > markdown that uses tabs is parsed differently.
+    ///
+    /// The default is `4`, which follows `CommonMark`, as tab stops are at
+    /// every 4 columns.
+    /// Pass another size to use other tab stops.
+    /// The size must be at least `1`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses tab stops of 4 by default:
+    /// assert_eq!(
+    ///     to_html("\tfoo"),
+    ///     "<pre><code>foo\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `tab_size` to use other tab stops:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "\tfoo",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               tab_size: 8,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code>    foo\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub tab_size: usize,
+
+    /// Which markers to allow in thematic breaks.
+    ///
+    /// This option does nothing if `thematic_break` is not turned on in
+    /// `constructs`.
+    /// Characters other than `*`, `-`, and `_` are ignored.
+    /// Lines that use another marker are not thematic breaks, so they
+    /// typically turn into paragraphs, or into setext heading underlines or
+    /// lists where those can form.
+    ///
+    /// The default is `None`, which follows `CommonMark`, as all three
+    /// markers can be used.
+    /// Pass a list of markers to only allow those.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports all markers by default:
+    /// assert_eq!(
+    ///     to_html("***\n\n___"),
+    ///     "<hr />\n<hr />"
+    /// );
+    ///
+    /// // Pass `thematic_break_markers` to only allow some:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "***\n\n___",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               thematic_break_markers: Some(vec!['*']),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<hr />\n<p>___</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_back_label: Option<String>,
+    pub thematic_break_markers: Option<Vec<char>>,
 
-    /// Prefix to use before the `id` attribute on footnotes to prevent them
-    /// from *clobbering*.
-    ///
-    /// The default is `"user-content-"`.
-    /// Pass `Some("".into())` for trusted markdown and when you are careful
-    /// with polyfilling.
-    /// You could pass a different prefix.
-    ///
-    /// DOM clobbering is this:
+    /// Minimum number of markers in thematic breaks.
     ///
-    /// ```html
-    /// <p id="x"></p>
-    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
-    /// ```
+    /// This option does nothing if `thematic_break` is not turned on in
+    /// `constructs`.
+    /// Sizes smaller than `3` are treated as `3`.
+    /// Whitespace between markers is not counted.
     ///
-    /// The above example shows that elements are made available by browsers,
-    /// by their ID, on the `window` object.
-    /// This is a security risk because you might be expecting some other
-    /// variable at that place.
-    /// It can also break polyfills.
-    /// Using a prefix solves these problems.
+    /// The default is `None`, which follows `CommonMark`, as three or more
+    /// markers are needed.
+    /// Pass a size to require more markers.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"user-content-"` is used by default:
+    /// // `markdown-rs` supports three markers by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("* * *"),
+    ///     "<hr />"
     /// );
     ///
-    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// // Pass `thematic_break_size_min` to require more:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "* * *\n\n*****",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
-    ///             compile: CompileOptions {
-    ///               gfm_footnote_clobber_prefix: Some("".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///             parse: ParseOptions {
+    ///               thematic_break_size_min: Some(5),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<ul>\n<li>\n<ul>\n<li>\n<ul>\n<li></li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n<hr />"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_clobber_prefix: Option<String>,
+    pub thematic_break_size_min: Option<usize>,
 
-    /// Whether to support the GFM tagfilter.
+    /// Whether to support titles in curly quotes.
     ///
-    /// This option does nothing if `allow_dangerous_html` is not turned on.
-    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
-    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    /// Titles occur in definitions (`[a]: b "c"`) and resources
+    /// (`[a](b "c")`).
+    /// Curly quotes are often used instead of straight quotes in text copied
+    /// from word processors.
+    /// A title opened with `“` can only be closed with `”`, and one opened
+    /// with `‘` only with `’`.
     ///
-    /// The tagfilter is kinda weird and kinda useless.
-    /// The tag filter is a naïve attempt at XSS protection.
-    /// You should use a proper HTML sanitizing algorithm instead.
+    /// The default is `false`, which follows `CommonMark`, as curly quotes
+    /// are not markers.
+    /// Pass `true` to allow titles in `“a”` and `‘a’`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// // `markdown-rs` follows CommonMark by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "<iframe>",
-    ///         &Options {
-    ///             parse: ParseOptions::gfm(),
-    ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
-    ///               ..CompileOptions::default()
-    ///             }
-    ///         }
-    ///     )?,
-    ///     "<iframe>"
+    ///     to_html("[a](b “c”)"),
+    ///     "<p>[a](b “c”)</p>"
     /// );
     ///
-    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// // Pass `title_smart_quotes: true` to allow curly quotes:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "[a](b “c”)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
-    ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
-    ///               gfm_tagfilter: true,
-    ///               ..CompileOptions::default()
-    ///             }
+    ///             parse: ParseOptions {
+    ///               title_smart_quotes: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "&lt;iframe>"
+    ///     "<p><a href=\"b\" title=\"c\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    ///
-    /// ## References
-    ///
-    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
-    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
-    pub gfm_tagfilter: bool,
-}
+    pub title_smart_quotes: bool,
 
-impl CompileOptions {
-    /// GFM.
-    ///
-    /// GFM stands for **GitHub flavored markdown**.
-    /// On the compilation side, GFM turns on the GFM tag filter.
-    /// The tagfilter is useless, but it’s included here for consistency, and
-    /// this method exists for parity to parse options.
+    /// Whether to allow balanced parens in parenthesized titles.
     ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>.
-    pub fn gfm() -> Self {
-        Self {
-            gfm_tagfilter: true,
-            ..Self::default()
-        }
-    }
-}
-
-/// Configuration that describes how to parse from markdown.
-///
-/// You can use this:
-///
-/// *   To control what markdown constructs are turned on and off
-/// *   To control some of those constructs
-/// *   To add support for certain programming languages when parsing MDX
-///
-/// In most cases, you will want to use the default trait or `gfm` method.
-///
-/// ## Examples
-///
-/// ```
-/// use markdown::ParseOptions;
-/// # fn main() {
-///
-/// // Use the default trait to parse markdown according to `CommonMark`:
-/// let commonmark = ParseOptions::default();
-///
-/// // Use the `gfm` method to parse markdown according to GFM:
-/// let gfm = ParseOptions::gfm();
-/// # }
-/// ```
-#[allow(clippy::struct_excessive_bools)]
-pub struct ParseOptions {
-    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
-    /// Which constructs to enable and disable.
+    /// Titles occur in definitions (`[a]: b (c)`) and resources
+    /// (`[a](b (c))`).
+    /// This option does not affect titles in double or single quotes.
+    /// Escaped parens are not counted.
     ///
-    /// The default is to follow `CommonMark`.
+    /// The default is `false`, which follows `CommonMark`, as a parenthesized
+    /// title ends at the first unescaped `)`.
+    /// Pass `true` to allow parens in titles to nest, so that a title only
+    /// ends at the `)` that matches its opening `(`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
     /// // `markdown-rs` follows CommonMark by default:
     /// assert_eq!(
-    ///     to_html("    indented code?"),
-    ///     "<pre><code>indented code?\n</code></pre>"
+    ///     to_html("[a](b (c (d) e))"),
+    ///     "<p>[a](b (c (d) e))</p>"
     /// );
     ///
-    /// // Pass `constructs` to choose what to enable and disable:
+    /// // Pass `title_balance_parens: true` to allow nested parens:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "    indented code?",
+    ///         "[a](b (c (d) e))",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 code_indented: false,
-    ///                 ..Constructs::default()
-    ///               },
+    ///               title_balance_parens: true,
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>indented code?</p>"
+    ///     "<p><a href=\"b\" title=\"c (d) e\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub constructs: Constructs,
+    pub title_balance_parens: bool,
 
-    /// Whether to support GFM strikethrough with a single tilde
+    /// Maximum size of the string content of titles, in bytes.
     ///
-    /// This option does nothing if `gfm_strikethrough` is not turned on in
-    /// `constructs`.
-    /// This option does not affect strikethrough with double tildes.
+    /// Titles occur in definitions (`[a]: b "c"`) and resources
+    /// (`[a](b "c")`).
+    /// When a title grows past this size, it is not a title, which typically
+    /// means the whole definition or resource does not form either.
+    /// Line endings and the whitespace around them are not counted.
     ///
-    /// The default is `true`, which follows how markdown on `github.com`
-    /// works, as strikethrough with single tildes is supported.
-    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
-    /// strikethrough with single tildes.
+    /// The default is `None`, which follows `CommonMark`, as titles can be of
+    /// any size.
+    /// Pass a size to limit how much work is done for unclosed titles in
+    /// untrusted input.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` supports single tildes by default:
+    /// // `markdown-rs` supports titles of any size by default:
+    /// assert_eq!(
+    ///     to_html("[a](b \"cd\")"),
+    ///     "<p><a href=\"b\" title=\"cd\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `title_size_max` to limit that:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         "[a](b \"cd\")",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
+    ///               title_size_max: Some(1),
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><del>a</del></p>"
+    ///     "<p>[a](b &quot;cd&quot;)</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub title_size_max: Option<usize>,
+
+    /// Function to check characters in titles with.
+    ///
+    /// Titles occur in definitions (`[a]: b "c"`) and resources
+    /// (`[a](b "c")`).
+    /// The function is called with each character in the title, except for
+    /// line endings and the whitespace at the start of following lines.
+    /// Character escapes and character references are passed as they are
+    /// written (such as `\"` and `&amp;`), not decoded.
+    ///
+    /// When the function returns `false`, the whole title is not a title,
+    /// which typically means the whole definition or resource does not form
+    /// either: titles are never cut short.
+    ///
+    /// The default is `None`, which follows `CommonMark`, as all characters
+    /// are allowed.
+    /// Pass a function to reject certain characters, such as control
+    /// characters, in untrusted input.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` allows all characters in titles by default:
+    /// assert_eq!(
+    ///     to_html("[a](b \"c\u{7}\")"),
+    ///     "<p><a href=\"b\" title=\"c\u{7}\">a</a></p>"
     /// );
     ///
-    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// // Pass `title_validate` to reject some:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         "[a](b \"c\u{7}\")",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               gfm_strikethrough_single_tilde: false,
+    ///               title_validate: Some(Box::new(|char| !char.is_control())),
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>~a~</p>"
+    ///     "<p>[a](b &quot;c\u{7}&quot;)</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_strikethrough_single_tilde: bool,
+    pub title_validate: Option<Box<dyn Fn(char) -> bool>>,
 
-    /// Whether to support math (text) with a single dollar
+    /// Function to call with warnings.
     ///
-    /// This option does nothing if `math_text` is not turned on in
-    /// `constructs`.
-    /// This option does not affect math (text) with two or more dollars.
+    /// Markdown does not have syntax errors, so things that look like a
+    /// construct but are not, silently turn into something else (typically
+    /// text).
+    /// Pass a function to be told about several such problems, for example
+    /// to show them in an editor.
+    /// See [`WarningKind`][crate::WarningKind] for which problems are found.
     ///
-    /// The default is `true`, which is more close to how code (text) and
-    /// Pandoc work, as it allows math with a single dollar to form.
-    /// However, single dollars can interfere with “normal” dollars in text.
-    /// Pass `false`, to only allow math (text) to form when two or more
-    /// dollars are used.
+    /// The default is `None`, in which case no work is done to find
+    /// warnings.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions, Warning};
+    /// use std::{cell::RefCell, rc::Rc};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let warnings = Rc::new(RefCell::new(vec![]));
+    /// let sink = warnings.clone();
+    ///
+    /// to_html_with_options(
+    ///     "[a]: b \"c",
+    ///     &Options {
+    ///         parse: ParseOptions {
+    ///           warning_sink: Some(Box::new(move |warning: Warning| {
+    ///               sink.borrow_mut().push(warning.to_string());
+    ///           })),
+    ///           ..ParseOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     *warnings.borrow(),
+    ///     vec![
+    ///         "1:8: Unexpected end of title, expected a corresponding closing `\"` for `\"`",
+    ///         "1:1: Unexpected reference to undefined definition `a`, expected a corresponding definition (`[a]: url`)"
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub warning_sink: Option<Box<WarningSink>>,
+
+    /// Function to turn the target of a wikilink into a URL.
+    ///
+    /// The default is `None`, which uses the same GitHub-style slugs as
+    /// `heading_id_slug`: `[[Some page]]` links to `some-page`.
+    ///
+    /// Pass a function to link somewhere else, such as to add a base path.
+    /// What it returns is still sanitized (and resolved against `base_url`)
+    /// when compiling to HTML.
+    ///
+    /// This option does nothing if `wikilink` is not turned on in
+    /// `constructs`.
     ///
     /// ## Examples
     ///
@@ -1040,14 +3184,14 @@ pub struct ParseOptions {
     /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` supports single dollars by default:
+    /// // `markdown-rs` with wikilinks turned on slugs targets by default:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "$a$",
+    ///         "[[Some page|this]]",
     ///         &Options {
     ///             parse: ParseOptions {
     ///               constructs: Constructs {
-    ///                 math_text: true,
+    ///                 wikilink: true,
     ///                 ..Constructs::default()
     ///               },
     ///               ..ParseOptions::default()
@@ -1055,59 +3199,31 @@ pub struct ParseOptions {
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    ///     "<p><a href=\"some-page\">this</a></p>"
     /// );
     ///
-    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// // Pass `wikilink_url` to link elsewhere:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "$a$",
+    ///         "[[Some page|this]]",
     ///         &Options {
     ///             parse: ParseOptions {
     ///               constructs: Constructs {
-    ///                 math_text: true,
+    ///                 wikilink: true,
     ///                 ..Constructs::default()
     ///               },
-    ///               math_text_single_dollar: false,
+    ///               wikilink_url: Some(Box::new(|target| format!("/wiki/{}", target))),
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>$a$</p>"
+    ///     "<p><a href=\"/wiki/Some%20page\">this</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub math_text_single_dollar: bool,
-
-    /// Function to parse expressions with.
-    ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within expressions.
-    ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
-    ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
-
-    /// Function to parse ESM with.
-    ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within ESM blocks, however, the keywords (`export`,
-    /// `import`) are currently hardcoded JavaScript-specific.
-    ///
-    /// > 👉 **Note**: please raise an issue if you’re interested in working on
-    /// > MDX that is aware of, say, Rust, or other programming languages.
-    ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
-    ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+    pub wikilink_url: Option<Box<WikilinkUrl>>,
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
 }
 
@@ -1115,10 +3231,22 @@ impl fmt::Debug for ParseOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ParseOptions")
             .field("constructs", &self.constructs)
+            .field(
+                "character_escape_characters",
+                &self.character_escape_characters,
+            )
+            .field("emoji_shortcodes", &self.emoji_shortcodes)
+            .field("frontmatter_toml", &self.frontmatter_toml)
+            .field("frontmatter_yaml", &self.frontmatter_yaml)
             .field(
                 "gfm_strikethrough_single_tilde",
                 &self.gfm_strikethrough_single_tilde,
             )
+            .field(
+                "heading_atx_closing_sequence",
+                &self.heading_atx_closing_sequence,
+            )
+            .field("list_marker_change", &self.list_marker_change)
             .field("math_text_single_dollar", &self.math_text_single_dollar)
             .field(
                 "mdx_expression_parse",
@@ -1128,6 +3256,25 @@ impl fmt::Debug for ParseOptions {
                 "mdx_esm_parse",
                 &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
             )
+            .field("mdx_jsx_strict", &self.mdx_jsx_strict)
+            .field("tab_size", &self.tab_size)
+            .field("thematic_break_markers", &self.thematic_break_markers)
+            .field("thematic_break_size_min", &self.thematic_break_size_min)
+            .field("title_balance_parens", &self.title_balance_parens)
+            .field("title_size_max", &self.title_size_max)
+            .field("title_smart_quotes", &self.title_smart_quotes)
+            .field(
+                "title_validate",
+                &self.title_validate.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "warning_sink",
+                &self.warning_sink.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "wikilink_url",
+                &self.wikilink_url.as_ref().map(|_d| "[Function]"),
+            )
             .finish()
     }
 }
@@ -1137,10 +3284,26 @@ impl Default for ParseOptions {
     fn default() -> Self {
         Self {
             constructs: Constructs::default(),
+            character_escape_characters: None,
+            emoji_shortcodes: vec![],
+            frontmatter_toml: true,
+            frontmatter_yaml: true,
             gfm_strikethrough_single_tilde: true,
+            heading_atx_closing_sequence: true,
+            list_marker_change: ListMarkerChange::Split,
             math_text_single_dollar: true,
             mdx_expression_parse: None,
             mdx_esm_parse: None,
+            mdx_jsx_strict: false,
+            tab_size: TAB_SIZE,
+            thematic_break_markers: None,
+            thematic_break_size_min: None,
+            title_balance_parens: false,
+            title_size_max: None,
+            title_smart_quotes: false,
+            title_validate: None,
+            warning_sink: None,
+            wikilink_url: None,
         }
     }
 }
@@ -1170,9 +3333,10 @@ impl ParseOptions {
     /// For more information, see the MDX website:
     /// <https://mdxjs.com>.
     ///
-    /// > 👉 **Note**: to support ESM, you *must* pass
-    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] too.
-    /// > Otherwise, ESM is treated as normal markdown.
+    /// > 👉 **Note**: you *can* pass
+    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] to parse ESM
+    /// > according to a certain grammar (typically, a programming language).
+    /// > Otherwise, ESM runs until a blank line.
     /// >
     /// > You *can* pass
     /// > [`mdx_expression_parse`][MdxExpressionParse]
@@ -1246,6 +3410,7 @@ mod tests {
         Constructs::default();
         Constructs::gfm();
         Constructs::mdx();
+        Constructs::text();
 
         let constructs = Constructs::default();
         assert!(constructs.attention, "should default to `CommonMark` (1)");
@@ -1282,6 +3447,12 @@ mod tests {
         );
         assert!(constructs.mdx_jsx_flow, "should support `mdx` shortcut (3)");
         assert!(!constructs.frontmatter, "should support `mdx` shortcut (4)");
+
+        let constructs = Constructs::text();
+        assert!(constructs.attention, "should support `text` shortcut (1)");
+        assert!(constructs.label_end, "should support `text` shortcut (2)");
+        assert!(!constructs.list_item, "should support `text` shortcut (3)");
+        assert!(!constructs.html_flow, "should support `text` shortcut (4)");
     }
 
     #[test]
@@ -1334,7 +3505,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, definition_list: false, directive_container: false, directive_leaf: false, directive_text: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, highlight: false, html_flow: true, html_text: true, inline_footnote: false, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, subscript: false, superscript: false, thematic_break: true, wikilink: false }, character_escape_characters: None, emoji_shortcodes: [], frontmatter_toml: true, frontmatter_yaml: true, gfm_strikethrough_single_tilde: true, heading_atx_closing_sequence: true, list_marker_change: Split, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None, mdx_jsx_strict: false, tab_size: 4, thematic_break_markers: None, thematic_break_size_min: None, title_balance_parens: false, title_size_max: None, title_smart_quotes: false, title_validate: None, warning_sink: None, wikilink_url: None }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1347,7 +3518,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, definition_list: false, directive_container: false, directive_leaf: false, directive_text: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, highlight: false, html_flow: true, html_text: true, inline_footnote: false, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, subscript: false, superscript: false, thematic_break: true, wikilink: false }, character_escape_characters: None, emoji_shortcodes: [], frontmatter_toml: true, frontmatter_yaml: true, gfm_strikethrough_single_tilde: true, heading_atx_closing_sequence: true, list_marker_change: Split, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\"), mdx_jsx_strict: false, tab_size: 4, thematic_break_markers: None, thematic_break_size_min: None, title_balance_parens: false, title_size_max: None, title_smart_quotes: false, title_validate: None, warning_sink: None, wikilink_url: None }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -1376,6 +3547,18 @@ mod tests {
             options.gfm_tagfilter,
             "should support safe `gfm` shortcut (1)"
         );
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                CompileOptions {
+                    heading_id_slug: Some(Box::new(|value| value.into())),
+                    ..Default::default()
+                }
+            ),
+            "CompileOptions { allow_dangerous_html: false, allow_dangerous_protocol: false, allowed_schemes: None, base_url: None, code_language_prefix: None, dangerous_html_sink: None, default_line_ending: LineFeed, definition_sink: None, event_html: None, gfm_footnote_label: None, gfm_footnote_label_tag_name: None, gfm_footnote_label_attributes: None, gfm_footnote_back_label: None, gfm_footnote_clobber_prefix: None, gfm_tagfilter: false, hard_break_html: None, heading_anchor: None, heading_anchor_class: None, heading_anchor_content: None, heading_id_slug: Some(\"[Function]\"), heading_ids: false, highlight: None, image_attributes: [], link_external: None, link_external_rel: None, link_external_target: None, normalize_line_endings: None, pre_without_code: false, smart_punctuation: false, soft_break_as_hard: false, strip_comments: false }",
+            "should support `Debug` trait on functions"
+        );
     }
 
     #[test]
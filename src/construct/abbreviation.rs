@@ -0,0 +1,425 @@
+//! Abbreviation occurs in the [flow][] content type (definitions) and in the
+//! [text][] content type (occurrences).
+//!
+//! ## Grammar
+//!
+//! Abbreviation definitions form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: maximum `999` codes between `[` and `]`.
+//! abbreviation_definition ::= '*' '[' 1*label ']' ':' *space_or_tab [ value ]
+//!
+//! label ::= code - eol - ']'
+//! value ::= 1*line
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//! Like [definitions][definition], abbreviation definitions cannot interrupt
+//! paragraphs.
+//!
+//! The value is interpreted as the [string][] content type.
+//! That means that [character escapes][character_escape] and
+//! [character references][character_reference] are allowed.
+//! The label is not: it is matched as is.
+//!
+//! Once a document is parsed up to the level of flow, the labels of all
+//! abbreviation definitions are known, and occurrences of them in text are
+//! found.
+//! Occurrences must match case-sensitively and must be whole words: a word
+//! character (a letter, digit, or `_`) at the edge of a label cannot be next
+//! to another word character.
+//! Nothing is found in code, links, images, or footnote calls.
+//! When labels overlap, the longest one wins.
+//! An abbreviation definition can occur before or after its occurrences.
+//! Multiple definitions with the same label are ignored: the first definition
+//! is preferred.
+//!
+//! ## HTML
+//!
+//! Abbreviation definitions do not, on their own, relate to anything in HTML.
+//! Occurrences relate to the `<abbr>` element in HTML.
+//! See [*§ 4.5.9 The `abbr` element*][html_abbr] in the HTML spec for more
+//! info.
+//! The value of the definition forms its `title` attribute.
+//!
+//! ## Tokens
+//!
+//! *   [`Abbreviation`][Name::Abbreviation]
+//! *   [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+//! *   [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+//! *   [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker]
+//! *   [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+//! *   [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker]
+//! *   [`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue]
+//! *   [`DefinitionMarker`][Name::DefinitionMarker]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Abbreviations* in PHP Markdown Extra](https://michelf.ca/projects/php-markdown/extra/#abbr)
+//!
+//! [flow]: crate::construct::flow
+//! [text]: crate::construct::text
+//! [string]: crate::construct::string
+//! [definition]: crate::construct::definition
+//! [character_escape]: crate::construct::character_escape
+//! [character_reference]: crate::construct::character_reference
+//! [html_abbr]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::{Content, Event, Kind, Link, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    char::{after_index, before_index, classify, Kind as CharacterKind},
+    constant::{LINK_REFERENCE_SIZE_MAX, TAB_SIZE},
+    skip,
+    slice::{Position, Slice},
+};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+/// Start of abbreviation definition.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    // Do not interrupt paragraphs.
+    if tokenizer.parse_state.options.constructs.abbreviation && !tokenizer.interrupt {
+        tokenizer.enter(Name::AbbreviationDefinition);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::AbbreviationDefinitionBefore),
+                State::Nok,
+            );
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::AbbreviationDefinitionBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `*`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'*') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `*`, at `[`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///      ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::AbbreviationDefinitionLabel);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelString);
+        State::Next(StateName::AbbreviationDefinitionLabelInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///       ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    // Too long.
+    if tokenizer.tokenize_state.size > LINK_REFERENCE_SIZE_MAX
+        || matches!(tokenizer.current, None | Some(b'\n'))
+        // Closing bracket with nothing.
+        || (tokenizer.current == Some(b']') && tokenizer.tokenize_state.size == 0)
+    {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    } else if tokenizer.current == Some(b']') {
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.exit(Name::AbbreviationDefinitionLabelString);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.exit(Name::AbbreviationDefinitionLabel);
+        State::Next(StateName::AbbreviationDefinitionLabelAfter)
+    } else {
+        tokenizer.consume();
+        tokenizer.tokenize_state.size += 1;
+        State::Next(StateName::AbbreviationDefinitionLabelInside)
+    }
+}
+
+/// After label, at `:`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///           ^
+/// ```
+pub fn label_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        let end = skip::to_back(
+            &tokenizer.events,
+            tokenizer.events.len() - 1,
+            &[Name::AbbreviationDefinitionLabelString],
+        );
+
+        // Note: we don’t care about uniqueness, the compilers do.
+        tokenizer.tokenize_state.abbreviations.push(
+            Slice::from_position(
+                tokenizer.parse_state.bytes,
+                &Position::from_exit_event(&tokenizer.events, end),
+                tokenizer.parse_state.options.tab_size,
+            )
+            .as_str()
+            .to_string(),
+        );
+
+        tokenizer.enter(Name::DefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DefinitionMarker);
+        tokenizer.attempt(
+            State::Next(StateName::AbbreviationDefinitionValueBefore),
+            State::Next(StateName::AbbreviationDefinitionValueBefore),
+        );
+        State::Next(space_or_tab(tokenizer))
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:` and optional whitespace, at value.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///              ^
+/// ```
+pub fn value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Retry(StateName::AbbreviationDefinitionAfter),
+        _ => {
+            tokenizer.enter(Name::AbbreviationDefinitionValue);
+            tokenizer.enter_link(
+                Name::Data,
+                Link {
+                    previous: None,
+                    next: None,
+                    content: Content::String,
+                },
+            );
+            State::Retry(StateName::AbbreviationDefinitionValueInside)
+        }
+    }
+}
+
+/// In value.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///              ^
+/// ```
+pub fn value_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::Data);
+            tokenizer.exit(Name::AbbreviationDefinitionValue);
+            State::Retry(StateName::AbbreviationDefinitionAfter)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionValueInside)
+        }
+    }
+}
+
+/// At eol or eof.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///                                        ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::AbbreviationDefinition);
+    State::Ok
+}
+
+/// Resolve: postprocess text to find occurrences of abbreviations.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    let bytes = tokenizer.parse_state.bytes;
+    let tab_size = tokenizer.parse_state.options.tab_size;
+    // Labels by their first byte, longest first, so that `HTML5` wins over
+    // `HTML`.
+    let mut labels: BTreeMap<u8, Vec<&[u8]>> = BTreeMap::new();
+
+    for label in &tokenizer.parse_state.abbreviations {
+        let label = label.as_bytes();
+        if let Some(first) = label.first() {
+            labels.entry(*first).or_default().push(label);
+        }
+    }
+
+    for list in labels.values_mut() {
+        list.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        list.dedup();
+    }
+
+    if labels.is_empty() {
+        return;
+    }
+
+    let mut index = 0;
+    let mut ignore = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if matches!(event.name, Name::GfmFootnoteCall | Name::Image | Name::Link) {
+            if event.kind == Kind::Enter {
+                ignore += 1;
+            } else {
+                ignore -= 1;
+            }
+        }
+        // Linked data is content of its own (such as the label of a
+        // directive), which is resolved when it is tokenized.
+        else if event.kind == Kind::Exit
+            && event.name == Name::Data
+            && ignore == 0
+            && tokenizer.events[index - 1].link.is_none()
+        {
+            let start = tokenizer.events[index - 1].point.index;
+            let end = event.point.index;
+            let mut point = tokenizer.events[index - 1].point.clone();
+            let mut replace = Vec::new();
+            let mut byte_index = start;
+            let mut min = start;
+
+            while byte_index < end {
+                // Only look for labels that start with this byte, and only
+                // if one could start here (not in a word).
+                let found = labels.get(&bytes[byte_index]).and_then(|list| {
+                    if word_edge(bytes, byte_index, true) {
+                        list.iter().find(|label| {
+                            byte_index + label.len() <= end
+                                && bytes[byte_index..].starts_with(label)
+                                && word_edge(bytes, byte_index + label.len(), false)
+                        })
+                    } else {
+                        None
+                    }
+                });
+
+                if let Some(label) = found {
+                    // If there is something between the last abbreviation
+                    // (or the start) and this one.
+                    if min != byte_index {
+                        replace.push(Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                        point = point.shift_to(bytes, tab_size, byte_index);
+                        replace.push(Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                    }
+
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Abbreviation,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    byte_index += label.len();
+                    point = point.shift_to(bytes, tab_size, byte_index);
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Abbreviation,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    min = byte_index;
+                } else {
+                    byte_index += 1;
+                }
+            }
+
+            // If there was an abbreviation, and we have more bytes left.
+            if !replace.is_empty() && min < end {
+                replace.push(Event {
+                    kind: Kind::Enter,
+                    name: Name::Data,
+                    point: point.clone(),
+                    link: None,
+                });
+                replace.push(Event {
+                    kind: Kind::Exit,
+                    name: Name::Data,
+                    point: tokenizer.events[index].point.clone(),
+                    link: None,
+                });
+            }
+
+            // If there were abbreviations.
+            if !replace.is_empty() {
+                tokenizer.map.add(index - 1, 2, replace);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check whether an abbreviation can start (`open: true`) or end at `index`.
+///
+/// A word character at the edge of the abbreviation cannot be next to
+/// another word character.
+fn word_edge(bytes: &[u8], index: usize, open: bool) -> bool {
+    let (inside, outside) = if open {
+        (after_index(bytes, index), before_index(bytes, index))
+    } else {
+        (before_index(bytes, index), after_index(bytes, index))
+    };
+
+    !word(inside) || !word(outside)
+}
+
+/// Check whether a char is a word character (a letter, digit, or `_`).
+fn word(char_opt: Option<char>) -> bool {
+    char_opt.map_or(false, |d| d == '_' || classify(d) == CharacterKind::Other)
+}
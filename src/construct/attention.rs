@@ -85,7 +85,11 @@ use crate::util::char::{
     after_index as char_after_index, before_index as char_before_index, classify_opt,
     Kind as CharacterKind,
 };
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+/// Key into the openers bottoms: innermost balanced event, marker, whether
+/// the closing sequence can open, and its size (modulo three).
+type BottomKey = (Option<usize>, u8, bool, usize);
 
 /// Attentention sequence that we can take markers from.
 #[derive(Debug)]
@@ -153,27 +157,41 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     // Find all sequences, gather info about them.
     let mut sequences = get_sequences(tokenizer);
+    // Sequences that can still open, as indices into `sequences`.
+    let mut openers: Vec<usize> = vec![];
+    // For each kind of closing sequence, the index into `sequences` before
+    // which no opener matches it (`openers_bottom` in `CommonMark`).
+    // This prevents walking back over the same openers again and again,
+    // which is quadratic.
+    let mut bottoms: BTreeMap<BottomKey, usize> = BTreeMap::new();
 
     // Now walk through them and match them.
     let mut close = 0;
 
     while close < sequences.len() {
-        let sequence_close = &sequences[close];
-        let mut next_index = close + 1;
+        let mut matched = false;
 
         // Find a sequence that can close.
-        if sequence_close.close {
-            let mut open = close;
+        if sequences[close].close {
+            let key = bottom_key(&sequences[close]);
+            let bottom = bottoms.get(&key).copied().unwrap_or(0);
+            let mut opener = openers.len();
 
             // Now walk back to find an opener.
-            while open > 0 {
-                open -= 1;
+            while opener > 0 {
+                opener -= 1;
+
+                let open = openers[opener];
+
+                if open < bottom {
+                    break;
+                }
 
                 let sequence_open = &sequences[open];
+                let sequence_close = &sequences[close];
 
                 // An opener matching our closer:
-                if sequence_open.open
-                    && sequence_close.marker == sequence_open.marker
+                if sequence_close.marker == sequence_open.marker
                     && sequence_close.stack == sequence_open.stack
                 {
                     // If the opening can close or the closing can open,
@@ -201,22 +219,62 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                     }
 
                     // We found a match!
-                    next_index = match_sequences(tokenizer, &mut sequences, open, close);
+                    match_sequences(tokenizer, &mut sequences, open, close);
+
+                    // Now we make sure that we can’t have misnested
+                    // attention:
+                    //
+                    // ```html
+                    // <em>a <strong>b</em> c</strong>
+                    // ```
+                    //
+                    // Do that by dropping everything between them as
+                    // possible openers.
+                    openers.truncate(if sequences[open].size == 0 {
+                        opener
+                    } else {
+                        opener + 1
+                    });
+
+                    // The opener changed size, so it might match closers
+                    // that it did not match before.
+                    for key in bottom_keys(&sequences[open]) {
+                        if let Some(bottom) = bottoms.get_mut(&key) {
+                            if *bottom > open {
+                                *bottom = open;
+                            }
+                        }
+                    }
 
+                    matched = true;
                     break;
                 }
             }
+
+            if !matched {
+                bottoms.insert(key, close);
+            }
         }
 
-        close = next_index;
+        // Stay on this closing sequence for the next iteration if it matched
+        // something and is not fully used: it might close more things.
+        if !matched || sequences[close].size == 0 {
+            if sequences[close].open && sequences[close].size > 0 {
+                openers.push(close);
+            }
+
+            close += 1;
+        }
     }
 
     // Mark remaining sequences as data.
     let mut index = 0;
     while index < sequences.len() {
         let sequence = &sequences[index];
-        tokenizer.events[sequence.index].name = Name::Data;
-        tokenizer.events[sequence.index + 1].name = Name::Data;
+        if sequence.size > 0 {
+            tokenizer.events[sequence.index].name = Name::Data;
+            tokenizer.events[sequence.index + 1].name = Name::Data;
+        }
         index += 1;
     }
 
@@ -224,6 +282,44 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     None
 }
 
+/// Get the key of a closing sequence into the openers bottoms.
+///
+/// Sequences can only match if they are in the same balanced events, which
+/// are the same if the innermost ones are.
+/// Whether the closing sequence can open and its size modulo three decide
+/// whether it can match an opener (see the “rule of three”), except for
+/// GFM strikethrough, where sizes must be the same.
+fn bottom_key(sequence: &Sequence) -> BottomKey {
+    (
+        sequence.stack.last().copied(),
+        sequence.marker,
+        sequence.open,
+        if sequence.marker == b'~' {
+            sequence.size
+        } else {
+            sequence.size % 3
+        },
+    )
+}
+
+/// Get all keys into the openers bottoms of closing sequences that could
+/// match an opening sequence.
+///
+/// Strikethrough sequences of more than two markers never match, so sizes
+/// up to two are enough.
+fn bottom_keys(sequence: &Sequence) -> Vec<BottomKey> {
+    let stack = sequence.stack.last().copied();
+    let mut keys = Vec::with_capacity(6);
+
+    for open in [false, true] {
+        for size in 0..3 {
+            keys.push((stack, sequence.marker, open, size));
+        }
+    }
+
+    keys
+}
+
 /// Get sequences.
 fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
     let mut index = 0;
@@ -239,18 +335,20 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                 let exit = &tokenizer.events[end];
 
                 let marker = tokenizer.parse_state.bytes[enter.point.index];
-                let before = classify_opt(char_before_index(
-                    tokenizer.parse_state.bytes,
-                    enter.point.index,
-                ));
-                let after = classify_opt(char_after_index(
-                    tokenizer.parse_state.bytes,
-                    exit.point.index,
-                ));
+                let char_before = char_before_index(tokenizer.parse_state.bytes, enter.point.index);
+                let char_after = char_after_index(tokenizer.parse_state.bytes, exit.point.index);
+                let before = classify_opt(char_before);
+                let after = classify_opt(char_after);
+                // Asterisk and underscore sequences can open and close next
+                // to strikethrough sequences, like in `**~~a~~**b`.
+                let other_markers =
+                    marker != b'~' && tokenizer.parse_state.options.constructs.gfm_strikethrough;
                 let open = after == CharacterKind::Other
-                    || (after == CharacterKind::Punctuation && before != CharacterKind::Other);
+                    || (after == CharacterKind::Punctuation && before != CharacterKind::Other)
+                    || (other_markers && char_after == Some('~'));
                 let close = before == CharacterKind::Other
-                    || (before == CharacterKind::Punctuation && after != CharacterKind::Other);
+                    || (before == CharacterKind::Punctuation && after != CharacterKind::Other)
+                    || (other_markers && char_before == Some('~'));
 
                 sequences.push(Sequence {
                     index,
@@ -287,16 +385,10 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
 #[allow(clippy::too_many_lines)]
 fn match_sequences(
     tokenizer: &mut Tokenizer,
-    sequences: &mut Vec<Sequence>,
+    sequences: &mut [Sequence],
     open: usize,
     close: usize,
-) -> usize {
-    // Where to move to next.
-    // Stay on this closing sequence for the next iteration: it
-    // might close more things.
-    // It’s changed if sequences are removed.
-    let mut next = close;
-
+) {
     // Number of markers to use from the sequence.
     let take = if sequences[open].size > 1 && sequences[close].size > 1 {
         2
@@ -304,25 +396,6 @@ fn match_sequences(
         1
     };
 
-    // We’re *on* a closing sequence, with a matching opening
-    // sequence.
-    // Now we make sure that we can’t have misnested attention:
-    //
-    // ```html
-    // <em>a <strong>b</em> c</strong>
-    // ```
-    //
-    // Do that by marking everything between it as no longer
-    // possible to open anything.
-    // Theoretically we should mark as `close: false` too, but
-    // we don’t look for closers backwards, so it’s not needed.
-    let mut between = open + 1;
-
-    while between < close {
-        sequences[between].open = false;
-        between += 1;
-    }
-
     let (group_name, seq_name, text_name) = if sequences[open].marker == b'~' {
         (
             Name::GfmStrikethrough,
@@ -413,7 +486,6 @@ fn match_sequences(
 
     // Remove closing sequence if fully used.
     if sequences[close].size == 0 {
-        sequences.remove(close);
         tokenizer.map.add(close_index, 2, vec![]);
     } else {
         // Shift remaining closing sequence forward.
@@ -424,13 +496,8 @@ fn match_sequences(
     }
 
     if sequences[open].size == 0 {
-        sequences.remove(open);
         tokenizer.map.add(open_index, 2, vec![]);
-        // Everything shifts one to the left, account for it in next iteration.
-        next -= 1;
     } else {
         tokenizer.events[open_index + 1].point = sequences[open].end_point.clone();
     }
-
-    next
 }
@@ -13,6 +13,9 @@
 //! slash, or a slash followed by anything other than an ASCII punctuation
 //! character, is just a slash.
 //!
+//! Which characters can be escaped can be changed with
+//! [`character_escape_characters`][character_escape_characters].
+//!
 //! To escape other characters, use a [character reference][character_reference]
 //! instead (as in, `&amp;`, `&#123;`, or say `&#x9;`).
 //!
@@ -40,6 +43,7 @@
 //! [text]: crate::construct::text
 //! [character_reference]: crate::construct::character_reference
 //! [hard_break_escape]: crate::construct::hard_break_escape
+//! [character_escape_characters]: crate::ParseOptions::character_escape_characters
 
 use crate::event::Name;
 use crate::state::{Name as StateName, State};
@@ -72,8 +76,16 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn inside(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        // ASCII punctuation.
-        Some(b'!'..=b'/' | b':'..=b'@' | b'['..=b'`' | b'{'..=b'~') => {
+        Some(byte)
+            if tokenizer
+                .parse_state
+                .options
+                .character_escape_characters
+                .as_ref()
+                .map_or(byte.is_ascii_punctuation(), |characters| {
+                    byte.is_ascii_graphic() && characters.contains(&char::from(byte))
+                }) =>
+        {
             tokenizer.enter(Name::CharacterEscapeValue);
             tokenizer.consume();
             tokenizer.exit(Name::CharacterEscapeValue);
@@ -55,6 +55,9 @@
 //! `<img>` when compiling, see
 //! [`sanitize_uri`][sanitize_uri].
 //!
+//! When [`warning_sink`][warning_sink] is set, definitions that are not
+//! referenced are reported.
+//!
 //! ## HTML
 //!
 //! Definitions in markdown do not, on their own, relate to anything in HTML.
@@ -98,6 +101,7 @@
 //! [title]: crate::construct::partial_title
 //! [sanitize_uri]: crate::util::sanitize_uri::sanitize
 //! [normalize_identifier]: crate::util::normalize_identifier
+//! [warning_sink]: crate::ParseOptions::warning_sink
 //! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
 //! [html_img]: https://html.spec.whatwg.org/multipage/embedded-content.html#the-img-element
 
@@ -316,6 +320,7 @@ pub fn after_whitespace(tokenizer: &mut Tokenizer) -> State {
                     Slice::from_position(
                         tokenizer.parse_state.bytes,
                         &Position::from_exit_event(&tokenizer.events, tokenizer.tokenize_state.end),
+                        tokenizer.parse_state.options.tab_size,
                     )
                     .as_str(),
                 ),
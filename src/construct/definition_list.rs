@@ -0,0 +1,491 @@
+//! Definition list occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Definition list forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must directly follow a term or another description.
+//! ; Restriction: if the first line after the marker starts with `5(space_or_tab)`,
+//! ; only the first `space_or_tab` is part of the start.
+//! definition_description_start ::= ':' 1*4 space_or_tab
+//!
+//! ; Restriction: blank line allowed.
+//! ; Restriction: if not blank, the line must be indented, exactly `n` times.
+//! definition_description_cont ::= [n(space_or_tab)]
+//! ```
+//!
+//! A definition list is made from terms and descriptions.
+//! A term is a [paragraph][], directly followed by one or more descriptions.
+//! Further terms, each with their own descriptions, belong to the same list
+//! (optionally with blank lines between them).
+//!
+//! Only descriptions are parsed here, as containers, like
+//! [list items][list_item]: their content is indented by the size of their
+//! prefix, and lazy continuation lines are allowed.
+//! When descriptions are resolved, the paragraphs before them turn into
+//! terms.
+//! If there is no paragraph before a description, such as when the line
+//! before it is a [definition][] (`[a]: b`), the description is turned back
+//! into a paragraph.
+//!
+//! ## HTML
+//!
+//! Definition list relates to the `<dl>`, `<dt>`, and `<dd>` elements in
+//! HTML.
+//! See [*§ 4.4.9 The `dl` element*][html_dl],
+//! [*§ 4.4.10 The `dt` element*][html_dt], and
+//! [*§ 4.4.11 The `dd` element*][html_dd] in the HTML spec for more info.
+//!
+//! Like list items, descriptions are not wrapped in `<p>` elements, unless
+//! the list has blank lines between its items or in its descriptions.
+//!
+//! ## Recommendation
+//!
+//! Use a single space after a marker.
+//! Never use lazy continuation.
+//!
+//! ## Tokens
+//!
+//! *   [`DefinitionDescription`][Name::DefinitionDescription]
+//! *   [`DefinitionDescriptionMarker`][Name::DefinitionDescriptionMarker]
+//! *   [`DefinitionDescriptionPrefix`][Name::DefinitionDescriptionPrefix]
+//! *   [`DefinitionList`][Name::DefinitionList]
+//! *   [`DefinitionTerm`][Name::DefinitionTerm]
+//!
+//! ## References
+//!
+//! *   [*Definition lists* in Pandoc](https://pandoc.org/MANUAL.html#definition-lists)
+//! *   [*Definition lists* in PHP Markdown Extra](https://michelf.ca/projects/php-markdown/extra/#def-list)
+//!
+//! [document]: crate::construct::document
+//! [paragraph]: crate::construct::paragraph
+//! [definition]: crate::construct::definition
+//! [list_item]: crate::construct::list_item
+//! [html_dl]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dl-element
+//! [html_dt]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dt-element
+//! [html_dd]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dd-element
+
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::event::{Content, Event, Kind, Link, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::{Container, Tokenizer};
+use crate::util::{
+    constant::TAB_SIZE,
+    skip,
+    slice::{Position, Slice},
+};
+use alloc::{vec, vec::Vec};
+
+/// Start of definition description.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    let stack = &tokenizer.tokenize_state.document_container_stack;
+    // The container that was at this place on the previous line, which is
+    // moved to the end while new containers are tried.
+    let after_description = stack.len() > tokenizer.tokenize_state.document_continued + 1
+        && stack[stack.len() - 1].kind == Container::DefinitionDescription;
+
+    if tokenizer.parse_state.options.constructs.definition_list
+        // Require a term (content) or another description before.
+        && (tokenizer.interrupt || after_description)
+    {
+        tokenizer.enter(Name::DefinitionDescription);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DefinitionListBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DefinitionListBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `:`.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DefinitionDescriptionPrefix);
+        tokenizer.enter(Name::DefinitionDescriptionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DefinitionDescriptionMarker);
+        State::Next(StateName::DefinitionListMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After marker.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///      ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(
+        State::Nok,
+        State::Next(StateName::DefinitionListMarkerAfterFilled),
+    );
+    State::Retry(StateName::BlankLineStart)
+}
+
+/// After marker, not followed by a blank line.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///      ^
+/// ```
+pub fn marker_after_filled(tokenizer: &mut Tokenizer) -> State {
+    // Attempt to parse up to the largest allowed indent, `nok` if there is more whitespace.
+    tokenizer.attempt(
+        State::Next(StateName::DefinitionListAfter),
+        State::Next(StateName::DefinitionListPrefixOther),
+    );
+    State::Retry(StateName::DefinitionListWhitespace)
+}
+
+/// After marker, at whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///      ^
+/// ```
+pub fn whitespace(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::DefinitionListWhitespaceAfter),
+        State::Nok,
+    );
+    State::Retry(space_or_tab_min_max(tokenizer, 1, TAB_SIZE))
+}
+
+/// After acceptable whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///       ^
+/// ```
+pub fn whitespace_after(tokenizer: &mut Tokenizer) -> State {
+    if let Some(b'\t' | b' ') = tokenizer.current {
+        State::Nok
+    } else {
+        State::Ok
+    }
+}
+
+/// After marker, followed by more whitespace than allowed.
+///
+/// ```markdown
+///   | a
+/// > | :     b
+///      ^
+/// ```
+pub fn prefix_other(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b' ') => {
+            tokenizer.enter(Name::SpaceOrTab);
+            tokenizer.consume();
+            tokenizer.exit(Name::SpaceOrTab);
+            State::Next(StateName::DefinitionListAfter)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After definition description prefix.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///       ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    let start = skip::to_back(
+        &tokenizer.events,
+        tokenizer.events.len() - 1,
+        &[Name::DefinitionDescription],
+    );
+    let prefix = Slice::from_position(
+        tokenizer.parse_state.bytes,
+        &Position {
+            start: &tokenizer.events[start].point,
+            end: &tokenizer.point,
+        },
+        tokenizer.parse_state.options.tab_size,
+    )
+    .len();
+
+    let container = &mut tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued];
+    container.blank_initial = false;
+    container.size = prefix;
+
+    tokenizer.exit(Name::DefinitionDescriptionPrefix);
+    // Terms are paragraphs, which are only known once content is resolved,
+    // so resolve in flow, after content.
+    tokenizer
+        .tokenize_state
+        .document_child
+        .as_mut()
+        .unwrap()
+        .register_resolver(ResolveName::DefinitionList);
+    State::Ok
+}
+
+/// Start of definition description continuation.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |   c
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(
+        State::Next(StateName::DefinitionListContBlank),
+        State::Next(StateName::DefinitionListContFilled),
+    );
+    State::Retry(StateName::BlankLineStart)
+}
+
+/// Start of blank definition description continuation.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |
+///     ^
+///   |   c
+/// ```
+pub fn cont_blank(tokenizer: &mut Tokenizer) -> State {
+    let size = tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size;
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        // Consume, optionally, at most `size`.
+        State::Retry(space_or_tab_min_max(tokenizer, 0, size))
+    } else {
+        State::Ok
+    }
+}
+
+/// Start of non-blank definition description continuation.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |   c
+///     ^
+/// ```
+pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
+    let size = tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size;
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        // Consume exactly `size`.
+        State::Retry(space_or_tab_min_max(tokenizer, size, size))
+    } else {
+        State::Nok
+    }
+}
+
+/// Turn paragraphs before descriptions into terms, and group them together.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    // Lists, as `Enter:DefinitionTerm` of their first term, and
+    // `Exit:DefinitionDescription` of their last description.
+    let mut lists: Vec<(usize, usize)> = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Enter && event.name == Name::DefinitionDescription {
+            // Find the matching exit (not `skip::opt`, which would also move
+            // past directly following descriptions).
+            let mut exit = index + 1;
+            let mut balance = 1;
+            loop {
+                if tokenizer.events[exit].kind == Kind::Enter {
+                    balance += 1;
+                } else {
+                    balance -= 1;
+                }
+
+                if balance == 0 {
+                    break;
+                }
+
+                exit += 1;
+            }
+
+            let before = skip::opt_back(
+                &tokenizer.events,
+                index - 1,
+                &[Name::LineEnding, Name::SpaceOrTab, Name::BlockQuotePrefix],
+            );
+            // Blank lines between descriptions are placed outside them.
+            let before_blank = skip::opt_back(
+                &tokenizer.events,
+                before,
+                &[
+                    Name::BlankLineEnding,
+                    Name::BlockQuotePrefix,
+                    Name::LineEnding,
+                    Name::SpaceOrTab,
+                ],
+            );
+
+            // Another description of the same term.
+            if let Some(list) = lists.iter_mut().find(|list| list.1 == before_blank) {
+                list.1 = exit;
+            }
+            // There’s a paragraph before: it’s the term.
+            else if tokenizer.events[before].name == Name::Paragraph {
+                let term_enter = skip::to_back(&tokenizer.events, before - 1, &[Name::Paragraph]);
+                // Change types of Enter:Paragraph, Exit:Paragraph.
+                tokenizer.events[term_enter].name = Name::DefinitionTerm;
+                tokenizer.events[before].name = Name::DefinitionTerm;
+
+                // A term after the descriptions of another term.
+                let previous = if term_enter == 0 {
+                    None
+                } else {
+                    Some(skip::opt_back(
+                        &tokenizer.events,
+                        term_enter - 1,
+                        &[
+                            Name::BlankLineEnding,
+                            Name::BlockQuotePrefix,
+                            Name::LineEnding,
+                            Name::SpaceOrTab,
+                        ],
+                    ))
+                };
+
+                if let Some(list) = lists.iter_mut().find(|list| Some(list.1) == previous) {
+                    list.1 = exit;
+                } else {
+                    lists.push((term_enter, exit));
+                }
+            }
+            // Nothing (such as a definition) before: it’s a paragraph.
+            else {
+                unwrap_description(tokenizer, index, exit);
+            }
+        }
+
+        index += 1;
+    }
+
+    // Inject events.
+    let mut index = 0;
+    while index < lists.len() {
+        let (enter, exit) = lists[index];
+        let mut list_enter = tokenizer.events[enter].clone();
+        list_enter.name = Name::DefinitionList;
+        list_enter.link = None;
+        let mut list_exit = tokenizer.events[exit].clone();
+        list_exit.name = Name::DefinitionList;
+
+        tokenizer.map.add(enter, 0, vec![list_enter]);
+        tokenizer.map.add(exit + 1, 0, vec![list_exit]);
+
+        index += 1;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Turn a description without a term into a paragraph, starting with its
+/// prefix.
+///
+/// The events of the prefix are reused, so that a later description can
+/// still see the paragraph and turn it into a term.
+fn unwrap_description(tokenizer: &mut Tokenizer, enter: usize, exit: usize) {
+    let prefix_enter = skip::to(
+        &tokenizer.events,
+        enter + 1,
+        &[Name::DefinitionDescriptionPrefix],
+    );
+    let prefix_exit = skip::to(
+        &tokenizer.events,
+        prefix_enter + 1,
+        &[Name::DefinitionDescriptionPrefix],
+    );
+    let data_enter = prefix_enter + 1;
+    let paragraph_after = tokenizer.events[prefix_exit + 1].name == Name::Paragraph;
+
+    // Remove Enter:DefinitionDescription, Exit:DefinitionDescription.
+    tokenizer.map.add(enter, 1, vec![]);
+    tokenizer.map.add(exit, 1, vec![]);
+
+    // Swap types: Enter:DefinitionDescriptionPrefix -> Enter:Paragraph,
+    // Enter:DefinitionDescriptionMarker -> Enter:Data.
+    tokenizer.events[prefix_enter].name = Name::Paragraph;
+    tokenizer.events[data_enter] = Event {
+        kind: Kind::Enter,
+        name: Name::Data,
+        point: tokenizer.events[prefix_enter].point.clone(),
+        link: Some(Link {
+            previous: None,
+            next: None,
+            content: Content::Text,
+        }),
+    };
+
+    // There’s a paragraph right after the prefix: move the prefix inside it,
+    // and link the data together.
+    if paragraph_after {
+        let data = prefix_exit + 2;
+        // Remove what’s inside the prefix (marker, whitespace), and its exit.
+        tokenizer
+            .map
+            .add(data_enter + 1, prefix_exit - data_enter, vec![]);
+        // Swap type: old Enter:Paragraph -> Exit:Data, which is at the same
+        // point.
+        let data_exit = &mut tokenizer.events[prefix_exit + 1];
+        data_exit.kind = Kind::Exit;
+        data_exit.name = Name::Data;
+        tokenizer.events[data_enter].link.as_mut().unwrap().next = Some(data);
+        tokenizer.events[data].link.as_mut().unwrap().previous = Some(data_enter);
+    } else {
+        // Remove what’s inside the prefix (marker, whitespace).
+        tokenizer
+            .map
+            .add(data_enter + 1, prefix_exit - data_enter - 1, vec![]);
+        // Swap type: Exit:DefinitionDescriptionPrefix -> Exit:Data.
+        tokenizer.events[prefix_exit].name = Name::Data;
+        let mut paragraph_exit = tokenizer.events[prefix_exit].clone();
+        paragraph_exit.name = Name::Paragraph;
+        tokenizer.map.add(prefix_exit + 1, 0, vec![paragraph_exit]);
+    }
+}
@@ -0,0 +1,350 @@
+//! Directive (container) occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (container) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: at least 3 markers must be used.
+//! ; Restriction: `eol` is not allowed in `directive`.
+//! directive_container_start ::= *space_or_tab 1*':' directive *space_or_tab
+//!
+//! ; Restriction: the number of markers in the closing fence must be equal
+//! ; to or greater than the number of markers in the opening fence.
+//! directive_container_end ::= *space_or_tab 1*':' *space_or_tab
+//! ```
+//!
+//! See [`directive`][directive] for more info on the name, label, and
+//! attributes.
+//!
+//! The opening and closing fence must each be on their own line.
+//! Everything between them is parsed as [flow][], until a closing fence is
+//! found or the document (or a container the directive is in) ends.
+//!
+//! Because the closing fence is checked before anything in the directive,
+//! containers can only be nested when the outer one uses more markers than
+//! the inner one:
+//!
+//! ```markdown
+//! ::::a
+//! :::b
+//! c
+//! :::
+//! ::::
+//! ```
+//!
+//! Unlike [code (fenced)][raw_flow], the content is not stripped of the
+//! indent of the opening fence.
+//!
+//! ## HTML
+//!
+//! Directives are extension points: they do not relate to anything in HTML.
+//! Unless a tool handles them, directive (container) is compiled to a
+//! `<div>`, with a `data-directive` attribute set to its name and the parsed
+//! attributes.
+//! The label, if any, is compiled as a first paragraph, followed by the
+//! content:
+//!
+//! ```markdown
+//! :::note[Heads up]{.warning}
+//! Something *important*.
+//! :::
+//! ```
+//!
+//! Yields:
+//!
+//! ```html
+//! <div data-directive="note" class="warning">
+//! <p>Heads up</p>
+//! <p>Something <em>important</em>.</p>
+//! </div>
+//! ```
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveContainer`][Name::DirectiveContainer]
+//! *   [`DirectiveContainerFence`][Name::DirectiveContainerFence]
+//! *   [`DirectiveContainerSequence`][Name::DirectiveContainerSequence]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-container.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-container.js)
+//!
+//! [document]: crate::construct::document
+//! [flow]: crate::construct::flow
+//! [raw_flow]: crate::construct::raw_flow
+//! [directive]: crate::construct::partial_directive
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::{DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN, TAB_SIZE};
+
+/// Start of directive (container).
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+///   | :::
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_container {
+        tokenizer.enter(Name::DirectiveContainer);
+        tokenizer.enter(Name::DirectiveContainerFence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DirectiveContainerBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DirectiveContainerBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at opening sequence.
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+///   | :::
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DirectiveContainerSequence);
+        State::Retry(StateName::DirectiveContainerSequenceOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// In opening sequence.
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+///   | :::
+/// ```
+pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.tokenize_state.size += 1;
+        tokenizer.consume();
+        State::Next(StateName::DirectiveContainerSequenceOpen)
+    } else if tokenizer.tokenize_state.size >= DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN {
+        tokenizer.exit(Name::DirectiveContainerSequence);
+        // The size of the sequence is needed to find the closing fence.
+        // It is stored now, as `size` is reused for the label.
+        tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size = tokenizer.tokenize_state.size;
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.tokenize_state.token_1 = Name::DirectiveContainer;
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerOpenAfter),
+            State::Next(StateName::DirectiveContainerNok),
+        );
+        State::Retry(StateName::DirectiveNameStart)
+    } else {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    }
+}
+
+/// After directive in opening fence, at optional whitespace.
+///
+/// ```markdown
+/// > | :::a
+///         ^
+///   | b
+///   | :::
+/// ```
+pub fn open_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerOpenEnd),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::DirectiveContainerOpenEnd)
+    }
+}
+
+/// After opening fence and optional whitespace, at eol.
+///
+/// ```markdown
+/// > | :::a
+///         ^
+///   | b
+///   | :::
+/// ```
+pub fn open_end(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::DirectiveContainerFence);
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
+
+/// At something that wasn’t a directive (container).
+///
+/// ```markdown
+/// > | :::1
+///        ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    tokenizer.tokenize_state.size = 0;
+    State::Nok
+}
+
+/// Start of directive (container) continuation.
+///
+/// A line is always part of an open container, but if it is a closing fence,
+/// the container is closed afterwards.
+///
+/// ```markdown
+///   | :::a
+/// > | b
+///     ^
+/// > | :::
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    // A size of `0` means the closing fence was already seen.
+    if tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size
+        == 0
+    {
+        State::Nok
+    } else {
+        tokenizer.attempt(State::Ok, State::Ok);
+        State::Retry(StateName::DirectiveContainerCloseStart)
+    }
+}
+
+/// Start of closing fence.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn close_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::DirectiveContainerFence);
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerBeforeSequenceClose),
+            State::Nok,
+        );
+        State::Retry(space_or_tab_min_max(
+            tokenizer,
+            0,
+            if tokenizer.parse_state.options.constructs.code_indented {
+                TAB_SIZE - 1
+            } else {
+                usize::MAX
+            },
+        ))
+    } else {
+        State::Retry(StateName::DirectiveContainerBeforeSequenceClose)
+    }
+}
+
+/// In closing fence, after optional whitespace, at sequence.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn before_sequence_close(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DirectiveContainerSequence);
+        State::Retry(StateName::DirectiveContainerSequenceClose)
+    } else {
+        State::Nok
+    }
+}
+
+/// In closing sequence.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.tokenize_state.size += 1;
+        tokenizer.consume();
+        State::Next(StateName::DirectiveContainerSequenceClose)
+    } else if tokenizer.tokenize_state.size
+        >= tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size
+    {
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.exit(Name::DirectiveContainerSequence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::DirectiveContainerCloseEnd),
+                State::Nok,
+            );
+            State::Retry(space_or_tab(tokenizer))
+        } else {
+            State::Retry(StateName::DirectiveContainerCloseEnd)
+        }
+    } else {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    }
+}
+
+/// After closing fence and optional whitespace, at eol.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn close_end(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::DirectiveContainerFence);
+            // Mark as closed: the next line is no longer part of it.
+            tokenizer.tokenize_state.document_container_stack
+                [tokenizer.tokenize_state.document_continued]
+                .size = 0;
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
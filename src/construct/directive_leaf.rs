@@ -0,0 +1,168 @@
+//! Directive (leaf) occurs in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (leaf) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: `eol` is not allowed in `directive`.
+//! directive_leaf ::= *space_or_tab '::' directive *space_or_tab
+//!
+//! ; See the `partial_directive` construct for the BNF of that part.
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! See [`directive`][directive] for more info on the name, label, and
+//! attributes.
+//!
+//! ## HTML
+//!
+//! Directives are extension points: they do not relate to anything in HTML.
+//! Unless a tool handles them, directive (leaf) is compiled to a `<div>`,
+//! with a `data-directive` attribute set to its name, the parsed attributes,
+//! and the label as its content:
+//!
+//! ```markdown
+//! ::youtube[Video of a cat]{#cat v=01ab2cd3efg}
+//! ```
+//!
+//! Yields:
+//!
+//! ```html
+//! <div data-directive="youtube" id="cat" v="01ab2cd3efg">Video of a cat</div>
+//! ```
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveLeaf`][Name::DirectiveLeaf]
+//! *   [`DirectiveLeafSequence`][Name::DirectiveLeafSequence]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-leaf.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-leaf.js)
+//!
+//! [flow]: crate::construct::flow
+//! [directive]: crate::construct::partial_directive
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::TAB_SIZE;
+
+/// Start of directive (leaf).
+///
+/// ```markdown
+/// > | ::a
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_leaf {
+        tokenizer.enter(Name::DirectiveLeaf);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DirectiveLeafBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DirectiveLeafBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at sequence.
+///
+/// ```markdown
+/// > | ::a
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DirectiveLeafSequence);
+        tokenizer.consume();
+        State::Next(StateName::DirectiveLeafSequence)
+    } else {
+        State::Nok
+    }
+}
+
+/// In sequence.
+///
+/// ```markdown
+/// > | ::a
+///      ^
+/// ```
+pub fn sequence(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.consume();
+        tokenizer.exit(Name::DirectiveLeafSequence);
+        tokenizer.tokenize_state.token_1 = Name::DirectiveLeaf;
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveLeafAfter),
+            State::Next(StateName::DirectiveLeafNok),
+        );
+        State::Next(StateName::DirectiveNameStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After directive, at optional whitespace.
+///
+/// ```markdown
+/// > | ::a[b]{c}
+///              ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::DirectiveLeafEnd), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::DirectiveLeafEnd)
+    }
+}
+
+/// After directive and optional whitespace, at eol.
+///
+/// ```markdown
+/// > | ::a
+///        ^
+/// ```
+pub fn end(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::DirectiveLeaf);
+            // Feel free to interrupt.
+            tokenizer.interrupt = false;
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
+
+/// At something that wasn’t a directive (leaf).
+///
+/// ```markdown
+/// > | ::1
+///       ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    State::Nok
+}
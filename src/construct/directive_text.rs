@@ -0,0 +1,119 @@
+//! Directive (text) occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (text) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: the byte before must not be `:`, unless it is escaped.
+//! directive_text ::= ':' directive
+//!
+//! ; See the `partial_directive` construct for the BNF of that part.
+//! ```
+//!
+//! See [`directive`][directive] for more info on the name, label, and
+//! attributes.
+//!
+//! ## HTML
+//!
+//! Directives are extension points: they do not relate to anything in HTML.
+//! Unless a tool handles them, directive (text) is compiled to a `<span>`,
+//! with a `data-directive` attribute set to its name, the parsed attributes,
+//! and the label as its content:
+//!
+//! ```markdown
+//! :abbr[HTML]{title="HyperText Markup Language"}
+//! ```
+//!
+//! Yields:
+//!
+//! ```html
+//! <p><span data-directive="abbr" title="HyperText Markup Language">HTML</span></p>
+//! ```
+//!
+//! Event handler attributes (`on*`) are dropped, unless
+//! [`allow_dangerous_html`][allow_dangerous_html] is on.
+//!
+//! ## Recommendation
+//!
+//! Because a colon followed by a letter is common in natural language (such
+//! as in `a:b`), it is recommended to only turn directive (text) on when it
+//! is actually used, and to escape colons that should not start a directive
+//! otherwise (`a\:b`).
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveText`][Name::DirectiveText]
+//! *   [`DirectiveTextMarker`][Name::DirectiveTextMarker]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-text.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-text.js)
+//!
+//! [text]: crate::construct::text
+//! [directive]: crate::construct::partial_directive
+//! [allow_dangerous_html]: crate::CompileOptions::allow_dangerous_html
+
+use crate::construct::emoji::shortcode_at;
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of directive (text).
+///
+/// ```markdown
+/// > | a :b c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_text
+        && tokenizer.current == Some(b':')
+        && (tokenizer.previous != Some(b':')
+            || matches!(tokenizer.events.last(), Some(event) if event.name == Name::CharacterEscape))
+        // Emoji shortcodes (`:tada:`) are not directives.
+        && !(tokenizer.parse_state.options.constructs.emoji
+            && shortcode_at(
+                tokenizer.parse_state.bytes,
+                tokenizer.point.index + 1,
+                &tokenizer.parse_state.options.emoji_shortcodes,
+            ))
+    {
+        tokenizer.enter(Name::DirectiveText);
+        tokenizer.enter(Name::DirectiveTextMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DirectiveTextMarker);
+        tokenizer.tokenize_state.token_1 = Name::DirectiveText;
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveTextAfter),
+            State::Next(StateName::DirectiveTextNok),
+        );
+        State::Next(StateName::DirectiveNameStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After directive (text).
+///
+/// ```markdown
+/// > | a :b c
+///         ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    tokenizer.exit(Name::DirectiveText);
+    State::Ok
+}
+
+/// At something that wasn’t a directive (text).
+///
+/// ```markdown
+/// > | a :1 c
+///        ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    State::Nok
+}
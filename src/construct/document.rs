@@ -8,6 +8,8 @@
 //! *   [Block quote][crate::construct::block_quote]
 //! *   [List item][crate::construct::list_item]
 //! *   [GFM: Footnote definition][crate::construct::gfm_footnote_definition]
+//! *   [Directive (container)][crate::construct::directive_container]
+//! *   [Definition list][crate::construct::definition_list] (description)
 
 use crate::event::{Content, Event, Kind, Link, Name};
 use crate::state::{Name as StateName, State};
@@ -54,10 +56,12 @@ enum Phase {
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.tokenize_state.document_child = Some(Box::new(Tokenizer::new(
-        tokenizer.point.clone(),
-        tokenizer.parse_state,
-    )));
+    // A BOM and frontmatter can only occur at the start of the document, which
+    // is not where parsing starts when only a part of a document is parsed
+    // again.
+    if tokenizer.point.index > 0 {
+        return State::Retry(StateName::DocumentContainerNewBefore);
+    }
 
     tokenizer.attempt(
         State::Next(StateName::DocumentBeforeFrontmatter),
@@ -100,6 +104,8 @@ pub fn container_existing_before(tokenizer: &mut Tokenizer) -> State {
 
         let name = match container.kind {
             Container::BlockQuote => StateName::BlockQuoteContStart,
+            Container::DefinitionDescription => StateName::DefinitionListContStart,
+            Container::Directive => StateName::DirectiveContainerContStart,
             Container::GfmFootnoteDefinition => StateName::GfmFootnoteDefinitionContStart,
             Container::ListItem => StateName::ListItemContStart,
         };
@@ -125,7 +131,21 @@ pub fn container_existing_before(tokenizer: &mut Tokenizer) -> State {
 ///       ^
 /// ```
 pub fn container_existing_after(tokenizer: &mut Tokenizer) -> State {
+    let container = &tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued];
+    let closed = container.kind == Container::Directive && container.size == 0;
     tokenizer.tokenize_state.document_continued += 1;
+
+    // A directive (container) that was just closed by its closing fence:
+    // nothing in it, such as flow or other containers, continues on this line.
+    if closed {
+        if let Err(message) = exit_containers(tokenizer, &Phase::Prefix) {
+            return State::Error(message);
+        }
+
+        return State::Retry(StateName::DocumentContainersAfter);
+    }
+
     State::Retry(StateName::DocumentContainerExistingBefore)
 }
 
@@ -138,6 +158,14 @@ pub fn container_existing_after(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
+    // The flow starts after the optional BOM and frontmatter.
+    if tokenizer.tokenize_state.document_child.is_none() {
+        tokenizer.tokenize_state.document_child = Some(Box::new(Tokenizer::new(
+            tokenizer.point.clone(),
+            tokenizer.parse_state,
+        )));
+    }
+
     // If we have completely continued, restore the flow’s past `interrupt`
     // status.
     if tokenizer.tokenize_state.document_continued
@@ -233,7 +261,59 @@ pub fn container_new_before_not_list(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -> State {
-    // It wasn’t a new block quote, list item, or footnote definition.
+    // Directive (container)?
+    // We replace the empty footnote definition container for this new
+    // directive one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::Directive,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotDirectiveContainer),
+    );
+    State::Retry(StateName::DirectiveContainerStart)
+}
+
+/// At new container, but not a block quote, list item, footnote definition,
+/// or directive (container).
+//
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn container_new_before_not_directive_container(tokenizer: &mut Tokenizer) -> State {
+    // Definition description?
+    // We replace the empty directive container for this new definition
+    // description one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::DefinitionDescription,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotDefinitionDescription),
+    );
+    State::Retry(StateName::DefinitionListStart)
+}
+
+/// At new container, but not a block quote, list item, footnote definition,
+/// directive (container), or definition description.
+//
+/// ```markdown
+/// > | a
+///     ^
+/// ```
+pub fn container_new_before_not_definition_description(tokenizer: &mut Tokenizer) -> State {
+    // It wasn’t a new block quote, list item, footnote definition, directive
+    // (container), or definition description.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Drop what was in the middle.
     tokenizer
@@ -253,7 +333,8 @@ pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -
 ///       ^
 /// ```
 pub fn container_new_after(tokenizer: &mut Tokenizer) -> State {
-    // It was a new block quote, list item, or footnote definition.
+    // It was a new block quote, list item, footnote definition, directive
+    // (container), or definition description.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Take the new container.
     let container = tokenizer
@@ -428,9 +509,6 @@ pub fn flow_end(tokenizer: &mut Tokenizer) -> State {
         }
     }
 
-    // Reset “piercing”.
-    child.pierce = false;
-
     if child.lazy
         && tokenizer.tokenize_state.document_lazy_accepting_before
         && document_lazy_continuation_current
@@ -459,6 +537,16 @@ pub fn flow_end(tokenizer: &mut Tokenizer) -> State {
             State::Ok
         }
         Some(_) => {
+            // Reset “piercing”.
+            // At the end, the flow is still to be flushed, where constructs
+            // that were waiting for more bytes (such as `::` in `> ::`) can
+            // fail, and what is tried after them must still see it.
+            tokenizer
+                .tokenize_state
+                .document_child
+                .as_mut()
+                .unwrap()
+                .pierce = false;
             tokenizer.tokenize_state.document_continued = 0;
             tokenizer.tokenize_state.document_lazy_accepting_before =
                 document_lazy_continuation_current;
@@ -486,7 +574,13 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
             .take()
             .unwrap_or(State::Next(StateName::FlowStart));
 
-        child.flush(state, false)?;
+        let mut result = child.flush(state, false)?;
+        // Abbreviation definitions are found by flow.
+        tokenizer
+            .tokenize_state
+            .abbreviations
+            .append(&mut result.abbreviations);
+        child.interrupt = false;
     }
 
     if !stack_close.is_empty() {
@@ -498,6 +592,8 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
             let container = stack_close.pop().unwrap();
             let name = match container.kind {
                 Container::BlockQuote => Name::BlockQuote,
+                Container::DefinitionDescription => Name::DefinitionDescription,
+                Container::Directive => Name::DirectiveContainer,
                 Container::GfmFootnoteDefinition => Name::GfmFootnoteDefinition,
                 Container::ListItem => Name::ListItem,
             };
@@ -532,8 +628,6 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
         tokenizer.tokenize_state.document_exits[index] = Some(exits);
     }
 
-    child.interrupt = false;
-
     Ok(())
 }
 
@@ -0,0 +1,200 @@
+//! Emoji (shortcode) occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Emoji (shortcode) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: `name` must be a known shortcode.
+//! emoji ::= ':' 1*name ':'
+//!
+//! name ::= ascii_alphanumeric | '+' | '-' | '_'
+//! ```
+//!
+//! Known shortcodes are the ones that GitHub supports (such as `tada` and
+//! `+1`), and those passed in [`emoji_shortcodes`][emoji_shortcodes].
+//! Unknown shortcodes (such as `:nope:`) and things that look like shortcodes
+//! but contain other characters (such as `:not a shortcode:`) are just text.
+//!
+//! Once text is parsed, shortcodes are found in data.
+//! That means that nothing is found in code, autolinks, HTML, or the like,
+//! and that an escaped colon (as in `\:tada:`) cannot start a shortcode.
+//! Nothing is found in GFM footnote calls either.
+//! A shortcode can occur directly next to other characters, as in
+//! `a:tada:b`.
+//!
+//! When [directive (text)][directive_text] is also turned on, a directive
+//! name directly followed by a colon is not a directive if it is a known
+//! shortcode: `:tada:` is an emoji, but `:tada` is a directive.
+//!
+//! Emoji shortcodes are supported on GitHub and by several other markdown
+//! parsers, but are not `CommonMark` or [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Emoji (shortcode) does not relate to an element in HTML: it is the emoji
+//! itself.
+//! Custom emoji whose value is a URL relate to the `<img>` element in HTML.
+//! See [*§ 4.8.3 The `img` element*][html_img] in the HTML spec for more
+//! info.
+//!
+//! ## Tokens
+//!
+//! *   [`Emoji`][Name::Emoji]
+//!
+//! ## References
+//!
+//! *   [`github/gemoji`](https://github.com/github/gemoji)
+//! *   [`markdown-it-emoji`](https://github.com/markdown-it/markdown-it-emoji)
+//!
+//! [text]: crate::construct::text
+//! [directive_text]: crate::construct::directive_text
+//! [emoji_shortcodes]: crate::ParseOptions::emoji_shortcodes
+//! [html_img]: https://html.spec.whatwg.org/multipage/embedded-content.html#the-img-element
+
+use crate::event::{Event, Kind, Name};
+use crate::tokenizer::Tokenizer;
+use crate::util::emoji::find;
+use alloc::{string::String, vec::Vec};
+use core::str;
+
+/// Find emoji in data.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    let bytes = tokenizer.parse_state.bytes;
+    let tab_size = tokenizer.parse_state.options.tab_size;
+    let custom = &tokenizer.parse_state.options.emoji_shortcodes;
+    let mut index = 0;
+    let mut ignore = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.name == Name::GfmFootnoteCall {
+            if event.kind == Kind::Enter {
+                ignore += 1;
+            } else {
+                ignore -= 1;
+            }
+        }
+        // Linked data is content of its own (such as the label of a
+        // directive), which is resolved when it is tokenized.
+        else if event.kind == Kind::Exit
+            && event.name == Name::Data
+            && ignore == 0
+            && tokenizer.events[index - 1].link.is_none()
+        {
+            let start = tokenizer.events[index - 1].point.index;
+            let end = event.point.index;
+            let mut point = tokenizer.events[index - 1].point.clone();
+            let mut replace = Vec::new();
+            let mut byte_index = start;
+            let mut min = start;
+
+            while byte_index < end {
+                if bytes[byte_index] != b':' {
+                    byte_index += 1;
+                    continue;
+                }
+
+                let name_end = name_end(bytes, byte_index + 1, end);
+
+                if name_end == byte_index + 1 || name_end == end || bytes[name_end] != b':' {
+                    byte_index = name_end;
+                    continue;
+                }
+
+                // Names are ASCII, so they are valid UTF-8.
+                let name = str::from_utf8(&bytes[byte_index + 1..name_end]).unwrap();
+
+                if find(name, custom).is_none() {
+                    // The closing colon could open another shortcode.
+                    byte_index = name_end;
+                    continue;
+                }
+
+                // If there is something between the last emoji (or the start)
+                // and this one.
+                if min != byte_index {
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Data,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    point = point.shift_to(bytes, tab_size, byte_index);
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Data,
+                        point: point.clone(),
+                        link: None,
+                    });
+                }
+
+                replace.push(Event {
+                    kind: Kind::Enter,
+                    name: Name::Emoji,
+                    point: point.clone(),
+                    link: None,
+                });
+                byte_index = name_end + 1;
+                point = point.shift_to(bytes, tab_size, byte_index);
+                replace.push(Event {
+                    kind: Kind::Exit,
+                    name: Name::Emoji,
+                    point: point.clone(),
+                    link: None,
+                });
+                min = byte_index;
+            }
+
+            // If there was an emoji, and we have more bytes left.
+            if !replace.is_empty() && min < end {
+                replace.push(Event {
+                    kind: Kind::Enter,
+                    name: Name::Data,
+                    point: point.clone(),
+                    link: None,
+                });
+                replace.push(Event {
+                    kind: Kind::Exit,
+                    name: Name::Data,
+                    point: tokenizer.events[index].point.clone(),
+                    link: None,
+                });
+            }
+
+            // If there were emoji.
+            if !replace.is_empty() {
+                tokenizer.map.add(index - 1, 2, replace);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check whether a known shortcode (such as `tada`, without colons) is at
+/// `index`, directly followed by a colon.
+///
+/// Used by directive (text), so that `:tada:` is not a directive.
+pub fn shortcode_at(bytes: &[u8], index: usize, custom: &[(String, String)]) -> bool {
+    let name_end = name_end(bytes, index, bytes.len());
+
+    name_end > index
+        && bytes.get(name_end) == Some(&b':')
+        && find(str::from_utf8(&bytes[index..name_end]).unwrap(), custom).is_some()
+}
+
+/// Get the index after the name characters starting at `index`.
+fn name_end(bytes: &[u8], mut index: usize, end: usize) -> usize {
+    while index < end
+        && matches!(bytes[index], b'+' | b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z')
+    {
+        index += 1;
+    }
+
+    index
+}
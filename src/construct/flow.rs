@@ -10,8 +10,10 @@
 //!
 //! The constructs found in flow are:
 //!
+//! *   [Abbreviation][crate::construct::abbreviation]
 //! *   [Blank line][crate::construct::blank_line]
 //! *   [Code (indented)][crate::construct::code_indented]
+//! *   [Directive (leaf)][crate::construct::directive_leaf]
 //! *   [Heading (atx)][crate::construct::heading_atx]
 //! *   [Heading (setext)][crate::construct::heading_setext]
 //! *   [HTML (flow)][crate::construct::html_flow]
@@ -51,9 +53,18 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::RawFlowStart)
         }
+        // Note: `*` can also start an abbreviation definition, which is also
+        // tried.
+        Some(b'*') => {
+            tokenizer.attempt(
+                State::Next(StateName::FlowAfter),
+                State::Next(StateName::FlowBeforeAbbreviationDefinition),
+            );
+            State::Retry(StateName::ThematicBreakStart)
+        }
         // Note: `-` is also used in setext heading underline so it’s not
         // included here.
-        Some(b'*' | b'_') => {
+        Some(b'_') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
                 State::Next(StateName::FlowBeforeContent),
@@ -74,6 +85,14 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::MdxEsmStart)
         }
+        // Note: `:` can also start a GFM table, which is also tried.
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::FlowAfter),
+                State::Next(StateName::FlowBeforeGfmTable),
+            );
+            State::Retry(StateName::DirectiveLeafStart)
+        }
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
@@ -196,11 +215,25 @@ pub fn before_heading_setext(tokenizer: &mut Tokenizer) -> State {
 pub fn before_thematic_break(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeMdxExpression),
+        State::Next(StateName::FlowBeforeDirectiveLeaf),
     );
     State::Retry(StateName::ThematicBreakStart)
 }
 
+/// At directive (leaf).
+///
+/// ```markdown
+/// > | ::a
+///     ^
+/// ```
+pub fn before_directive_leaf(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeMdxExpression),
+    );
+    State::Retry(StateName::DirectiveLeafStart)
+}
+
 /// At MDX expression (flow).
 ///
 /// ```markdown
@@ -224,11 +257,25 @@ pub fn before_mdx_expression(tokenizer: &mut Tokenizer) -> State {
 pub fn before_gfm_table(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeContent),
+        State::Next(StateName::FlowBeforeAbbreviationDefinition),
     );
     State::Retry(StateName::GfmTableStart)
 }
 
+/// At abbreviation definition.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^
+/// ```
+pub fn before_abbreviation_definition(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeContent),
+    );
+    State::Retry(StateName::AbbreviationDefinitionStart)
+}
+
 /// At content.
 ///
 /// ```markdown
@@ -29,6 +29,13 @@
 //! As there is no spec for frontmatter in markdown, this extension follows how
 //! YAML frontmatter works on `github.com`.
 //! It also parses TOML frontmatter, just like YAML except that it uses a `+`.
+//! YAML and TOML can be turned off separately with
+//! [`frontmatter_yaml`][frontmatter_yaml] and
+//! [`frontmatter_toml`][frontmatter_toml].
+//! Which of the two was used can be found by looking at the markers in
+//! [`FrontmatterSequence`][Name::FrontmatterSequence], or, in mdast, by
+//! whether the node is [`Yaml`][crate::mdast::Yaml] or
+//! [`Toml`][crate::mdast::Toml].
 //!
 //! ## Recommendation
 //!
@@ -55,6 +62,8 @@
 //! *   [`micromark-extension-frontmatter`](https://github.com/micromark/micromark-extension-frontmatter)
 //!
 //! [constructs]: crate::Constructs
+//! [frontmatter_toml]: crate::ParseOptions::frontmatter_toml
+//! [frontmatter_yaml]: crate::ParseOptions::frontmatter_yaml
 
 use crate::construct::partial_space_or_tab::space_or_tab;
 use crate::event::Name;
@@ -71,9 +80,15 @@ use crate::util::constant::FRONTMATTER_SEQUENCE_SIZE;
 ///   | ---
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
+    let options = &tokenizer.parse_state.options;
+
     // Indent not allowed.
-    if tokenizer.parse_state.options.constructs.frontmatter
-        && matches!(tokenizer.current, Some(b'+' | b'-'))
+    if options.constructs.frontmatter
+        && match tokenizer.current {
+            Some(b'+') => options.frontmatter_toml,
+            Some(b'-') => options.frontmatter_yaml,
+            _ => false,
+        }
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.enter(Name::Frontmatter);
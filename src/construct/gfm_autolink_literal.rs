@@ -627,10 +627,14 @@ pub fn resolve(tokenizer: &mut Tokenizer) {
                 links += 1;
             }
         } else {
-            if event.name == Name::Data && links == 0 {
+            // Linked data is content of its own (such as the label of a
+            // directive), which is resolved when it is tokenized.
+            if event.name == Name::Data && links == 0 && tokenizer.events[index - 1].link.is_none()
+            {
                 let slice = Slice::from_position(
                     tokenizer.parse_state.bytes,
                     &Position::from_exit_event(&tokenizer.events, index),
+                    tokenizer.parse_state.options.tab_size,
                 );
                 let bytes = slice.bytes;
                 let mut byte_index = 0;
@@ -675,8 +679,11 @@ pub fn resolve(tokenizer: &mut Tokenizer) {
                                     point: point.clone(),
                                     link: None,
                                 });
-                                point = point
-                                    .shift_to(tokenizer.parse_state.bytes, start_index + range.0);
+                                point = point.shift_to(
+                                    tokenizer.parse_state.bytes,
+                                    tokenizer.parse_state.options.tab_size,
+                                    start_index + range.0,
+                                );
                                 replace.push(Event {
                                     kind: Kind::Exit,
                                     name: Name::Data,
@@ -692,8 +699,11 @@ pub fn resolve(tokenizer: &mut Tokenizer) {
                                 point: point.clone(),
                                 link: None,
                             });
-                            point =
-                                point.shift_to(tokenizer.parse_state.bytes, start_index + range.1);
+                            point = point.shift_to(
+                                tokenizer.parse_state.bytes,
+                                tokenizer.parse_state.options.tab_size,
+                                start_index + range.1,
+                            );
                             replace.push(Event {
                                 kind: Kind::Exit,
                                 name: range.2.clone(),
@@ -6,6 +6,8 @@
 //! (<small>see [construct][crate::construct] for character groups</small>):
 //!
 //! ```bnf
+//! ; Restriction: the closing `#`s are only supported with
+//! ; `heading_atx_closing_sequence` (on by default).
 //! heading_atx ::= 1*6'#' [ 1*space_or_tab line [ 1*space_or_tab 1*'#' ] ] *space_or_tab
 //! ```
 //!
@@ -163,7 +165,7 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
             tokenizer.attempt(State::Next(StateName::HeadingAtxAtBreak), State::Nok);
             State::Retry(space_or_tab(tokenizer))
         }
-        Some(b'#') => {
+        Some(b'#') if tokenizer.parse_state.options.heading_atx_closing_sequence => {
             tokenizer.enter(Name::HeadingAtxSequence);
             State::Retry(StateName::HeadingAtxSequenceFurther)
         }
@@ -27,8 +27,19 @@
 //!
 //! [Thematic breaks][thematic_break] formed with dashes and without whitespace
 //! could be interpreted as a heading (setext).
-//! Which one forms depends on whether there is text directly in fron of the
-//! sequence.
+//! Which one forms depends on whether there is text directly in front of the
+//! sequence:
+//!
+//! *   after a paragraph, the dashes are an underline, and the events are
+//!     [`HeadingSetext`][Name::HeadingSetext], wrapping
+//!     [`HeadingSetextText`][Name::HeadingSetextText] and
+//!     [`HeadingSetextUnderline`][Name::HeadingSetextUnderline]
+//! *   otherwise (such as after a blank line, another construct, or only
+//!     [definitions][definition]), they are a thematic break, and the events
+//!     are [`ThematicBreak`][Name::ThematicBreak]
+//!
+//! A sequence of equals signs cannot be a thematic break: without a paragraph
+//! before it, it is text.
 //!
 //! > 🏛 **Background**: the word *setext* originates from a small markup
 //! > language by Ian Feldman from 1991.
@@ -65,6 +76,7 @@
 //! [paragraph]: crate::construct::paragraph
 //! [heading_atx]: crate::construct::heading_atx
 //! [thematic_break]: crate::construct::thematic_break
+//! [definition]: crate::construct::definition
 //! [hard_break_escape]: crate::construct::hard_break_escape
 //! [html]: https://html.spec.whatwg.org/multipage/sections.html#the-h1,-h2,-h3,-h4,-h5,-and-h6-elements
 //! [wiki_setext]: https://en.wikipedia.org/wiki/Setext
@@ -76,7 +88,10 @@ use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
-use crate::util::{constant::TAB_SIZE, skip};
+use crate::util::{
+    constant::{TAB_SIZE, THEMATIC_BREAK_MARKER_COUNT_MIN},
+    skip,
+};
 use alloc::vec;
 
 /// At start of heading (setext) underline.
@@ -220,6 +235,15 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             let mut heading_exit = tokenizer.events[exit].clone();
             heading_exit.name = Name::HeadingSetext;
             tokenizer.map.add(exit + 1, 0, vec![heading_exit]);
+        } else if let Some(sequence) = thematic_break_sequence(tokenizer, enter) {
+            // There’s no paragraph (only definitions), but the dashes form a
+            // thematic break: that one wins.
+            // The underline has the same structure as a thematic break, so
+            // only the names change.
+            tokenizer.events[enter].name = Name::ThematicBreak;
+            tokenizer.events[exit].name = Name::ThematicBreak;
+            tokenizer.events[sequence].name = Name::ThematicBreakSequence;
+            tokenizer.events[sequence + 1].name = Name::ThematicBreakSequence;
         } else {
             // There’s a following paragraph, move this underline inside it.
             if exit + 3 < tokenizer.events.len()
@@ -280,3 +304,34 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Get the index of the sequence of the heading (setext) underline at
+/// `enter`, if that underline can also be a thematic break.
+fn thematic_break_sequence(tokenizer: &Tokenizer, enter: usize) -> Option<usize> {
+    let options = &tokenizer.parse_state.options;
+    let sequence = skip::to(
+        &tokenizer.events,
+        enter + 1,
+        &[Name::HeadingSetextUnderlineSequence],
+    );
+    let start = tokenizer.events[sequence].point.index;
+    let size = tokenizer.events[sequence + 1].point.index - start;
+
+    if options.constructs.thematic_break
+        && tokenizer.parse_state.bytes[start] == b'-'
+        && options
+            .thematic_break_markers
+            .as_ref()
+            .map_or(true, |markers| markers.contains(&'-'))
+        && size
+            >= options
+                .thematic_break_size_min
+                .map_or(THEMATIC_BREAK_MARKER_COUNT_MIN, |size| {
+                    size.max(THEMATIC_BREAK_MARKER_COUNT_MIN)
+                })
+    {
+        Some(sequence)
+    } else {
+        None
+    }
+}
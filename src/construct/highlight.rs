@@ -0,0 +1,225 @@
+//! Highlight occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Highlight sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! highlight_sequence ::= '=' '='
+//! ```
+//!
+//! Highlight is matched like GFM strikethrough in [attention][]: sequences
+//! are parsed first, and matched together later, based on what character
+//! occurs before and after each sequence.
+//! A sequence can open when it is not followed by whitespace, and close when
+//! it is not preceded by whitespace.
+//! So, `==a==` is highlight, but `a == b` is not.
+//! Sequences of other sizes (such as `=` or `===`) never match.
+//! Sequences that don’t match are turned into data.
+//!
+//! Highlight can contain other phrasing, and can be in it, such as in
+//! `*==a==*` and `==*a*==`, as long as they don’t overlap.
+//!
+//! A line of only equals signs after a paragraph is a
+//! [heading (setext)][heading_setext] underline instead.
+//!
+//! Highlight (or mark) is supported by several markdown editors and extensions
+//! but is not [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Highlight relates to the `<mark>` element in HTML.
+//! See [*§ 4.5.23 The `mark` element*][html_mark] in the HTML spec for more
+//! info.
+//!
+//! ## Tokens
+//!
+//! *   [`Highlight`][Name::Highlight]
+//! *   [`HighlightSequence`][Name::HighlightSequence]
+//! *   [`HighlightText`][Name::HighlightText]
+//!
+//! ## References
+//!
+//! *   [*Highlight* in Obsidian](https://help.obsidian.md/Editing+and+formatting/Basic+formatting+syntax#Bold%2C+italics%2C+highlights)
+//! *   [`markdown-it-mark`](https://github.com/markdown-it/markdown-it-mark)
+//!
+//! [text]: crate::construct::text
+//! [attention]: crate::construct::attention
+//! [heading_setext]: crate::construct::heading_setext
+//! [html_mark]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-mark-element
+
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::char::{
+    after_index as char_after_index, before_index as char_before_index, classify_opt,
+    Kind as CharacterKind,
+};
+use alloc::{vec, vec::Vec};
+
+/// At start of highlight sequence.
+///
+/// ```markdown
+/// > | ==a==
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.highlight && tokenizer.current == Some(b'=') {
+        tokenizer.enter(Name::HighlightSequence);
+        tokenizer.consume();
+        State::Next(StateName::HighlightInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In highlight sequence.
+///
+/// ```markdown
+/// > | ==a==
+///      ^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'=') {
+        tokenizer.consume();
+        State::Next(StateName::HighlightAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After highlight sequence.
+///
+/// ```markdown
+/// > | ==a==
+///       ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'=') {
+        State::Nok
+    } else {
+        tokenizer.exit(Name::HighlightSequence);
+        tokenizer.register_resolver(ResolveName::Highlight);
+        State::Ok
+    }
+}
+
+/// Resolve highlight sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let bytes = tokenizer.parse_state.bytes;
+    // Balanced events we are in, so that sequences in, say, a link don’t
+    // match sequences outside of it.
+    let mut stack: Vec<usize> = vec![];
+    // Sequences that can still open: their index into events and their stack.
+    let mut openers: Vec<(usize, Vec<usize>)> = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        // Sequences are void, so this is always an enter, which is followed
+        // by its exit (skipped below).
+        if event.name == Name::HighlightSequence {
+            let char_before = char_before_index(bytes, event.point.index);
+            let char_after = char_after_index(bytes, tokenizer.events[index + 1].point.index);
+            // Sequences directly next to more equals signs are too long.
+            let next_to_marker = char_before == Some('=') || char_after == Some('=');
+            let open = !next_to_marker && classify_opt(char_after) != CharacterKind::Whitespace;
+            let close = !next_to_marker && classify_opt(char_before) != CharacterKind::Whitespace;
+            let mut matched = false;
+
+            if close {
+                if let Some(opener) = openers.iter().rposition(|d| d.1 == stack) {
+                    let open_index = openers[opener].0;
+
+                    // Openers between the two can no longer match: that would
+                    // be misnested.
+                    for (between, _) in openers.drain(opener..).skip(1) {
+                        tokenizer.events[between].name = Name::Data;
+                        tokenizer.events[between + 1].name = Name::Data;
+                    }
+
+                    wrap(tokenizer, open_index, index);
+                    matched = true;
+                }
+            }
+
+            if !matched {
+                if open {
+                    openers.push((index, stack.clone()));
+                } else {
+                    tokenizer.events[index].name = Name::Data;
+                    tokenizer.events[index + 1].name = Name::Data;
+                }
+            }
+
+            index += 1;
+        } else if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            stack.pop();
+        }
+
+        index += 1;
+    }
+
+    for (index, _) in openers {
+        tokenizer.events[index].name = Name::Data;
+        tokenizer.events[index + 1].name = Name::Data;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Wrap two sequences, and what is between them, in highlight.
+fn wrap(tokenizer: &mut Tokenizer, open: usize, close: usize) {
+    let open_enter = tokenizer.events[open].point.clone();
+    let open_exit = tokenizer.events[open + 1].point.clone();
+    let close_enter = tokenizer.events[close].point.clone();
+    let close_exit = tokenizer.events[close + 1].point.clone();
+
+    tokenizer.map.add(
+        open,
+        0,
+        vec![Event {
+            kind: Kind::Enter,
+            name: Name::Highlight,
+            point: open_enter,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        open + 2,
+        0,
+        vec![Event {
+            kind: Kind::Enter,
+            name: Name::HighlightText,
+            point: open_exit,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        close,
+        0,
+        vec![Event {
+            kind: Kind::Exit,
+            name: Name::HighlightText,
+            point: close_enter,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        close + 2,
+        0,
+        vec![Event {
+            kind: Kind::Exit,
+            name: Name::Highlight,
+            point: close_exit,
+            link: None,
+        }],
+    );
+}
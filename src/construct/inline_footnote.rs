@@ -0,0 +1,101 @@
+//! Inline footnote occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Inline footnote forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! inline_footnote ::= '^' '['
+//! ```
+//!
+//! Only the start (`^[`) of an inline footnote is parsed here.
+//! Like the other label starts, it is matched with a [label end][label_end]
+//! (`]`), and what is between them is the content of the footnote.
+//! That content is parsed as [text][], so it can contain emphasis, code,
+//! links, and even [GFM footnote calls][gfm_label_start_footnote].
+//! Unlike links and GFM footnote calls, nothing can follow the label end:
+//! `^[a](b)` is a footnote followed by the text `(b)`.
+//!
+//! This is the footnote syntax of Pandoc.
+//! Inline footnotes do not need a definition, and are not
+//! [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Inline footnote relates to `<sup>` and `<a>` elements in HTML, just like
+//! [GFM footnote calls][gfm_label_start_footnote], and its content is turned
+//! into a list item in the footnote section at the end of the document.
+//! Inline footnotes and GFM footnote calls are numbered together, in the
+//! order in which they are called.
+//! Inline footnotes get an identifier that is the lowest number that is not
+//! used by a GFM footnote definition, so that `[^1]: a` and `^[b]` do not
+//! clash.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sup] and
+//! [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//! Without an end, the characters (`^[`) are output.
+//!
+//! ## Tokens
+//!
+//! *   [`InlineFootnoteLabel`][Name::InlineFootnoteLabel]
+//! *   [`InlineFootnoteMarker`][Name::InlineFootnoteMarker]
+//! *   [`LabelMarker`][Name::LabelMarker]
+//!
+//! ## References
+//!
+//! *   [*Inline notes* in Pandoc](https://pandoc.org/MANUAL.html#extension-inline_notes)
+//! *   [`mdast-util-footnote`](https://github.com/syntax-tree/mdast-util-footnote)
+//!
+//! [text]: crate::construct::text
+//! [label_end]: crate::construct::label_end
+//! [gfm_label_start_footnote]: crate::construct::gfm_label_start_footnote
+//! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
+//! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
+
+use crate::event::Name;
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::{LabelKind, LabelStart, Tokenizer};
+
+/// Start of inline footnote.
+///
+/// ```markdown
+/// > | a ^[b] c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.inline_footnote && tokenizer.current == Some(b'^') {
+        tokenizer.enter(Name::InlineFootnoteLabel);
+        tokenizer.enter(Name::InlineFootnoteMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::InlineFootnoteMarker);
+        State::Next(StateName::InlineFootnoteOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `^`, at `[`.
+///
+/// ```markdown
+/// > | a ^[b] c
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'[') => {
+            tokenizer.enter(Name::LabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::LabelMarker);
+            tokenizer.exit(Name::InlineFootnoteLabel);
+            tokenizer.tokenize_state.label_starts.push(LabelStart {
+                kind: LabelKind::InlineFootnote,
+                start: (tokenizer.events.len() - 6, tokenizer.events.len() - 1),
+                inactive: false,
+            });
+            tokenizer.register_resolver_before(ResolveName::Label);
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
@@ -30,6 +30,11 @@
 //! To match, the effective label of the reference must be equal to the label
 //! of the definition after normalizing with
 //! [`normalize_identifier`][normalize_identifier].
+//! Like labels of definitions, the implicit label of a collapsed or shortcut
+//! reference can be at most `999` bytes, before normalizing.
+//!
+//! When [`warning_sink`][warning_sink] is set, references whose label does
+//! not match a definition are reported.
 //!
 //! Importantly, while the label of a full reference *can* include [string][]
 //! content, and in case of collapsed and shortcut references even [text][]
@@ -113,6 +118,8 @@
 //! in which case they form `<sup>` and `<a>` elements in HTML.
 //! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sup] and
 //! [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//! The same goes for [inline footnote][inline_footnote], which is done right
+//! after the label end.
 //!
 //! ## Recommendation
 //!
@@ -130,6 +137,7 @@
 //! *   [`Data`][Name::Data]
 //! *   [`GfmFootnoteCall`][Name::GfmFootnoteCall]
 //! *   [`Image`][Name::Image]
+//! *   [`InlineFootnote`][Name::InlineFootnote]
 //! *   [`Label`][Name::Label]
 //! *   [`LabelEnd`][Name::LabelEnd]
 //! *   [`LabelMarker`][Name::LabelMarker]
@@ -171,10 +179,12 @@
 //! [label_start_image]: crate::construct::label_start_image
 //! [label_start_link]: crate::construct::label_start_link
 //! [gfm_label_start_footnote]: crate::construct::gfm_label_start_footnote
+//! [inline_footnote]: crate::construct::inline_footnote
 //! [definition]: crate::construct::definition
 //! [autolink]: crate::construct::autolink
 //! [sanitize_uri]: crate::util::sanitize_uri::sanitize
 //! [normalize_identifier]: crate::util::normalize_identifier::normalize_identifier
+//! [warning_sink]: crate::ParseOptions::warning_sink
 //! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
 //! [html_img]: https://html.spec.whatwg.org/multipage/embedded-content.html#the-img-element
 //! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
@@ -186,10 +196,11 @@ use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::{Label, LabelKind, LabelStart, Tokenizer};
 use crate::util::{
-    constant::RESOURCE_DESTINATION_BALANCE_MAX,
+    constant::{LINK_REFERENCE_SIZE_MAX, RESOURCE_DESTINATION_BALANCE_MAX},
     normalize_identifier::normalize_identifier,
     skip,
     slice::{Position, Slice},
+    warning::Kind as WarningKind,
 };
 use alloc::{string::String, vec};
 
@@ -253,32 +264,80 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     let start_index = tokenizer.tokenize_state.label_starts.len() - 1;
     let start = &tokenizer.tokenize_state.label_starts[start_index];
 
+    // Inline footnotes need no definition, and nothing can follow them.
+    if start.kind == LabelKind::InlineFootnote {
+        return State::Retry(StateName::LabelEndOk);
+    }
+
     let indices = (
         tokenizer.events[start.start.1].point.index,
         tokenizer.events[tokenizer.tokenize_state.end].point.index,
     );
 
-    // We don’t care about virtual spaces, so `indices` and `as_str` are fine.
-    let mut id = normalize_identifier(
-        Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
-    );
+    // Labels that are too long cannot match definitions.
+    // Not normalizing them prevents doing that again and again for nested
+    // brackets, which is quadratic.
+    let id = if indices.1 - indices.0 > LINK_REFERENCE_SIZE_MAX {
+        None
+    } else {
+        // We don’t care about virtual spaces, so `indices` and `as_str` are fine.
+        Some(normalize_identifier(
+            Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
+        ))
+    };
 
     // See if this matches a footnote definition.
-    if start.kind == LabelKind::GfmFootnote {
-        if tokenizer.parse_state.gfm_footnote_definitions.contains(&id) {
-            return State::Retry(StateName::LabelEndOk);
+    let id = if start.kind == LabelKind::GfmFootnote {
+        if let Some(id) = &id {
+            if tokenizer.parse_state.gfm_footnote_definitions.contains(id) {
+                return State::Retry(StateName::LabelEndOk);
+            }
         }
 
         // Nope, this might be a normal link?
         tokenizer.tokenize_state.label_starts[start_index].kind = LabelKind::GfmUndefinedFootnote;
-        let mut new_id = String::new();
-        new_id.push('^');
-        new_id.push_str(&id);
-        id = new_id;
+        id.map(|id| {
+            let mut new_id = String::new();
+            new_id.push('^');
+            new_id.push_str(&id);
+            new_id
+        })
+    } else {
+        id
+    };
+
+    let defined = id
+        .as_ref()
+        .map_or(false, |id| tokenizer.parse_state.definitions.contains(id));
+
+    // Collapsed (`[asd][]`) and shortcut (`[asd]`) references that are not
+    // defined are likely mistakes.
+    // They are not when in a link (`[[asd]](fgh)`), which we don’t know yet.
+    if !defined
+        && tokenizer.parse_state.options.warning_sink.is_some()
+        && id.map_or(false, |id| !id.is_empty())
+        && match tokenizer.current {
+            Some(b'(') => false,
+            Some(b'[') => tokenizer.parse_state.bytes.get(tokenizer.point.index + 1) == Some(&b']'),
+            _ => true,
+        }
+    {
+        let start = &tokenizer.tokenize_state.label_starts[start_index];
+        let label = Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1)
+            .as_str()
+            .into();
+        let kind = if start.kind == LabelKind::GfmUndefinedFootnote {
+            WarningKind::FootnoteUndefined(label)
+        } else {
+            WarningKind::ReferenceUndefined(label)
+        };
+        let point = tokenizer.events[start.start.0].point.clone();
+        tokenizer
+            .tokenize_state
+            .reference_warnings
+            .push((start.start.0, kind, point));
     }
 
-    let defined = tokenizer.parse_state.definitions.contains(&id);
-
     match tokenizer.current {
         // Resource (`[asd](fgh)`)?
         Some(b'(') => {
@@ -351,14 +410,30 @@ pub fn ok(tokenizer: &mut Tokenizer) -> State {
     // longer viable for use (as they would otherwise contain a link).
     // These link starts are still looking for balanced closing brackets, so
     // we can’t remove them, but we can mark them.
+    // When we find one that is already marked, earlier ones are marked too.
+    // Inline footnotes can contain links, so their starts are never marked.
     if label_start.kind != LabelKind::Image {
-        let mut index = 0;
-        while index < tokenizer.tokenize_state.label_starts.len() {
+        // References in links or footnote calls are not mistakes, as links
+        // cannot contain links.
+        if label_start.kind != LabelKind::InlineFootnote {
+            tokenizer
+                .tokenize_state
+                .reference_warnings
+                .retain(|d| d.0 < label_start.start.0);
+        }
+
+        let mut index = tokenizer.tokenize_state.label_starts.len();
+        while index > 0 {
+            index -= 1;
             let label_start = &mut tokenizer.tokenize_state.label_starts[index];
-            if label_start.kind != LabelKind::Image {
+            if label_start.kind != LabelKind::Image && label_start.kind != LabelKind::InlineFootnote
+            {
+                if label_start.inactive {
+                    break;
+                }
+
                 label_start.inactive = true;
             }
-            index += 1;
         }
     }
 
@@ -501,18 +576,24 @@ pub fn resource_destination_missing(tokenizer: &mut Tokenizer) -> State {
 ///           ^
 /// ```
 pub fn resource_between(tokenizer: &mut Tokenizer) -> State {
-    match tokenizer.current {
-        Some(b'"' | b'\'' | b'(') => {
-            tokenizer.tokenize_state.token_1 = Name::ResourceTitle;
-            tokenizer.tokenize_state.token_2 = Name::ResourceTitleMarker;
-            tokenizer.tokenize_state.token_3 = Name::ResourceTitleString;
-            tokenizer.attempt(
-                State::Next(StateName::LabelEndResourceTitleAfter),
-                State::Nok,
-            );
-            State::Retry(StateName::TitleStart)
-        }
-        _ => State::Retry(StateName::LabelEndResourceEnd),
+    let title = match tokenizer.current {
+        Some(b'"' | b'\'' | b'(') => true,
+        // Curly quotes (`“`, `‘`).
+        Some(0xE2) => tokenizer.parse_state.options.title_smart_quotes,
+        _ => false,
+    };
+
+    if title {
+        tokenizer.tokenize_state.token_1 = Name::ResourceTitle;
+        tokenizer.tokenize_state.token_2 = Name::ResourceTitleMarker;
+        tokenizer.tokenize_state.token_3 = Name::ResourceTitleString;
+        tokenizer.attempt(
+            State::Next(StateName::LabelEndResourceTitleAfter),
+            State::Nok,
+        );
+        State::Retry(StateName::TitleStart)
+    } else {
+        State::Retry(StateName::LabelEndResourceEnd)
     }
 }
 
@@ -605,6 +686,7 @@ pub fn reference_full_after(tokenizer: &mut Tokenizer) -> State {
                         &[Name::ReferenceString],
                     ),
                 ),
+                tokenizer.parse_state.options.tab_size,
             )
             .as_str(),
         ))
@@ -680,6 +762,8 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
         let label = &labels[index];
         let group_name = if label.kind == LabelKind::GfmFootnote {
             Name::GfmFootnoteCall
+        } else if label.kind == LabelKind::InlineFootnote {
+            Name::InlineFootnote
         } else if label.kind == LabelKind::Image {
             Name::Image
         } else {
@@ -739,7 +823,9 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
         // Though: if this was what looked like a footnote, but didn’t match,
         // it’s a link instead, and we need to inject the `^`.
         if label.start.1 != label.end.0 || !caret.is_empty() {
-            tokenizer.map.add(
+            // Before, so that a nested label that starts right here (the
+            // last one added, as inner labels close first) goes inside.
+            tokenizer.map.add_before(
                 label.start.1 + 1,
                 0,
                 vec![Event {
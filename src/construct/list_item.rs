@@ -25,6 +25,10 @@
 //! As list item is a container, it takes several bytes from the start of the
 //! line, while the rest of the line includes more containers or flow.
 //!
+//! Adjacent list items form a list when they use the same marker.
+//! A different marker (such as `*` after `-`, or `)` after `.`) starts a new
+//! list, unless [`list_marker_change`][list_marker_change] says otherwise.
+//!
 //! ## HTML
 //!
 //! List item relates to the `<li>`, `<ol>`, and `<ul>` elements in HTML.
@@ -53,6 +57,7 @@
 //! *   [*§ 5.3 Lists* in `CommonMark`](https://spec.commonmark.org/0.30/#lists)
 //!
 //! [document]: crate::construct::document
+//! [list_marker_change]: crate::ParseOptions::list_marker_change
 //! [html_li]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-li-element
 //! [html_ol]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-ol-element
 //! [html_ul]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-ul-element
@@ -68,7 +73,9 @@ use crate::util::{
     constant::{LIST_ITEM_VALUE_SIZE_MAX, TAB_SIZE},
     skip,
     slice::{Position, Slice},
+    warning::Kind as WarningKind,
 };
+use crate::ListMarkerChange;
 use alloc::{vec, vec::Vec};
 
 /// Start of list item.
@@ -291,6 +298,7 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
                 start: &tokenizer.events[start].point,
                 end: &tokenizer.point,
             },
+            tokenizer.parse_state.options.tab_size,
         )
         .len();
 
@@ -372,6 +380,7 @@ pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
 
 /// Find adjacent list items with the same marker.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let change = tokenizer.parse_state.options.list_marker_change;
     let mut lists_wip: Vec<(u8, usize, usize, usize)> = vec![];
     let mut lists: Vec<(u8, usize, usize, usize)> = vec![];
     let mut index = 0;
@@ -406,12 +415,27 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         ],
                     );
 
-                    if previous.0 == current.0 && previous.1 == current.1 && before == current.2 {
-                        let previous_mut = &mut lists_wip[list_index];
-                        previous_mut.3 = current.3;
-                        lists.append(&mut lists_wip.split_off(list_index + 1));
-                        matched = true;
-                        break;
+                    if previous.1 == current.1 && before == current.2 {
+                        let same_marker = previous.0 == current.0;
+                        let same_kind = ordered(previous.0) == ordered(current.0);
+
+                        if same_marker || (same_kind && change == ListMarkerChange::Join) {
+                            let previous_mut = &mut lists_wip[list_index];
+                            previous_mut.3 = current.3;
+                            lists.append(&mut lists_wip.split_off(list_index + 1));
+                            matched = true;
+                            break;
+                        }
+
+                        if same_kind && change == ListMarkerChange::Warn {
+                            tokenizer.warn(
+                                WarningKind::ListMarkerChange(
+                                    char::from(previous.0),
+                                    char::from(current.0),
+                                ),
+                                &tokenizer.events[index].point,
+                            );
+                        }
                     }
                 }
 
@@ -456,9 +480,10 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         let list_item = &lists[index];
         let mut list_start = tokenizer.events[list_item.2].clone();
         let mut list_end = tokenizer.events[list_item.3].clone();
-        let name = match list_item.0 {
-            b'.' | b')' => Name::ListOrdered,
-            _ => Name::ListUnordered,
+        let name = if ordered(list_item.0) {
+            Name::ListOrdered
+        } else {
+            Name::ListUnordered
         };
         list_start.name = name.clone();
         list_end.name = name;
@@ -472,3 +497,8 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Check whether `marker` is the delimiter of an ordered list item.
+fn ordered(marker: u8) -> bool {
+    matches!(marker, b'.' | b')')
+}
@@ -14,6 +14,8 @@
 //! This construct must be followed by a blank line or eof (end of file).
 //! It can include blank lines if [`MdxEsmParse`][crate::MdxEsmParse] passed in
 //! [`ParseOptions`][parse_options] allows it.
+//! Without such a parser, the ESM is not checked, and runs until the first
+//! blank line.
 //!
 //! ## Tokens
 //!
@@ -45,8 +47,6 @@ use alloc::format;
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // If it’s turned on.
     if tokenizer.parse_state.options.constructs.mdx_esm
-        // If there is a gnostic parser.
-        && tokenizer.parse_state.options.mdx_esm_parse.is_some()
         // When not interrupting.
         && !tokenizer.interrupt
         // Only at the start of a line, not at whitespace or in a container.
@@ -185,6 +185,11 @@ pub fn at_end(tokenizer: &mut Tokenizer) -> State {
 
 /// Parse ESM with a given function.
 fn parse_esm(tokenizer: &mut Tokenizer) -> State {
+    // Without a gnostic parser, the ESM ends at the blank line.
+    if tokenizer.parse_state.options.mdx_esm_parse.is_none() {
+        return State::Ok;
+    }
+
     // We can `unwrap` because we don’t parse if this is `None`.
     let parse = tokenizer
         .parse_state
@@ -197,6 +202,7 @@ fn parse_esm(tokenizer: &mut Tokenizer) -> State {
     let result = collect(
         &tokenizer.events,
         tokenizer.parse_state.bytes,
+        tokenizer.parse_state.options.tab_size,
         tokenizer.tokenize_state.start,
         &[Name::MdxEsmData, Name::LineEnding],
         &[],
@@ -212,8 +218,11 @@ fn parse_esm(tokenizer: &mut Tokenizer) -> State {
                 .as_ref()
                 .expect("expected location index if aware mdx is on")
                 .relative_to_point(&result.stops, relative)
-                .expect("expected non-empty string");
-            State::Error(format!("{}:{}: {}", point.line, point.column, message))
+                .map_or((tokenizer.point.line, tokenizer.point.column), |d| {
+                    (d.line, d.column)
+                });
+
+            State::Error(format!("{}:{}: {}", point.0, point.1, message))
         }
         MdxSignal::Eof(message) => {
             if tokenizer.current == None {
@@ -62,23 +62,36 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [abbreviation][]
+//! *   [definition list][definition_list]
+//! *   [directive (container)][directive_container]
+//! *   [directive (leaf)][directive_leaf]
+//! *   [directive (text)][directive_text]
+//! *   [emoji][]
 //! *   [frontmatter][]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
 //! *   [gfm label start footnote][gfm_label_start_footnote]
 //! *   [gfm table][gfm_table]
 //! *   [gfm task list item check][gfm_task_list_item_check]
+//! *   [highlight][]
+//! *   [inline footnote][inline_footnote]
 //! *   [mdx esm][mdx_esm]
 //! *   [mdx expression (flow)][mdx_expression_flow]
 //! *   [mdx expression (text)][mdx_expression_text]
 //! *   [mdx jsx (flow)][mdx_jsx_flow]
 //! *   [mdx jsx (text)][mdx_jsx_text]
+//! *   [subscript][]
+//! *   [superscript][]
+//! *   [wikilink][]
 //!
 //! There are also several small subroutines typically used in different places:
 //!
+//! *   [attributes][partial_attributes]
 //! *   [bom][partial_bom]
 //! *   [data][partial_data]
 //! *   [destination][partial_destination]
+//! *   [directive][partial_directive]
 //! *   [label][partial_label]
 //! *   [mdx expression][partial_mdx_expression]
 //! *   [mdx jsx][partial_mdx_jsx]
@@ -148,6 +161,7 @@
 //!
 //! [bnf]: http://trevorjim.com/a-specification-for-markdown/
 
+pub mod abbreviation;
 pub mod attention;
 pub mod autolink;
 pub mod blank_line;
@@ -157,7 +171,12 @@ pub mod character_reference;
 pub mod code_indented;
 pub mod content;
 pub mod definition;
+pub mod definition_list;
+pub mod directive_container;
+pub mod directive_leaf;
+pub mod directive_text;
 pub mod document;
+pub mod emoji;
 pub mod flow;
 pub mod frontmatter;
 pub mod gfm_autolink_literal;
@@ -168,8 +187,10 @@ pub mod gfm_task_list_item_check;
 pub mod hard_break_escape;
 pub mod heading_atx;
 pub mod heading_setext;
+pub mod highlight;
 pub mod html_flow;
 pub mod html_text;
+pub mod inline_footnote;
 pub mod label_end;
 pub mod label_start_image;
 pub mod label_start_link;
@@ -180,9 +201,11 @@ pub mod mdx_expression_text;
 pub mod mdx_jsx_flow;
 pub mod mdx_jsx_text;
 pub mod paragraph;
+pub mod partial_attributes;
 pub mod partial_bom;
 pub mod partial_data;
 pub mod partial_destination;
+pub mod partial_directive;
 pub mod partial_label;
 pub mod partial_mdx_expression;
 pub mod partial_mdx_jsx;
@@ -194,5 +217,8 @@ pub mod partial_whitespace;
 pub mod raw_flow;
 pub mod raw_text;
 pub mod string;
+pub mod subscript;
+pub mod superscript;
 pub mod text;
 pub mod thematic_break;
+pub mod wikilink;
@@ -0,0 +1,334 @@
+//! Attributes occur in [directive][partial_directive].
+//!
+//! ## Grammar
+//!
+//! Attributes form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! attributes ::= '{' *attributes_whitespace *(attribute *attributes_whitespace) '}'
+//! attribute ::= attribute_id | attribute_class | attribute_key
+//! attribute_id ::= '#' 1*attribute_shortcut_byte
+//! attribute_class ::= '.' 1*attribute_shortcut_byte
+//! attribute_key ::= attribute_name [*attributes_whitespace '=' *attributes_whitespace attribute_value]
+//! attribute_name ::= (ascii_alphabetic | ':' | '_') *(ascii_alphanumeric | '-' | '.' | ':' | '_')
+//! attribute_value ::= '"' *(line - '"') '"' | '\'' *(line - '\'') '\'' | 1*attribute_unquoted_byte
+//! attribute_shortcut_byte ::= attribute_unquoted_byte - '#' - '.'
+//! attribute_unquoted_byte ::= text - '"' - '\'' - '<' - '=' - '>' - '`' - '}'
+//! ; Restriction: `eol` is only allowed when turned on.
+//! attributes_whitespace ::= space_or_tab | eol
+//! ```
+//!
+//! `#b` is a shortcut for `id="b"` and `.c` for `class="c"`.
+//! Any number of them, and of other attributes, can be used, in any order.
+//! Attributes without initializer (`{b}`) and with empty quoted values
+//! (`{b=""}`) both have an empty value.
+//!
+//! Values are not interpreted as markdown, and there are no character escapes
+//! in them: a backslash is just a backslash.
+//! To include the quote itself in a quoted value, use the other quote
+//! (`{b='c"d'}`) or a [character reference][character_reference]
+//! (`{b="c&quot;d"}`).
+//! Character references are decoded when compiling, by
+//! [`directive_attributes`][directive_attributes].
+//! That is also where duplicates are handled: the last one wins, except for
+//! classes, which are joined together.
+//!
+//! When a quoted value is not closed before the end of the line, or when
+//! something else that is not allowed is found, the attributes do not form
+//! at all.
+//!
+//! The attributes, their markers, names, and values are given as
+//! [`DirectiveAttributes`][Name::DirectiveAttributes] and the events it
+//! contains, regardless of which construct uses this factory.
+//!
+//! ## References
+//!
+//! *   [`micromark-extension-directive/dev/lib/factory-attributes.js`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/factory-attributes.js)
+//!
+//! [partial_directive]: crate::construct::partial_directive
+//! [character_reference]: crate::construct::character_reference
+//! [directive_attributes]: crate::util::infer::directive_attributes
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::construct::partial_space_or_tab_eol::space_or_tab_eol;
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Attributes, optionally with line endings between them.
+pub fn attributes(tokenizer: &mut Tokenizer, eol: bool) -> StateName {
+    tokenizer.tokenize_state.attributes_eol = eol;
+    StateName::AttributesStart
+}
+
+/// Start of attributes.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///          ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'{'), "expected `{{`");
+    tokenizer.enter(Name::DirectiveAttributes);
+    tokenizer.enter(Name::DirectiveAttributesMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::DirectiveAttributesMarker);
+    State::Next(StateName::AttributesBetween)
+}
+
+/// In attributes, before an attribute or the closing marker.
+///
+/// ```markdown
+/// > | :a{b c}
+///        ^^ ^
+/// ```
+pub fn between(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b'\n' | b' ') => {
+            tokenizer.attempt(
+                State::Next(StateName::AttributesBetween),
+                State::Next(StateName::AttributesNok),
+            );
+            State::Retry(StateName::AttributesWhitespace)
+        }
+        Some(byte @ (b'#' | b'.')) => {
+            let name = if byte == b'#' {
+                Name::DirectiveAttributeIdMarker
+            } else {
+                Name::DirectiveAttributeClassMarker
+            };
+            tokenizer.enter(name.clone());
+            tokenizer.consume();
+            tokenizer.exit(name);
+            State::Next(StateName::AttributesShortcutStart)
+        }
+        Some(b':' | b'A'..=b'Z' | b'_' | b'a'..=b'z') => {
+            tokenizer.enter(Name::DirectiveAttributeName);
+            tokenizer.consume();
+            State::Next(StateName::AttributesNameInside)
+        }
+        Some(b'}') => {
+            tokenizer.enter(Name::DirectiveAttributesMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::DirectiveAttributesMarker);
+            tokenizer.exit(Name::DirectiveAttributes);
+            tokenizer.tokenize_state.attributes_eol = false;
+            State::Ok
+        }
+        _ => State::Retry(StateName::AttributesNok),
+    }
+}
+
+/// In attributes, at whitespace.
+///
+/// Line endings are only allowed when turned on with `eol`.
+///
+/// ```markdown
+/// > | :a{b c}
+///         ^
+/// ```
+pub fn whitespace(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.tokenize_state.attributes_eol {
+        State::Retry(space_or_tab_eol(tokenizer))
+    } else if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Nok
+    }
+}
+
+/// After id or class marker, at value.
+///
+/// ```markdown
+/// > | :a{#b .c}
+///         ^  ^
+/// ```
+pub fn shortcut_start(tokenizer: &mut Tokenizer) -> State {
+    if shortcut_byte(tokenizer.current) {
+        tokenizer.enter(Name::DirectiveAttributeValue);
+        tokenizer.consume();
+        State::Next(StateName::AttributesShortcutInside)
+    } else {
+        State::Retry(StateName::AttributesNok)
+    }
+}
+
+/// In id or class value.
+///
+/// ```markdown
+/// > | :a{#bc}
+///          ^
+/// ```
+pub fn shortcut_inside(tokenizer: &mut Tokenizer) -> State {
+    if shortcut_byte(tokenizer.current) {
+        tokenizer.consume();
+        State::Next(StateName::AttributesShortcutInside)
+    } else {
+        tokenizer.exit(Name::DirectiveAttributeValue);
+        State::Retry(StateName::AttributesBetween)
+    }
+}
+
+/// In attribute name.
+///
+/// ```markdown
+/// > | :a{bc=d}
+///         ^
+/// ```
+pub fn name_inside(tokenizer: &mut Tokenizer) -> State {
+    if let Some(b'-' | b'.' | b'0'..=b'9' | b':' | b'A'..=b'Z' | b'_' | b'a'..=b'z') =
+        tokenizer.current
+    {
+        tokenizer.consume();
+        State::Next(StateName::AttributesNameInside)
+    } else {
+        tokenizer.exit(Name::DirectiveAttributeName);
+        State::Retry(StateName::AttributesNameAfter)
+    }
+}
+
+/// After attribute name, at optional initializer.
+///
+/// ```markdown
+/// > | :a{b=c}
+///         ^
+/// > | :a{b c}
+///         ^
+/// ```
+pub fn name_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b'\n' | b' ') => {
+            tokenizer.attempt(
+                State::Next(StateName::AttributesNameAfter),
+                State::Next(StateName::AttributesNok),
+            );
+            State::Retry(StateName::AttributesWhitespace)
+        }
+        Some(b'=') => {
+            tokenizer.enter(Name::DirectiveAttributeInitializerMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::DirectiveAttributeInitializerMarker);
+            State::Next(StateName::AttributesValueBefore)
+        }
+        _ => State::Retry(StateName::AttributesBetween),
+    }
+}
+
+/// After initializer, at value.
+///
+/// ```markdown
+/// > | :a{b=c}
+///          ^
+/// ```
+pub fn value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b'\n' | b' ') => {
+            tokenizer.attempt(
+                State::Next(StateName::AttributesValueBefore),
+                State::Next(StateName::AttributesNok),
+            );
+            State::Retry(StateName::AttributesWhitespace)
+        }
+        Some(byte @ (b'"' | b'\'')) => {
+            tokenizer.tokenize_state.marker = byte;
+            tokenizer.enter(Name::DirectiveAttributeValueMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::DirectiveAttributeValueMarker);
+            State::Next(StateName::AttributesValueQuotedStart)
+        }
+        _ => {
+            if unquoted_byte(tokenizer.current) {
+                tokenizer.enter(Name::DirectiveAttributeValue);
+                tokenizer.consume();
+                State::Next(StateName::AttributesValueUnquoted)
+            } else {
+                State::Retry(StateName::AttributesNok)
+            }
+        }
+    }
+}
+
+/// In unquoted attribute value.
+///
+/// ```markdown
+/// > | :a{b=cd}
+///           ^
+/// ```
+pub fn value_unquoted(tokenizer: &mut Tokenizer) -> State {
+    if unquoted_byte(tokenizer.current) {
+        tokenizer.consume();
+        State::Next(StateName::AttributesValueUnquoted)
+    } else {
+        tokenizer.exit(Name::DirectiveAttributeValue);
+        State::Retry(StateName::AttributesBetween)
+    }
+}
+
+/// After opening quote of attribute value, at value or closing quote.
+///
+/// ```markdown
+/// > | :a{b="c"}
+///           ^ ^
+/// ```
+pub fn value_quoted_start(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Retry(StateName::AttributesNok),
+        Some(byte) if byte == tokenizer.tokenize_state.marker => {
+            tokenizer.tokenize_state.marker = 0;
+            tokenizer.enter(Name::DirectiveAttributeValueMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::DirectiveAttributeValueMarker);
+            State::Next(StateName::AttributesBetween)
+        }
+        _ => {
+            tokenizer.enter(Name::DirectiveAttributeValue);
+            State::Retry(StateName::AttributesValueQuoted)
+        }
+    }
+}
+
+/// In quoted attribute value.
+///
+/// ```markdown
+/// > | :a{b="c"}
+///           ^
+/// ```
+pub fn value_quoted(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Retry(StateName::AttributesNok),
+        Some(byte) if byte == tokenizer.tokenize_state.marker => {
+            tokenizer.exit(Name::DirectiveAttributeValue);
+            State::Retry(StateName::AttributesValueQuotedStart)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::AttributesValueQuoted)
+        }
+    }
+}
+
+/// In attributes, on something disallowed.
+///
+/// ```markdown
+/// > | :a{b="c
+///            ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.attributes_eol = false;
+    tokenizer.tokenize_state.marker = 0;
+    State::Nok
+}
+
+/// Check if a byte can be in an unquoted attribute value.
+fn unquoted_byte(byte: Option<u8>) -> bool {
+    !matches!(
+        byte,
+        None | Some(b'\t' | b'\n' | b' ' | b'"' | b'\'' | b'<' | b'=' | b'>' | b'`' | b'}')
+    )
+}
+
+/// Check if a byte can be in an id or class value.
+fn shortcut_byte(byte: Option<u8>) -> bool {
+    unquoted_byte(byte) && !matches!(byte, Some(b'#' | b'.'))
+}
@@ -26,7 +26,7 @@ use crate::state::{Name as StateName, State};
 use crate::tokenizer::Tokenizer;
 
 /// Bytes of a BOM.
-const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+pub const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
 
 /// Before BOM.
 ///
@@ -54,6 +54,9 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
         tokenizer.tokenize_state.size += 1;
         tokenizer.consume();
         if tokenizer.tokenize_state.size == BOM.len() {
+            // The BOM is not part of the content: what follows it starts at
+            // column 1, such as for tab stops and for MDX ESM.
+            tokenizer.point.column = 1;
             tokenizer.exit(Name::ByteOrderMark);
             tokenizer.tokenize_state.size = 0;
             State::Ok
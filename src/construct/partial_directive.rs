@@ -0,0 +1,305 @@
+//! Directive occurs in [directive (container)][directive_container],
+//! [directive (leaf)][directive_leaf], and [directive (text)][directive_text].
+//!
+//! ## Grammar
+//!
+//! Directive forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! directive ::= directive_name [directive_label] [directive_attributes]
+//!
+//! ; Restriction: the last byte cannot be `-` or `_`.
+//! directive_name ::= ascii_alphabetic *(ascii_alphanumeric | '-' | '_')
+//!
+//! ; Restriction: maximum `999` codes allowed between brackets.
+//! ; Restriction: brackets in `directive_label_byte` must be balanced.
+//! ; Restriction: `eol` is only allowed in directive (text).
+//! directive_label ::= '[' *(directive_label_byte | directive_label_escape) ']'
+//! directive_label_byte ::= byte - '\\'
+//! directive_label_escape ::= '\\' ['[' | '\\' | ']']
+//!
+//! ; See the attributes factory for the grammar.
+//! ; Restriction: `eol` is only allowed in directive (text).
+//! directive_attributes ::= attributes
+//! ```
+//!
+//! The label is optional.
+//! When there is an opening bracket, but no valid label follows it, the
+//! directive stops right after its name.
+//! The same goes for attributes.
+//! Whether that is fine, depends on what comes after the directive: directive
+//! (text) is fine with anything, but directive (leaf) and the opening fence of
+//! directive (container) must end at a line ending.
+//!
+//! The label is interpreted as the [text][] content type.
+//! That means that [character escapes][character_escape],
+//! [character references][character_reference], and other phrasing
+//! constructs such as [attention][] are allowed.
+//!
+//! The attributes are parsed by the [attributes][partial_attributes] factory,
+//! which also describes how their values are interpreted.
+//!
+//! ## References
+//!
+//! *   [`micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive)
+//! *   [Generic directives/plugins syntax](https://talk.commonmark.org/t/generic-directives-plugins-syntax/444)
+//!
+//! [directive_container]: crate::construct::directive_container
+//! [directive_leaf]: crate::construct::directive_leaf
+//! [directive_text]: crate::construct::directive_text
+//! [text]: crate::construct::text
+//! [attention]: crate::construct::attention
+//! [character_escape]: crate::construct::character_escape
+//! [character_reference]: crate::construct::character_reference
+//! [partial_attributes]: crate::construct::partial_attributes
+
+use crate::construct::partial_attributes::attributes;
+use crate::construct::partial_space_or_tab_eol::{space_or_tab_eol_with_options, Options};
+use crate::event::{Content, Link, Name};
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::link;
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::LINK_REFERENCE_SIZE_MAX;
+
+/// Start of directive, at the name.
+///
+/// The kind of directive (`DirectiveContainer`, `DirectiveLeaf`, or
+/// `DirectiveText`) must be in `tokenize_state.token_1`.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///      ^
+/// ```
+pub fn name_start(tokenizer: &mut Tokenizer) -> State {
+    if let Some(b'A'..=b'Z' | b'a'..=b'z') = tokenizer.current {
+        tokenizer.enter(Name::DirectiveName);
+        tokenizer.consume();
+        State::Next(StateName::DirectiveNameInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In name.
+///
+/// ```markdown
+/// > | :ab[c]{d}
+///       ^
+/// ```
+pub fn name_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z') => {
+            tokenizer.consume();
+            State::Next(StateName::DirectiveNameInside)
+        }
+        _ => {
+            if matches!(tokenizer.previous, Some(b'-' | b'_')) {
+                State::Nok
+            } else {
+                tokenizer.exit(Name::DirectiveName);
+                State::Retry(StateName::DirectiveLabelBefore)
+            }
+        }
+    }
+}
+
+/// After name, at optional label.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///       ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveAttributesBefore),
+            State::Next(StateName::DirectiveAttributesBefore),
+        );
+        State::Retry(StateName::DirectiveLabelStart)
+    } else {
+        State::Retry(StateName::DirectiveAttributesBefore)
+    }
+}
+
+/// Start of label.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///       ^
+/// ```
+pub fn label_start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'['), "expected `[`");
+    tokenizer.enter(Name::DirectiveLabel);
+    tokenizer.enter(Name::DirectiveLabelMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::DirectiveLabelMarker);
+    State::Next(StateName::DirectiveLabelAtBreak)
+}
+
+/// In label, at something, before something else.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///        ^
+/// ```
+pub fn label_at_break(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.tokenize_state.size > LINK_REFERENCE_SIZE_MAX || tokenizer.current.is_none() {
+        return State::Retry(StateName::DirectiveLabelNok);
+    }
+
+    // Closing bracket, of the label itself.
+    if tokenizer.current == Some(b']') && tokenizer.tokenize_state.size_b == 0 {
+        if tokenizer.tokenize_state.seen {
+            tokenizer.exit(Name::DirectiveLabelString);
+        }
+
+        tokenizer.enter(Name::DirectiveLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DirectiveLabelMarker);
+        tokenizer.exit(Name::DirectiveLabel);
+        tokenizer.tokenize_state.connect = false;
+        tokenizer.tokenize_state.seen = false;
+        tokenizer.tokenize_state.size = 0;
+        return State::Ok;
+    }
+
+    if !tokenizer.tokenize_state.seen {
+        tokenizer.enter(Name::DirectiveLabelString);
+        tokenizer.tokenize_state.seen = true;
+    }
+
+    if tokenizer.current == Some(b'\n') {
+        if tokenizer.tokenize_state.token_1 == Name::DirectiveText {
+            tokenizer.attempt(
+                State::Next(StateName::DirectiveLabelEolAfter),
+                State::Next(StateName::DirectiveLabelNok),
+            );
+            State::Retry(space_or_tab_eol_with_options(
+                tokenizer,
+                Options {
+                    content: Some(Content::Text),
+                    connect: tokenizer.tokenize_state.connect,
+                },
+            ))
+        } else {
+            State::Retry(StateName::DirectiveLabelNok)
+        }
+    } else {
+        tokenizer.enter_link(
+            Name::Data,
+            Link {
+                previous: None,
+                next: None,
+                content: Content::Text,
+            },
+        );
+
+        if tokenizer.tokenize_state.connect {
+            let index = tokenizer.events.len() - 1;
+            link(&mut tokenizer.events, index);
+        } else {
+            tokenizer.tokenize_state.connect = true;
+        }
+
+        State::Retry(StateName::DirectiveLabelInside)
+    }
+}
+
+/// In label, after whitespace.
+///
+/// ```markdown
+///   | :a[b␊
+/// > | c]
+///     ^
+/// ```
+pub fn label_eol_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.connect = true;
+    State::Retry(StateName::DirectiveLabelAtBreak)
+}
+
+/// In label, on something disallowed.
+///
+/// ```markdown
+/// > | :a[b
+///         ^
+/// ```
+pub fn label_nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.connect = false;
+    tokenizer.tokenize_state.seen = false;
+    tokenizer.tokenize_state.size = 0;
+    tokenizer.tokenize_state.size_b = 0;
+    State::Nok
+}
+
+/// In label, in text.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///        ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::DirectiveLabelAtBreak)
+        }
+        Some(b']') if tokenizer.tokenize_state.size_b == 0 => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::DirectiveLabelAtBreak)
+        }
+        Some(byte) => {
+            if tokenizer.tokenize_state.size > LINK_REFERENCE_SIZE_MAX {
+                tokenizer.exit(Name::Data);
+                State::Retry(StateName::DirectiveLabelAtBreak)
+            } else {
+                if byte == b'[' {
+                    tokenizer.tokenize_state.size_b += 1;
+                } else if byte == b']' {
+                    tokenizer.tokenize_state.size_b -= 1;
+                }
+
+                tokenizer.consume();
+                tokenizer.tokenize_state.size += 1;
+                State::Next(if byte == b'\\' {
+                    StateName::DirectiveLabelEscape
+                } else {
+                    StateName::DirectiveLabelInside
+                })
+            }
+        }
+    }
+}
+
+/// After `\`, at a special character.
+///
+/// ```markdown
+/// > | :a[b\]c]
+///          ^
+/// ```
+pub fn label_escape(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'[' | b'\\' | b']') => {
+            tokenizer.consume();
+            tokenizer.tokenize_state.size += 1;
+            State::Next(StateName::DirectiveLabelInside)
+        }
+        _ => State::Retry(StateName::DirectiveLabelInside),
+    }
+}
+
+/// After name or label, at optional attributes.
+///
+/// ```markdown
+/// > | :a[b]{c}
+///          ^
+/// ```
+pub fn attributes_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'{') {
+        tokenizer.attempt(State::Ok, State::Ok);
+        let eol = tokenizer.tokenize_state.token_1 == Name::DirectiveText;
+        State::Retry(attributes(tokenizer, eol))
+    } else {
+        State::Ok
+    }
+}
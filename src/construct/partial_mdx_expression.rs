@@ -204,6 +204,7 @@ fn parse_expression(tokenizer: &mut Tokenizer, parse: &MdxExpressionParse) -> St
     let result = collect(
         &tokenizer.events,
         tokenizer.parse_state.bytes,
+        tokenizer.parse_state.options.tab_size,
         tokenizer.tokenize_state.start,
         &[Name::MdxExpressionData, Name::LineEnding],
         &[],
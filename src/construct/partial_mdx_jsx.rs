@@ -68,6 +68,10 @@
 //! information about where it happened, what occurred, and what was expected
 //! instead.
 //!
+//! The exception is a `<` followed by whitespace or the end of the file,
+//! which is not a tag but text, unless [`mdx_jsx_strict`][mdx_jsx_strict] is
+//! on, in which case it crashes too.
+//!
 //! ## Tokens
 //!
 //! *   [`LineEnding`][Name::LineEnding]
@@ -159,6 +163,7 @@
 //!
 //! [mdx_jsx_flow]: crate::construct::mdx_jsx_flow
 //! [mdx_jsx_text]: crate::construct::mdx_jsx_text
+//! [mdx_jsx_strict]: crate::ParseOptions::mdx_jsx_strict
 //! [interleaving]: https://mdxjs.com/docs/what-is-mdx/#interleaving
 
 use crate::event::Name;
@@ -198,8 +203,16 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 pub fn start_after(tokenizer: &mut Tokenizer) -> State {
     // Deviate from JSX, which allows arbitrary whitespace.
     // See: <https://github.com/micromark/micromark-extension-mdx-jsx/issues/7>.
-    if let Some(b'\t' | b'\n' | b' ') = tokenizer.current {
-        State::Nok
+    if let None | Some(b'\t' | b'\n' | b' ') = tokenizer.current {
+        if tokenizer.parse_state.options.mdx_jsx_strict {
+            crash(
+                tokenizer,
+                "after `<`",
+                "a character that can start a name, such as a letter, `$`, or `_` (note: to create a less than sign in MDX, use `&lt;` or `{'<'}`)",
+            )
+        } else {
+            State::Nok
+        }
     } else {
         tokenizer.attempt(State::Next(StateName::MdxJsxNameBefore), State::Nok);
         State::Retry(StateName::MdxJsxEsWhitespaceStart)
@@ -1038,8 +1051,9 @@ pub fn es_whitespace_start(tokenizer: &mut Tokenizer) -> State {
             State::Next(StateName::MdxJsxEsWhitespaceEolAfter)
         }
         _ => {
-            if kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
-                == CharacterKind::Whitespace
+            if tokenizer.current.is_some()
+                && kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
+                    == CharacterKind::Whitespace
             {
                 tokenizer.enter(Name::MdxJsxEsWhitespace);
                 State::Retry(StateName::MdxJsxEsWhitespaceInside)
@@ -1071,8 +1085,9 @@ pub fn es_whitespace_inside(tokenizer: &mut Tokenizer) -> State {
             State::Next(StateName::MdxJsxEsWhitespaceInside)
         }
         _ => {
-            if kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
-                == CharacterKind::Whitespace
+            if tokenizer.current.is_some()
+                && kind_after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
+                    == CharacterKind::Whitespace
             {
                 tokenizer.consume();
                 State::Next(StateName::MdxJsxEsWhitespaceInside)
@@ -10,17 +10,54 @@
 //! ; Restriction: markers must match (in case of `(` with `)`).
 //! title ::= marker *(title_byte | title_escape) marker
 //! title_byte ::= code - '\\' - marker
-//! title_escape ::= '\\' ['\\' | marker]
+//! title_escape ::= '\\' ['\\' | '(' | marker]
 //! marker ::= '"' | '\'' | '('
+//! ; Restriction: only with `title_smart_quotes`.
+//! marker ::= '“' | '‘'
 //! ```
 //!
 //! Titles can be double quoted (`"a"`), single quoted (`'a'`), or
 //! parenthesized (`(a)`).
+//! Which one was used is not stored on events, but can be inferred from the
+//! opening marker with [`TitleKind`][TitleKind].
 //!
 //! Titles can contain line endings and whitespace, but they are not allowed to
 //! contain blank lines.
 //! They are allowed to be blank themselves.
 //!
+//! > 👉 **Note**: blank lines cannot be allowed in titles with an option.
+//! > Titles are only parsed in [content][] and [text][], which are both
+//! > split at blank lines by the flow tokenizer before this factory runs:
+//! > a blank line ends the paragraph or definition, never the title.
+//!
+//! When [`title_smart_quotes`][title_smart_quotes] is on, titles can also be
+//! in curly quotes (`“a”`, `‘a’`).
+//! Those only close with the matching curly quote: `“a"` is not closed.
+//! Curly quotes cannot be escaped, so `“a\”` is closed, with `a\` as its
+//! string content.
+//!
+//! When [`title_balance_parens`][title_balance_parens] is on, parenthesized
+//! titles can contain balanced unescaped parens (`(a (b) c)`): only the `)`
+//! that matches the opening marker closes the title.
+//! Escaped parens (`\(`, `\)`) do not count.
+//! This does not affect double or single quoted titles.
+//!
+//! When [`warning_sink`][warning_sink] is set, titles that are opened but not
+//! closed are reported.
+//!
+//! When [`title_validate`][title_validate] is set, it is called with each
+//! character in titles, except for the line endings and whitespace at the
+//! start of lines.
+//! When it returns `false`, the title does not form, which typically means
+//! the whole definition or resource does not form either: titles are never
+//! cut short.
+//!
+//! When [`title_size_max`][title_size_max] is set, titles whose string content
+//! is larger than that many bytes are not allowed.
+//!
+//! Line endings and the whitespace around them are kept in titles.
+//! To compare titles, use [`normalize_title`][normalize_title].
+//!
 //! The title is interpreted as the [string][] content type.
 //! That means that [character escapes][character_escape] and
 //! [character references][character_reference] are allowed.
@@ -30,16 +67,26 @@
 //! *   [`micromark-factory-title/index.js` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark-factory-title/dev/index.js)
 //!
 //! [definition]: crate::construct::definition
+//! [content]: crate::construct::content
+//! [text]: crate::construct::text
 //! [string]: crate::construct::string
 //! [character_escape]: crate::construct::character_escape
 //! [character_reference]: crate::construct::character_reference
 //! [label_end]: crate::construct::label_end
+//! [TitleKind]: crate::event::TitleKind
+//! [normalize_title]: crate::util::normalize_title::normalize_title
+//! [title_balance_parens]: crate::ParseOptions::title_balance_parens
+//! [title_size_max]: crate::ParseOptions::title_size_max
+//! [title_smart_quotes]: crate::ParseOptions::title_smart_quotes
+//! [title_validate]: crate::ParseOptions::title_validate
+//! [warning_sink]: crate::ParseOptions::warning_sink
 
 use crate::construct::partial_space_or_tab_eol::{space_or_tab_eol_with_options, Options};
-use crate::event::{Content, Link, Name};
+use crate::event::{Content, Kind, Link, Name, TitleKind};
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::link;
 use crate::tokenizer::Tokenizer;
+use crate::util::{char::after_index, warning::Kind as WarningKind};
 
 /// Start of title.
 ///
@@ -48,17 +95,47 @@ use crate::tokenizer::Tokenizer;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    match tokenizer.current {
-        Some(b'"' | b'\'' | b'(') => {
-            let marker = tokenizer.current.unwrap();
-            tokenizer.tokenize_state.marker = if marker == b'(' { b')' } else { marker };
-            tokenizer.enter(tokenizer.tokenize_state.token_1.clone());
-            tokenizer.enter(tokenizer.tokenize_state.token_2.clone());
-            tokenizer.consume();
-            tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
-            State::Next(StateName::TitleBegin)
+    let kind = match tokenizer.current {
+        Some(b'"' | b'\'' | b'(') => TitleKind::from_char(tokenizer.current.unwrap() as char),
+        // `“` and `‘` are `E2 80 9C` and `E2 80 98` in UTF-8.
+        Some(0xE2) if tokenizer.parse_state.options.title_smart_quotes => {
+            after_index(tokenizer.parse_state.bytes, tokenizer.point.index)
+                .and_then(TitleKind::from_char)
         }
-        _ => State::Nok,
+        _ => None,
+    };
+
+    if let Some(kind) = kind {
+        let mut buffer = [0; 4];
+        let closing = kind.closing().encode_utf8(&mut buffer).as_bytes();
+        tokenizer.tokenize_state.marker = closing[0];
+        tokenizer.tokenize_state.marker_b = if closing.len() > 1 {
+            closing[closing.len() - 1]
+        } else {
+            0
+        };
+        tokenizer.enter(tokenizer.tokenize_state.token_1.clone());
+        tokenizer.enter(tokenizer.tokenize_state.token_2.clone());
+        tokenizer.consume();
+        State::Next(StateName::TitleOpenMarker)
+    } else {
+        State::Nok
+    }
+}
+
+/// In opening marker, after its first byte.
+///
+/// ```markdown
+/// > | “a”
+///     ^
+/// ```
+pub fn open_marker(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(0x80..=0xBF)) {
+        tokenizer.consume();
+        State::Next(StateName::TitleOpenMarker)
+    } else {
+        tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
+        State::Retry(StateName::TitleBegin)
     }
 }
 
@@ -71,17 +148,35 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///      ^
 /// ```
 pub fn begin(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+    if at_closing_marker(tokenizer) {
         tokenizer.enter(tokenizer.tokenize_state.token_2.clone());
         tokenizer.consume();
+        State::Next(StateName::TitleCloseMarker)
+    } else {
+        tokenizer.enter(tokenizer.tokenize_state.token_3.clone());
+        State::Retry(StateName::TitleAtBreak)
+    }
+}
+
+/// In closing marker, after its first byte.
+///
+/// ```markdown
+/// > | “a”
+///       ^
+/// ```
+pub fn close_marker(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(0x80..=0xBF)) {
+        tokenizer.consume();
+        State::Next(StateName::TitleCloseMarker)
+    } else {
         tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
         tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
         tokenizer.tokenize_state.marker = 0;
+        tokenizer.tokenize_state.marker_b = 0;
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.tokenize_state.size_b = 0;
         tokenizer.tokenize_state.connect = false;
         State::Ok
-    } else {
-        tokenizer.enter(tokenizer.tokenize_state.token_3.clone());
-        State::Retry(StateName::TitleAtBreak)
     }
 }
 
@@ -93,7 +188,7 @@ pub fn begin(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn at_break(tokenizer: &mut Tokenizer) -> State {
     if let Some(byte) = tokenizer.current {
-        if byte == tokenizer.tokenize_state.marker {
+        if at_closing_marker(tokenizer) {
             tokenizer.exit(tokenizer.tokenize_state.token_3.clone());
             State::Retry(StateName::TitleBegin)
         } else if byte == b'\n' {
@@ -128,6 +223,10 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
             State::Retry(StateName::TitleInside)
         }
     } else {
+        if tokenizer.parse_state.options.warning_sink.is_some() {
+            warn_unclosed(tokenizer);
+        }
+
         State::Retry(StateName::TitleNok)
     }
 }
@@ -152,6 +251,9 @@ pub fn after_eol(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn nok(tokenizer: &mut Tokenizer) -> State {
     tokenizer.tokenize_state.marker = 0;
+    tokenizer.tokenize_state.marker_b = 0;
+    tokenizer.tokenize_state.size = 0;
+    tokenizer.tokenize_state.size_b = 0;
     tokenizer.tokenize_state.connect = false;
     State::Nok
 }
@@ -163,18 +265,30 @@ pub fn nok(tokenizer: &mut Tokenizer) -> State {
 ///      ^
 /// ```
 pub fn inside(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker)
-        || matches!(tokenizer.current, None | Some(b'\n'))
-    {
+    if matches!(tokenizer.current, None | Some(b'\n')) || at_closing_marker(tokenizer) {
         tokenizer.exit(Name::Data);
         State::Retry(StateName::TitleAtBreak)
+    } else if too_big(tokenizer) || !valid(tokenizer) {
+        State::Retry(StateName::TitleNok)
     } else {
         let name = if tokenizer.current == Some(b'\\') {
             StateName::TitleEscape
         } else {
             StateName::TitleInside
         };
+
+        if tokenizer.tokenize_state.marker == b')'
+            && tokenizer.parse_state.options.title_balance_parens
+        {
+            if tokenizer.current == Some(b'(') {
+                tokenizer.tokenize_state.size_b += 1;
+            } else if tokenizer.current == Some(b')') {
+                tokenizer.tokenize_state.size_b -= 1;
+            }
+        }
+
         tokenizer.consume();
+        tokenizer.tokenize_state.size += 1;
         State::Next(name)
     }
 }
@@ -187,10 +301,70 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn escape(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        Some(b'"' | b'\'' | b')') => {
-            tokenizer.consume();
-            State::Next(StateName::TitleInside)
+        Some(b'"' | b'\'' | b'(' | b')') => {
+            if too_big(tokenizer) || !valid(tokenizer) {
+                State::Retry(StateName::TitleNok)
+            } else {
+                tokenizer.consume();
+                tokenizer.tokenize_state.size += 1;
+                State::Next(StateName::TitleInside)
+            }
         }
         _ => State::Retry(StateName::TitleInside),
     }
 }
+
+/// Warn about a title that is not closed, at its opening marker.
+fn warn_unclosed(tokenizer: &Tokenizer) {
+    let mut index = tokenizer.events.len();
+
+    while index > 0 {
+        index -= 1;
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Enter && event.name == tokenizer.tokenize_state.token_2 {
+            let kind = after_index(tokenizer.parse_state.bytes, event.point.index)
+                .and_then(TitleKind::from_char)
+                .expect("expected title marker");
+            tokenizer.warn(WarningKind::TitleUnclosed(kind), &event.point);
+            break;
+        }
+    }
+}
+
+/// Check whether the current byte starts the closing marker.
+///
+/// Nested parens (with `title_balance_parens`) must be closed first.
+fn at_closing_marker(tokenizer: &Tokenizer) -> bool {
+    let state = &tokenizer.tokenize_state;
+
+    tokenizer.current == Some(state.marker)
+        && state.size_b == 0
+        && (state.marker_b == 0
+            // `”` and `’` are `E2 80 9D` and `E2 80 99` in UTF-8.
+            || tokenizer.parse_state.bytes[tokenizer.point.index..]
+                .starts_with(&[state.marker, 0x80, state.marker_b]))
+}
+
+/// Check whether the current character is allowed by `title_validate`.
+fn valid(tokenizer: &Tokenizer) -> bool {
+    if let Some(validate) = &tokenizer.parse_state.options.title_validate {
+        // Only check the first byte of characters: not virtual spaces or
+        // UTF-8 continuation bytes.
+        tokenizer.point.vs > 0
+            || matches!(tokenizer.current, Some(0x80..=0xBF))
+            || validate(after_index(tokenizer.parse_state.bytes, tokenizer.point.index).unwrap())
+    } else {
+        true
+    }
+}
+
+/// Check whether the title would grow past `title_size_max` if the current
+/// byte was consumed.
+fn too_big(tokenizer: &Tokenizer) -> bool {
+    if let Some(max) = tokenizer.parse_state.options.title_size_max {
+        tokenizer.tokenize_state.size >= max
+    } else {
+        false
+    }
+}
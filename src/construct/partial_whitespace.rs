@@ -57,7 +57,7 @@
 //! [hard_break_prefix_size_min]: crate::util::constant::HARD_BREAK_PREFIX_SIZE_MIN
 //! [html]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-br-element
 
-use crate::event::{Event, Kind, Name};
+use crate::event::{Event, Kind, Name, Point};
 use crate::tokenizer::Tokenizer;
 use crate::util::{
     constant::HARD_BREAK_PREFIX_SIZE_MIN,
@@ -72,7 +72,12 @@ pub fn resolve_whitespace(tokenizer: &mut Tokenizer, hard_break: bool, trim_whol
     while index < tokenizer.events.len() {
         let event = &tokenizer.events[index];
 
-        if event.kind == Kind::Exit && event.name == Name::Data {
+        // Linked data (such as the label of a directive) is subcontent,
+        // which is trimmed when it is tokenized itself.
+        if event.kind == Kind::Exit
+            && event.name == Name::Data
+            && tokenizer.events[index - 1].link.is_none()
+        {
             let trim_start = (trim_whole && index == 1)
                 || (index > 1 && tokenizer.events[index - 2].name == Name::LineEnding);
             let trim_end = (trim_whole && index == tokenizer.events.len() - 1)
@@ -99,6 +104,7 @@ fn trim_data(
     let mut slice = Slice::from_position(
         tokenizer.parse_state.bytes,
         &Position::from_exit_event(&tokenizer.events, exit_index),
+        tokenizer.parse_state.options.tab_size,
     );
 
     if trim_end {
@@ -135,10 +141,12 @@ fn trim_data(
 
         if diff > 0 || slice.after > 0 {
             let exit_point = tokenizer.events[exit_index].point.clone();
-            let mut enter_point = exit_point.clone();
-            enter_point.index -= diff;
-            enter_point.column -= diff;
-            enter_point.vs = 0;
+            let enter_point = point_in(
+                tokenizer,
+                &tokenizer.events[exit_index - 1].point,
+                slice.before,
+                exit_point.index - diff,
+            );
 
             tokenizer.map.add(
                 exit_index + 1,
@@ -183,10 +191,8 @@ fn trim_data(
 
         if index > 0 || slice.before > 0 {
             let enter_point = tokenizer.events[exit_index - 1].point.clone();
-            let mut exit_point = enter_point.clone();
-            exit_point.index += index;
-            exit_point.column += index;
-            exit_point.vs = 0;
+            let bytes_index = enter_point.index + usize::from(slice.before > 0) + index;
+            let exit_point = point_in(tokenizer, &enter_point, slice.before, bytes_index);
 
             tokenizer.map.add(
                 exit_index - 1,
@@ -211,3 +217,29 @@ fn trim_data(
         }
     }
 }
+
+/// Get the point at `index`, from the `start` of data that has `before`
+/// virtual spaces.
+///
+/// Tabs are expanded to the next tab stop.
+fn point_in(tokenizer: &Tokenizer, start: &Point, before: usize, index: usize) -> Point {
+    let mut point = start.clone();
+
+    // When in a tab (such as after a container prefix that took part of it),
+    // the slice starts after that tab.
+    if before > 0 {
+        point.index += 1;
+        point.column += before;
+        point.vs = 0;
+    }
+
+    if index > point.index {
+        point = point.shift_to(
+            tokenizer.parse_state.bytes,
+            tokenizer.parse_state.options.tab_size,
+            index,
+        );
+    }
+
+    point
+}
@@ -46,6 +46,11 @@
 //! The optional `meta` part is ignored: it is not used when parsing or
 //! rendering.
 //!
+//! Together, `info` and `meta` form the info string.
+//! In code (fenced), the whole info string is also wrapped in a token, so
+//! that tools can get it as it was written (but with escapes and references
+//! decoded), regardless of how it is split.
+//!
 //! The optional `info` part is used and is expected to specify the programming
 //! language that the content is in.
 //! Which value it holds depends on what your syntax highlighter supports, if
@@ -109,6 +114,7 @@
 //! *   [`CodeFenced`][Name::CodeFenced]
 //! *   [`CodeFencedFence`][Name::CodeFencedFence]
 //! *   [`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo]
+//! *   [`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString]
 //! *   [`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta]
 //! *   [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence]
 //! *   [`CodeFlowChunk`][Name::CodeFlowChunk]
@@ -200,6 +206,7 @@ pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
             prefix = Slice::from_position(
                 tokenizer.parse_state.bytes,
                 &Position::from_exit_event(&tokenizer.events, tokenizer.events.len() - 1),
+                tokenizer.parse_state.options.tab_size,
             )
             .len();
         }
@@ -310,6 +317,7 @@ pub fn info_before(tokenizer: &mut Tokenizer) -> State {
             State::Retry(StateName::NonLazyContinuationStart)
         }
         _ => {
+            tokenizer.enter(Name::CodeFencedFenceInfoString);
             tokenizer.enter(tokenizer.tokenize_state.token_4.clone());
             tokenizer.enter_link(
                 Name::Data,
@@ -337,6 +345,7 @@ pub fn info(tokenizer: &mut Tokenizer) -> State {
         None | Some(b'\n') => {
             tokenizer.exit(Name::Data);
             tokenizer.exit(tokenizer.tokenize_state.token_4.clone());
+            tokenizer.exit(Name::CodeFencedFenceInfoString);
             State::Retry(StateName::RawFlowInfoBefore)
         }
         Some(b'\t' | b' ') => {
@@ -379,7 +388,14 @@ pub fn info(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn meta_before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        None | Some(b'\n') => State::Retry(StateName::RawFlowInfoBefore),
+        None | Some(b'\n') => {
+            // Math (flow) has no info string token.
+            if tokenizer.tokenize_state.marker != b'$' {
+                tokenizer.exit(Name::CodeFencedFenceInfoString);
+            }
+
+            State::Retry(StateName::RawFlowInfoBefore)
+        }
         _ => {
             tokenizer.enter(tokenizer.tokenize_state.token_5.clone());
             tokenizer.enter_link(
@@ -408,6 +424,11 @@ pub fn meta(tokenizer: &mut Tokenizer) -> State {
         None | Some(b'\n') => {
             tokenizer.exit(Name::Data);
             tokenizer.exit(tokenizer.tokenize_state.token_5.clone());
+
+            if tokenizer.tokenize_state.marker != b'$' {
+                tokenizer.exit(Name::CodeFencedFenceInfoString);
+            }
+
             State::Retry(StateName::RawFlowInfoBefore)
         }
         Some(byte) => {
@@ -0,0 +1,99 @@
+//! Subscript occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Subscript sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! subscript_sequence ::= '~'
+//! ```
+//!
+//! Subscript is matched like [superscript][], but with tildes instead of
+//! carets: `H~2~O` is subscript, but `a ~ b` and `~a b~` are not.
+//!
+//! Tildes are also used by [GFM strikethrough][attention].
+//! A single tilde is tried as subscript first, and the rest as strikethrough.
+//! So, when subscript is turned on, `~a~` is subscript and `~~a~~` is
+//! strikethrough.
+//! A single tilde then never forms strikethrough, also when there is
+//! whitespace inside: `~a b~` is not subscript or strikethrough.
+//!
+//! This is the subscript syntax of Pandoc.
+//! Subscript is not [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Subscript relates to the `<sub>` element in HTML.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sub] in the HTML spec
+//! for more info.
+//!
+//! ## Tokens
+//!
+//! *   [`Subscript`][Name::Subscript]
+//! *   [`SubscriptSequence`][Name::SubscriptSequence]
+//! *   [`SubscriptText`][Name::SubscriptText]
+//!
+//! ## References
+//!
+//! *   [*Superscripts and subscripts* in Pandoc](https://pandoc.org/MANUAL.html#extension-superscript-subscript)
+//!
+//! [text]: crate::construct::text
+//! [attention]: crate::construct::attention
+//! [superscript]: crate::construct::superscript
+//! [html_sub]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
+
+use crate::construct::superscript::resolve_sequences;
+use crate::event::Name;
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+
+/// At start of subscript sequence.
+///
+/// ```markdown
+/// > | H~2~O
+///      ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.subscript && tokenizer.current == Some(b'~') {
+        tokenizer.enter(Name::SubscriptSequence);
+        tokenizer.consume();
+        State::Next(StateName::SubscriptAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After subscript sequence.
+///
+/// More tildes are attention (GFM strikethrough) instead.
+///
+/// ```markdown
+/// > | H~2~O
+///       ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'~') {
+        State::Nok
+    } else {
+        tokenizer.exit(Name::SubscriptSequence);
+        tokenizer.register_resolver(ResolveName::Subscript);
+        State::Ok
+    }
+}
+
+/// Resolve subscript sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    resolve_sequences(
+        tokenizer,
+        b'~',
+        &[
+            Name::Subscript,
+            Name::SubscriptSequence,
+            Name::SubscriptText,
+        ],
+    );
+    None
+}
@@ -0,0 +1,236 @@
+//! Superscript occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Superscript sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! superscript_sequence ::= '^'
+//! ```
+//!
+//! Like [attention][], sequences are parsed first and matched together later.
+//! Two sequences match when there is something between them, and when that
+//! something contains no whitespace or line endings.
+//! So, `x^2^` is superscript, but `a ^ b`, `^a b^`, and `^^` are not.
+//! Sequences next to another caret (such as in `^^a^^`) never match.
+//! Sequences that don’t match are turned into data.
+//!
+//! Because what is between sequences is parsed before they are matched,
+//! other constructs take precedence: in `` ^a`^` ``, the second caret is
+//! code, so there is no superscript.
+//!
+//! When [inline footnotes][inline_footnote] are turned on, `^[` starts an
+//! inline footnote instead.
+//!
+//! This is the superscript syntax of Pandoc.
+//! Superscript is not [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Superscript relates to the `<sup>` element in HTML.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sup] in the HTML spec
+//! for more info.
+//!
+//! ## Tokens
+//!
+//! *   [`Superscript`][Name::Superscript]
+//! *   [`SuperscriptSequence`][Name::SuperscriptSequence]
+//! *   [`SuperscriptText`][Name::SuperscriptText]
+//!
+//! ## References
+//!
+//! *   [*Superscripts and subscripts* in Pandoc](https://pandoc.org/MANUAL.html#extension-superscript-subscript)
+//!
+//! [text]: crate::construct::text
+//! [attention]: crate::construct::attention
+//! [inline_footnote]: crate::construct::inline_footnote
+//! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
+
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::char::{
+    after_index as char_after_index, before_index as char_before_index, classify, classify_opt,
+    Kind as CharacterKind,
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// At start of superscript sequence.
+///
+/// ```markdown
+/// > | x^2^
+///      ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.superscript && tokenizer.current == Some(b'^') {
+        tokenizer.enter(Name::SuperscriptSequence);
+        tokenizer.consume();
+        State::Next(StateName::SuperscriptAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After superscript sequence.
+///
+/// ```markdown
+/// > | x^2^
+///       ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'^') {
+        State::Nok
+    } else {
+        tokenizer.exit(Name::SuperscriptSequence);
+        tokenizer.register_resolver(ResolveName::Superscript);
+        State::Ok
+    }
+}
+
+/// Resolve superscript sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    resolve_sequences(
+        tokenizer,
+        b'^',
+        &[
+            Name::Superscript,
+            Name::SuperscriptSequence,
+            Name::SuperscriptText,
+        ],
+    );
+    None
+}
+
+/// Match sequences of `marker`, and wrap them in the group and text in
+/// `names` (group, sequence, text).
+///
+/// Also used for [subscript][crate::construct::subscript].
+pub fn resolve_sequences(tokenizer: &mut Tokenizer, marker: u8, names: &[Name; 3]) {
+    let [group_name, sequence_name, text_name] = names;
+    let bytes = tokenizer.parse_state.bytes;
+    // Balanced events we are in, so that sequences in, say, a link don’t
+    // match sequences outside of it.
+    let mut stack: Vec<usize> = vec![];
+    // Sequence that can still open: its index into events and its stack.
+    let mut opener: Option<(usize, Vec<usize>)> = None;
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        // Sequences are void, so this is always an enter, which is followed
+        // by its exit (skipped below).
+        if event.name == *sequence_name {
+            let start = event.point.index;
+            let end = tokenizer.events[index + 1].point.index;
+            let char_before = char_before_index(bytes, start);
+            let char_after = char_after_index(bytes, end);
+            let next_to_marker =
+                char_before == Some(marker as char) || char_after == Some(marker as char);
+            let open = !next_to_marker && classify_opt(char_after) != CharacterKind::Whitespace;
+            let close = !next_to_marker && classify_opt(char_before) != CharacterKind::Whitespace;
+            let mut matched = false;
+
+            if close {
+                if let Some((open_index, open_stack)) = opener.take() {
+                    let open_end = tokenizer.events[open_index + 1].point.index;
+
+                    if open_stack == stack
+                        && open_end < start
+                        && !String::from_utf8_lossy(&bytes[open_end..start])
+                            .chars()
+                            .any(|char| classify(char) == CharacterKind::Whitespace)
+                    {
+                        wrap(tokenizer, open_index, index, group_name, text_name);
+                        matched = true;
+                    } else {
+                        tokenizer.events[open_index].name = Name::Data;
+                        tokenizer.events[open_index + 1].name = Name::Data;
+                    }
+                }
+            }
+
+            if !matched {
+                if open {
+                    if let Some((open_index, _)) = opener.take() {
+                        tokenizer.events[open_index].name = Name::Data;
+                        tokenizer.events[open_index + 1].name = Name::Data;
+                    }
+
+                    opener = Some((index, stack.clone()));
+                } else {
+                    tokenizer.events[index].name = Name::Data;
+                    tokenizer.events[index + 1].name = Name::Data;
+                }
+            }
+
+            index += 1;
+        } else if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            stack.pop();
+        }
+
+        index += 1;
+    }
+
+    if let Some((open_index, _)) = opener {
+        tokenizer.events[open_index].name = Name::Data;
+        tokenizer.events[open_index + 1].name = Name::Data;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+}
+
+/// Wrap two sequences, and what is between them, in a group.
+fn wrap(tokenizer: &mut Tokenizer, open: usize, close: usize, group_name: &Name, text_name: &Name) {
+    let events = &tokenizer.events;
+    let open_enter = events[open].point.clone();
+    let open_exit = events[open + 1].point.clone();
+    let close_enter = events[close].point.clone();
+    let close_exit = events[close + 1].point.clone();
+
+    tokenizer.map.add(
+        open,
+        0,
+        vec![Event {
+            kind: Kind::Enter,
+            name: group_name.clone(),
+            point: open_enter,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        open + 2,
+        0,
+        vec![Event {
+            kind: Kind::Enter,
+            name: text_name.clone(),
+            point: open_exit,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        close,
+        0,
+        vec![Event {
+            kind: Kind::Exit,
+            name: text_name.clone(),
+            point: close_enter,
+            link: None,
+        }],
+    );
+    tokenizer.map.add(
+        close + 2,
+        0,
+        vec![Event {
+            kind: Kind::Exit,
+            name: group_name.clone(),
+            point: close_exit,
+            link: None,
+        }],
+    );
+}
@@ -10,20 +10,32 @@
 //! *   [Autolink][crate::construct::autolink]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
+//! *   [Directive (text)][crate::construct::directive_text]
 //! *   [Raw (text)][crate::construct::raw_text] (code (text), math (text))
 //! *   [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote]
 //! *   [GFM: Task list item check][crate::construct::gfm_task_list_item_check]
 //! *   [Hard break (escape)][crate::construct::hard_break_escape]
+//! *   [Highlight][crate::construct::highlight]
 //! *   [HTML (text)][crate::construct::html_text]
+//! *   [Inline footnote][crate::construct::inline_footnote]
 //! *   [Label start (image)][crate::construct::label_start_image]
 //! *   [Label start (link)][crate::construct::label_start_link]
 //! *   [Label end][crate::construct::label_end]
 //! *   [MDX: expression (text)][crate::construct::mdx_expression_text]
 //! *   [MDX: JSX (text)][crate::construct::mdx_jsx_text]
+//! *   [Subscript][crate::construct::subscript]
+//! *   [Superscript][crate::construct::superscript]
+//! *   [Wikilink][crate::construct::wikilink]
 //!
 //! > 👉 **Note**: for performance reasons, hard break (trailing) is formed by
 //! > [whitespace][crate::construct::partial_whitespace].
+//!
+//! > 👉 **Note**: occurrences of [abbreviations][crate::construct::abbreviation]
+//! > and [emoji][crate::construct::emoji] are found in data after everything
+//! > else in text is parsed.
 
+use crate::construct::abbreviation::resolve as resolve_abbreviation;
+use crate::construct::emoji::resolve as resolve_emoji;
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
@@ -32,23 +44,26 @@ use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 
 /// Characters that can start something in text.
-const MARKERS: [u8; 16] = [
+const MARKERS: [u8; 19] = [
     b'!',  // `label_start_image`
     b'$',  // `raw_text` (math (text))
     b'&',  // `character_reference`
     b'*',  // `attention` (emphasis, strong)
+    b':',  // `directive_text`
     b'<',  // `autolink`, `html_text`, `mdx_jsx_text`
+    b'=',  // `highlight`
     b'H',  // `gfm_autolink_literal` (`protocol` kind)
     b'W',  // `gfm_autolink_literal` (`www.` kind)
-    b'[',  // `label_start_link`
+    b'[',  // `label_start_link`, `wikilink`
     b'\\', // `character_escape`, `hard_break_escape`
     b']',  // `label_end`, `gfm_label_start_footnote`
+    b'^',  // `inline_footnote`, `superscript`
     b'_',  // `attention` (emphasis, strong)
     b'`',  // `raw_text` (code (text))
     b'h',  // `gfm_autolink_literal` (`protocol` kind)
     b'w',  // `gfm_autolink_literal` (`www.` kind)
     b'{',  // `mdx_expression_text`
-    b'~',  // `attention` (gfm strikethrough)
+    b'~',  // `subscript`, `attention` (gfm strikethrough)
 ];
 
 /// Start of text.
@@ -79,6 +94,11 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 pub fn before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
+            // Labels can no longer form, so what is left are mistakes.
+            for (_, kind, point) in tokenizer.tokenize_state.reference_warnings.split_off(0) {
+                tokenizer.warn(kind, &point);
+            }
+
             tokenizer.register_resolver(ResolveName::Data);
             tokenizer.register_resolver(ResolveName::Text);
             State::Ok
@@ -105,14 +125,28 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::CharacterReferenceStart)
         }
-        // attention (emphasis, gfm strikethrough, strong)
-        Some(b'*' | b'_' | b'~') => {
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::DirectiveTextStart)
+        }
+        // attention (emphasis, strong)
+        Some(b'*' | b'_') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
                 State::Next(StateName::TextBeforeData),
             );
             State::Retry(StateName::AttentionStart)
         }
+        Some(b'=') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::HighlightStart)
+        }
         // `autolink`, `html_text` (order does not matter), `mdx_jsx_text` (order matters).
         Some(b'<') => {
             tokenizer.attempt(
@@ -138,9 +172,9 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
         Some(b'[') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeLabelStartLink),
+                State::Next(StateName::TextBeforeGfmLabelStartFootnote),
             );
-            State::Retry(StateName::GfmLabelStartFootnoteStart)
+            State::Retry(StateName::WikilinkStart)
         }
         Some(b'\\') => {
             tokenizer.attempt(
@@ -156,6 +190,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::LabelEndStart)
         }
+        Some(b'^') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeSuperscript),
+            );
+            State::Retry(StateName::InlineFootnoteStart)
+        }
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
@@ -163,6 +204,14 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::MdxExpressionTextStart)
         }
+        // `subscript` (order matters), attention (gfm strikethrough).
+        Some(b'~') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeAttention),
+            );
+            State::Retry(StateName::SubscriptStart)
+        }
         _ => State::Retry(StateName::TextBeforeData),
     }
 }
@@ -215,9 +264,25 @@ pub fn before_hard_break_escape(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::HardBreakEscapeStart)
 }
 
+/// Before GFM label start (footnote).
+///
+/// At `[`, which wasn’t a wikilink.
+///
+/// ```markdown
+/// > | [^a]
+///     ^
+/// ```
+pub fn before_gfm_label_start_footnote(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeLabelStartLink),
+    );
+    State::Retry(StateName::GfmLabelStartFootnoteStart)
+}
+
 /// Before label start (link).
 ///
-/// At `[`, which wasn’t a GFM label start (footnote).
+/// At `[`, which wasn’t a wikilink or GFM label start (footnote).
 ///
 /// ```markdown
 /// > | [a](b)
@@ -231,6 +296,38 @@ pub fn before_label_start_link(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::LabelStartLinkStart)
 }
 
+/// Before attention.
+///
+/// At `~`, which wasn’t subscript.
+///
+/// ```markdown
+/// > | ~~a~~
+///     ^
+/// ```
+pub fn before_attention(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::AttentionStart)
+}
+
+/// Before superscript.
+///
+/// At `^`, which wasn’t an inline footnote.
+///
+/// ```markdown
+/// > | x^2^
+///      ^
+/// ```
+pub fn before_superscript(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::SuperscriptStart)
+}
+
 /// Before data.
 ///
 /// ```markdown
@@ -259,6 +356,16 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         resolve_gfm_autolink_literal(tokenizer);
     }
 
+    if tokenizer.parse_state.options.constructs.abbreviation
+        && !tokenizer.parse_state.abbreviations.is_empty()
+    {
+        resolve_abbreviation(tokenizer);
+    }
+
+    if tokenizer.parse_state.options.constructs.emoji {
+        resolve_emoji(tokenizer);
+    }
+
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
@@ -7,7 +7,9 @@
 //!
 //! ```bnf
 //! ; Restriction: all markers must be identical.
-//! ; Restriction: at least 3 markers must be used.
+//! ; Restriction: at least 3 markers must be used, or more with
+//! ; `thematic_break_size_min`.
+//! ; Restriction: markers can be limited with `thematic_break_markers`.
 //! thematic_break ::= *space_or_tab 1*(1*marker *space_or_tab)
 //!
 //! marker ::= '*' | '-' | '_'
@@ -99,8 +101,15 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        Some(b'*' | b'-' | b'_') => {
-            tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+        Some(byte @ (b'*' | b'-' | b'_'))
+            if tokenizer
+                .parse_state
+                .options
+                .thematic_break_markers
+                .as_ref()
+                .map_or(true, |markers| markers.contains(&char::from(byte))) =>
+        {
+            tokenizer.tokenize_state.marker = byte;
             State::Retry(StateName::ThematicBreakAtBreak)
         }
         _ => State::Nok,
@@ -117,7 +126,14 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
     if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
         tokenizer.enter(Name::ThematicBreakSequence);
         State::Retry(StateName::ThematicBreakSequence)
-    } else if tokenizer.tokenize_state.size >= THEMATIC_BREAK_MARKER_COUNT_MIN
+    } else if tokenizer.tokenize_state.size
+        >= tokenizer
+            .parse_state
+            .options
+            .thematic_break_size_min
+            .map_or(THEMATIC_BREAK_MARKER_COUNT_MIN, |size| {
+                size.max(THEMATIC_BREAK_MARKER_COUNT_MIN)
+            })
         && matches!(tokenizer.current, None | Some(b'\n'))
     {
         tokenizer.tokenize_state.marker = 0;
@@ -0,0 +1,207 @@
+//! Wikilink occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Wikilink forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! wikilink ::= '[' '[' target [ '|' [ label ] ] ']' ']'
+//!
+//! ; Restriction: `target` must contain something other than whitespace.
+//! target ::= 1*byte
+//! label ::= 1*byte
+//! byte ::= code - eol - '[' - ']' - '|'
+//! ```
+//!
+//! The target is the name of a page, which is turned into a URL with
+//! [`wikilink_url`][wikilink_url] (by default, a GitHub-style slug of it).
+//! The label, if there is one, is what is shown instead of the target.
+//! An empty label (as in `[[a|]]`) is the same as no label.
+//!
+//! Both are taken as they are: they cannot contain other constructs, or
+//! brackets, and character escapes and references are not decoded.
+//! A bracket inside, or an empty target, means that it is not a wikilink:
+//! `[[]]` and `[[[a]]]` are (partly) just text, and in the latter, only
+//! `[[a]]` is a wikilink.
+//!
+//! Wikilinks are tried before [GFM label start (footnote)][gfm_label_start_footnote]
+//! and [label start (link)][label_start_link].
+//!
+//! Wikilinks are used by several wikis and note taking apps, but are not
+//! `CommonMark` or [GFM](https://github.github.com/gfm/).
+//!
+//! ## HTML
+//!
+//! Wikilink relates to the `<a>` element in HTML.
+//! See [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//!
+//! ## Tokens
+//!
+//! *   [`Wikilink`][Name::Wikilink]
+//! *   [`WikilinkLabel`][Name::WikilinkLabel]
+//! *   [`WikilinkLabelMarker`][Name::WikilinkLabelMarker]
+//! *   [`WikilinkMarker`][Name::WikilinkMarker]
+//! *   [`WikilinkTarget`][Name::WikilinkTarget]
+//!
+//! ## References
+//!
+//! *   [*Wikilinks* in Pandoc](https://pandoc.org/MANUAL.html#extension-wikilinks_title_after_pipe)
+//! *   [`micromark-extension-wiki-link`](https://github.com/landakram/micromark-extension-wiki-link)
+//!
+//! [text]: crate::construct::text
+//! [gfm_label_start_footnote]: crate::construct::gfm_label_start_footnote
+//! [label_start_link]: crate::construct::label_start_link
+//! [wikilink_url]: crate::ParseOptions::wikilink_url
+//! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of wikilink.
+///
+/// ```markdown
+/// > | a [[b]] c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.wikilink && tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::Wikilink);
+        tokenizer.enter(Name::WikilinkMarker);
+        tokenizer.consume();
+        State::Next(StateName::WikilinkOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at another `[`.
+///
+/// ```markdown
+/// > | a [[b]] c
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.consume();
+        tokenizer.exit(Name::WikilinkMarker);
+        tokenizer.enter(Name::WikilinkTarget);
+        State::Next(StateName::WikilinkTargetInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In target.
+///
+/// ```markdown
+/// > | a [[b]] c
+///         ^
+/// ```
+pub fn target_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b']' | b'|') => {
+            if tokenizer.tokenize_state.seen {
+                tokenizer.tokenize_state.seen = false;
+                tokenizer.exit(Name::WikilinkTarget);
+                State::Retry(StateName::WikilinkTargetAfter)
+            } else {
+                State::Nok
+            }
+        }
+        Some(byte) => {
+            if !matches!(byte, b'\t' | b' ') {
+                tokenizer.tokenize_state.seen = true;
+            }
+
+            tokenizer.consume();
+            State::Next(StateName::WikilinkTargetInside)
+        }
+    }
+}
+
+/// After target.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///          ^
+/// ```
+pub fn target_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'|') {
+        tokenizer.enter(Name::WikilinkLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::WikilinkLabelMarker);
+        State::Next(StateName::WikilinkLabelBefore)
+    } else {
+        State::Retry(StateName::WikilinkClose)
+    }
+}
+
+/// After `|`, before label.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///           ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b']') {
+        State::Retry(StateName::WikilinkClose)
+    } else {
+        tokenizer.enter(Name::WikilinkLabel);
+        State::Retry(StateName::WikilinkLabelInside)
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///           ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b'|') => State::Nok,
+        Some(b']') => {
+            tokenizer.exit(Name::WikilinkLabel);
+            State::Retry(StateName::WikilinkClose)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::WikilinkLabelInside)
+        }
+    }
+}
+
+/// At `]`.
+///
+/// ```markdown
+/// > | a [[b]] c
+///          ^
+/// ```
+pub fn close(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::WikilinkMarker);
+    tokenizer.consume();
+    State::Next(StateName::WikilinkCloseAfter)
+}
+
+/// After `]`, at another `]`.
+///
+/// ```markdown
+/// > | a [[b]] c
+///           ^
+/// ```
+pub fn close_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b']') {
+        tokenizer.consume();
+        tokenizer.exit(Name::WikilinkMarker);
+        tokenizer.exit(Name::Wikilink);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
@@ -1,10 +1,145 @@
 //! Semantic labels of things happening.
 
-use crate::util::constant::TAB_SIZE;
+use crate::unist;
 
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Name {
+    /// Abbreviation.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a HTML b
+    ///       ^^^^
+    ///   |
+    ///   | *[HTML]: Hyper Text Markup Language
+    /// ```
+    Abbreviation,
+    /// Whole abbreviation definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker],
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel],
+    ///     [`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue],
+    ///     [`DefinitionMarker`][Name::DefinitionMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinition,
+    /// Abbreviation definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker],
+    ///     [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///      ^^^^^^
+    /// ```
+    AbbreviationDefinitionLabel,
+    /// Abbreviation definition label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///      ^    ^
+    /// ```
+    AbbreviationDefinitionLabelMarker,
+    /// Abbreviation definition label string.
+    ///
+    /// The abbreviation itself, matched literally (case-sensitive) in text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///       ^^^^
+    /// ```
+    AbbreviationDefinitionLabelString,
+    /// Abbreviation definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^
+    /// ```
+    AbbreviationDefinitionMarker,
+    /// Abbreviation definition value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///              ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinitionValue,
     /// Attention sequence.
     ///
     /// > 👉 **Note**: this is used while parsing but compiled away.
@@ -373,8 +508,7 @@ pub enum Name {
     /// *   **Context**:
     ///     [`CodeFenced`][Name::CodeFenced]
     /// *   **Content model**:
-    ///     [`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo],
-    ///     [`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],
+    ///     [`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString],
     ///     [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
@@ -395,7 +529,7 @@ pub enum Name {
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFencedFence`][Name::CodeFencedFence]
+    ///     [`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString]
     /// *   **Content model**:
     ///     [string content][crate::construct::string]
     /// *   **Construct**:
@@ -410,13 +544,39 @@ pub enum Name {
     ///   | ```
     /// ````
     CodeFencedFenceInfo,
-    /// A code (fenced) fence meta string.
+    /// A code (fenced) fence info string.
+    ///
+    /// The whole info string: the info word and, if there is one, the meta
+    /// string.
+    /// Includes trailing whitespace.
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [`CodeFencedFence`][Name::CodeFencedFence]
     /// *   **Content model**:
+    ///     [`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo],
+    ///     [`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ````markdown
+    /// > | ```js highlight="1"
+    ///        ^^^^^^^^^^^^^^^^
+    ///   | console.log(1)
+    ///   | ```
+    /// ````
+    CodeFencedFenceInfoString,
+    /// A code (fenced) fence meta string.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString]
+    /// *   **Content model**:
     ///     [string content][crate::construct::string]
     /// *   **Construct**:
     ///     [`raw_flow`][crate::construct::raw_flow]
@@ -561,291 +721,800 @@ pub enum Name {
     /// *   **Context**:
     ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     [content][crate::construct::content]
+    ///     [content][crate::construct::content]
+    /// *   **Construct**:
+    ///     [`content`][crate::construct::content]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b
+    ///     ^^^^^^
+    /// > | c.
+    ///     ^^
+    /// ```
+    Content,
+    /// Data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [string content][crate::construct::string],
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`data`][crate::construct::partial_data]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | aa *bb* cc
+    ///     ^^^ ^^ ^^^
+    /// ```
+    Data,
+    /// Whole definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`DefinitionMarker`][Name::DefinitionMarker],
+    ///     [`DefinitionLabel`][Name::DefinitionLabel],
+    ///     [`DefinitionDestination`][Name::DefinitionDestination],
+    ///     [`DefinitionTitle`][Name::DefinitionTitle],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition`][crate::construct::definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///     ^^^^^^^^^^
+    /// ```
+    Definition,
+    /// Whole definition description.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionList`][Name::DefinitionList]
+    /// *   **Content model**:
+    ///     [`DefinitionDescriptionPrefix`][Name::DefinitionDescriptionPrefix],
+    ///     [flow content][crate::construct::flow]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^^
+    /// > |   c
+    ///     ^^^
+    /// ```
+    DefinitionDescription,
+    /// Definition description marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDescriptionPrefix`][Name::DefinitionDescriptionPrefix]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^
+    /// ```
+    DefinitionDescriptionMarker,
+    /// Definition description prefix.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDescription`][Name::DefinitionDescription]
+    /// *   **Content model**:
+    ///     [`DefinitionDescriptionMarker`][Name::DefinitionDescriptionMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^
+    /// ```
+    DefinitionDescriptionPrefix,
+    /// Whole definition destination.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Definition`][Name::Definition]
+    /// *   **Content model**:
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
+    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///          ^
+    /// > | [a]: <b> "c"
+    ///          ^^^
+    /// ```
+    DefinitionDestination,
+    /// Definition destination literal.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    /// *   **Content model**:
+    ///     [`DefinitionDestinationLiteralMarker`][Name::DefinitionDestinationLiteralMarker],
+    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: <b> "c"
+    ///          ^^^
+    /// ```
+    DefinitionDestinationLiteral,
+    /// Definition destination literal marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: <b> "c"
+    ///          ^ ^
+    /// ```
+    DefinitionDestinationLiteralMarker,
+    /// Definition destination raw.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    /// *   **Content model**:
+    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///          ^
+    /// ```
+    DefinitionDestinationRaw,
+    /// Definition destination data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
+    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///          ^
+    /// > | [a]: <b> "c"
+    ///           ^
+    /// ```
+    DefinitionDestinationString,
+    /// Whole definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Definition`][Name::Definition]
+    /// *   **Content model**:
+    ///     [`DefinitionLabelMarker`][Name::DefinitionLabelMarker],
+    ///     [`DefinitionLabelString`][Name::DefinitionLabelString],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///     ^^^
+    /// ```
+    DefinitionLabel,
+    /// Definition label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///     ^ ^
+    /// ```
+    DefinitionLabelMarker,
+    /// Definition label data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///      ^
+    /// ```
+    DefinitionLabelString,
+    /// Whole definition list.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`BlankLineEnding`][Name::BlankLineEnding],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [`DefinitionDescription`][Name::DefinitionDescription],
+    ///     [`DefinitionTerm`][Name::DefinitionTerm],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DefinitionList,
+    /// Definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition],
+    ///     [`Definition`][Name::Definition],
+    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation],
+    ///     [`definition`][crate::construct::definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///        ^
+    /// ```
+    DefinitionMarker,
+    /// Definition term.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionList`][Name::DefinitionList]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    ///   | : b
+    /// ```
+    DefinitionTerm,
+    /// Whole definition title.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Definition`][Name::Definition]
+    /// *   **Content model**:
+    ///     [`DefinitionTitleMarker`][Name::DefinitionTitleMarker],
+    ///     [`DefinitionTitleString`][Name::DefinitionTitleString],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`title`][crate::construct::partial_title]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///            ^^^
+    /// ```
+    DefinitionTitle,
+    /// Definition title marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`title`][crate::construct::partial_title]
+    ///
+    /// ## Example
+    ///
+    /// The kind of title can be inferred from the character at the opening
+    /// marker with [`TitleKind::from_char`][TitleKind::from_char].
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///            ^ ^
+    /// ```
+    DefinitionTitleMarker,
+    /// Definition title data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`title`][crate::construct::partial_title]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///             ^
+    /// ```
+    DefinitionTitleString,
+    /// Directive attribute class marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`directive`][crate::construct::partial_directive]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | :a{.b}
+    ///        ^
+    /// ```
+    DirectiveAttributeClassMarker,
+    /// Directive attribute id marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`directive`][crate::construct::partial_directive]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | :a{#b}
+    ///        ^
+    /// ```
+    DirectiveAttributeIdMarker,
+    /// Directive attribute initializer marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`directive`][crate::construct::partial_directive]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | :a{b=c}
+    ///         ^
+    /// ```
+    DirectiveAttributeInitializerMarker,
+    /// Directive attribute name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`directive`][crate::construct::partial_directive]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | :a{b=c}
+    ///        ^
+    /// ```
+    DirectiveAttributeName,
+    /// Directive attribute value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`directive`][crate::construct::partial_directive]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | :a{#b .c d="e" f=g}
+    ///         ^  ^    ^    ^
+    /// ```
+    DirectiveAttributeValue,
+    /// Directive attribute value marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
+    /// *   **Content model**:
+    ///     void
     /// *   **Construct**:
-    ///     [`content`][crate::construct::content]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b
-    ///     ^^^^^^
-    /// > | c.
-    ///     ^^
+    /// > | :a{b="c"}
+    ///          ^ ^
     /// ```
-    Content,
-    /// Data.
+    DirectiveAttributeValueMarker,
+    /// Whole directive attributes.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [string content][crate::construct::string],
-    ///     [text content][crate::construct::text]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     void
+    ///     [`DirectiveAttributesMarker`][Name::DirectiveAttributesMarker],
+    ///     [`DirectiveAttributeClassMarker`][Name::DirectiveAttributeClassMarker],
+    ///     [`DirectiveAttributeIdMarker`][Name::DirectiveAttributeIdMarker],
+    ///     [`DirectiveAttributeInitializerMarker`][Name::DirectiveAttributeInitializerMarker],
+    ///     [`DirectiveAttributeName`][Name::DirectiveAttributeName],
+    ///     [`DirectiveAttributeValue`][Name::DirectiveAttributeValue],
+    ///     [`DirectiveAttributeValueMarker`][Name::DirectiveAttributeValueMarker],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`data`][crate::construct::partial_data]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | aa *bb* cc
-    ///     ^^^ ^^ ^^^
+    /// > | :a{b=c}
+    ///       ^^^^^
     /// ```
-    Data,
-    /// Whole definition.
+    DirectiveAttributes,
+    /// Directive attributes marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes]
     /// *   **Content model**:
-    ///     [`DefinitionMarker`][Name::DefinitionMarker],
-    ///     [`DefinitionLabel`][Name::DefinitionLabel],
-    ///     [`DefinitionDestination`][Name::DefinitionDestination],
-    ///     [`DefinitionTitle`][Name::DefinitionTitle],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`definition`][crate::construct::definition]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^^^^^^^^^^
+    /// > | :a{b=c}
+    ///       ^   ^
     /// ```
-    Definition,
-    /// Whole definition destination.
+    DirectiveAttributesMarker,
+    /// Whole directive (container).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
-    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [flow content][crate::construct::flow]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
-    /// > | [a]: <b> "c"
-    ///          ^^^
+    /// > | :::a
+    ///     ^^^^
+    /// > | b
+    ///     ^
+    /// > | :::
+    ///     ^^^
     /// ```
-    DefinitionDestination,
-    /// Definition destination literal.
+    DirectiveContainer,
+    /// Directive (container) fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    ///     [`DirectiveContainer`][Name::DirectiveContainer]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationLiteralMarker`][Name::DefinitionDestinationLiteralMarker],
-    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveContainerSequence`][Name::DirectiveContainerSequence],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveName`][Name::DirectiveName],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: <b> "c"
-    ///          ^^^
+    /// > | :::a
+    ///     ^^^^
+    ///   | b
+    /// > | :::
+    ///     ^^^
     /// ```
-    DefinitionDestinationLiteral,
-    /// Definition destination literal marker.
+    DirectiveContainerFence,
+    /// Directive (container) fence sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: <b> "c"
-    ///          ^ ^
+    /// > | :::a
+    ///     ^^^
+    ///   | b
+    /// > | :::
+    ///     ^^^
     /// ```
-    DefinitionDestinationLiteralMarker,
-    /// Definition destination raw.
+    DirectiveContainerSequence,
+    /// Whole directive label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    ///     [`DirectiveLabelMarker`][Name::DirectiveLabelMarker],
+    ///     [`DirectiveLabelString`][Name::DirectiveLabelString],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
+    /// > | :a[b]
+    ///       ^^^
     /// ```
-    DefinitionDestinationRaw,
-    /// Definition destination data.
+    DirectiveLabel,
+    /// Directive label marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
-    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    ///     [`DirectiveLabel`][Name::DirectiveLabel]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
-    /// > | [a]: <b> "c"
-    ///           ^
+    /// > | :a[b]
+    ///       ^ ^
     /// ```
-    DefinitionDestinationString,
-    /// Whole definition label.
+    DirectiveLabelMarker,
+    /// Directive label data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [`DirectiveLabel`][Name::DirectiveLabel]
     /// *   **Content model**:
-    ///     [`DefinitionLabelMarker`][Name::DefinitionLabelMarker],
-    ///     [`DefinitionLabelString`][Name::DefinitionLabelString],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^^^
+    /// > | :a[b]
+    ///        ^
     /// ```
-    DefinitionLabel,
-    /// Definition label marker.
+    DirectiveLabelString,
+    /// Whole directive (leaf).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveLeafSequence`][Name::DirectiveLeafSequence],
+    ///     [`DirectiveName`][Name::DirectiveName],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`directive_leaf`][crate::construct::directive_leaf]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^ ^
+    /// > | ::a[b]{c}
+    ///     ^^^^^^^^^
     /// ```
-    DefinitionLabelMarker,
-    /// Definition label data.
+    DirectiveLeaf,
+    /// Directive (leaf) sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`directive_leaf`][crate::construct::directive_leaf]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///      ^
+    /// > | ::a
+    ///     ^^
     /// ```
-    DefinitionLabelString,
-    /// Definition marker.
+    DirectiveLeafSequence,
+    /// Directive name.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition],
-    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`definition`][crate::construct::definition]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///        ^
+    /// > | :a[b]
+    ///      ^
     /// ```
-    DefinitionMarker,
-    /// Whole definition title.
+    DirectiveName,
+    /// Whole directive (text).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`DefinitionTitleMarker`][Name::DefinitionTitleMarker],
-    ///     [`DefinitionTitleString`][Name::DefinitionTitleString],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveName`][Name::DirectiveName],
+    ///     [`DirectiveTextMarker`][Name::DirectiveTextMarker]
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`directive_text`][crate::construct::directive_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///            ^^^
+    /// > | a :b[c]{d} e
+    ///       ^^^^^^^^
     /// ```
-    DefinitionTitle,
-    /// Definition title marker.
+    DirectiveText,
+    /// Directive (text) marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`directive_text`][crate::construct::directive_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///            ^ ^
+    /// > | a :b c
+    ///       ^
     /// ```
-    DefinitionTitleMarker,
-    /// Definition title data.
+    DirectiveTextMarker,
+    /// Emoji (shortcode).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`emoji`][crate::construct::emoji]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///             ^
+    /// > | a :tada: b
+    ///       ^^^^^^
     /// ```
-    DefinitionTitleString,
+    Emoji,
     /// Emphasis.
     ///
     /// ## Info
@@ -1806,6 +2475,61 @@ pub enum Name {
     ///     ^^^^^
     /// ```
     HeadingSetextUnderlineSequence,
+    /// Highlight.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`HighlightSequence`][Name::HighlightSequence],
+    ///     [`HighlightText`][Name::HighlightText]
+    /// *   **Construct**:
+    ///     [`highlight`][crate::construct::highlight]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ==a==
+    ///     ^^^^^
+    /// ```
+    Highlight,
+    /// Highlight sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Highlight`][Name::Highlight]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`highlight`][crate::construct::highlight]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ==a==
+    ///     ^^ ^^
+    /// ```
+    HighlightSequence,
+    /// Highlight text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Highlight`][Name::Highlight]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`highlight`][crate::construct::highlight]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ==a==
+    ///       ^
+    /// ```
+    HighlightText,
     /// Whole html (flow).
     ///
     /// ## Info
@@ -1855,65 +2579,124 @@ pub enum Name {
     ///     [`LineEnding`][Name::LineEnding],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`html_text`][crate::construct::html_text]
+    ///     [`html_text`][crate::construct::html_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///       ^^^
+    /// ```
+    HtmlText,
+    /// HTML (text) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HtmlText`][Name::HtmlText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`html_text`][crate::construct::html_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///       ^^^
+    /// ```
+    HtmlTextData,
+    /// Image.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Label`][Name::Label],
+    ///     [`Resource`][Name::Resource],
+    ///     [`Reference`][Name::Reference]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b] c
+    ///       ^^^^
+    /// > | a ![b][c] d
+    ///       ^^^^^^^
+    /// > | a ![b](c) d
+    ///       ^^^^^^^
+    /// ```
+    Image,
+    /// Inline footnote.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Label`][Name::Label]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///       ^^^
+    /// > | a ^[b] c
+    ///       ^^^^
     /// ```
-    HtmlText,
-    /// HTML (text) data.
+    InlineFootnote,
+    /// Label start (inline footnote).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HtmlText`][Name::HtmlText]
+    ///     [`Label`][Name::Label]
     /// *   **Content model**:
-    ///     void
+    ///     [`InlineFootnoteMarker`][Name::InlineFootnoteMarker],
+    ///     [`LabelMarker`][Name::LabelMarker]
     /// *   **Construct**:
-    ///     [`html_text`][crate::construct::html_text]
+    ///     [`inline_footnote`][crate::construct::inline_footnote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///       ^^^
+    /// > | a ^[b] c
+    ///       ^^
     /// ```
-    HtmlTextData,
-    /// Image.
+    InlineFootnoteLabel,
+    /// Label start (inline footnote) marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`InlineFootnoteLabel`][Name::InlineFootnoteLabel]
     /// *   **Content model**:
-    ///     [`Label`][Name::Label],
-    ///     [`Resource`][Name::Resource],
-    ///     [`Reference`][Name::Reference]
+    ///     void
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`inline_footnote`][crate::construct::inline_footnote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b] c
-    ///       ^^^^
-    /// > | a ![b][c] d
-    ///       ^^^^^^^
-    /// > | a ![b](c) d
-    ///       ^^^^^^^
+    /// > | a ^[b] c
+    ///       ^
     /// ```
-    Image,
+    InlineFootnoteMarker,
     /// Label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
+    ///     [`GfmFootnoteCall`][Name::GfmFootnoteCall],
     ///     [`Image`][Name::Image],
+    ///     [`InlineFootnote`][Name::InlineFootnote],
     ///     [`Link`][Name::Link]
     /// *   **Content model**:
+    ///     [`GfmFootnoteCallLabel`][Name::GfmFootnoteCallLabel],
+    ///     [`InlineFootnoteLabel`][Name::InlineFootnoteLabel],
     ///     [`LabelImage`][Name::LabelImage],
     ///     [`LabelLink`][Name::LabelLink],
     ///     [`LabelEnd`][Name::LabelEnd],
@@ -2012,12 +2795,16 @@ pub enum Name {
     /// ## Info
     ///
     /// *   **Context**:
+    ///     [`GfmFootnoteCallLabel`][Name::GfmFootnoteCallLabel],
+    ///     [`InlineFootnoteLabel`][Name::InlineFootnoteLabel],
     ///     [`LabelImage`][Name::LabelImage],
     ///     [`LabelLink`][Name::LabelLink],
     ///     [`LabelEnd`][Name::LabelEnd]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
+    ///     [`gfm_label_start_footnote`][crate::construct::gfm_label_start_footnote],
+    ///     [`inline_footnote`][crate::construct::inline_footnote],
     ///     [`label_start_image`][crate::construct::label_start_image],
     ///     [`label_start_link`][crate::construct::label_start_link],
     ///     [`label_end`][crate::construct::label_end]
@@ -3239,6 +4026,9 @@ pub enum Name {
     ///
     /// ## Example
     ///
+    /// The kind of title can be inferred from the character at the opening
+    /// marker with [`TitleKind::from_char`][TitleKind::from_char].
+    ///
     /// ```markdown
     /// > | a ![b](<c> "d") e
     ///                ^ ^
@@ -3335,6 +4125,116 @@ pub enum Name {
     ///       ^
     /// ```
     StrongText,
+    /// Subscript.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`SubscriptSequence`][Name::SubscriptSequence],
+    ///     [`SubscriptText`][Name::SubscriptText]
+    /// *   **Construct**:
+    ///     [`subscript`][crate::construct::subscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^^^
+    /// ```
+    Subscript,
+    /// Subscript sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Subscript`][Name::Subscript]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`subscript`][crate::construct::subscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^ ^
+    /// ```
+    SubscriptSequence,
+    /// Subscript text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Subscript`][Name::Subscript]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`subscript`][crate::construct::subscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///       ^
+    /// ```
+    SubscriptText,
+    /// Superscript.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`SuperscriptSequence`][Name::SuperscriptSequence],
+    ///     [`SuperscriptText`][Name::SuperscriptText]
+    /// *   **Construct**:
+    ///     [`superscript`][crate::construct::superscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^^^
+    /// ```
+    Superscript,
+    /// Superscript sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Superscript`][Name::Superscript]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`superscript`][crate::construct::superscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^ ^
+    /// ```
+    SuperscriptSequence,
+    /// Superscript text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Superscript`][Name::Superscript]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`superscript`][crate::construct::superscript]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///       ^
+    /// ```
+    SuperscriptText,
     /// Whole thematic break.
     ///
     /// ## Info
@@ -3372,10 +4272,107 @@ pub enum Name {
     ///     ^ ^ ^
     /// ```
     ThematicBreakSequence,
+    /// Whole wikilink.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`WikilinkLabel`][Name::WikilinkLabel],
+    ///     [`WikilinkLabelMarker`][Name::WikilinkLabelMarker],
+    ///     [`WikilinkMarker`][Name::WikilinkMarker],
+    ///     [`WikilinkTarget`][Name::WikilinkTarget]
+    /// *   **Construct**:
+    ///     [`wikilink`][crate::construct::wikilink]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///       ^^^^^^^
+    /// ```
+    Wikilink,
+    /// Wikilink label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Wikilink`][Name::Wikilink]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`wikilink`][crate::construct::wikilink]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///           ^
+    /// ```
+    WikilinkLabel,
+    /// Wikilink label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Wikilink`][Name::Wikilink]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`wikilink`][crate::construct::wikilink]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///          ^
+    /// ```
+    WikilinkLabelMarker,
+    /// Wikilink marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Wikilink`][Name::Wikilink]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`wikilink`][crate::construct::wikilink]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///       ^^   ^^
+    /// ```
+    WikilinkMarker,
+    /// Wikilink target.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Wikilink`][Name::Wikilink]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`wikilink`][crate::construct::wikilink]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///         ^
+    /// ```
+    WikilinkTarget,
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 102] = [
+    Name::Abbreviation,
+    Name::AbbreviationDefinitionLabelMarker,
+    Name::AbbreviationDefinitionLabelString,
+    Name::AbbreviationDefinitionMarker,
     Name::AttentionSequence,
     Name::AutolinkEmail,
     Name::AutolinkMarker,
@@ -3395,10 +4392,24 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::CodeTextData,
     Name::CodeTextSequence,
     Name::Data,
+    Name::DefinitionDescriptionMarker,
     Name::DefinitionDestinationLiteralMarker,
     Name::DefinitionLabelMarker,
     Name::DefinitionMarker,
     Name::DefinitionTitleMarker,
+    Name::DirectiveAttributeClassMarker,
+    Name::DirectiveAttributeIdMarker,
+    Name::DirectiveAttributeInitializerMarker,
+    Name::DirectiveAttributeName,
+    Name::DirectiveAttributeValue,
+    Name::DirectiveAttributeValueMarker,
+    Name::DirectiveAttributesMarker,
+    Name::DirectiveContainerSequence,
+    Name::DirectiveLabelMarker,
+    Name::DirectiveLeafSequence,
+    Name::DirectiveName,
+    Name::DirectiveTextMarker,
+    Name::Emoji,
     Name::EmphasisSequence,
     Name::FrontmatterChunk,
     Name::GfmAutolinkLiteralEmail,
@@ -3419,8 +4430,10 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::HardBreakTrailing,
     Name::HeadingAtxSequence,
     Name::HeadingSetextUnderlineSequence,
+    Name::HighlightSequence,
     Name::HtmlFlowData,
     Name::HtmlTextData,
+    Name::InlineFootnoteMarker,
     Name::LabelImageMarker,
     Name::LabelMarker,
     Name::LineEnding,
@@ -3451,10 +4464,18 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::ResourceTitleMarker,
     Name::SpaceOrTab,
     Name::StrongSequence,
+    Name::SubscriptSequence,
+    Name::SuperscriptSequence,
     Name::ThematicBreakSequence,
+    Name::WikilinkLabel,
+    Name::WikilinkLabelMarker,
+    Name::WikilinkMarker,
+    Name::WikilinkTarget,
 ];
 
 /// Embedded content type.
+///
+/// Used on a [`Link`][] to say how the chunks in it are parsed.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Content {
     /// Represents [flow content][crate::construct::flow].
@@ -3467,14 +4488,137 @@ pub enum Content {
     Text,
 }
 
+/// Kind of title.
+///
+/// Titles occur in definitions and resources, and the same kind of marker is
+/// used to open and close them (except for parens and curly quotes).
+/// The kind is not stored on events: it can be inferred from the character at
+/// the start of an opening
+/// [`DefinitionTitleMarker`][Name::DefinitionTitleMarker] or
+/// [`ResourceTitleMarker`][Name::ResourceTitleMarker].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::TitleKind;
+///
+/// assert_eq!(TitleKind::from_char('('), Some(TitleKind::Paren));
+/// assert_eq!(TitleKind::Paren.opening(), '(');
+/// assert_eq!(TitleKind::Paren.closing(), ')');
+/// assert_eq!(TitleKind::from_char('a'), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TitleKind {
+    /// In double quotes.
+    ///
+    /// ```markdown
+    /// > | [a]: b "c"
+    ///            ^^^
+    /// ```
+    Double,
+    /// In single quotes.
+    ///
+    /// ```markdown
+    /// > | [a]: b 'c'
+    ///            ^^^
+    /// ```
+    Single,
+    /// In parens.
+    ///
+    /// ```markdown
+    /// > | [a]: b (c)
+    ///            ^^^
+    /// ```
+    Paren,
+    /// In curly double quotes.
+    ///
+    /// Only with [`title_smart_quotes`][crate::ParseOptions::title_smart_quotes].
+    ///
+    /// ```markdown
+    /// > | [a]: b “c”
+    ///            ^^^
+    /// ```
+    SmartDouble,
+    /// In curly single quotes.
+    ///
+    /// Only with [`title_smart_quotes`][crate::ParseOptions::title_smart_quotes].
+    ///
+    /// ```markdown
+    /// > | [a]: b ‘c’
+    ///            ^^^
+    /// ```
+    SmartSingle,
+}
+
+impl TitleKind {
+    /// Turn an opening marker into a kind.
+    ///
+    /// Returns `None` if `char` cannot open a title.
+    pub fn from_char(char: char) -> Option<TitleKind> {
+        match char {
+            '"' => Some(TitleKind::Double),
+            '\'' => Some(TitleKind::Single),
+            '(' => Some(TitleKind::Paren),
+            '“' => Some(TitleKind::SmartDouble),
+            '‘' => Some(TitleKind::SmartSingle),
+            _ => None,
+        }
+    }
+
+    /// Marker that opens a title of this kind.
+    pub fn opening(self) -> char {
+        match self {
+            TitleKind::Double => '"',
+            TitleKind::Single => '\'',
+            TitleKind::Paren => '(',
+            TitleKind::SmartDouble => '“',
+            TitleKind::SmartSingle => '‘',
+        }
+    }
+
+    /// Marker that closes a title of this kind.
+    pub fn closing(self) -> char {
+        match self {
+            TitleKind::Paren => ')',
+            TitleKind::SmartDouble => '”',
+            TitleKind::SmartSingle => '’',
+            _ => self.opening(),
+        }
+    }
+}
+
 /// Link to another event.
+///
+/// Content that is parsed as another content type, such as the text of a
+/// paragraph or the string of a title, is first tokenized as chunks: void
+/// events (an enter directly followed by its exit), usually
+/// [`Data`][Name::Data], one for each line.
+/// The enter event of each chunk has a link, and the chunks of one piece of
+/// content form a chain.
+/// Chunks are later replaced by the events that parsing their content type
+/// results in, which is why [`parse()`][crate::parse()] does not have links,
+/// but [`parse_chunks()`][crate::parse_chunks()] does.
+///
+/// In a chain:
+///
+/// *   `previous` and `next` are indices into the same list of events, and
+///     point to the enter events of the chunks before and after, or are
+///     `None` at the start and end
+/// *   `previous` of a chunk is the one whose `next` points to it, and the
+///     other way around
+/// *   `content` is the same for each chunk
+/// *   chunks are in order and do not overlap, but other events (such as
+///     block quote prefixes or indentation) can be between them
+/// *   the content is parsed as if the bytes of the chunks were joined, so
+///     line endings are either in a chunk or are a separate linked
+///     [`LineEnding`][Name::LineEnding] chunk
 #[derive(Clone, Debug)]
 pub struct Link {
-    /// Previous event.
+    /// Index of the enter event of the previous chunk.
     pub previous: Option<usize>,
-    /// Next event.
+    /// Index of the enter event of the next chunk.
     pub next: Option<usize>,
-    /// Content type.
+    /// Content type of the chain.
     pub content: Content,
 }
 
@@ -3503,7 +4647,11 @@ pub struct Point {
 impl Point {
     /// Create a new point, that is shifted from the close earlier current
     /// point, to `index.`
-    pub fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
+    ///
+    /// A `tab_size` of `0` is treated as `1`.
+    #[must_use]
+    pub fn shift_to(&self, bytes: &[u8], tab_size: usize, index: usize) -> Point {
+        let tab_size = tab_size.max(1);
         let mut next = self.clone();
         debug_assert!(index > next.index, "expected to shift forward");
 
@@ -3511,12 +4659,7 @@ impl Point {
             match bytes[next.index] {
                 b'\n' | b'\r' => unreachable!("cannot move past line endings"),
                 b'\t' => {
-                    let remainder = next.column % TAB_SIZE;
-                    let vs = if remainder == 0 {
-                        0
-                    } else {
-                        TAB_SIZE - remainder
-                    };
+                    let vs = tab_size - 1 - (next.column - 1) % tab_size;
                     next.index += 1;
                     next.column += 1 + vs;
                 }
@@ -3529,6 +4672,15 @@ impl Point {
 
         next
     }
+
+    /// Turn this point into a unist point.
+    ///
+    /// The virtual step (`vs`) is dropped: it is already accounted for in
+    /// `column`, which is expanded to the next tab stop for tabs.
+    #[must_use]
+    pub fn to_unist(&self) -> unist::Point {
+        unist::Point::new(self.line, self.column, self.index)
+    }
 }
 
 /// Event kinds.
@@ -3549,6 +4701,182 @@ pub struct Event {
     pub name: Name,
     /// Place where this happens.
     pub point: Point,
-    /// Link to another event.
+    /// Link to other chunks of the same content, on enter events of chunks.
     pub link: Option<Link>,
 }
+
+/// Get the range of the span that the event at `index` in `events` belongs
+/// to.
+///
+/// When `index` points to an enter event, the range is from there to its
+/// matching exit; when it points to an exit event, the range is from its
+/// matching enter to there.
+///
+/// Returns `None` when given out of bounds input, or when the matching event
+/// cannot be found.
+#[must_use]
+pub fn position(events: &[Event], index: usize) -> Option<unist::Position> {
+    let event = events.get(index)?;
+    let mut depth = 0;
+    let mut other = index;
+
+    if event.kind == Kind::Enter {
+        loop {
+            other += 1;
+            let current = events.get(other)?;
+
+            if current.kind == Kind::Enter {
+                depth += 1;
+            } else if depth == 0 {
+                break;
+            } else {
+                depth -= 1;
+            }
+        }
+    } else {
+        loop {
+            if other == 0 {
+                return None;
+            }
+
+            other -= 1;
+
+            if events[other].kind == Kind::Exit {
+                depth += 1;
+            } else if depth == 0 {
+                break;
+            } else {
+                depth -= 1;
+            }
+        }
+    }
+
+    let (start, end) = if other > index {
+        (&events[index], &events[other])
+    } else {
+        (&events[other], &events[index])
+    };
+
+    debug_assert_eq!(start.name, end.name, "expected matching events");
+
+    Some(unist::Position {
+        start: start.point.to_unist(),
+        end: end.point.to_unist(),
+    })
+}
+
+/// Get the markdown in `value` between two points, without copying.
+///
+/// `value` must be what the points were made for.
+/// Parts of tabs cannot be represented in a string: when `start` is in a
+/// tab (its `vs` is not `0`), that tab is not included, and when `end` is
+/// in a tab, that tab is included.
+///
+/// Returns `None` when given out of bounds points.
+#[must_use]
+pub fn slice<'a>(value: &'a str, start: &Point, end: &Point) -> Option<&'a str> {
+    let start = if start.vs > 0 {
+        start.index + 1
+    } else {
+        start.index
+    };
+    let end = if end.vs > 0 { end.index + 1 } else { end.index };
+    value.get(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use alloc::string::String;
+
+    #[test]
+    fn position_events() -> Result<(), String> {
+        let options = crate::ParseOptions::default();
+        let (events, _) = parse("*a\tb*", &options)?;
+        let enter = events
+            .iter()
+            .position(|d| d.kind == Kind::Enter && d.name == Name::Emphasis)
+            .unwrap();
+        let exit = events
+            .iter()
+            .position(|d| d.kind == Kind::Exit && d.name == Name::Emphasis)
+            .unwrap();
+
+        assert_eq!(
+            position(&events, enter),
+            Some(unist::Position::new(1, 1, 0, 1, 7, 5)),
+            "should support an enter event"
+        );
+
+        assert_eq!(
+            position(&events, exit),
+            Some(unist::Position::new(1, 1, 0, 1, 7, 5)),
+            "should support an exit event"
+        );
+
+        assert_eq!(
+            position(&events, events.len()),
+            None,
+            "should not support an out of bounds index"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_events() -> Result<(), String> {
+        let options = crate::ParseOptions::default();
+        let value = "*a\tb*";
+        let (events, _) = parse(value, &options)?;
+        let enter = events
+            .iter()
+            .find(|d| d.kind == Kind::Enter && d.name == Name::Emphasis)
+            .unwrap();
+        let exit = events
+            .iter()
+            .find(|d| d.kind == Kind::Exit && d.name == Name::Emphasis)
+            .unwrap();
+
+        assert_eq!(
+            slice(value, &enter.point, &exit.point),
+            Some("*a\tb*"),
+            "should support two points"
+        );
+
+        let value = ">\ta";
+        let (events, _) = parse(value, &options)?;
+        let enter = events
+            .iter()
+            .find(|d| d.kind == Kind::Enter && d.name == Name::Paragraph)
+            .unwrap();
+        let exit = events
+            .iter()
+            .find(|d| d.kind == Kind::Exit && d.name == Name::Paragraph)
+            .unwrap();
+
+        assert_eq!(enter.point.vs, 1, "expected a point in a tab");
+        assert_eq!(
+            slice(value, &enter.point, &exit.point),
+            Some("a"),
+            "should not include a tab that a start point is in"
+        );
+
+        assert_eq!(
+            slice(
+                value,
+                &exit.point,
+                &Point {
+                    line: 1,
+                    column: 10,
+                    index: 10,
+                    vs: 0
+                }
+            ),
+            None,
+            "should not support out of bounds points"
+        );
+
+        Ok(())
+    }
+}
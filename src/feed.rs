@@ -0,0 +1,80 @@
+//! Pass markdown in chunks.
+//!
+//! Chunks are bytes, so that they can be split anywhere, such as in the
+//! middle of a character, between a carriage return and a line feed, or
+//! between a tab and what follows it.
+//! They are buffered, and parsed together when finishing.
+
+use crate::reparse::ParseTree;
+use crate::util::error::MarkdownError;
+use crate::ParseOptions;
+use alloc::{string::String, vec::Vec};
+
+/// Turn markdown, passed in chunks, into a parse tree.
+///
+/// Pass chunks with [`write()`][Parser::write], and get the result with
+/// [`finish()`][Parser::finish], which is the same as
+/// [`ParseTree::new()`][] on all chunks together.
+/// Bytes that are not valid UTF-8 are replaced with the replacement
+/// character (`U+FFFD`).
+///
+/// > 👉 **Note**: the chunks are buffered and parsed when finishing.
+/// > Whether something in markdown is done often depends on what comes much
+/// > later, such as a definition at the end of a document that turns link
+/// > references earlier on into links, or a line that makes a lazy
+/// > paragraph continuation or a setext heading underline.
+/// > So no events are final before the whole document is seen, and this does
+/// > not use less memory than [`parse()`][crate::parse()].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{parse, EventName, ParseOptions, Parser};
+/// # fn main() -> Result<(), String> {
+///
+/// let options = ParseOptions::default();
+/// let mut parser = Parser::new(&options);
+/// parser.write(b"*\xC3");
+/// parser.write(b"\xA9*\r");
+/// parser.write(b"\n\tb");
+/// let tree = parser.finish()?;
+///
+/// assert_eq!(tree.value(), "*é*\r\n\tb");
+/// assert_eq!(tree.events()[1].name, EventName::Emphasis);
+/// assert_eq!(tree.events().len(), parse("*é*\r\n\tb", &options)?.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Parser<'a> {
+    /// Configuration.
+    options: &'a ParseOptions,
+    /// Bytes passed so far.
+    value: Vec<u8>,
+}
+
+impl<'a> Parser<'a> {
+    /// Create a parser.
+    #[must_use]
+    pub fn new(options: &'a ParseOptions) -> Parser<'a> {
+        Parser {
+            options,
+            value: Vec::new(),
+        }
+    }
+
+    /// Pass a chunk of markdown.
+    pub fn write(&mut self, chunk: &[u8]) {
+        self.value.extend_from_slice(chunk);
+    }
+
+    /// Turn all chunks into a parse tree.
+    ///
+    /// ## Errors
+    ///
+    /// `finish()` errors in the same cases as [`parse()`][crate::parse()].
+    pub fn finish(self) -> Result<ParseTree<'a>, MarkdownError> {
+        let value = String::from_utf8_lossy(&self.value);
+        ParseTree::new(&value, self.options)
+    }
+}
@@ -1,7 +1,8 @@
 //! Public API of `markdown-rs`.
 //!
 //! This module exposes primarily [`to_html()`][].
-//! It also exposes [`to_html_with_options()`][] and [`to_mdast()`][].
+//! It also exposes [`to_html_with_options()`][], [`to_mdast()`][], and
+//! [`parse()`][].
 //!
 //! *   [`to_html()`][]
 //!     — safe way to transform (untrusted?) markdown into HTML
@@ -11,6 +12,20 @@
 //!     constructs (GFM, MDX, and the like)
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_plain()`][]
+//!     — turn markdown into plain text, such as for search indexes
+//! *   [`headings()`][]
+//!     — get the headings in markdown, such as for a table of contents
+//! *   [`link_report()`][]
+//!     — get the links, images, and definitions in markdown, such as to
+//!     check for dead links
+//! *   [`count_words()`][]
+//!     — count the words and characters in the text of markdown
+//! *   [`parse()`][]
+//!     — turn markdown into events, to build other things (such as a
+//!     different compiler) on
+//! *   [`Parser`][]
+//!     — like `parse` but lets you pass markdown in chunks of bytes
 #![no_std]
 #![deny(clippy::pedantic)]
 #![allow(clippy::doc_link_with_quotes)]
@@ -25,12 +40,15 @@ extern crate alloc;
 mod configuration;
 mod construct;
 mod event;
+mod feed;
 mod parser;
+mod reparse;
 mod resolve;
 mod state;
 mod subtokenize;
 mod to_html;
 mod to_mdast;
+mod to_plain;
 mod tokenizer;
 mod util;
 
@@ -46,16 +64,42 @@ pub use util::sanitize_uri::sanitize;
 #[doc(hidden)]
 pub use util::location::Location;
 
+pub use event::{
+    position as event_position, slice as event_slice, Content as EventContent, Event,
+    Kind as EventKind, Link as EventLink, Name as EventName, Point as EventPoint, TitleKind,
+};
+
 pub use util::line_ending::LineEnding;
 
+pub use util::normalize_title::normalize_title;
+
+pub use util::slug::Slug as HeadingIdSlug;
+
 pub use util::mdx::{
     EsmParse as MdxEsmParse, ExpressionKind as MdxExpressionKind,
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use util::error::MarkdownError;
+
+pub use util::count::Counts;
 
-use alloc::string::String;
+pub use util::headings::HeadingInfo;
+
+pub use util::links::{DefinitionInfo, LinkRef, LinkReport};
+
+pub use reparse::{reparse, Edit, ParseTree};
+
+pub use feed::Parser;
+
+pub use util::warning::{Kind as WarningKind, Sink as WarningSink, Warning};
+
+pub use configuration::{
+    CompileOptions, Constructs, DangerousHtmlSink, DefinitionSink, EventHtml, EventOutput,
+    HeadingAnchor, Highlight, LinkExternal, ListMarkerChange, Options, ParseOptions, WikilinkUrl,
+};
+
+use alloc::{string::String, vec::Vec};
 
 /// Turn markdown into HTML.
 ///
@@ -63,12 +107,23 @@ use alloc::string::String;
 /// Use [`to_html_with_options()`][] to configure how markdown is turned into
 /// HTML.
 ///
+/// The generated HTML is also valid XHTML (such as for feeds or EPUB): void
+/// elements (`<br />`, `<hr />`, `<img />`, and `<input />`) are always
+/// self-closing, and attribute values are always double-quoted.
+/// This is not configurable.
+/// HTML written by the author (with `allow_dangerous_html`) and
+/// `hard_break_html` are passed through as-is.
+///
 /// ## Examples
 ///
 /// ```
 /// use markdown::to_html;
 ///
 /// assert_eq!(to_html("# Hello, world!"), "<h1>Hello, world!</h1>");
+/// assert_eq!(
+///     to_html("a\\\nb\n\n***\n\n![c](d \"e\")"),
+///     "<p>a<br />\nb</p>\n<hr />\n<p><img src=\"d\" alt=\"c\" title=\"e\" /></p>"
+/// );
 /// ```
 pub fn to_html(value: &str) -> String {
     to_html_with_options(value, &Options::default()).unwrap()
@@ -78,11 +133,15 @@ pub fn to_html(value: &str) -> String {
 ///
 /// ## Errors
 ///
-/// `to_html_with_options()` never errors with normal markdown because markdown
-/// does not have syntax errors, so feel free to `unwrap()`.
+/// `to_html_with_options()` never errors with normal markdown and valid
+/// options because markdown does not have syntax errors, so feel free to
+/// `unwrap()`.
 /// However, MDX does have syntax errors.
 /// When MDX is turned on, there are several errors that can occur with how
 /// expressions, ESM, and JSX are written.
+/// Those are [`MarkdownError::Syntax`][].
+/// It also errors with [`MarkdownError::InvalidOptions`][] when given options
+/// that cannot be used, such as conflicting constructs.
 ///
 /// ## Examples
 ///
@@ -109,11 +168,12 @@ pub fn to_html(value: &str) -> String {
 /// # Ok(())
 /// # }
 /// ```
-pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, String> {
+pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, MarkdownError> {
     let (events, parse_state) = parser::parse(value, &options.parse)?;
     Ok(to_html::compile(
         &events,
         parse_state.bytes,
+        &options.parse,
         &options.compile,
     ))
 }
@@ -122,11 +182,14 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, St
 ///
 /// ## Errors
 ///
-/// `to_mdast()` never errors with normal markdown because markdown does not
-/// have syntax errors, so feel free to `unwrap()`.
+/// `to_mdast()` never errors with normal markdown and valid options because
+/// markdown does not have syntax errors, so feel free to `unwrap()`.
 /// However, MDX does have syntax errors.
 /// When MDX is turned on, there are several errors that can occur with how
 /// JSX, expressions, or ESM are written.
+/// Those are [`MarkdownError::Syntax`][].
+/// It also errors with [`MarkdownError::InvalidOptions`][] when given options
+/// that cannot be used, such as conflicting constructs.
 ///
 /// ## Examples
 ///
@@ -141,8 +204,314 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, St
 /// # Ok(())
 /// # }
 /// ```
-pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, String> {
+pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, MarkdownError> {
     let (events, parse_state) = parser::parse(value, options)?;
-    let node = to_mdast::compile(&events, parse_state.bytes)?;
+    let node =
+        to_mdast::compile(&events, parse_state.bytes, options).map_err(MarkdownError::Syntax)?;
     Ok(node)
 }
+
+/// Turn markdown into plain text.
+///
+/// All formatting is dropped: markers, HTML, link destinations, and
+/// definitions are not included.
+/// Links are turned into their text, images into their alt text, and
+/// autolinks into their URL.
+/// Code is kept as it is.
+///
+/// Blocks are separated by blank lines, or by a line ending in tight lists.
+/// List items are prefixed with `- `, or their number in ordered lists, and
+/// cells in tables are separated by tabs.
+/// Line endings are turned into `\n`.
+///
+/// ## Errors
+///
+/// `to_plain()` errors in the same cases as [`parse()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_plain, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// assert_eq!(
+///     to_plain("# Hi, *you*!\n\n* [a](b)\n* ![c](d)", &ParseOptions::default())?,
+///     "Hi, you!\n\n- a\n- c"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_plain(value: &str, options: &ParseOptions) -> Result<String, MarkdownError> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(to_plain::compile(&events, parse_state.bytes, options))
+}
+
+/// Get the headings in markdown.
+///
+/// ATX and setext headings are collected in the order they occur, with
+/// their rank, their text content (without formatting), and a slug of that
+/// text.
+/// The slugs are the same as the IDs generated with
+/// [`heading_ids`][CompileOptions::heading_ids] (without a custom
+/// `heading_id_slug`), so they can be used to link to the headings in the
+/// HTML.
+///
+/// ## Errors
+///
+/// `headings()` errors in the same cases as [`parse()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{headings, HeadingInfo, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// assert_eq!(
+///     headings("# Hi, *you*!\n\nTwo\n---", &ParseOptions::default())?,
+///     vec![
+///         HeadingInfo { level: 1, text: "Hi, you!".into(), id: "hi-you".into() },
+///         HeadingInfo { level: 2, text: "Two".into(), id: "two".into() },
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn headings(value: &str, options: &ParseOptions) -> Result<Vec<HeadingInfo>, MarkdownError> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(util::headings::headings(
+        &events,
+        parse_state.bytes,
+        options.tab_size,
+    ))
+}
+
+/// Get the links, images, and definitions in markdown.
+///
+/// Every link and image (including autolinks) is included with its
+/// destination and title, and where it starts.
+/// For references (such as `[a]`), the destination and title are those of
+/// the definition they reference.
+/// Every definition is included with whether it is referenced, which is
+/// also what the [`DefinitionUnused`][WarningKind::DefinitionUnused]
+/// warning is based on.
+///
+/// ## Errors
+///
+/// `link_report()` errors in the same cases as [`parse()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{link_report, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let report = link_report("[a] [b](c)\n\n[a]: d\n[e]: f", &ParseOptions::default())?;
+///
+/// assert_eq!(report.links.len(), 2);
+/// assert_eq!(report.links[0].destination, "d");
+/// assert!(report.links[0].resolved_from_definition);
+/// assert_eq!(report.links[1].destination, "c");
+/// assert_eq!(report.links[1].point.column, 5);
+/// assert!(report.definitions[0].used);
+/// assert!(!report.definitions[1].used);
+/// # Ok(())
+/// # }
+/// ```
+pub fn link_report(value: &str, options: &ParseOptions) -> Result<LinkReport, MarkdownError> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(util::links::report(
+        &events,
+        parse_state.bytes,
+        options.tab_size,
+    ))
+}
+
+/// Count the words and characters in markdown.
+///
+/// Only what people read is counted: the text of paragraphs, headings, table
+/// cells, and the like, and code (text).
+/// Markers (such as `*` or `#`), link destinations and titles, definitions,
+/// HTML, code (flow), and math are not.
+/// CJK characters are each counted as a word.
+///
+/// ## Errors
+///
+/// `count_words()` errors in the same cases as [`parse()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{count_words, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let counts = count_words("# Hi, [*you*](https://example.com)!", &ParseOptions::default())?;
+///
+/// assert_eq!(counts.words, 2);
+/// assert_eq!(counts.chars, 7);
+/// # Ok(())
+/// # }
+/// ```
+pub fn count_words(value: &str, options: &ParseOptions) -> Result<Counts, MarkdownError> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(util::count::count(
+        &events,
+        parse_state.bytes,
+        options.tab_size,
+    ))
+}
+
+/// Turn markdown into events.
+///
+/// Events are what [`to_html()`][] and [`to_mdast()`][] are built on: each
+/// thing in markdown is represented by an enter and an exit event, with the
+/// [point][EventPoint] where it happens.
+/// Use [`event_position()`][] to get the range of the thing an event
+/// belongs to, and [`event_slice()`][] to get the markdown between two
+/// points without copying.
+///
+/// ## Errors
+///
+/// `parse()` never errors with normal markdown and valid options because
+/// markdown does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+/// Those are [`MarkdownError::Syntax`][].
+/// It also errors with [`MarkdownError::InvalidOptions`][] when given options
+/// that cannot be used, such as conflicting constructs.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{parse, EventKind, EventName, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let events = parse("*a*", &ParseOptions::default())?;
+///
+/// assert_eq!(events[1].kind, EventKind::Enter);
+/// assert_eq!(events[1].name, EventName::Emphasis);
+/// assert_eq!(events[1].point.column, 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse(value: &str, options: &ParseOptions) -> Result<Vec<Event>, MarkdownError> {
+    let (events, _) = parser::parse(value, options)?;
+    Ok(events)
+}
+
+/// Turn markdown into events, leaving string and text unparsed.
+///
+/// Like [`parse()`][], but the [string][EventContent::String] and
+/// [text][EventContent::Text] content in things such as paragraphs,
+/// headings, titles, and the info of fenced code is not parsed.
+/// Each line of such content is instead a void [`Data`][EventName::Data]
+/// event, whose [`link`][Event::link] says which content type it is, and
+/// which chunks come before and after it.
+/// See [`EventLink`][] for how chunks are linked.
+/// This is useful to run another parser over that content, such as one for
+/// a different inline syntax.
+///
+/// Definitions and the other things that make up flow are parsed as normal.
+///
+/// ## Errors
+///
+/// `parse_chunks()` errors in the same cases as [`parse()`][], except for
+/// syntax errors in MDX text, which is not parsed.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{parse_chunks, EventContent, EventKind, EventName, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let events = parse_chunks("*a\nb*", &ParseOptions::default())?;
+/// let first = events
+///     .iter()
+///     .position(|d| d.kind == EventKind::Enter && d.name == EventName::Data)
+///     .unwrap();
+/// let link = events[first].link.as_ref().unwrap();
+///
+/// assert_eq!(link.content, EventContent::Text);
+/// assert_eq!(link.previous, None);
+///
+/// let second = link.next.unwrap();
+/// assert_eq!(events[second].point.line, 2);
+/// assert_eq!(events[second].link.as_ref().unwrap().previous, Some(first));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_chunks(value: &str, options: &ParseOptions) -> Result<Vec<Event>, MarkdownError> {
+    let (events, _) = parser::parse_chunks(value, options)?;
+    Ok(events)
+}
+
+/// Get the point of a byte offset in markdown.
+///
+/// The line and column are the same as in the [points][EventPoint] of events
+/// and the positions in mdast: a CRLF, CR, or LF ends a line, each byte takes
+/// up a column, and a tab takes up the columns up to the next tab stop (see
+/// [`tab_size`][ParseOptions::tab_size]).
+/// The carriage return of a CRLF is at the same column as its line feed, and
+/// a BOM at the start is at the same column as what comes after it.
+/// [`point_to_offset()`][] does the opposite.
+///
+/// Returns `None` when `offset` is past the end of `value` or inside a
+/// character.
+///
+/// Each call looks for the line endings in `value` again: to get many points
+/// in the same markdown, use [`ParseTree::offset_to_point()`][] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{offset_to_point, unist::Point, ParseOptions};
+///
+/// let options = ParseOptions::default();
+///
+/// assert_eq!(offset_to_point("a\tb", 2, &options), Some(Point::new(1, 5, 2)));
+/// assert_eq!(offset_to_point("a\r\nb", 3, &options), Some(Point::new(2, 1, 3)));
+/// assert_eq!(offset_to_point("\u{feff}a", 3, &options), Some(Point::new(1, 1, 3)));
+/// assert_eq!(offset_to_point("a", 2, &options), None);
+/// ```
+#[must_use]
+pub fn offset_to_point(value: &str, offset: usize, options: &ParseOptions) -> Option<unist::Point> {
+    let bytes = value.as_bytes();
+    Location::new(bytes).offset_to_point(bytes, options.tab_size, offset)
+}
+
+/// Get the byte offset of a line and column in markdown.
+///
+/// Lines and columns are counted like in [`offset_to_point()`][].
+/// A column in the middle of a tab gets the offset of that tab, the column
+/// of a CRLF gets the offset of its carriage return, and the first column
+/// after a BOM gets the offset after it.
+///
+/// Returns `None` when `line` or `column` is not in `value`, or when
+/// `column` is inside a character.
+///
+/// Each call looks for the line endings in `value` again: to get many offsets
+/// in the same markdown, use [`ParseTree::point_to_offset()`][] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{point_to_offset, ParseOptions};
+///
+/// let options = ParseOptions::default();
+///
+/// assert_eq!(point_to_offset("a\tb", 1, 5, &options), Some(2));
+/// assert_eq!(point_to_offset("a\tb", 1, 3, &options), Some(1));
+/// assert_eq!(point_to_offset("a\r\nb", 2, 1, &options), Some(3));
+/// assert_eq!(point_to_offset("\u{feff}a", 1, 1, &options), Some(3));
+/// assert_eq!(point_to_offset("a", 2, 1, &options), None);
+/// ```
+#[must_use]
+pub fn point_to_offset(
+    value: &str,
+    line: usize,
+    column: usize,
+    options: &ParseOptions,
+) -> Option<usize> {
+    let bytes = value.as_bytes();
+    Location::new(bytes).point_to_offset(bytes, options.tab_size, line, column)
+}
@@ -81,6 +81,10 @@ pub enum Node {
     // Container:
     /// Block quote.
     BlockQuote(BlockQuote),
+    /// Directive (container).
+    ContainerDirective(ContainerDirective),
+    /// Definition list.
+    DefinitionList(DefinitionList),
     /// Footnote definition.
     FootnoteDefinition(FootnoteDefinition),
     /// MDX: JSX element (container).
@@ -97,6 +101,12 @@ pub enum Node {
     Yaml(Yaml),
 
     // Phrasing:
+    /// Abbreviation.
+    Abbreviation(Abbreviation),
+    /// Wikilink.
+    Wikilink(Wikilink),
+    /// Emoji.
+    Emoji(Emoji),
     /// Break.
     Break(Break),
     /// Code (phrasing).
@@ -105,12 +115,18 @@ pub enum Node {
     InlineMath(InlineMath),
     /// Delete.
     Delete(Delete),
+    /// Directive (text).
+    TextDirective(TextDirective),
     /// Emphasis.
     Emphasis(Emphasis),
     // MDX: expression (text).
     MdxTextExpression(MdxTextExpression),
     /// Footnote reference.
     FootnoteReference(FootnoteReference),
+    /// Footnote (inline).
+    Footnote(Footnote),
+    /// Highlight.
+    Highlight(Highlight),
     /// Html (phrasing).
     Html(Html),
     /// Image.
@@ -125,6 +141,10 @@ pub enum Node {
     LinkReference(LinkReference),
     /// Strong
     Strong(Strong),
+    /// Subscript.
+    Subscript(Subscript),
+    /// Superscript.
+    Superscript(Superscript),
     /// Text.
     Text(Text),
 
@@ -137,6 +157,8 @@ pub enum Node {
     MdxFlowExpression(MdxFlowExpression),
     /// Heading.
     Heading(Heading),
+    /// Directive (leaf).
+    LeafDirective(LeafDirective),
     /// Html (flow).
     // Html(Html),
     /// Table.
@@ -156,7 +178,15 @@ pub enum Node {
     /// List item.
     ListItem(ListItem),
 
+    // Definition list content.
+    /// Definition term.
+    DefinitionTerm(DefinitionTerm),
+    /// Definition description.
+    DefinitionDescription(DefinitionDescription),
+
     // Content.
+    /// Abbreviation definition.
+    AbbreviationDefinition(AbbreviationDefinition),
     /// Definition.
     Definition(Definition),
     /// Paragraph.
@@ -169,12 +199,19 @@ impl fmt::Debug for Node {
         match self {
             Node::Root(x) => write!(f, "{:?}", x),
             Node::BlockQuote(x) => write!(f, "{:?}", x),
+            Node::ContainerDirective(x) => write!(f, "{:?}", x),
+            Node::LeafDirective(x) => write!(f, "{:?}", x),
+            Node::TextDirective(x) => write!(f, "{:?}", x),
+            Node::Footnote(x) => write!(f, "{:?}", x),
             Node::FootnoteDefinition(x) => write!(f, "{:?}", x),
             Node::MdxJsxFlowElement(x) => write!(f, "{:?}", x),
             Node::List(x) => write!(f, "{:?}", x),
             Node::MdxjsEsm(x) => write!(f, "{:?}", x),
             Node::Toml(x) => write!(f, "{:?}", x),
             Node::Yaml(x) => write!(f, "{:?}", x),
+            Node::Abbreviation(x) => write!(f, "{:?}", x),
+            Node::Wikilink(x) => write!(f, "{:?}", x),
+            Node::Emoji(x) => write!(f, "{:?}", x),
             Node::Break(x) => write!(f, "{:?}", x),
             Node::InlineCode(x) => write!(f, "{:?}", x),
             Node::InlineMath(x) => write!(f, "{:?}", x),
@@ -189,6 +226,9 @@ impl fmt::Debug for Node {
             Node::Link(x) => write!(f, "{:?}", x),
             Node::LinkReference(x) => write!(f, "{:?}", x),
             Node::Strong(x) => write!(f, "{:?}", x),
+            Node::Subscript(x) => write!(f, "{:?}", x),
+            Node::Superscript(x) => write!(f, "{:?}", x),
+            Node::Highlight(x) => write!(f, "{:?}", x),
             Node::Text(x) => write!(f, "{:?}", x),
             Node::Code(x) => write!(f, "{:?}", x),
             Node::Math(x) => write!(f, "{:?}", x),
@@ -199,6 +239,10 @@ impl fmt::Debug for Node {
             Node::TableRow(x) => write!(f, "{:?}", x),
             Node::TableCell(x) => write!(f, "{:?}", x),
             Node::ListItem(x) => write!(f, "{:?}", x),
+            Node::DefinitionList(x) => write!(f, "{:?}", x),
+            Node::DefinitionTerm(x) => write!(f, "{:?}", x),
+            Node::DefinitionDescription(x) => write!(f, "{:?}", x),
+            Node::AbbreviationDefinition(x) => write!(f, "{:?}", x),
             Node::Definition(x) => write!(f, "{:?}", x),
             Node::Paragraph(x) => write!(f, "{:?}", x),
         }
@@ -215,6 +259,10 @@ impl ToString for Node {
             // Parents.
             Node::Root(x) => children_to_string(&x.children),
             Node::BlockQuote(x) => children_to_string(&x.children),
+            Node::ContainerDirective(x) => children_to_string(&x.children),
+            Node::LeafDirective(x) => children_to_string(&x.children),
+            Node::TextDirective(x) => children_to_string(&x.children),
+            Node::Footnote(x) => children_to_string(&x.children),
             Node::FootnoteDefinition(x) => children_to_string(&x.children),
             Node::MdxJsxFlowElement(x) => children_to_string(&x.children),
             Node::List(x) => children_to_string(&x.children),
@@ -224,17 +272,26 @@ impl ToString for Node {
             Node::Link(x) => children_to_string(&x.children),
             Node::LinkReference(x) => children_to_string(&x.children),
             Node::Strong(x) => children_to_string(&x.children),
+            Node::Subscript(x) => children_to_string(&x.children),
+            Node::Superscript(x) => children_to_string(&x.children),
+            Node::Highlight(x) => children_to_string(&x.children),
             Node::Heading(x) => children_to_string(&x.children),
             Node::Table(x) => children_to_string(&x.children),
             Node::TableRow(x) => children_to_string(&x.children),
             Node::TableCell(x) => children_to_string(&x.children),
             Node::ListItem(x) => children_to_string(&x.children),
+            Node::DefinitionList(x) => children_to_string(&x.children),
+            Node::DefinitionTerm(x) => children_to_string(&x.children),
+            Node::DefinitionDescription(x) => children_to_string(&x.children),
             Node::Paragraph(x) => children_to_string(&x.children),
 
             // Literals.
             Node::MdxjsEsm(x) => x.value.clone(),
             Node::Toml(x) => x.value.clone(),
             Node::Yaml(x) => x.value.clone(),
+            Node::Abbreviation(x) => x.value.clone(),
+            Node::Wikilink(x) => x.value.clone(),
+            Node::Emoji(x) => x.value.clone(),
             Node::InlineCode(x) => x.value.clone(),
             Node::InlineMath(x) => x.value.clone(),
             Node::MdxTextExpression(x) => x.value.clone(),
@@ -250,6 +307,7 @@ impl ToString for Node {
             | Node::Image(_)
             | Node::ImageReference(_)
             | Node::ThematicBreak(_)
+            | Node::AbbreviationDefinition(_)
             | Node::Definition(_) => "".into(),
         }
     }
@@ -266,8 +324,14 @@ impl Node {
             Node::BlockQuote(x) => Some(&x.children),
             Node::List(x) => Some(&x.children),
             Node::ListItem(x) => Some(&x.children),
+            Node::DefinitionList(x) => Some(&x.children),
+            Node::DefinitionTerm(x) => Some(&x.children),
+            Node::DefinitionDescription(x) => Some(&x.children),
             Node::Emphasis(x) => Some(&x.children),
             Node::Strong(x) => Some(&x.children),
+            Node::Subscript(x) => Some(&x.children),
+            Node::Superscript(x) => Some(&x.children),
+            Node::Highlight(x) => Some(&x.children),
             Node::Link(x) => Some(&x.children),
             Node::LinkReference(x) => Some(&x.children),
             Node::FootnoteDefinition(x) => Some(&x.children),
@@ -277,6 +341,10 @@ impl Node {
             Node::Delete(x) => Some(&x.children),
             Node::MdxJsxFlowElement(x) => Some(&x.children),
             Node::MdxJsxTextElement(x) => Some(&x.children),
+            Node::ContainerDirective(x) => Some(&x.children),
+            Node::LeafDirective(x) => Some(&x.children),
+            Node::TextDirective(x) => Some(&x.children),
+            Node::Footnote(x) => Some(&x.children),
             // Non-parent.
             _ => None,
         }
@@ -291,8 +359,14 @@ impl Node {
             Node::BlockQuote(x) => Some(&mut x.children),
             Node::List(x) => Some(&mut x.children),
             Node::ListItem(x) => Some(&mut x.children),
+            Node::DefinitionList(x) => Some(&mut x.children),
+            Node::DefinitionTerm(x) => Some(&mut x.children),
+            Node::DefinitionDescription(x) => Some(&mut x.children),
             Node::Emphasis(x) => Some(&mut x.children),
             Node::Strong(x) => Some(&mut x.children),
+            Node::Subscript(x) => Some(&mut x.children),
+            Node::Superscript(x) => Some(&mut x.children),
+            Node::Highlight(x) => Some(&mut x.children),
             Node::Link(x) => Some(&mut x.children),
             Node::LinkReference(x) => Some(&mut x.children),
             Node::FootnoteDefinition(x) => Some(&mut x.children),
@@ -302,6 +376,10 @@ impl Node {
             Node::Delete(x) => Some(&mut x.children),
             Node::MdxJsxFlowElement(x) => Some(&mut x.children),
             Node::MdxJsxTextElement(x) => Some(&mut x.children),
+            Node::ContainerDirective(x) => Some(&mut x.children),
+            Node::LeafDirective(x) => Some(&mut x.children),
+            Node::TextDirective(x) => Some(&mut x.children),
+            Node::Footnote(x) => Some(&mut x.children),
             // Non-parent.
             _ => None,
         }
@@ -312,16 +390,22 @@ impl Node {
         match self {
             Node::Root(x) => x.position.as_ref(),
             Node::BlockQuote(x) => x.position.as_ref(),
+            Node::ContainerDirective(x) => x.position.as_ref(),
             Node::FootnoteDefinition(x) => x.position.as_ref(),
             Node::MdxJsxFlowElement(x) => x.position.as_ref(),
             Node::List(x) => x.position.as_ref(),
             Node::MdxjsEsm(x) => x.position.as_ref(),
             Node::Toml(x) => x.position.as_ref(),
             Node::Yaml(x) => x.position.as_ref(),
+            Node::Abbreviation(x) => x.position.as_ref(),
+            Node::Wikilink(x) => x.position.as_ref(),
+            Node::Emoji(x) => x.position.as_ref(),
             Node::Break(x) => x.position.as_ref(),
             Node::InlineCode(x) => x.position.as_ref(),
             Node::InlineMath(x) => x.position.as_ref(),
             Node::Delete(x) => x.position.as_ref(),
+            Node::TextDirective(x) => x.position.as_ref(),
+            Node::Footnote(x) => x.position.as_ref(),
             Node::Emphasis(x) => x.position.as_ref(),
             Node::MdxTextExpression(x) => x.position.as_ref(),
             Node::FootnoteReference(x) => x.position.as_ref(),
@@ -332,16 +416,24 @@ impl Node {
             Node::Link(x) => x.position.as_ref(),
             Node::LinkReference(x) => x.position.as_ref(),
             Node::Strong(x) => x.position.as_ref(),
+            Node::Subscript(x) => x.position.as_ref(),
+            Node::Superscript(x) => x.position.as_ref(),
+            Node::Highlight(x) => x.position.as_ref(),
             Node::Text(x) => x.position.as_ref(),
             Node::Code(x) => x.position.as_ref(),
             Node::Math(x) => x.position.as_ref(),
             Node::MdxFlowExpression(x) => x.position.as_ref(),
             Node::Heading(x) => x.position.as_ref(),
+            Node::LeafDirective(x) => x.position.as_ref(),
             Node::Table(x) => x.position.as_ref(),
             Node::ThematicBreak(x) => x.position.as_ref(),
             Node::TableRow(x) => x.position.as_ref(),
             Node::TableCell(x) => x.position.as_ref(),
             Node::ListItem(x) => x.position.as_ref(),
+            Node::DefinitionList(x) => x.position.as_ref(),
+            Node::DefinitionTerm(x) => x.position.as_ref(),
+            Node::DefinitionDescription(x) => x.position.as_ref(),
+            Node::AbbreviationDefinition(x) => x.position.as_ref(),
             Node::Definition(x) => x.position.as_ref(),
             Node::Paragraph(x) => x.position.as_ref(),
         }
@@ -351,16 +443,22 @@ impl Node {
         match self {
             Node::Root(x) => x.position.as_mut(),
             Node::BlockQuote(x) => x.position.as_mut(),
+            Node::ContainerDirective(x) => x.position.as_mut(),
             Node::FootnoteDefinition(x) => x.position.as_mut(),
             Node::MdxJsxFlowElement(x) => x.position.as_mut(),
             Node::List(x) => x.position.as_mut(),
             Node::MdxjsEsm(x) => x.position.as_mut(),
             Node::Toml(x) => x.position.as_mut(),
             Node::Yaml(x) => x.position.as_mut(),
+            Node::Abbreviation(x) => x.position.as_mut(),
+            Node::Wikilink(x) => x.position.as_mut(),
+            Node::Emoji(x) => x.position.as_mut(),
             Node::Break(x) => x.position.as_mut(),
             Node::InlineCode(x) => x.position.as_mut(),
             Node::InlineMath(x) => x.position.as_mut(),
             Node::Delete(x) => x.position.as_mut(),
+            Node::TextDirective(x) => x.position.as_mut(),
+            Node::Footnote(x) => x.position.as_mut(),
             Node::Emphasis(x) => x.position.as_mut(),
             Node::MdxTextExpression(x) => x.position.as_mut(),
             Node::FootnoteReference(x) => x.position.as_mut(),
@@ -371,16 +469,24 @@ impl Node {
             Node::Link(x) => x.position.as_mut(),
             Node::LinkReference(x) => x.position.as_mut(),
             Node::Strong(x) => x.position.as_mut(),
+            Node::Subscript(x) => x.position.as_mut(),
+            Node::Superscript(x) => x.position.as_mut(),
+            Node::Highlight(x) => x.position.as_mut(),
             Node::Text(x) => x.position.as_mut(),
             Node::Code(x) => x.position.as_mut(),
             Node::Math(x) => x.position.as_mut(),
             Node::MdxFlowExpression(x) => x.position.as_mut(),
             Node::Heading(x) => x.position.as_mut(),
+            Node::LeafDirective(x) => x.position.as_mut(),
             Node::Table(x) => x.position.as_mut(),
             Node::ThematicBreak(x) => x.position.as_mut(),
             Node::TableRow(x) => x.position.as_mut(),
             Node::TableCell(x) => x.position.as_mut(),
             Node::ListItem(x) => x.position.as_mut(),
+            Node::DefinitionList(x) => x.position.as_mut(),
+            Node::DefinitionTerm(x) => x.position.as_mut(),
+            Node::DefinitionDescription(x) => x.position.as_mut(),
+            Node::AbbreviationDefinition(x) => x.position.as_mut(),
             Node::Definition(x) => x.position.as_mut(),
             Node::Paragraph(x) => x.position.as_mut(),
         }
@@ -390,16 +496,22 @@ impl Node {
         match self {
             Node::Root(x) => x.position = position,
             Node::BlockQuote(x) => x.position = position,
+            Node::ContainerDirective(x) => x.position = position,
             Node::FootnoteDefinition(x) => x.position = position,
             Node::MdxJsxFlowElement(x) => x.position = position,
             Node::List(x) => x.position = position,
             Node::MdxjsEsm(x) => x.position = position,
             Node::Toml(x) => x.position = position,
             Node::Yaml(x) => x.position = position,
+            Node::Abbreviation(x) => x.position = position,
+            Node::Wikilink(x) => x.position = position,
+            Node::Emoji(x) => x.position = position,
             Node::Break(x) => x.position = position,
             Node::InlineCode(x) => x.position = position,
             Node::InlineMath(x) => x.position = position,
             Node::Delete(x) => x.position = position,
+            Node::TextDirective(x) => x.position = position,
+            Node::Footnote(x) => x.position = position,
             Node::Emphasis(x) => x.position = position,
             Node::MdxTextExpression(x) => x.position = position,
             Node::FootnoteReference(x) => x.position = position,
@@ -410,16 +522,24 @@ impl Node {
             Node::Link(x) => x.position = position,
             Node::LinkReference(x) => x.position = position,
             Node::Strong(x) => x.position = position,
+            Node::Subscript(x) => x.position = position,
+            Node::Superscript(x) => x.position = position,
+            Node::Highlight(x) => x.position = position,
             Node::Text(x) => x.position = position,
             Node::Code(x) => x.position = position,
             Node::Math(x) => x.position = position,
             Node::MdxFlowExpression(x) => x.position = position,
             Node::Heading(x) => x.position = position,
+            Node::LeafDirective(x) => x.position = position,
             Node::Table(x) => x.position = position,
             Node::ThematicBreak(x) => x.position = position,
             Node::TableRow(x) => x.position = position,
             Node::TableCell(x) => x.position = position,
             Node::ListItem(x) => x.position = position,
+            Node::DefinitionList(x) => x.position = position,
+            Node::DefinitionTerm(x) => x.position = position,
+            Node::DefinitionDescription(x) => x.position = position,
+            Node::AbbreviationDefinition(x) => x.position = position,
             Node::Definition(x) => x.position = position,
             Node::Paragraph(x) => x.position = position,
         }
@@ -586,6 +706,59 @@ pub struct ListItem {
     pub checked: Option<bool>,
 }
 
+/// Definition list.
+///
+/// ```markdown
+/// > | a
+///     ^
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinitionList {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// One or more of its children are separated with a blank line from its
+    /// siblings (when `true`), or not (when `false`).
+    pub spread: bool,
+}
+
+/// Definition term.
+///
+/// ```markdown
+/// > | a
+///     ^
+///   | : b
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinitionTerm {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Definition description.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinitionDescription {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
 /// Html (flow or phrasing).
 ///
 /// ```markdown
@@ -623,6 +796,9 @@ pub struct Code {
     pub lang: Option<String>,
     /// Custom info relating to the node.
     pub meta: Option<String>,
+    /// The whole info string (the language and the meta), as written, but
+    /// with character escapes and character references decoded.
+    pub info: Option<String>,
 }
 
 /// Math (flow).
@@ -647,6 +823,24 @@ pub struct Math {
     pub meta: Option<String>,
 }
 
+/// Abbreviation definition.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AbbreviationDefinition {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// The abbreviation, which is matched case-sensitively in text.
+    pub label: String,
+    /// What the abbreviation stands for.
+    pub title: Option<String>,
+}
+
 /// Definition.
 ///
 /// ```markdown
@@ -679,6 +873,66 @@ pub struct Definition {
     pub label: Option<String>,
 }
 
+/// Abbreviation.
+///
+/// ```markdown
+///   | *[HTML]: Hyper Text Markup Language
+///   |
+/// > | a HTML b
+///       ^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Abbreviation {
+    // Text.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Wikilink.
+///
+/// ```markdown
+/// > | [[a|b]]
+///     ^^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Wikilink {
+    // Text.
+    /// Content model: the label, or the target if there is no label.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Name of the page that is linked to.
+    pub target: String,
+    /// URL to the page, made from `target` with
+    /// [`wikilink_url`][crate::ParseOptions::wikilink_url].
+    pub url: String,
+}
+
+/// Emoji.
+///
+/// ```markdown
+/// > | :tada:
+///     ^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Emoji {
+    // Text.
+    /// Content model: the emoji, or the shortcode (such as `:ferris:`) for
+    /// custom emoji that are images.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Shortcode, without colons.
+    pub name: String,
+    /// URL to the image, for custom emoji that are images, from
+    /// [`emoji_shortcodes`][crate::ParseOptions::emoji_shortcodes].
+    pub url: Option<String>,
+}
+
 /// Text.
 ///
 /// ```markdown
@@ -724,6 +978,51 @@ pub struct Strong {
     pub position: Option<Position>,
 }
 
+/// Subscript.
+///
+/// ```markdown
+/// > | H~2~O
+///      ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscript {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Superscript.
+///
+/// ```markdown
+/// > | x^2^
+///      ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Superscript {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Highlight.
+///
+/// ```markdown
+/// > | ==a==
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Highlight {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
 /// Code (phrasing).
 ///
 /// ```markdown
@@ -930,6 +1229,21 @@ pub struct FootnoteReference {
     pub label: Option<String>,
 }
 
+/// Footnote (inline).
+///
+/// ```markdown
+/// > | ^[a]
+///     ^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Footnote {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
 /// GFM: table.
 ///
 /// ```markdown
@@ -1148,6 +1462,73 @@ pub struct MdxJsxAttribute {
     pub value: Option<AttributeValue>,
 }
 
+/// Directive (container).
+///
+/// ```markdown
+/// > | :::a
+///     ^^^^
+/// > | b
+///     ^
+/// > | :::
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContainerDirective {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Directive.
+    /// Name.
+    pub name: String,
+    /// Attributes, in order, as key/value pairs.
+    ///
+    /// Duplicate keys are merged: classes are joined with a space, other
+    /// values are overwritten by later ones.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Directive (leaf).
+///
+/// ```markdown
+/// > | ::a[b]
+///     ^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeafDirective {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Directive.
+    /// Name.
+    pub name: String,
+    /// Attributes, in order, as key/value pairs.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Directive (text).
+///
+/// ```markdown
+/// > | a :b[c] d.
+///       ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextDirective {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Directive.
+    /// Name.
+    pub name: String,
+    /// Attributes, in order, as key/value pairs.
+    pub attributes: Vec<(String, String)>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1181,6 +1562,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn emoji() {
+        let mut node = Node::Emoji(Emoji {
+            value: "🎉".into(),
+            position: None,
+            name: "tada".into(),
+            url: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Emoji { value: \"🎉\", position: None, name: \"tada\", url: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "🎉", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 7, 6)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Emoji { value: \"🎉\", position: Some(1:1-1:7 (0-6)), name: \"tada\", url: None }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn wikilink() {
+        let mut node = Node::Wikilink(Wikilink {
+            value: "b".into(),
+            position: None,
+            target: "a".into(),
+            url: "a".into(),
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Wikilink { value: \"b\", position: None, target: \"a\", url: \"a\" }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "b", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 8, 7)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Wikilink { value: \"b\", position: Some(1:1-1:8 (0-7)), target: \"a\", url: \"a\" }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn abbreviation() {
+        let mut node = Node::Abbreviation(Abbreviation {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Abbreviation { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Abbreviation { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn inline_code() {
         let mut node = Node::InlineCode(InlineCode {
@@ -1213,11 +1673,12 @@ mod tests {
             position: None,
             lang: None,
             meta: None,
+            info: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: None, lang: None, meta: None }",
+            "Code { value: \"a\", position: None, lang: None, meta: None, info: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1228,7 +1689,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None }",
+            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None, info: None }",
             "should support `position_set`"
         );
     }
@@ -1484,33 +1945,62 @@ mod tests {
     }
 
     #[test]
-    fn footnote_reference() {
-        let mut node = Node::FootnoteReference(FootnoteReference {
+    fn footnote() {
+        let mut node = Node::Footnote(Footnote {
             position: None,
-            identifier: "a".into(),
-            label: Some("b".into()),
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\") }",
+            "Footnote { children: [], position: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\") }",
+            "Footnote { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn image_reference() {
+    fn footnote_reference() {
+        let mut node = Node::FootnoteReference(FootnoteReference {
+            position: None,
+            identifier: "a".into(),
+            label: Some("b".into()),
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\") }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\") }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn image_reference() {
         let mut node = Node::ImageReference(ImageReference {
             position: None,
             alt: "a".into(),
@@ -1564,6 +2054,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn abbreviation_definition() {
+        let mut node = Node::AbbreviationDefinition(AbbreviationDefinition {
+            position: None,
+            label: "a".into(),
+            title: Some("b".into()),
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "AbbreviationDefinition { position: None, label: \"a\", title: Some(\"b\") }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "AbbreviationDefinition { position: Some(1:1-1:2 (0-1)), label: \"a\", title: Some(\"b\") }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn definition() {
         let mut node = Node::Definition(Definition {
@@ -1739,6 +2255,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn subscript() {
+        let mut node = Node::Subscript(Subscript {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Subscript { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Subscript { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn superscript() {
+        let mut node = Node::Superscript(Superscript {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Superscript { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Superscript { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn highlight() {
+        let mut node = Node::Highlight(Highlight {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Highlight { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Highlight { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn paragraph() {
         let mut node = Node::Paragraph(Paragraph {
@@ -1886,6 +2489,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn definition_list() {
+        let mut node = Node::DefinitionList(DefinitionList {
+            position: None,
+            spread: false,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionList { children: [], position: None, spread: false }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionList { children: [], position: Some(1:1-1:2 (0-1)), spread: false }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn definition_term() {
+        let mut node = Node::DefinitionTerm(DefinitionTerm {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionTerm { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionTerm { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn definition_description() {
+        let mut node = Node::DefinitionDescription(DefinitionDescription {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionDescription { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionDescription { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn list_item() {
         let mut node = Node::ListItem(ListItem {
@@ -2104,4 +2795,97 @@ mod tests {
             "should support `position_set`"
         );
     }
+
+    #[test]
+    fn container_directive() {
+        let mut node = Node::ContainerDirective(ContainerDirective {
+            position: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "ContainerDirective { children: [], position: None, name: \"a\", attributes: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "ContainerDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn leaf_directive() {
+        let mut node = Node::LeafDirective(LeafDirective {
+            position: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "LeafDirective { children: [], position: None, name: \"a\", attributes: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "LeafDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn text_directive() {
+        let mut node = Node::TextDirective(TextDirective {
+            position: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "TextDirective { children: [], position: None, name: \"a\", attributes: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "TextDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
+            "should support `position_set`"
+        );
+    }
 }
@@ -2,11 +2,18 @@
 
 use crate::event::{Event, Point};
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::subtokenize;
+use crate::subtokenize::{subtokenize, Subresult};
 use crate::tokenizer::Tokenizer;
+use crate::unist;
 use crate::util::location::Location;
+use crate::util::{
+    error::MarkdownError,
+    links,
+    normalize_identifier::normalize_identifier,
+    warning::{Kind as WarningKind, Sink as WarningSink, Warning},
+};
 use crate::ParseOptions;
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
 
 /// Info needed, in all content types, when parsing markdown.
 ///
@@ -20,6 +27,8 @@ pub struct ParseState<'a> {
     pub options: &'a ParseOptions,
     /// List of chars.
     pub bytes: &'a [u8],
+    /// Set of defined abbreviations.
+    pub abbreviations: Vec<String>,
     /// Set of defined definition identifiers.
     pub definitions: Vec<String>,
     /// Set of defined GFM footnote definition identifiers.
@@ -32,10 +41,88 @@ pub struct ParseState<'a> {
 pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
-) -> Result<(Vec<Event>, ParseState<'a>), String> {
+) -> Result<(Vec<Event>, ParseState<'a>), MarkdownError> {
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    };
+    parse_part(value, options, start, value.len(), vec![], vec![])
+}
+
+/// Turn a part of a string of markdown into events.
+///
+/// Parsing starts at `start` and stops at `end`, which must both be at the
+/// start of a line that is not in a container or another construct.
+/// `definitions` and `gfm_footnote_definitions` are the identifiers that are
+/// defined in the rest of the document.
+pub fn parse_part<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    start: Point,
+    end: usize,
+    definitions: Vec<String>,
+    gfm_footnote_definitions: Vec<String>,
+) -> Result<(Vec<Event>, ParseState<'a>), MarkdownError> {
+    let (mut events, mut parse_state, mut result) = tokenize(
+        value,
+        options,
+        start,
+        end,
+        definitions,
+        gfm_footnote_definitions,
+    )?;
+
+    loop {
+        collect(&mut parse_state, &mut result);
+
+        if result.done {
+            if let Some(sink) = &options.warning_sink {
+                warn(&events, &parse_state, sink);
+            }
+
+            return Ok((events, parse_state));
+        }
+
+        result = subtokenize(&mut events, &parse_state, &None).map_err(MarkdownError::Syntax)?;
+    }
+}
+
+/// Turn a string of markdown into events, without parsing string and text.
+///
+/// Flow and content are parsed, so definitions are known, but the string and
+/// text in them are left as linked chunks.
+pub fn parse_chunks<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), MarkdownError> {
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    };
+    let (events, mut parse_state, mut result) =
+        tokenize(value, options, start, value.len(), vec![], vec![])?;
+    collect(&mut parse_state, &mut result);
+    Ok((events, parse_state))
+}
+
+/// Tokenize the document, up to the first level of linked content.
+fn tokenize<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    start: Point,
+    end: usize,
+    definitions: Vec<String>,
+    gfm_footnote_definitions: Vec<String>,
+) -> Result<(Vec<Event>, ParseState<'a>, Subresult), MarkdownError> {
+    validate(options)?;
+
     let bytes = value.as_bytes();
 
-    let mut parse_state = ParseState {
+    let parse_state = ParseState {
         options,
         bytes,
         location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
@@ -43,36 +130,114 @@ pub fn parse<'a>(
         } else {
             None
         },
-        definitions: vec![],
-        gfm_footnote_definitions: vec![],
+        abbreviations: vec![],
+        definitions,
+        gfm_footnote_definitions,
     };
 
-    let start = Point {
-        line: 1,
-        column: 1,
-        index: 0,
-        vs: 0,
-    };
+    let from = start.index;
     let mut tokenizer = Tokenizer::new(start, &parse_state);
 
-    let state = tokenizer.push(
-        (0, 0),
-        (parse_state.bytes.len(), 0),
-        State::Next(StateName::DocumentStart),
-    );
-    let mut result = tokenizer.flush(state, true)?;
-    let mut events = tokenizer.events;
+    let state = tokenizer.push((from, 0), (end, 0), State::Next(StateName::DocumentStart));
+    let result = tokenizer
+        .flush(state, true)
+        .map_err(MarkdownError::Syntax)?;
+    let events = tokenizer.events;
 
-    loop {
-        let fn_defs = &mut parse_state.gfm_footnote_definitions;
-        let defs = &mut parse_state.definitions;
-        fn_defs.append(&mut result.gfm_footnote_definitions);
-        defs.append(&mut result.definitions);
+    Ok((events, parse_state, result))
+}
 
-        if result.done {
-            return Ok((events, parse_state));
+/// Add the identifiers defined in `result` to `parse_state`.
+fn collect(parse_state: &mut ParseState, result: &mut Subresult) {
+    parse_state.abbreviations.append(&mut result.abbreviations);
+    parse_state
+        .gfm_footnote_definitions
+        .append(&mut result.gfm_footnote_definitions);
+    parse_state.definitions.append(&mut result.definitions);
+}
+
+/// Check that `options` can be used.
+///
+/// Constructs that start with the same markers cannot both be turned on, as
+/// it is unclear which one is meant.
+fn validate(options: &ParseOptions) -> Result<(), MarkdownError> {
+    let constructs = &options.constructs;
+    let conflicts = [
+        (
+            constructs.html_flow,
+            "html_flow",
+            constructs.mdx_jsx_flow,
+            "mdx_jsx_flow",
+        ),
+        (
+            constructs.html_text,
+            "html_text",
+            constructs.mdx_jsx_text,
+            "mdx_jsx_text",
+        ),
+        (
+            constructs.autolink,
+            "autolink",
+            constructs.mdx_jsx_text,
+            "mdx_jsx_text",
+        ),
+    ];
+
+    for (left, left_name, right, right_name) in conflicts {
+        if left && right {
+            return Err(MarkdownError::InvalidOptions(format!(
+                "Unexpected `{}` and `{}`, expected only one of them to be turned on, as both start with `<`",
+                left_name, right_name
+            )));
+        }
+    }
+
+    if options.tab_size == 0 {
+        return Err(MarkdownError::InvalidOptions(
+            "Unexpected `tab_size` of `0`, expected a size of at least `1`".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Find problems that can only be found once everything is parsed.
+///
+/// These are unused and duplicate definitions, which need all definitions
+/// and references, and split tabs, which need the final events as constructs
+/// are tried with backtracking.
+fn warn(events: &[Event], parse_state: &ParseState, sink: &WarningSink) {
+    let mut tab_index = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.point.vs > 0 && tab_index != Some(event.point.index) {
+            tab_index = Some(event.point.index);
+            sink(Warning {
+                point: unist::Point::new(event.point.line, event.point.column, event.point.index),
+                kind: WarningKind::TabSplit,
+            });
         }
 
-        result = subtokenize(&mut events, &parse_state, &None)?;
+        index += 1;
+    }
+
+    let report = links::report(events, parse_state.bytes, parse_state.options.tab_size);
+    let mut seen = BTreeSet::new();
+
+    for definition in report.definitions {
+        if !seen.insert(normalize_identifier(&definition.label)) {
+            sink(Warning {
+                point: definition.point,
+                kind: WarningKind::DefinitionDuplicate(definition.label),
+            });
+        } else if !definition.used {
+            sink(Warning {
+                point: definition.point,
+                kind: WarningKind::DefinitionUnused(definition.label),
+            });
+        }
     }
 }
@@ -0,0 +1,579 @@
+//! Turn markdown into events again after an edit, reusing the events of
+//! blocks that did not change.
+//!
+//! Blocks at the root of the document that are followed by a blank line are
+//! places where parsing can start again: nothing before them can change how
+//! they, or what comes after them, are parsed, except for definitions
+//! (which are checked separately), and containers (such as list items)
+//! that continue after a blank line (which is why the block before the edit
+//! is always parsed again too).
+//!
+//! The region that is parsed again starts at the block before the edit and
+//! runs to the block after the edit.
+//! When the last block in that region does not result in the same events as
+//! before, such as because a fenced code block was opened, the region is
+//! made bigger, until the events match up again or the end of the document
+//! is reached.
+
+use crate::event::{Event, Kind, Link, Name, Point};
+use crate::parser::{parse, parse_part};
+use crate::util::{
+    error::MarkdownError,
+    location::Location,
+    normalize_identifier::normalize_identifier,
+    slice::{Position, Slice},
+};
+use crate::{unist, ParseOptions};
+use alloc::{collections::BTreeSet, string::String, vec, vec::Vec};
+
+/// Markdown and the events it was turned into.
+///
+/// Made with [`ParseTree::new()`][], and made again after an edit with
+/// [`reparse()`][].
+#[derive(Debug)]
+pub struct ParseTree<'a> {
+    /// Configuration.
+    options: &'a ParseOptions,
+    /// Markdown.
+    value: String,
+    /// Events.
+    events: Vec<Event>,
+    /// Line endings in the markdown, to turn offsets into points and back.
+    location: Location,
+}
+
+impl<'a> ParseTree<'a> {
+    /// Turn markdown into a parse tree.
+    ///
+    /// ## Errors
+    ///
+    /// `new()` errors in the same cases as [`parse()`][crate::parse()].
+    pub fn new(value: &str, options: &'a ParseOptions) -> Result<ParseTree<'a>, MarkdownError> {
+        let (events, _) = parse(value, options)?;
+        Ok(ParseTree {
+            options,
+            value: value.into(),
+            events,
+            location: Location::new(value.as_bytes()),
+        })
+    }
+
+    /// Get the markdown.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the events.
+    #[must_use]
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Get the events, dropping the markdown.
+    #[must_use]
+    pub fn into_events(self) -> Vec<Event> {
+        self.events
+    }
+
+    /// Get the point of a byte offset in the markdown.
+    ///
+    /// Like [`offset_to_point()`][crate::offset_to_point()], but the line
+    /// endings are only looked for once, when the tree is made, instead of
+    /// for each call.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{unist::Point, ParseOptions, ParseTree};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let options = ParseOptions::default();
+    /// let tree = ParseTree::new("a\tb\r\nc", &options)?;
+    ///
+    /// assert_eq!(tree.offset_to_point(2), Some(Point::new(1, 5, 2)));
+    /// assert_eq!(tree.offset_to_point(5), Some(Point::new(2, 1, 5)));
+    /// assert_eq!(tree.offset_to_point(7), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn offset_to_point(&self, offset: usize) -> Option<unist::Point> {
+        self.location
+            .offset_to_point(self.value.as_bytes(), self.options.tab_size, offset)
+    }
+
+    /// Get the byte offset of a line and column in the markdown.
+    ///
+    /// Like [`point_to_offset()`][crate::point_to_offset()], but the line
+    /// endings are only looked for once, when the tree is made, instead of
+    /// for each call.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{ParseOptions, ParseTree};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let options = ParseOptions::default();
+    /// let tree = ParseTree::new("a\tb\r\nc", &options)?;
+    ///
+    /// assert_eq!(tree.point_to_offset(1, 5), Some(2));
+    /// assert_eq!(tree.point_to_offset(2, 1), Some(5));
+    /// assert_eq!(tree.point_to_offset(3, 1), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn point_to_offset(&self, line: usize, column: usize) -> Option<usize> {
+        self.location
+            .point_to_offset(self.value.as_bytes(), self.options.tab_size, line, column)
+    }
+}
+
+/// Change to markdown: the bytes from `start` to `end` are replaced by
+/// `value`.
+///
+/// `start` and `end` are byte offsets into the markdown (such as
+/// [`point.index`][crate::EventPoint::index]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edit {
+    /// Offset of the first byte to replace.
+    pub start: usize,
+    /// Offset after the last byte to replace.
+    pub end: usize,
+    /// Markdown to insert.
+    pub value: String,
+}
+
+/// Amount to move a value by: what to add to it, and what to subtract from
+/// it.
+type Delta = (usize, usize);
+
+/// Place at the root of a document, after a blank line, where parsing can
+/// start again.
+#[derive(Debug)]
+struct Resync {
+    /// Line, 1-indexed.
+    line: usize,
+    /// Byte offset.
+    index: usize,
+    /// Index of the first event after this place.
+    event: usize,
+}
+
+/// Turn markdown into events again after an edit.
+///
+/// Only the blocks around the edit are parsed again.
+/// The events of other blocks are reused, with their points and links moved
+/// to make room.
+/// The result is the same as making a new [`ParseTree`][] for the edited
+/// markdown.
+///
+/// When a definition is added, removed, or changed, references anywhere in
+/// the document can turn into links or back into text, so everything is
+/// parsed again.
+/// As abbreviations apply to all text in the document, the same happens
+/// when it has (or gets) an abbreviation definition.
+/// The same happens when there is a
+/// [`warning_sink`][crate::ParseOptions::warning_sink], which needs to see
+/// the whole document.
+///
+/// ## Errors
+///
+/// `reparse()` errors in the same cases as [`parse()`][crate::parse()].
+///
+/// ## Panics
+///
+/// Panics when `edit.start` or `edit.end` are not at character boundaries in
+/// the markdown, or when `edit.start` is after `edit.end`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{reparse, Edit, EventName, ParseTree, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let options = ParseOptions::default();
+/// let tree = ParseTree::new("# a\n\nb\n\nc", &options)?;
+/// let tree = reparse(
+///     &tree,
+///     Edit {
+///         start: 5,
+///         end: 6,
+///         value: "*b*".into(),
+///     },
+/// )?;
+///
+/// assert_eq!(tree.value(), "# a\n\n*b*\n\nc");
+/// assert!(tree.events().iter().any(|d| d.name == EventName::Emphasis));
+/// # Ok(())
+/// # }
+/// ```
+pub fn reparse<'a>(old: &ParseTree<'a>, edit: Edit) -> Result<ParseTree<'a>, MarkdownError> {
+    let options = old.options;
+    let Edit {
+        start: edit_start,
+        end: edit_end,
+        value: insert,
+    } = edit;
+    let mut value = old.value.clone();
+    value.replace_range(edit_start..edit_end, &insert);
+
+    if options.warning_sink.is_some() || has_abbreviation_definition(&old.events) {
+        return ParseTree::new(&value, options);
+    }
+
+    let old_bytes = old.value.as_bytes();
+    let new_bytes = value.as_bytes();
+    let resyncs = resyncs(&old.events, old_bytes.len());
+    let last = resyncs.len() - 1;
+    let delta = (insert.len(), edit_end - edit_start);
+    let line_delta = (count_eols(new_bytes), count_eols(old_bytes));
+
+    // The block before the edit, skipping blank lines.
+    let mut start = resyncs
+        .iter()
+        .rposition(|d| d.index <= edit_start)
+        .unwrap_or(0)
+        .saturating_sub(1);
+    while start > 0 && blank(&old.events[resyncs[start].event..resyncs[start + 1].event]) {
+        start -= 1;
+    }
+
+    // The block after the edit.
+    let after = resyncs
+        .iter()
+        .position(|d| d.index >= edit_end)
+        .unwrap_or(last);
+    let mut end = (after + 1).min(last);
+
+    let (mut events, from, to, event_delta) = loop {
+        let from = &resyncs[start];
+        let to = &resyncs[end];
+        let definition = identifiers(
+            &old.events,
+            old_bytes,
+            options,
+            from,
+            to,
+            &Name::DefinitionLabelString,
+        );
+        let footnote = identifiers(
+            &old.events,
+            old_bytes,
+            options,
+            from,
+            to,
+            &Name::GfmFootnoteDefinitionLabelString,
+        );
+        let result = parse_part(
+            &value,
+            options,
+            Point {
+                line: from.line,
+                column: 1,
+                index: from.index,
+                vs: 0,
+            },
+            shift(to.index, delta),
+            definition.0.iter().cloned().collect(),
+            footnote.0.iter().cloned().collect(),
+        );
+
+        let events = match result {
+            Ok((events, _)) => events,
+            // Errors in MDX, such as an expression that is not closed, can be
+            // because the region stops early.
+            Err(_) if end < last => {
+                end = last;
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+
+        // Something was defined, or no longer defined.
+        if has_abbreviation_definition(&events)
+            || !same(
+                &definition,
+                &region_identifiers(&events, new_bytes, options, &Name::DefinitionLabelString),
+            )
+            || !same(
+                &footnote,
+                &region_identifiers(
+                    &events,
+                    new_bytes,
+                    options,
+                    &Name::GfmFootnoteDefinitionLabelString,
+                ),
+            )
+        {
+            return ParseTree::new(&value, options);
+        }
+
+        let event_delta = (from.event + events.len(), to.event);
+
+        if end < last
+            && !matches(
+                &old.events[resyncs[end - 1].event..to.event],
+                &events,
+                resyncs[end - 1].index,
+                delta,
+                line_delta,
+                event_delta,
+                from.event,
+            )
+        {
+            end = (end + (end - after).max(1)).min(last);
+            continue;
+        }
+
+        break (events, from, to, event_delta);
+    };
+
+    let mut result = Vec::with_capacity(shift(old.events.len(), event_delta));
+    result.extend_from_slice(&old.events[..from.event]);
+
+    for event in &mut events {
+        if let Some(link) = &mut event.link {
+            link.previous = link.previous.map(|d| d + from.event);
+            link.next = link.next.map(|d| d + from.event);
+        }
+    }
+
+    result.append(&mut events);
+
+    for event in &old.events[to.event..] {
+        let mut event = event.clone();
+        event.point.line = shift(event.point.line, line_delta);
+        event.point.index = shift(event.point.index, delta);
+
+        if let Some(link) = &mut event.link {
+            link.previous = link.previous.map(|d| shift(d, event_delta));
+            link.next = link.next.map(|d| shift(d, event_delta));
+        }
+
+        result.push(event);
+    }
+
+    let location = Location::new(value.as_bytes());
+
+    Ok(ParseTree {
+        options,
+        value,
+        events: result,
+        location,
+    })
+}
+
+/// Find places where parsing can start again.
+///
+/// The first place is the start of the document, the last the end.
+fn resyncs(events: &[Event], len: usize) -> Vec<Resync> {
+    let mut result = vec![Resync {
+        line: 1,
+        index: 0,
+        event: 0,
+    }];
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            depth += 1;
+        } else {
+            depth -= 1;
+
+            if depth == 0 && event.name == Name::BlankLineEnding {
+                result.push(Resync {
+                    line: event.point.line,
+                    index: event.point.index,
+                    event: index + 1,
+                });
+            }
+        }
+
+        index += 1;
+    }
+
+    if result[result.len() - 1].index != len {
+        result.push(Resync {
+            line: events.last().map_or(1, |d| d.point.line),
+            index: len,
+            event: events.len(),
+        });
+    }
+
+    result
+}
+
+/// Check if the events of a block are only whitespace and blank lines.
+fn blank(events: &[Event]) -> bool {
+    events
+        .iter()
+        .all(|d| matches!(d.name, Name::BlankLineEnding | Name::SpaceOrTab))
+}
+
+/// Check if the events of the last block before `index` in the old
+/// document are what `events` ends with, after the edit.
+#[allow(clippy::too_many_arguments)]
+fn matches(
+    old: &[Event],
+    events: &[Event],
+    index: usize,
+    delta: Delta,
+    line_delta: Delta,
+    event_delta: Delta,
+    offset: usize,
+) -> bool {
+    // A blank block could now continue a container before it.
+    if blank(old) || events.len() <= old.len() {
+        return false;
+    }
+
+    let before = &events[events.len() - old.len() - 1];
+
+    if !(before.kind == Kind::Exit
+        && before.name == Name::BlankLineEnding
+        && before.point.index == shift(index, delta))
+    {
+        return false;
+    }
+
+    old.iter()
+        .zip(&events[events.len() - old.len()..])
+        .all(|(old, new)| {
+            old.kind == new.kind
+                && old.name == new.name
+                && shift(old.point.line, line_delta) == new.point.line
+                && old.point.column == new.point.column
+                && shift(old.point.index, delta) == new.point.index
+                && old.point.vs == new.point.vs
+                && link_matches(old.link.as_ref(), new.link.as_ref(), event_delta, offset)
+        })
+}
+
+/// Check if an old link, moved by `event_delta`, is a new link, moved by
+/// `offset`.
+fn link_matches(old: Option<&Link>, new: Option<&Link>, event_delta: Delta, offset: usize) -> bool {
+    match (old, new) {
+        (None, None) => true,
+        (Some(old), Some(new)) => {
+            old.content == new.content
+                && old.previous.map(|d| shift(d, event_delta)) == new.previous.map(|d| d + offset)
+                && old.next.map(|d| shift(d, event_delta)) == new.next.map(|d| d + offset)
+        }
+        _ => false,
+    }
+}
+
+/// Check if there are abbreviation definitions in `events`.
+fn has_abbreviation_definition(events: &[Event]) -> bool {
+    events
+        .iter()
+        .any(|d| d.name == Name::AbbreviationDefinition)
+}
+
+/// Get the identifiers of definitions (or footnote definitions, depending on
+/// `name`) in the old document, outside and inside the region from `from`
+/// to `to`.
+fn identifiers(
+    events: &[Event],
+    bytes: &[u8],
+    options: &ParseOptions,
+    from: &Resync,
+    to: &Resync,
+    name: &Name,
+) -> (BTreeSet<String>, BTreeSet<String>) {
+    let mut outside = BTreeSet::new();
+    let mut inside = BTreeSet::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit && event.name == *name {
+            let id = identifier(events, bytes, options, index);
+
+            if index >= from.event && index < to.event {
+                inside.insert(id);
+            } else {
+                outside.insert(id);
+            }
+        }
+
+        index += 1;
+    }
+
+    (outside, inside)
+}
+
+/// Check if the identifiers defined in a region, outside and inside it
+/// before the edit, are the same as those defined with `inside` after it.
+fn same(before: &(BTreeSet<String>, BTreeSet<String>), inside: &BTreeSet<String>) -> bool {
+    let (outside, old) = before;
+    old.iter()
+        .filter(|d| !outside.contains(*d))
+        .eq(inside.iter().filter(|d| !outside.contains(*d)))
+}
+
+/// Get the identifiers of definitions (or footnote definitions, depending on
+/// `name`) in the new events of a region.
+fn region_identifiers(
+    events: &[Event],
+    bytes: &[u8],
+    options: &ParseOptions,
+    name: &Name,
+) -> BTreeSet<String> {
+    let mut result = BTreeSet::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit && event.name == *name {
+            result.insert(identifier(events, bytes, options, index));
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Get the normalized identifier of the label string that exits at `index`.
+fn identifier(events: &[Event], bytes: &[u8], options: &ParseOptions, index: usize) -> String {
+    // Note: we don’t care about virtual spaces, so `as_str` is fine.
+    normalize_identifier(
+        Slice::from_position(
+            bytes,
+            &Position::from_exit_event(events, index),
+            options.tab_size,
+        )
+        .as_str(),
+    )
+}
+
+/// Count line endings.
+fn count_eols(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'\n' || (bytes[index] == b'\r' && bytes.get(index + 1) != Some(&b'\n'))
+        {
+            count += 1;
+        }
+
+        index += 1;
+    }
+
+    count
+}
+
+/// Move `value` by `delta`.
+fn shift(value: usize, delta: Delta) -> usize {
+    value + delta.0 - delta.1
+}
@@ -21,6 +21,22 @@ pub enum Name {
     /// and what occurs before and after each sequence.
     /// Otherwise they are turned into data.
     Attention,
+    /// Resolve highlight.
+    ///
+    /// Highlight sequences are parsed and finally matched together based on
+    /// what occurs before and after each sequence, like GFM strikethrough.
+    /// Otherwise they are turned into data.
+    Highlight,
+    /// Resolve subscript.
+    ///
+    /// Subscript sequences are parsed and finally matched together if there
+    /// is no whitespace between them.
+    /// Otherwise they are turned into data.
+    Subscript,
+    /// Resolve superscript.
+    ///
+    /// Like subscript, but with carets instead of tildes.
+    Superscript,
     /// Resolve GFM tables.
     ///
     /// The table head, and later each row, are all parsed separately.
@@ -43,6 +59,12 @@ pub enum Name {
     /// They are wrapped into ordered or unordered lists based on whether items
     /// with the same marker occur next to each other.
     ListItem,
+    /// Resolve definition list.
+    ///
+    /// Definition descriptions are parsed on their own.
+    /// The paragraphs before them are turned into terms, and terms and
+    /// descriptions that occur next to each other are wrapped into lists.
+    DefinitionList,
     /// Resolve content.
     ///
     /// Content is parsed as single lines, as what remains if other flow
@@ -67,10 +89,14 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> Result<Option<Subresult>,
     let result = match name {
         Name::Label => construct::label_end::resolve(tokenizer),
         Name::Attention => construct::attention::resolve(tokenizer),
+        Name::Highlight => construct::highlight::resolve(tokenizer),
+        Name::Subscript => construct::subscript::resolve(tokenizer),
+        Name::Superscript => construct::superscript::resolve(tokenizer),
         Name::GfmTable => construct::gfm_table::resolve(tokenizer),
         Name::HeadingAtx => construct::heading_atx::resolve(tokenizer),
         Name::HeadingSetext => construct::heading_setext::resolve(tokenizer),
         Name::ListItem => construct::list_item::resolve(tokenizer),
+        Name::DefinitionList => construct::definition_list::resolve(tokenizer),
         Name::Content => construct::content::resolve(tokenizer)?,
         Name::Data => construct::partial_data::resolve(tokenizer),
         Name::String => construct::string::resolve(tokenizer),
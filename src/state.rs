@@ -43,9 +43,31 @@ impl State {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum Name {
+    AbbreviationDefinitionStart,
+    AbbreviationDefinitionBefore,
+    AbbreviationDefinitionLabelBefore,
+    AbbreviationDefinitionLabelInside,
+    AbbreviationDefinitionLabelAfter,
+    AbbreviationDefinitionValueBefore,
+    AbbreviationDefinitionValueInside,
+    AbbreviationDefinitionAfter,
+
     AttentionStart,
     AttentionInside,
 
+    AttributesStart,
+    AttributesBetween,
+    AttributesWhitespace,
+    AttributesShortcutStart,
+    AttributesShortcutInside,
+    AttributesNameInside,
+    AttributesNameAfter,
+    AttributesValueBefore,
+    AttributesValueUnquoted,
+    AttributesValueQuotedStart,
+    AttributesValueQuoted,
+    AttributesNok,
+
     AutolinkStart,
     AutolinkOpen,
     AutolinkSchemeOrEmailAtext,
@@ -107,6 +129,18 @@ pub enum Name {
     DefinitionTitleAfter,
     DefinitionTitleAfterOptionalWhitespace,
 
+    DefinitionListStart,
+    DefinitionListBefore,
+    DefinitionListMarkerAfter,
+    DefinitionListMarkerAfterFilled,
+    DefinitionListWhitespace,
+    DefinitionListWhitespaceAfter,
+    DefinitionListPrefixOther,
+    DefinitionListAfter,
+    DefinitionListContStart,
+    DefinitionListContBlank,
+    DefinitionListContFilled,
+
     DestinationStart,
     DestinationEnclosedBefore,
     DestinationEnclosed,
@@ -114,6 +148,40 @@ pub enum Name {
     DestinationRaw,
     DestinationRawEscape,
 
+    DirectiveContainerStart,
+    DirectiveContainerBefore,
+    DirectiveContainerSequenceOpen,
+    DirectiveContainerOpenAfter,
+    DirectiveContainerOpenEnd,
+    DirectiveContainerNok,
+    DirectiveContainerContStart,
+    DirectiveContainerCloseStart,
+    DirectiveContainerBeforeSequenceClose,
+    DirectiveContainerSequenceClose,
+    DirectiveContainerCloseEnd,
+
+    DirectiveLeafStart,
+    DirectiveLeafBefore,
+    DirectiveLeafSequence,
+    DirectiveLeafAfter,
+    DirectiveLeafEnd,
+    DirectiveLeafNok,
+
+    DirectiveTextStart,
+    DirectiveTextAfter,
+    DirectiveTextNok,
+
+    DirectiveNameStart,
+    DirectiveNameInside,
+    DirectiveLabelBefore,
+    DirectiveLabelStart,
+    DirectiveLabelAtBreak,
+    DirectiveLabelEolAfter,
+    DirectiveLabelNok,
+    DirectiveLabelInside,
+    DirectiveLabelEscape,
+    DirectiveAttributesBefore,
+
     DocumentStart,
     DocumentBeforeFrontmatter,
     DocumentContainerExistingBefore,
@@ -122,6 +190,8 @@ pub enum Name {
     DocumentContainerNewBeforeNotBlockQuote,
     DocumentContainerNewBeforeNotList,
     DocumentContainerNewBeforeNotGfmFootnoteDefinition,
+    DocumentContainerNewBeforeNotDirectiveContainer,
+    DocumentContainerNewBeforeNotDefinitionDescription,
     DocumentContainerNewAfter,
     DocumentContainersAfter,
     DocumentFlowInside,
@@ -130,6 +200,7 @@ pub enum Name {
     FlowStart,
     FlowBeforeGfmTable,
     FlowBeforeCodeIndented,
+    FlowBeforeDirectiveLeaf,
     FlowBeforeRaw,
     FlowBeforeHtml,
     FlowBeforeMdxExpression,
@@ -137,6 +208,7 @@ pub enum Name {
     FlowBeforeHeadingAtx,
     FlowBeforeHeadingSetext,
     FlowBeforeThematicBreak,
+    FlowBeforeAbbreviationDefinition,
     FlowAfter,
     FlowBlankLineBefore,
     FlowBlankLineAfter,
@@ -232,6 +304,10 @@ pub enum Name {
     HeadingSetextInside,
     HeadingSetextAfter,
 
+    HighlightStart,
+    HighlightInside,
+    HighlightAfter,
+
     HtmlFlowStart,
     HtmlFlowBefore,
     HtmlFlowOpen,
@@ -295,6 +371,9 @@ pub enum Name {
     HtmlTextLineEndingAfter,
     HtmlTextLineEndingAfterPrefix,
 
+    InlineFootnoteStart,
+    InlineFootnoteOpen,
+
     LabelStart,
     LabelAtBreak,
     LabelEolAfter,
@@ -445,12 +524,21 @@ pub enum Name {
     StringBefore,
     StringBeforeData,
 
+    SubscriptStart,
+    SubscriptAfter,
+
+    SuperscriptStart,
+    SuperscriptAfter,
+
     TextStart,
     TextBefore,
     TextBeforeHtml,
     TextBeforeMdxJsx,
     TextBeforeHardBreakEscape,
+    TextBeforeGfmLabelStartFootnote,
     TextBeforeLabelStartLink,
+    TextBeforeAttention,
+    TextBeforeSuperscript,
     TextBeforeData,
 
     ThematicBreakStart,
@@ -459,21 +547,54 @@ pub enum Name {
     ThematicBreakAtBreak,
 
     TitleStart,
+    TitleOpenMarker,
     TitleBegin,
+    TitleCloseMarker,
     TitleAfterEol,
     TitleAtBreak,
     TitleEscape,
     TitleInside,
     TitleNok,
+
+    WikilinkStart,
+    WikilinkOpen,
+    WikilinkTargetInside,
+    WikilinkTargetAfter,
+    WikilinkLabelBefore,
+    WikilinkLabelInside,
+    WikilinkClose,
+    WikilinkCloseAfter,
 }
 
 #[allow(clippy::too_many_lines)]
 /// Call the corresponding state for a state name.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
     let func = match name {
+        Name::AbbreviationDefinitionStart => construct::abbreviation::start,
+        Name::AbbreviationDefinitionBefore => construct::abbreviation::before,
+        Name::AbbreviationDefinitionLabelBefore => construct::abbreviation::label_before,
+        Name::AbbreviationDefinitionLabelInside => construct::abbreviation::label_inside,
+        Name::AbbreviationDefinitionLabelAfter => construct::abbreviation::label_after,
+        Name::AbbreviationDefinitionValueBefore => construct::abbreviation::value_before,
+        Name::AbbreviationDefinitionValueInside => construct::abbreviation::value_inside,
+        Name::AbbreviationDefinitionAfter => construct::abbreviation::after,
+
         Name::AttentionStart => construct::attention::start,
         Name::AttentionInside => construct::attention::inside,
 
+        Name::AttributesStart => construct::partial_attributes::start,
+        Name::AttributesBetween => construct::partial_attributes::between,
+        Name::AttributesWhitespace => construct::partial_attributes::whitespace,
+        Name::AttributesShortcutStart => construct::partial_attributes::shortcut_start,
+        Name::AttributesShortcutInside => construct::partial_attributes::shortcut_inside,
+        Name::AttributesNameInside => construct::partial_attributes::name_inside,
+        Name::AttributesNameAfter => construct::partial_attributes::name_after,
+        Name::AttributesValueBefore => construct::partial_attributes::value_before,
+        Name::AttributesValueUnquoted => construct::partial_attributes::value_unquoted,
+        Name::AttributesValueQuotedStart => construct::partial_attributes::value_quoted_start,
+        Name::AttributesValueQuoted => construct::partial_attributes::value_quoted,
+        Name::AttributesNok => construct::partial_attributes::nok,
+
         Name::AutolinkStart => construct::autolink::start,
         Name::AutolinkOpen => construct::autolink::open,
         Name::AutolinkSchemeOrEmailAtext => construct::autolink::scheme_or_email_atext,
@@ -537,6 +658,18 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
             construct::definition::title_after_optional_whitespace
         }
 
+        Name::DefinitionListStart => construct::definition_list::start,
+        Name::DefinitionListBefore => construct::definition_list::before,
+        Name::DefinitionListMarkerAfter => construct::definition_list::marker_after,
+        Name::DefinitionListMarkerAfterFilled => construct::definition_list::marker_after_filled,
+        Name::DefinitionListWhitespace => construct::definition_list::whitespace,
+        Name::DefinitionListWhitespaceAfter => construct::definition_list::whitespace_after,
+        Name::DefinitionListPrefixOther => construct::definition_list::prefix_other,
+        Name::DefinitionListAfter => construct::definition_list::after,
+        Name::DefinitionListContStart => construct::definition_list::cont_start,
+        Name::DefinitionListContBlank => construct::definition_list::cont_blank,
+        Name::DefinitionListContFilled => construct::definition_list::cont_filled,
+
         Name::DestinationStart => construct::partial_destination::start,
         Name::DestinationEnclosedBefore => construct::partial_destination::enclosed_before,
         Name::DestinationEnclosed => construct::partial_destination::enclosed,
@@ -544,6 +677,42 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DestinationRaw => construct::partial_destination::raw,
         Name::DestinationRawEscape => construct::partial_destination::raw_escape,
 
+        Name::DirectiveContainerStart => construct::directive_container::start,
+        Name::DirectiveContainerBefore => construct::directive_container::before,
+        Name::DirectiveContainerSequenceOpen => construct::directive_container::sequence_open,
+        Name::DirectiveContainerOpenAfter => construct::directive_container::open_after,
+        Name::DirectiveContainerOpenEnd => construct::directive_container::open_end,
+        Name::DirectiveContainerNok => construct::directive_container::nok,
+        Name::DirectiveContainerContStart => construct::directive_container::cont_start,
+        Name::DirectiveContainerCloseStart => construct::directive_container::close_start,
+        Name::DirectiveContainerBeforeSequenceClose => {
+            construct::directive_container::before_sequence_close
+        }
+        Name::DirectiveContainerSequenceClose => construct::directive_container::sequence_close,
+        Name::DirectiveContainerCloseEnd => construct::directive_container::close_end,
+
+        Name::DirectiveLeafStart => construct::directive_leaf::start,
+        Name::DirectiveLeafBefore => construct::directive_leaf::before,
+        Name::DirectiveLeafSequence => construct::directive_leaf::sequence,
+        Name::DirectiveLeafAfter => construct::directive_leaf::after,
+        Name::DirectiveLeafEnd => construct::directive_leaf::end,
+        Name::DirectiveLeafNok => construct::directive_leaf::nok,
+
+        Name::DirectiveTextStart => construct::directive_text::start,
+        Name::DirectiveTextAfter => construct::directive_text::after,
+        Name::DirectiveTextNok => construct::directive_text::nok,
+
+        Name::DirectiveNameStart => construct::partial_directive::name_start,
+        Name::DirectiveNameInside => construct::partial_directive::name_inside,
+        Name::DirectiveLabelBefore => construct::partial_directive::label_before,
+        Name::DirectiveLabelStart => construct::partial_directive::label_start,
+        Name::DirectiveLabelAtBreak => construct::partial_directive::label_at_break,
+        Name::DirectiveLabelEolAfter => construct::partial_directive::label_eol_after,
+        Name::DirectiveLabelNok => construct::partial_directive::label_nok,
+        Name::DirectiveLabelInside => construct::partial_directive::label_inside,
+        Name::DirectiveLabelEscape => construct::partial_directive::label_escape,
+        Name::DirectiveAttributesBefore => construct::partial_directive::attributes_before,
+
         Name::DocumentStart => construct::document::start,
         Name::DocumentBeforeFrontmatter => construct::document::before_frontmatter,
         Name::DocumentContainerExistingBefore => construct::document::container_existing_before,
@@ -558,6 +727,12 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentContainerNewBeforeNotGfmFootnoteDefinition => {
             construct::document::container_new_before_not_footnote_definition
         }
+        Name::DocumentContainerNewBeforeNotDirectiveContainer => {
+            construct::document::container_new_before_not_directive_container
+        }
+        Name::DocumentContainerNewBeforeNotDefinitionDescription => {
+            construct::document::container_new_before_not_definition_description
+        }
         Name::DocumentContainerNewAfter => construct::document::container_new_after,
         Name::DocumentContainersAfter => construct::document::containers_after,
         Name::DocumentFlowEnd => construct::document::flow_end,
@@ -566,6 +741,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::FlowStart => construct::flow::start,
         Name::FlowBeforeGfmTable => construct::flow::before_gfm_table,
         Name::FlowBeforeCodeIndented => construct::flow::before_code_indented,
+        Name::FlowBeforeDirectiveLeaf => construct::flow::before_directive_leaf,
         Name::FlowBeforeRaw => construct::flow::before_raw,
         Name::FlowBeforeHtml => construct::flow::before_html,
         Name::FlowBeforeMdxExpression => construct::flow::before_mdx_expression,
@@ -573,6 +749,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::FlowBeforeHeadingAtx => construct::flow::before_heading_atx,
         Name::FlowBeforeHeadingSetext => construct::flow::before_heading_setext,
         Name::FlowBeforeThematicBreak => construct::flow::before_thematic_break,
+        Name::FlowBeforeAbbreviationDefinition => construct::flow::before_abbreviation_definition,
         Name::FlowAfter => construct::flow::after,
         Name::FlowBlankLineBefore => construct::flow::blank_line_before,
         Name::FlowBlankLineAfter => construct::flow::blank_line_after,
@@ -689,6 +866,10 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::HeadingSetextInside => construct::heading_setext::inside,
         Name::HeadingSetextAfter => construct::heading_setext::after,
 
+        Name::HighlightStart => construct::highlight::start,
+        Name::HighlightInside => construct::highlight::inside,
+        Name::HighlightAfter => construct::highlight::after,
+
         Name::HtmlFlowStart => construct::html_flow::start,
         Name::HtmlFlowBefore => construct::html_flow::before,
         Name::HtmlFlowOpen => construct::html_flow::open,
@@ -778,6 +959,9 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::HtmlTextLineEndingAfter => construct::html_text::line_ending_after,
         Name::HtmlTextLineEndingAfterPrefix => construct::html_text::line_ending_after_prefix,
 
+        Name::InlineFootnoteStart => construct::inline_footnote::start,
+        Name::InlineFootnoteOpen => construct::inline_footnote::open,
+
         Name::LabelStart => construct::partial_label::start,
         Name::LabelAtBreak => construct::partial_label::at_break,
         Name::LabelEolAfter => construct::partial_label::eol_after,
@@ -943,12 +1127,21 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::StringBefore => construct::string::before,
         Name::StringBeforeData => construct::string::before_data,
 
+        Name::SubscriptStart => construct::subscript::start,
+        Name::SubscriptAfter => construct::subscript::after,
+
+        Name::SuperscriptStart => construct::superscript::start,
+        Name::SuperscriptAfter => construct::superscript::after,
+
         Name::TextStart => construct::text::start,
         Name::TextBefore => construct::text::before,
         Name::TextBeforeHtml => construct::text::before_html,
         Name::TextBeforeMdxJsx => construct::text::before_mdx_jsx,
         Name::TextBeforeHardBreakEscape => construct::text::before_hard_break_escape,
+        Name::TextBeforeGfmLabelStartFootnote => construct::text::before_gfm_label_start_footnote,
         Name::TextBeforeLabelStartLink => construct::text::before_label_start_link,
+        Name::TextBeforeAttention => construct::text::before_attention,
+        Name::TextBeforeSuperscript => construct::text::before_superscript,
         Name::TextBeforeData => construct::text::before_data,
 
         Name::ThematicBreakStart => construct::thematic_break::start,
@@ -957,12 +1150,23 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::ThematicBreakAtBreak => construct::thematic_break::at_break,
 
         Name::TitleStart => construct::partial_title::start,
+        Name::TitleOpenMarker => construct::partial_title::open_marker,
         Name::TitleBegin => construct::partial_title::begin,
+        Name::TitleCloseMarker => construct::partial_title::close_marker,
         Name::TitleAfterEol => construct::partial_title::after_eol,
         Name::TitleAtBreak => construct::partial_title::at_break,
         Name::TitleEscape => construct::partial_title::escape,
         Name::TitleInside => construct::partial_title::inside,
         Name::TitleNok => construct::partial_title::nok,
+
+        Name::WikilinkStart => construct::wikilink::start,
+        Name::WikilinkOpen => construct::wikilink::open,
+        Name::WikilinkTargetInside => construct::wikilink::target_inside,
+        Name::WikilinkTargetAfter => construct::wikilink::target_after,
+        Name::WikilinkLabelBefore => construct::wikilink::label_before,
+        Name::WikilinkLabelInside => construct::wikilink::label_inside,
+        Name::WikilinkClose => construct::wikilink::close,
+        Name::WikilinkCloseAfter => construct::wikilink::close_after,
     };
 
     func(tokenizer)
@@ -27,6 +27,7 @@ use alloc::{string::String, vec, vec::Vec};
 #[derive(Debug)]
 pub struct Subresult {
     pub done: bool,
+    pub abbreviations: Vec<String>,
     pub gfm_footnote_definitions: Vec<String>,
     pub definitions: Vec<String>,
 }
@@ -83,6 +84,7 @@ pub fn subtokenize(
     let mut index = 0;
     let mut value = Subresult {
         done: true,
+        abbreviations: vec![],
         gfm_footnote_definitions: vec![],
         definitions: vec![],
     };
@@ -164,6 +166,7 @@ pub fn subtokenize(
                 }
 
                 let mut result = tokenizer.flush(state, true)?;
+                value.abbreviations.append(&mut result.abbreviations);
                 value
                     .gfm_footnote_definitions
                     .append(&mut result.gfm_footnote_definitions);
@@ -1,19 +1,26 @@
 //! Turn events into a string of HTML.
-use crate::event::{Event, Kind, Name};
+use crate::event::{position as event_position, Event, Kind, Name};
 use crate::mdast::AlignKind;
 use crate::util::{
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
     encode::encode,
     gfm_tagfilter::gfm_tagfilter,
-    infer::{gfm_table_align, list_loose},
+    infer::{
+        code_fenced_info, definition_list_loose, directive_attributes, emoji, gfm_table_align,
+        list_loose, text_content, wikilink,
+    },
     normalize_identifier::normalize_identifier,
-    sanitize_uri::{sanitize, sanitize_with_protocols},
+    resolve_uri::resolve,
+    sanitize_uri::{protocol, sanitize, sanitize_with_protocols},
     skip,
     slice::{Position, Slice},
+    slug::{slug, unique as unique_slug, Seen},
+    smart_punctuation::smart_punctuation,
 };
-use crate::{CompileOptions, LineEnding};
+use crate::{CompileOptions, EventOutput, HeadingAnchor, LineEnding, ParseOptions};
 use alloc::{
+    collections::BTreeSet,
     format,
     string::{String, ToString},
     vec,
@@ -71,6 +78,23 @@ struct Definition {
     title: Option<String>,
 }
 
+/// Representation of a directive.
+#[derive(Debug)]
+struct Directive {
+    /// Name.
+    name: String,
+    /// The result of interpreting the text between the brackets
+    /// (`b` in `:a[b]`).
+    label: Option<String>,
+    /// Attributes, as key/value pairs.
+    attributes: Vec<(String, String)>,
+    /// Whether the opening tag was written.
+    ///
+    /// Only used for directive (container), whose fences are both
+    /// [`DirectiveContainerFence`][Name::DirectiveContainerFence].
+    open: bool,
+}
+
 /// Context used to compile markdown.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug)]
@@ -80,22 +104,34 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Size of a tab stop.
+    tab_size: usize,
     /// Configuration.
     options: &'a CompileOptions,
+    /// Configuration used to parse (for `wikilink_url`).
+    parse_options: &'a ParseOptions,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     /// Rank of heading (atx).
     heading_atx_rank: Option<usize>,
+    /// ID of heading (atx) to add an anchor to after its text.
+    heading_atx_anchor: Option<String>,
     /// Buffer of heading (setext) text.
     heading_setext_buffer: Option<String>,
+    /// Heading IDs that were generated.
+    heading_ids: Seen,
     /// Whether raw (flow) (code (fenced), math (flow)) or code (indented) contains data.
     raw_flow_seen_data: Option<bool>,
     /// Number of raw (flow) fences.
     raw_flow_fences_count: Option<usize>,
+    /// Language and meta of code (fenced, indented), if it is highlighted.
+    raw_flow_info: Option<(String, String)>,
     /// Whether we are in code (text).
     raw_text_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in a paragraph.
+    paragraph_inside: bool,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
@@ -104,12 +140,21 @@ struct CompileContext<'a> {
     media_stack: Vec<Media>,
     /// Stack of containers.
     tight_stack: Vec<bool>,
+    /// Stack of directives.
+    directive_stack: Vec<Directive>,
+    /// List of abbreviations, as label and title.
+    abbreviations: Vec<(String, Option<String>)>,
     /// List of definitions.
     definitions: Vec<Definition>,
     /// List of definitions.
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
     gfm_footnote_definition_stack: Vec<(usize, usize)>,
+    /// Identifiers of all GFM footnote definitions, which inline footnotes
+    /// must not use.
+    gfm_footnote_definition_identifiers: BTreeSet<String>,
+    /// Number to try for the next inline footnote.
+    inline_footnote_number: usize,
     /// Whether we are in a GFM table head.
     gfm_table_in_head: bool,
     /// Current GFM table alignment.
@@ -121,6 +166,12 @@ struct CompileContext<'a> {
     slurp_one_line_ending: bool,
     /// Whether to encode HTML.
     encode_html: bool,
+    /// Whether we are in a comment in HTML (flow, text) that is dropped.
+    html_comment_inside: bool,
+    /// Where the current line of HTML (flow) starts in the current buffer,
+    /// where the line ending before it starts, and whether a comment was
+    /// dropped from it, when comments are dropped.
+    html_flow_line: Option<(usize, usize, bool)>,
     // Configuration
     /// Line ending to use.
     line_ending_default: LineEnding,
@@ -136,35 +187,48 @@ impl<'a> CompileContext<'a> {
     fn new(
         events: &'a [Event],
         bytes: &'a [u8],
+        parse_options: &'a ParseOptions,
         options: &'a CompileOptions,
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            tab_size: parse_options.tab_size,
             heading_atx_rank: None,
+            heading_atx_anchor: None,
             heading_setext_buffer: None,
+            heading_ids: Seen::default(),
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
+            raw_flow_info: None,
             raw_text_inside: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
             media_stack: vec![],
+            directive_stack: vec![],
+            abbreviations: vec![],
             definitions: vec![],
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
+            gfm_footnote_definition_identifiers: BTreeSet::new(),
+            inline_footnote_number: 1,
             gfm_table_in_head: false,
             gfm_table_align: None,
             gfm_table_column: 0,
             tight_stack: vec![],
             slurp_one_line_ending: false,
             image_alt_inside: false,
+            paragraph_inside: false,
             encode_html: true,
+            html_comment_inside: false,
+            html_flow_line: None,
             line_ending_default: line_ending,
             buffers: vec![String::new()],
             index: 0,
             options,
+            parse_options,
         }
     }
 
@@ -204,7 +268,13 @@ impl<'a> CompileContext<'a> {
 }
 
 /// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> String {
+    let tab_size = parse_options.tab_size;
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -216,7 +286,8 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
         if event.kind == Kind::Exit
             && (event.name == Name::BlankLineEnding || event.name == Name::LineEnding)
         {
-            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+            let slice =
+                Slice::from_position(bytes, &Position::from_exit_event(events, index), tab_size);
             line_ending_inferred = Some(slice.as_str().parse().unwrap());
             break;
         }
@@ -225,15 +296,19 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     }
 
     // Figure out which line ending style we’ll use.
-    let line_ending_default =
-        line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
-
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let line_ending_default = options
+        .normalize_line_endings
+        .clone()
+        .or(line_ending_inferred)
+        .unwrap_or_else(|| options.default_line_ending.clone());
+
+    let mut context =
+        CompileContext::new(events, bytes, parse_options, options, line_ending_default);
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
 
-    // Handle all definitions first.
+    // Handle all definitions (and abbreviation definitions) first.
     // We must do two passes because we need to compile the events in
     // definitions which come after references already.
     //
@@ -244,6 +319,8 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     // unlike normal definitions, what they produce is not used in calls.
     // It would also get very complex, because footnote definitions can be
     // nested.
+    // We only collect their identifiers, so that inline footnotes can get
+    // an identifier that is not used by them.
     while index < events.len() {
         let event = &events[index];
 
@@ -252,14 +329,20 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
         }
 
         if event.kind == Kind::Enter {
-            if event.name == Name::Definition {
+            if matches!(event.name, Name::AbbreviationDefinition | Name::Definition) {
                 handle(&mut context, index); // Also handle start.
                 definition_inside = true;
                 definition_indices.push((index, index));
             }
-        } else if event.name == Name::Definition {
+        } else if matches!(event.name, Name::AbbreviationDefinition | Name::Definition) {
             definition_inside = false;
             definition_indices.last_mut().unwrap().1 = index;
+        } else if event.name == Name::GfmFootnoteDefinitionLabelString {
+            let slice =
+                Slice::from_position(bytes, &Position::from_exit_event(events, index), tab_size);
+            context
+                .gfm_footnote_definition_identifiers
+                .insert(normalize_identifier(slice.as_str()));
         }
 
         index += 1;
@@ -302,21 +385,74 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
 fn handle(context: &mut CompileContext, index: usize) {
     context.index = index;
 
+    if context.options.event_html.is_none() {
+        if context.events[index].kind == Kind::Enter {
+            enter(context);
+        } else {
+            exit(context);
+        }
+
+        return;
+    }
+
+    // The default is always compiled, as later events depend on the state it
+    // sets.
+    // What it adds to the current buffer is then passed to `event_html`, which
+    // can replace it.
+    // Buffers added (on enter) or removed (on exit) are left alone.
+    let image_alt_inside = context.image_alt_inside;
+    let lengths = context.buffers.iter().map(String::len).collect::<Vec<_>>();
+
     if context.events[index].kind == Kind::Enter {
         enter(context);
     } else {
         exit(context);
     }
+
+    // Image alt text is plain text, but the enter and exit of images are not.
+    if image_alt_inside && context.image_alt_inside {
+        return;
+    }
+
+    let current = context.buffers.len().min(lengths.len()) - 1;
+    let buffer = &context.buffers[current];
+    let length = lengths[current].min(buffer.len());
+    let event = &context.events[index];
+    let output = if let Some(position) = event_position(context.events, index) {
+        let value = Slice::from_indices(context.bytes, position.start.offset, position.end.offset);
+        let event_html = context.options.event_html.as_ref().unwrap();
+        event_html(
+            &event.kind,
+            &event.name,
+            value.as_str(),
+            &buffer[..length],
+            &buffer[length..],
+        )
+    } else {
+        None
+    };
+
+    if let Some(output) = output {
+        let html = match output {
+            EventOutput::Raw(value) => value,
+            EventOutput::Escaped(value) => encode(&value, true),
+        };
+        let buffer = &mut context.buffers[current];
+        buffer.truncate(length);
+        buffer.push_str(&html);
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceInfo
-        | Name::CodeFencedFenceMeta
+        Name::AbbreviationDefinition
+        | Name::AbbreviationDefinitionValue
+        | Name::CodeFencedFenceInfoString
         | Name::MathFlowFenceMeta
         | Name::DefinitionLabelString
         | Name::DefinitionTitleString
+        | Name::DirectiveLabel
         | Name::GfmFootnoteDefinitionPrefix
         | Name::HeadingAtxText
         | Name::HeadingSetextText
@@ -334,11 +470,17 @@ fn enter(context: &mut CompileContext) {
         Name::CodeFenced | Name::MathFlow => on_enter_raw_flow(context),
         Name::CodeText | Name::MathText => on_enter_raw_text(context),
         Name::Definition => on_enter_definition(context),
+        Name::DefinitionDescription => on_enter_definition_description(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
+        Name::DefinitionList => on_enter_definition_list(context),
+        Name::DefinitionTerm => on_enter_definition_term(context),
+        Name::DirectiveAttributes => on_enter_directive_attributes(context),
+        Name::DirectiveContainer => on_enter_directive_container(context),
+        Name::DirectiveLeaf | Name::DirectiveText => on_enter_directive(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
-        Name::GfmFootnoteCall => on_enter_gfm_footnote_call(context),
+        Name::GfmFootnoteCall | Name::InlineFootnote => on_enter_gfm_footnote_call(context),
         Name::GfmStrikethrough => on_enter_gfm_strikethrough(context),
         Name::GfmTable => on_enter_gfm_table(context),
         Name::GfmTableBody => on_enter_gfm_table_body(context),
@@ -346,6 +488,7 @@ fn enter(context: &mut CompileContext) {
         Name::GfmTableHead => on_enter_gfm_table_head(context),
         Name::GfmTableRow => on_enter_gfm_table_row(context),
         Name::GfmTaskListItemCheck => on_enter_gfm_task_list_item_check(context),
+        Name::Highlight => on_enter_highlight(context),
         Name::HtmlFlow => on_enter_html_flow(context),
         Name::HtmlText => on_enter_html_text(context),
         Name::Image => on_enter_image(context),
@@ -356,6 +499,8 @@ fn enter(context: &mut CompileContext) {
         Name::Resource => on_enter_resource(context),
         Name::ResourceDestinationString => on_enter_resource_destination_string(context),
         Name::Strong => on_enter_strong(context),
+        Name::Subscript => on_enter_subscript(context),
+        Name::Superscript => on_enter_superscript(context),
         _ => {}
     }
 }
@@ -363,7 +508,13 @@ fn enter(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceMeta
+        Name::Abbreviation => on_exit_abbreviation(context),
+        Name::AbbreviationDefinition => on_exit_abbreviation_definition(context),
+        Name::AbbreviationDefinitionLabelString => {
+            on_exit_abbreviation_definition_label_string(context);
+        }
+        Name::AbbreviationDefinitionValue => on_exit_abbreviation_definition_value(context),
+        Name::DirectiveAttributes
         | Name::MathFlowFenceMeta
         | Name::MdxJsxTextTag
         | Name::MdxTextExpression
@@ -388,13 +539,23 @@ fn exit(context: &mut CompileContext) {
         Name::CharacterReferenceValue => on_exit_character_reference_value(context),
         Name::CodeFenced | Name::CodeIndented | Name::MathFlow => on_exit_raw_flow(context),
         Name::CodeFencedFence | Name::MathFlowFence => on_exit_raw_flow_fence(context),
-        Name::CodeFencedFenceInfo => on_exit_raw_flow_fence_info(context),
+        Name::CodeFencedFenceInfoString => on_exit_code_fenced_fence_info_string(context),
         Name::CodeFlowChunk | Name::MathFlowChunk => on_exit_raw_flow_chunk(context),
         Name::CodeText | Name::MathText => on_exit_raw_text(context),
         Name::Definition => on_exit_definition(context),
+        Name::DefinitionDescription => on_exit_definition_description(context),
         Name::DefinitionDestinationString => on_exit_definition_destination_string(context),
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
+        Name::DefinitionList => on_exit_definition_list(context),
+        Name::DefinitionTerm => on_exit_definition_term(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::DirectiveContainer => on_exit_directive_container(context),
+        Name::DirectiveContainerFence => on_exit_directive_container_fence(context),
+        Name::DirectiveLabel => on_exit_directive_label(context),
+        Name::DirectiveLeaf => on_exit_directive_leaf(context),
+        Name::DirectiveName => on_exit_directive_name(context),
+        Name::DirectiveText => on_exit_directive_text(context),
+        Name::Emoji => on_exit_emoji(context),
         Name::Emphasis => on_exit_emphasis(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
@@ -422,9 +583,11 @@ fn exit(context: &mut CompileContext) {
         Name::HeadingAtxText => on_exit_heading_atx_text(context),
         Name::HeadingSetextText => on_exit_heading_setext_text(context),
         Name::HeadingSetextUnderlineSequence => on_exit_heading_setext_underline_sequence(context),
+        Name::Highlight => on_exit_highlight(context),
         Name::HtmlFlow | Name::HtmlText => on_exit_html(context),
         Name::HtmlFlowData | Name::HtmlTextData => on_exit_html_data(context),
         Name::Image | Name::Link => on_exit_media(context),
+        Name::InlineFootnote => on_exit_inline_footnote(context),
         Name::Label => on_exit_label(context),
         Name::LabelText => on_exit_label_text(context),
         Name::LineEnding => on_exit_line_ending(context),
@@ -436,7 +599,10 @@ fn exit(context: &mut CompileContext) {
         Name::ResourceDestinationString => on_exit_resource_destination_string(context),
         Name::ResourceTitleString => on_exit_resource_title_string(context),
         Name::Strong => on_exit_strong(context),
+        Name::Subscript => on_exit_subscript(context),
+        Name::Superscript => on_exit_superscript(context),
         Name::ThematicBreak => on_exit_thematic_break(context),
+        Name::Wikilink => on_exit_wikilink(context),
         _ => {}
     }
 }
@@ -459,7 +625,17 @@ fn on_enter_block_quote(context: &mut CompileContext) {
 fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
-    context.push("<pre><code>");
+    context.push(if raw_flow_code_element(context) {
+        "<pre><code>"
+    } else {
+        "<pre>"
+    });
+
+    if context.options.highlight.is_some() {
+        context.raw_flow_info = Some((String::new(), String::new()));
+        context.encode_html = false;
+        context.buffer();
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
@@ -467,7 +643,11 @@ fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
     // Note that no `>` is used, which is added later (due to info)
-    context.push("<pre><code");
+    context.push(if raw_flow_code_element(context) {
+        "<pre><code"
+    } else {
+        "<pre"
+    });
     context.raw_flow_fences_count = Some(0);
 
     if context.events[context.index].name == Name::MathFlow {
@@ -501,12 +681,61 @@ fn on_enter_definition(context: &mut CompileContext) {
     });
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionDescription`][Name::DefinitionDescription].
+fn on_enter_definition_description(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<dd>");
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`DefinitionDestinationString`][Name::DefinitionDestinationString].
 fn on_enter_definition_destination_string(context: &mut CompileContext) {
     context.buffer();
     context.encode_html = false;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionList`][Name::DefinitionList].
+fn on_enter_definition_list(context: &mut CompileContext) {
+    let loose = definition_list_loose(context.events, context.index);
+    context.tight_stack.push(!loose);
+    context.line_ending_if_needed();
+    context.push("<dl>");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionTerm`][Name::DefinitionTerm].
+fn on_enter_definition_term(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<dt>");
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`DirectiveLeaf`][Name::DirectiveLeaf],[`DirectiveText`][Name::DirectiveText]}.
+fn on_enter_directive(context: &mut CompileContext) {
+    context.directive_stack.push(Directive {
+        name: String::new(),
+        label: None,
+        attributes: vec![],
+        open: false,
+    });
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveAttributes`][Name::DirectiveAttributes].
+fn on_enter_directive_attributes(context: &mut CompileContext) {
+    let attributes = directive_attributes(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+    );
+    context.directive_stack.last_mut().unwrap().attributes = attributes;
+    // Drop the raw attributes.
+    context.buffer();
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_enter_directive_container(context: &mut CompileContext) {
+    on_enter_directive(context);
+    context.tight_stack.push(false);
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -524,7 +753,7 @@ fn on_enter_gfm_footnote_definition(context: &mut CompileContext) {
     context.tight_stack.push(false);
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`GfmFootnoteCall`][Name::GfmFootnoteCall].
+/// Handle [`Enter`][Kind::Enter]:{[`GfmFootnoteCall`][Name::GfmFootnoteCall],[`InlineFootnote`][Name::InlineFootnote]}.
 fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
     context.media_stack.push(Media {
         image: false,
@@ -605,11 +834,27 @@ fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Highlight`][Name::Highlight].
+fn on_enter_highlight(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<mark>");
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
 fn on_enter_html_flow(context: &mut CompileContext) {
-    context.line_ending_if_needed();
+    if context.options.strip_comments {
+        // Capture the HTML, to find out on exit whether anything is left.
+        context.buffer();
+        context.html_flow_line = Some((0, 0, false));
+    } else {
+        context.line_ending_if_needed();
+    }
+
     if context.options.allow_dangerous_html {
         context.encode_html = false;
+    } else if !context.options.strip_comments {
+        html_shown_as_text(context);
     }
 }
 
@@ -617,6 +862,8 @@ fn on_enter_html_flow(context: &mut CompileContext) {
 fn on_enter_html_text(context: &mut CompileContext) {
     if context.options.allow_dangerous_html {
         context.encode_html = false;
+    } else if !(context.options.strip_comments && html_comment_start(context)) {
+        html_shown_as_text(context);
     }
 }
 
@@ -675,6 +922,7 @@ fn on_enter_list_item_marker(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Paragraph`][Name::Paragraph].
 fn on_enter_paragraph(context: &mut CompileContext) {
     let tight = context.tight_stack.last().unwrap_or(&false);
+    context.paragraph_inside = true;
 
     if !tight {
         context.line_ending_if_needed();
@@ -703,6 +951,75 @@ fn on_enter_strong(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Subscript`][Name::Subscript].
+fn on_enter_subscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<sub>");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Superscript`][Name::Superscript].
+fn on_enter_superscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<sup>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+        context.tab_size,
+    );
+    let value = slice.as_str();
+    // The first definition wins.
+    let title = context
+        .abbreviations
+        .iter()
+        .find(|d| d.0 == value)
+        .and_then(|d| d.1.clone());
+
+    if !context.image_alt_inside {
+        context.push("<abbr");
+        if let Some(title) = title {
+            context.push(" title=\"");
+            context.push(&title);
+            context.push("\"");
+        }
+        context.push(">");
+    }
+
+    context.push(&encode(value, context.encode_html));
+
+    if !context.image_alt_inside {
+        context.push("</abbr>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinition`][Name::AbbreviationDefinition].
+fn on_exit_abbreviation_definition(context: &mut CompileContext) {
+    context.resume();
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString].
+fn on_exit_abbreviation_definition_label_string(context: &mut CompileContext) {
+    let label = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+        context.tab_size,
+    )
+    .as_str()
+    .to_string();
+    context.abbreviations.push((label, None));
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue].
+fn on_exit_abbreviation_definition_value(context: &mut CompileContext) {
+    let value = context.resume();
+    context.abbreviations.last_mut().unwrap().1 = Some(value.trim_end().into());
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkEmail`][Name::AutolinkEmail].
 fn on_exit_autolink_email(context: &mut CompileContext) {
     generate_autolink(
@@ -711,6 +1028,7 @@ fn on_exit_autolink_email(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         false,
@@ -725,6 +1043,7 @@ fn on_exit_autolink_protocol(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         false,
@@ -734,13 +1053,24 @@ fn on_exit_autolink_protocol(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:{[`HardBreakEscape`][Name::HardBreakEscape],[`HardBreakTrailing`][Name::HardBreakTrailing]}.
 fn on_exit_break(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<br />");
+        context.push(
+            context
+                .options
+                .hard_break_html
+                .as_deref()
+                .unwrap_or("<br />"),
+        );
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`BlankLineEnding`][Name::BlankLineEnding].
 fn on_exit_blank_line_ending(context: &mut CompileContext) {
-    if context.index == context.events.len() - 1 {
+    if context.index == context.events.len() - 1
+        // Rest of a closing fence of directive (container), which can be
+        // followed by a tight paragraph.
+        || (context.index > 1
+            && context.events[context.index - 2].name == Name::DirectiveContainer)
+    {
         context.line_ending_if_needed();
     }
 }
@@ -777,6 +1107,7 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &Position::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let value = decode_character_reference(slice.as_str(), marker, true)
         .expect("expected to parse only valid named references");
@@ -791,6 +1122,7 @@ fn on_exit_raw_flow_chunk(context: &mut CompileContext) {
         &Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         // Must serialize to get virtual spaces.
         .serialize(),
@@ -807,17 +1139,57 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
     if count == 0 {
         context.push(">");
         context.slurp_one_line_ending = true;
+
+        if context.options.highlight.is_some()
+            && context.events[context.index].name == Name::CodeFencedFence
+        {
+            let mut language = String::new();
+            let mut meta = String::new();
+            let mut index = context.index - 1;
+
+            while context.events[index].name != Name::CodeFencedFence {
+                if context.events[index].kind == Kind::Enter
+                    && context.events[index].name == Name::CodeFencedFenceInfoString
+                {
+                    let info =
+                        code_fenced_info(context.events, context.bytes, context.tab_size, index);
+                    language = info.1;
+                    meta = info.2.unwrap_or_default();
+                }
+
+                index -= 1;
+            }
+
+            context.raw_flow_info = Some((language, meta));
+            context.encode_html = false;
+            context.buffer();
+        }
     }
 
     context.raw_flow_fences_count = Some(count + 1);
 }
 
-/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo].
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString].
 ///
 /// Note: math (flow) does not support `info`.
-fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
-    let value = context.resume();
-    context.push(" class=\"language-");
+fn on_exit_code_fenced_fence_info_string(context: &mut CompileContext) {
+    context.resume();
+    let mut index = context.index - 1;
+
+    while context.events[index].name != Name::CodeFencedFenceInfoString {
+        index -= 1;
+    }
+
+    let (_, language, _) = code_fenced_info(context.events, context.bytes, context.tab_size, index);
+    let value = encode(&language, context.encode_html);
+    context.push(" class=\"");
+    context.push(
+        context
+            .options
+            .code_language_prefix
+            .as_deref()
+            .unwrap_or("language-"),
+    );
     context.push(&value);
     context.push("\"");
 }
@@ -836,6 +1208,10 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
             && !context.tight_stack.is_empty()
             // Empty (as the closing is right at the opening fence)
             && !matches!(context.events[context.index - 1].name, Name::CodeFencedFence | Name::MathFlowFence)
+            // The line ending after data is already inside (as the flow was
+            // flushed).
+            && (context.events[context.index - 1].name != Name::LineEnding
+                || !matches!(context.events[context.index - 3].name, Name::CodeFlowChunk | Name::MathFlowChunk))
         {
             context.line_ending();
         }
@@ -851,7 +1227,23 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
         context.line_ending_if_needed();
     }
 
-    context.push("</code></pre>");
+    if let Some((language, meta)) = context.raw_flow_info.take() {
+        let code = context.resume();
+        let highlight = context
+            .options
+            .highlight
+            .as_ref()
+            .expect("expected `highlight` if there is an info");
+        let value = highlight(&language, &meta, &code);
+        context.encode_html = true;
+        context.push(&value);
+    }
+
+    context.push(if raw_flow_code_element(context) {
+        "</code></pre>"
+    } else {
+        "</pre>"
+    });
 
     if let Some(count) = context.raw_flow_fences_count.take() {
         if count < 2 {
@@ -930,14 +1322,22 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        context.encode_html,
-    ));
+    let position = Position::from_exit_event(context.events, context.index);
+
+    // Only change text: not escapes, code, URLs, or the info of fenced code.
+    if context.options.smart_punctuation
+        && context.encode_html
+        && context.raw_flow_fences_count.is_none()
+        && context.events[context.index].name == Name::Data
+    {
+        let value = smart_punctuation(context.bytes, position.start.index, position.end.index);
+        context.push(&encode(&value, true));
+    } else {
+        context.push(&encode(
+            Slice::from_position(context.bytes, &position, context.tab_size).as_str(),
+            context.encode_html,
+        ));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -948,6 +1348,34 @@ fn on_exit_definition(context: &mut CompileContext) {
     let id =
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str());
 
+    if let Some(sink) = &context.options.definition_sink {
+        // The title is HTML-encoded, so get it from the events instead.
+        let mut index = context.index - 1;
+        let mut title = None;
+
+        while context.events[index].name != Name::Definition {
+            if context.events[index].kind == Kind::Enter
+                && context.events[index].name == Name::DefinitionTitleString
+            {
+                title = Some(text_content(
+                    context.events,
+                    context.bytes,
+                    context.tab_size,
+                    index,
+                ));
+                break;
+            }
+
+            index -= 1;
+        }
+
+        sink(
+            &id.to_lowercase(),
+            media.destination.as_deref().unwrap_or_default(),
+            title.as_deref(),
+        );
+    }
+
     context.definitions.push(Definition {
         id,
         destination: media.destination,
@@ -955,6 +1383,34 @@ fn on_exit_definition(context: &mut CompileContext) {
     });
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionDescription`][Name::DefinitionDescription].
+fn on_exit_definition_description(context: &mut CompileContext) {
+    let tight = context.tight_stack.last().unwrap_or(&false);
+    let before = skip::opt_back(
+        context.events,
+        context.index - 1,
+        &[
+            Name::BlankLineEnding,
+            Name::BlockQuotePrefix,
+            Name::LineEnding,
+            Name::SpaceOrTab,
+            // Also ignore things that don’t contribute to the document.
+            Name::AbbreviationDefinition,
+            Name::Definition,
+            Name::GfmFootnoteDefinition,
+        ],
+    );
+    let tight_paragraph = *tight && context.events[before].name == Name::Paragraph;
+
+    context.slurp_one_line_ending = false;
+
+    if !tight_paragraph {
+        context.line_ending_if_needed();
+    }
+
+    context.push("</dd>");
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`DefinitionDestinationString`][Name::DefinitionDestinationString].
 fn on_exit_definition_destination_string(context: &mut CompileContext) {
     let buf = context.resume();
@@ -970,12 +1426,137 @@ fn on_exit_definition_label_string(context: &mut CompileContext) {
         Some(Position::from_exit_event(context.events, context.index).to_indices());
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionList`][Name::DefinitionList].
+fn on_exit_definition_list(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.push("</dl>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionTerm`][Name::DefinitionTerm].
+fn on_exit_definition_term(context: &mut CompileContext) {
+    context.push("</dt>");
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`DefinitionTitleString`][Name::DefinitionTitleString].
 fn on_exit_definition_title_string(context: &mut CompileContext) {
     let buf = context.resume();
     context.media_stack.last_mut().unwrap().title = Some(buf);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_exit_directive_container(context: &mut CompileContext) {
+    context.directive_stack.pop();
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.slurp_one_line_ending = false;
+    context.push("</div>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveContainerFence`][Name::DirectiveContainerFence].
+fn on_exit_directive_container_fence(context: &mut CompileContext) {
+    let directive = context.directive_stack.pop().unwrap();
+
+    // Opening fence.
+    if !directive.open {
+        context.line_ending_if_needed();
+        directive_open_tag(context, "div", &directive);
+
+        if let Some(label) = &directive.label {
+            if !label.is_empty() {
+                context.line_ending();
+                context.push("<p>");
+                context.push(label);
+                context.push("</p>");
+            }
+        }
+    }
+
+    context.directive_stack.push(Directive {
+        open: true,
+        ..directive
+    });
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLabel`][Name::DirectiveLabel].
+fn on_exit_directive_label(context: &mut CompileContext) {
+    let buf = context.resume();
+    context.directive_stack.last_mut().unwrap().label = Some(buf);
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLeaf`][Name::DirectiveLeaf].
+fn on_exit_directive_leaf(context: &mut CompileContext) {
+    let directive = context.directive_stack.pop().unwrap();
+    context.line_ending_if_needed();
+    directive_open_tag(context, "div", &directive);
+    if let Some(label) = &directive.label {
+        context.push(label);
+    }
+    context.push("</div>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveName`][Name::DirectiveName].
+fn on_exit_directive_name(context: &mut CompileContext) {
+    let name = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+        context.tab_size,
+    )
+    .serialize();
+    context.directive_stack.last_mut().unwrap().name = name;
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveText`][Name::DirectiveText].
+fn on_exit_directive_text(context: &mut CompileContext) {
+    let directive = context.directive_stack.pop().unwrap();
+
+    if context.image_alt_inside {
+        if let Some(label) = &directive.label {
+            context.push(label);
+        }
+    } else {
+        directive_open_tag(context, "span", &directive);
+        if let Some(label) = &directive.label {
+            context.push(label);
+        }
+        context.push("</span>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Emoji`][Name::Emoji].
+fn on_exit_emoji(context: &mut CompileContext) {
+    let (name, value, image) = emoji(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+        &context.parse_options.emoji_shortcodes,
+    );
+
+    if !image {
+        context.push(&encode(&value, context.encode_html));
+    } else if context.image_alt_inside {
+        context.push(&encode(&format!(":{}:", name), context.encode_html));
+    } else {
+        let url = if let Some(base_url) = &context.options.base_url {
+            resolve(base_url, &value)
+        } else {
+            value
+        };
+        let url = sanitize_url(context, &url, true);
+        let url = if scheme_allowed(context, &url) {
+            url
+        } else {
+            String::new()
+        };
+        context.push("<img src=\"");
+        context.push(&url);
+        context.push("\" alt=\":");
+        context.push(&encode(&name, context.encode_html));
+        context.push(":\" />");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
 fn on_exit_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -997,6 +1578,7 @@ fn on_exit_gfm_autolink_literal_email(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         true,
@@ -1011,6 +1593,7 @@ fn on_exit_gfm_autolink_literal_mailto(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         true,
@@ -1025,6 +1608,7 @@ fn on_exit_gfm_autolink_literal_protocol(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         true,
@@ -1039,6 +1623,7 @@ fn on_exit_gfm_autolink_literal_www(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         true,
@@ -1053,6 +1638,7 @@ fn on_exit_gfm_autolink_literal_xmpp(context: &mut CompileContext) {
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .as_str(),
         true,
@@ -1064,6 +1650,11 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
     let indices = context.media_stack.pop().unwrap().label_id.unwrap();
     let id =
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str());
+    generate_footnote_call(context, id);
+}
+
+/// Generate a footnote call.
+fn generate_footnote_call(context: &mut CompileContext, id: String) {
     let safe_id = sanitize(&id.to_lowercase());
     let mut call_index = 0;
 
@@ -1229,6 +1820,10 @@ fn on_exit_heading_atx(context: &mut CompileContext) {
         .take()
         .expect("`heading_atx_rank` must be set in headings");
 
+    if let Some(id) = context.heading_atx_anchor.take() {
+        heading_anchor(context, &id);
+    }
+
     context.push("</h");
     context.push(&rank.to_string());
     context.push(">");
@@ -1241,13 +1836,43 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         let rank = Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         )
         .len();
         context.line_ending_if_needed();
         context.heading_atx_rank = Some(rank);
         context.push("<h");
         context.push(&rank.to_string());
+        let mut id = None;
+
+        if context.options.heading_ids {
+            let mut index = context.index;
+
+            // Find the text, if there is any, before the end of the heading.
+            while !(context.events[index].kind == Kind::Exit
+                && context.events[index].name == Name::HeadingAtx)
+            {
+                if context.events[index].kind == Kind::Enter
+                    && context.events[index].name == Name::HeadingAtxText
+                {
+                    break;
+                }
+
+                index += 1;
+            }
+
+            id = heading_id(context, index);
+        }
+
         context.push(">");
+
+        if let Some(id) = id {
+            match context.options.heading_anchor {
+                HeadingAnchor::None => {}
+                HeadingAnchor::Before => heading_anchor(context, &id),
+                HeadingAnchor::After => context.heading_atx_anchor = Some(id),
+            }
+        }
     }
 }
 
@@ -1277,15 +1902,79 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
     context.line_ending_if_needed();
     context.push("<h");
     context.push(rank);
+    let mut id = None;
+
+    if context.options.heading_ids {
+        let mut index = context.index;
+
+        while !(context.events[index].kind == Kind::Enter
+            && context.events[index].name == Name::HeadingSetextText)
+        {
+            index -= 1;
+        }
+
+        id = heading_id(context, index);
+    }
+
     context.push(">");
+
+    if let Some(ref id) = id {
+        if context.options.heading_anchor == HeadingAnchor::Before {
+            heading_anchor(context, id);
+        }
+    }
+
     context.push(&text);
+
+    if let Some(ref id) = id {
+        if context.options.heading_anchor == HeadingAnchor::After {
+            heading_anchor(context, id);
+        }
+    }
+
     context.push("</h");
     context.push(rank);
     context.push(">");
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Highlight`][Name::Highlight].
+fn on_exit_highlight(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</mark>");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
+    if let Some((start, line_ending_start, stripped)) = context.html_flow_line.take() {
+        let mut value = context.resume();
+
+        // Drop the last line, and the line ending before it, if only a
+        // comment was on it.
+        if stripped && value[start..].trim().is_empty() {
+            value.truncate(line_ending_start);
+        }
+
+        if value.trim().is_empty() {
+            // Drop the line endings around the HTML, as nothing is left.
+            let buffer = context.buffers.last_mut().expect("expected buffer");
+            if buffer.ends_with('\n') {
+                buffer.pop();
+            }
+            if buffer.ends_with('\r') {
+                buffer.pop();
+            }
+            context.slurp_one_line_ending = true;
+        } else {
+            context.line_ending_if_needed();
+            if !context.options.allow_dangerous_html {
+                html_shown_as_text(context);
+            }
+            context.push(&value);
+        }
+    }
+
+    context.html_comment_inside = false;
     context.encode_html = true;
 }
 
@@ -1294,8 +1983,22 @@ fn on_exit_html_data(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &Position::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
-    let value = slice.as_str();
+    let mut value = slice.as_str();
+    let stripped;
+
+    if context.options.strip_comments {
+        let (rest, dropped) = strip_html_comments(context, value);
+        stripped = rest;
+        value = &stripped;
+
+        if dropped {
+            if let Some(line) = &mut context.html_flow_line {
+                line.2 = true;
+            }
+        }
+    }
 
     let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
         encode(&gfm_tagfilter(value), context.encode_html)
@@ -1306,6 +2009,32 @@ fn on_exit_html_data(context: &mut CompileContext) {
     context.push(&encoded);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`InlineFootnote`][Name::InlineFootnote].
+fn on_exit_inline_footnote(context: &mut CompileContext) {
+    let label = context.media_stack.pop().unwrap().label.unwrap();
+
+    // Use the lowest number that is not taken by another footnote.
+    // Numbers below the current one are taken by GFM footnote definitions or
+    // earlier inline footnotes.
+    let id = loop {
+        let id = context.inline_footnote_number.to_string();
+        context.inline_footnote_number += 1;
+
+        if !context.gfm_footnote_definition_identifiers.contains(&id) {
+            break id;
+        }
+    };
+
+    let mut value = String::new();
+    if !label.is_empty() {
+        value.push_str("<p>");
+        value.push_str(&label);
+        value.push_str("</p>");
+    }
+    context.gfm_footnote_definitions.push((id.clone(), value));
+    generate_footnote_call(context, id);
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Label`][Name::Label].
 fn on_exit_label(context: &mut CompileContext) {
     let buf = context.resume();
@@ -1320,24 +2049,74 @@ fn on_exit_label_text(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`LineEnding`][Name::LineEnding].
 fn on_exit_line_ending(context: &mut CompileContext) {
+    let mut html_line_ending_start = None;
+
+    if let Some((start, _, stripped)) = context.html_flow_line {
+        let buffer = context.buffers.last_mut().expect("expected buffer");
+
+        // Drop line endings in comments, and lines with only a comment.
+        if context.html_comment_inside {
+            return;
+        }
+
+        if stripped && buffer[start..].trim().is_empty() {
+            buffer.truncate(start);
+            return;
+        }
+
+        html_line_ending_start = Some(buffer.len());
+    } else if context.html_comment_inside {
+        return;
+    }
+
     if context.raw_text_inside {
         context.push(" ");
     } else if context.slurp_one_line_ending
         // Ignore line endings after definitions.
         || (context.index > 1
-            && (context.events[context.index - 2].name == Name::Definition
-                || context.events[context.index - 2].name == Name::GfmFootnoteDefinition))
+            && matches!(
+                context.events[context.index - 2].name,
+                Name::AbbreviationDefinition | Name::Definition | Name::GfmFootnoteDefinition
+            ))
     {
         context.slurp_one_line_ending = false;
     } else {
-        context.push(&encode(
-            Slice::from_position(
-                context.bytes,
-                &Position::from_exit_event(context.events, context.index),
+        if context.options.soft_break_as_hard
+            && context.paragraph_inside
+            // Not in raw HTML or in a dropped image or resource.
+            && context.encode_html
+            && !context.image_alt_inside
+            && !context
+                .media_stack
+                .last()
+                .map_or(false, |media| media.destination.is_some())
+            // Not right after a hard break.
+            && !matches!(
+                context.events[context.index - 2].name,
+                Name::HardBreakEscape | Name::HardBreakTrailing
             )
-            .as_str(),
-            context.encode_html,
-        ));
+        {
+            on_exit_break(context);
+        }
+
+        if let Some(line_ending) = &context.options.normalize_line_endings {
+            context.push(line_ending.as_str());
+        } else {
+            context.push(&encode(
+                Slice::from_position(
+                    context.bytes,
+                    &Position::from_exit_event(context.events, context.index),
+                    context.tab_size,
+                )
+                .as_str(),
+                context.encode_html,
+            ));
+        }
+    }
+
+    if let Some(line_ending_start) = html_line_ending_start {
+        let start = context.buffers.last().expect("expected buffer").len();
+        context.html_flow_line = Some((start, line_ending_start, false));
     }
 }
 
@@ -1364,6 +2143,7 @@ fn on_exit_list_item(context: &mut CompileContext) {
             Name::LineEnding,
             Name::SpaceOrTab,
             // Also ignore things that don’t contribute to the document.
+            Name::AbbreviationDefinition,
             Name::Definition,
             Name::GfmFootnoteDefinition,
         ],
@@ -1387,6 +2167,7 @@ fn on_exit_list_item_value(context: &mut CompileContext) {
         let slice = Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
+            context.tab_size,
         );
         let value = slice.as_str().parse::<u32>().ok().unwrap();
 
@@ -1439,6 +2220,8 @@ fn on_exit_media(context: &mut CompileContext) {
         None
     };
 
+    let mut href = None;
+
     if !is_in_image {
         if media.image {
             context.push("<img src=\"");
@@ -1453,19 +2236,23 @@ fn on_exit_media(context: &mut CompileContext) {
         };
 
         if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
-                sanitize(destination)
+            let resolved;
+            let destination = if let Some(base_url) = &context.options.base_url {
+                resolved = resolve(base_url, destination);
+                &resolved
             } else {
-                sanitize_with_protocols(
-                    destination,
-                    if media.image {
-                        &SAFE_PROTOCOL_SRC
-                    } else {
-                        &SAFE_PROTOCOL_HREF
-                    },
-                )
+                destination
+            };
+            let url = sanitize_url(context, destination, media.image);
+            let url = if scheme_allowed(context, &url) {
+                url
+            } else if media.image {
+                String::new()
+            } else {
+                "#".into()
             };
             context.push(&url);
+            href = Some(url);
         }
 
         if media.image {
@@ -1480,6 +2267,16 @@ fn on_exit_media(context: &mut CompileContext) {
     if !is_in_image {
         context.push("\"");
 
+        if media.image {
+            for (key, value) in &context.options.image_attributes {
+                context.push(" ");
+                context.push(key);
+                context.push("=\"");
+                context.push(&encode(value, true));
+                context.push("\"");
+            }
+        }
+
         let title = if let Some(index) = definition_index {
             context.definitions[index].title.clone()
         } else {
@@ -1494,6 +2291,8 @@ fn on_exit_media(context: &mut CompileContext) {
 
         if media.image {
             context.push(" /");
+        } else if let Some(href) = href {
+            link_external_attributes(context, &href);
         }
 
         context.push(">");
@@ -1511,6 +2310,7 @@ fn on_exit_media(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`Paragraph`][Name::Paragraph].
 fn on_exit_paragraph(context: &mut CompileContext) {
     let tight = context.tight_stack.last().unwrap_or(&false);
+    context.paragraph_inside = false;
 
     if *tight {
         context.slurp_one_line_ending = true;
@@ -1548,12 +2348,66 @@ fn on_exit_strong(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Subscript`][Name::Subscript].
+fn on_exit_subscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</sub>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Superscript`][Name::Superscript].
+fn on_exit_superscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</sup>");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
     context.line_ending_if_needed();
     context.push("<hr />");
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Wikilink`][Name::Wikilink].
+fn on_exit_wikilink(context: &mut CompileContext) {
+    let (target, text) = wikilink(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+    );
+    let is_in_link = context.media_stack.iter().any(|d| !d.image);
+
+    // Links cannot contain links.
+    if !context.image_alt_inside && !is_in_link {
+        let url = context
+            .parse_options
+            .wikilink_url
+            .as_ref()
+            .map_or_else(|| slug(&target), |d| d(&target));
+        let url = if let Some(base_url) = &context.options.base_url {
+            resolve(base_url, &url)
+        } else {
+            url
+        };
+        let url = sanitize_url(context, &url, false);
+        let url = if scheme_allowed(context, &url) {
+            url
+        } else {
+            "#".into()
+        };
+        context.push("<a href=\"");
+        context.push(&url);
+        context.push("\"");
+        link_external_attributes(context, &url);
+        context.push(">");
+        context.push(&encode(&text, context.encode_html));
+        context.push("</a>");
+    } else {
+        context.push(&encode(&text, context.encode_html));
+    }
+}
+
 /// Generate a footnote section.
 fn generate_footnote_section(context: &mut CompileContext) {
     context.line_ending_if_needed();
@@ -1718,27 +2572,254 @@ fn generate_autolink(
         index += 1;
     }
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
-        context.push("<a href=\"");
-        let url = if let Some(protocol) = protocol {
-            format!("{}{}", protocol, value)
-        } else {
-            value.into()
-        };
+    let url = if let Some(protocol) = protocol {
+        format!("{}{}", protocol, value)
+    } else {
+        value.into()
+    };
 
-        let url = if context.options.allow_dangerous_protocol {
-            sanitize(&url)
-        } else {
-            sanitize_with_protocols(&url, &SAFE_PROTOCOL_HREF)
-        };
+    let url = sanitize_url(context, &url, false);
+
+    // Autolinks to schemes that are not allowed are shown as text.
+    let link = !context.image_alt_inside
+        && (!is_in_link || !is_gfm_literal)
+        && scheme_allowed(context, &url);
 
+    if link {
+        context.push("<a href=\"");
         context.push(&url);
-        context.push("\">");
+        context.push("\"");
+        link_external_attributes(context, &url);
+        context.push(">");
     }
 
     context.push(&encode(value, context.encode_html));
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    if link {
         context.push("</a>");
     }
 }
+
+/// Check whether to use a `<code>` element in the `<pre>` of the current
+/// raw (flow).
+///
+/// Math (flow) always has one, code (fenced, indented) not with
+/// `pre_without_code`.
+fn raw_flow_code_element(context: &CompileContext) -> bool {
+    !context.options.pre_without_code || context.events[context.index].name == Name::MathFlow
+}
+
+/// Make `url` safe to use in `href` (or `src`, when `image`), for all
+/// constructs that generate URLs.
+///
+/// Characters that are not allowed in URLs (such as spaces, `"`, `<`, and
+/// `>`) are percent-encoded, and `&` is encoded as a character reference.
+/// Dangerous protocols are dropped, unless `allow_dangerous_protocol` is on,
+/// or `allowed_schemes` is given (which is checked with [`scheme_allowed`]).
+fn sanitize_url(context: &CompileContext, url: &str, image: bool) -> String {
+    if context.options.allow_dangerous_protocol || context.options.allowed_schemes.is_some() {
+        sanitize(url)
+    } else {
+        sanitize_with_protocols(
+            url,
+            if image {
+                &SAFE_PROTOCOL_SRC
+            } else {
+                &SAFE_PROTOCOL_HREF
+            },
+        )
+    }
+}
+
+/// Check whether the scheme of `url` is in `allowed_schemes`.
+///
+/// URLs w/o scheme (relative URLs, fragments) are always allowed, and so is
+/// everything when `allowed_schemes` is not given.
+fn scheme_allowed(context: &CompileContext, url: &str) -> bool {
+    match (&context.options.allowed_schemes, protocol(url)) {
+        (Some(schemes), Some(protocol)) => schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(protocol)),
+        _ => true,
+    }
+}
+
+/// Generate `rel` and `target` attributes for a link to `url`, if it is
+/// external.
+fn link_external_attributes(context: &mut CompileContext, url: &str) {
+    if context.options.link_external_rel.is_none() && context.options.link_external_target.is_none()
+    {
+        return;
+    }
+
+    let external = if let Some(link_external) = &context.options.link_external {
+        link_external(url)
+    } else {
+        let bytes = url.as_bytes();
+        bytes.starts_with(b"//")
+            || (bytes.len() > 5 && bytes[..5].eq_ignore_ascii_case(b"http:"))
+            || (bytes.len() > 6 && bytes[..6].eq_ignore_ascii_case(b"https:"))
+    };
+
+    if external {
+        if let Some(rel) = &context.options.link_external_rel {
+            context.push(" rel=\"");
+            context.push(&encode(rel, true));
+            context.push("\"");
+        }
+
+        if let Some(target) = &context.options.link_external_target {
+            context.push(" target=\"");
+            context.push(&encode(target, true));
+            context.push("\"");
+        }
+    }
+}
+
+/// Generate the opening tag of a directive.
+///
+/// Event handler attributes (`on*`) are dropped, unless dangerous HTML is
+/// allowed.
+fn directive_open_tag(context: &mut CompileContext, tag_name: &str, directive: &Directive) {
+    context.push("<");
+    context.push(tag_name);
+    context.push(" data-directive=\"");
+    context.push(&encode(&directive.name, context.encode_html));
+    context.push("\"");
+
+    for (key, value) in &directive.attributes {
+        if !context.options.allow_dangerous_html
+            && key.len() > 1
+            && key.as_bytes()[..2].eq_ignore_ascii_case(b"on")
+        {
+            continue;
+        }
+
+        context.push(" ");
+        context.push(key);
+        context.push("=\"");
+        context.push(&encode(value, context.encode_html));
+        context.push("\"");
+    }
+
+    context.push(">");
+}
+
+/// Drop comments from a chunk of HTML (flow, text), and get what is left,
+/// and whether something was dropped.
+///
+/// Comments can continue over several chunks, so whether we are in one is
+/// kept in `html_comment_inside`.
+/// Comments that are not closed run to the end of the HTML.
+fn strip_html_comments(context: &mut CompileContext, value: &str) -> (String, bool) {
+    let mut result = String::new();
+    let mut rest = value;
+    let mut dropped = context.html_comment_inside;
+
+    loop {
+        if context.html_comment_inside {
+            if let Some(end) = rest.find("-->") {
+                rest = &rest[(end + 3)..];
+                context.html_comment_inside = false;
+            } else {
+                break;
+            }
+        } else if let Some(start) = rest.find("<!--") {
+            result.push_str(&rest[..start]);
+            rest = &rest[(start + 4)..];
+            dropped = true;
+
+            // `<!-->` and `<!--->` are empty comments.
+            if rest.starts_with('>') {
+                rest = &rest[1..];
+            } else if rest.starts_with("->") {
+                rest = &rest[2..];
+            } else {
+                context.html_comment_inside = true;
+            }
+        } else {
+            result.push_str(rest);
+            break;
+        }
+    }
+
+    (result, dropped)
+}
+
+/// Check whether the HTML (text) entered at the current event starts with a
+/// comment.
+fn html_comment_start(context: &CompileContext) -> bool {
+    let mut index = context.events[context.index].point.index;
+
+    while index < context.bytes.len() && matches!(context.bytes[index], b'\t' | b' ') {
+        index += 1;
+    }
+
+    context.bytes[index..].starts_with(b"<!--")
+}
+
+/// Generate and push an `id` attribute for a heading, from the text that
+/// starts at the event at `index`, and return the ID, if there is one.
+///
+/// When `index` does not point to an enter event, the heading has no text.
+fn heading_id(context: &mut CompileContext, index: usize) -> Option<String> {
+    let text = if context.events[index].kind == Kind::Enter {
+        text_content(context.events, context.bytes, context.tab_size, index)
+    } else {
+        String::new()
+    };
+    let id = if let Some(heading_id_slug) = &context.options.heading_id_slug {
+        heading_id_slug(&text)
+    } else {
+        slug(&text)
+    };
+
+    if id.is_empty() {
+        None
+    } else {
+        let id = unique_slug(&mut context.heading_ids, &id);
+        context.push(" id=\"");
+        context.push(&encode(&id, true));
+        context.push("\"");
+        Some(id)
+    }
+}
+
+/// Push an anchor that links to the heading with `id`.
+fn heading_anchor(context: &mut CompileContext, id: &str) {
+    context.push("<a href=\"#");
+    context.push(&sanitize(id));
+    context.push("\"");
+
+    let class = context
+        .options
+        .heading_anchor_class
+        .as_deref()
+        .unwrap_or("anchor");
+
+    if !class.is_empty() {
+        context.push(" class=\"");
+        context.push(&encode(class, true));
+        context.push("\"");
+    }
+
+    context.push(" aria-hidden=\"true\" tabindex=\"-1\">");
+    context.push(
+        context
+            .options
+            .heading_anchor_content
+            .as_deref()
+            .unwrap_or("¶"),
+    );
+    context.push("</a>");
+}
+
+/// Tell `dangerous_html_sink`, if given, about the HTML (flow, text) that
+/// starts at the current enter event.
+fn html_shown_as_text(context: &CompileContext) {
+    if let Some(sink) = &context.options.dangerous_html_sink {
+        if let Some(position) = event_position(context.events, context.index) {
+            sink(position);
+        }
+    }
+}
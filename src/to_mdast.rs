@@ -2,22 +2,30 @@
 
 use crate::event::{Event, Kind, Name, Point as EventPoint};
 use crate::mdast::{
-    AttributeContent, AttributeValue, BlockQuote, Break, Code, Definition, Delete, Emphasis,
-    FootnoteDefinition, FootnoteReference, Heading, Html, Image, ImageReference, InlineCode,
-    InlineMath, Link, LinkReference, List, ListItem, Math, MdxFlowExpression, MdxJsxAttribute,
-    MdxJsxFlowElement, MdxJsxTextElement, MdxTextExpression, MdxjsEsm, Node, Paragraph,
-    ReferenceKind, Root, Strong, Table, TableCell, TableRow, Text, ThematicBreak, Toml, Yaml,
+    Abbreviation, AbbreviationDefinition, AttributeContent, AttributeValue, BlockQuote, Break,
+    Code, ContainerDirective, Definition, DefinitionDescription, DefinitionList, DefinitionTerm,
+    Delete, Emoji, Emphasis, Footnote, FootnoteDefinition, FootnoteReference, Heading, Highlight,
+    Html, Image, ImageReference, InlineCode, InlineMath, LeafDirective, Link, LinkReference, List,
+    ListItem, Math, MdxFlowExpression, MdxJsxAttribute, MdxJsxFlowElement, MdxJsxTextElement,
+    MdxTextExpression, MdxjsEsm, Node, Paragraph, ReferenceKind, Root, Strong, Subscript,
+    Superscript, Table, TableCell, TableRow, Text, TextDirective, ThematicBreak, Toml, Wikilink,
+    Yaml,
 };
 use crate::unist::{Point, Position};
 use crate::util::{
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
-    infer::{gfm_table_align, list_item_loose, list_loose},
+    infer::{
+        code_fenced_info, definition_list_loose, directive_attributes, emoji, gfm_table_align,
+        list_item_loose, list_loose, wikilink,
+    },
     mdx_collect::collect,
     normalize_identifier::normalize_identifier,
     slice::{Position as SlicePosition, Slice},
+    slug::slug,
 };
+use crate::ParseOptions;
 use alloc::{
     format,
     string::{String, ToString},
@@ -86,6 +94,10 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Size of a tab stop.
+    tab_size: usize,
+    /// Configuration.
+    options: &'a ParseOptions,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     character_reference_marker: u8,
@@ -106,7 +118,7 @@ struct CompileContext<'a> {
 
 impl<'a> CompileContext<'a> {
     /// Create a new compile context.
-    fn new(events: &'a [Event], bytes: &'a [u8]) -> CompileContext<'a> {
+    fn new(events: &'a [Event], bytes: &'a [u8], options: &'a ParseOptions) -> CompileContext<'a> {
         let tree = Node::Root(Root {
             children: vec![],
             position: Some(Position {
@@ -126,6 +138,8 @@ impl<'a> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            tab_size: options.tab_size,
+            options,
             character_reference_marker: 0,
             gfm_table_inside: false,
             gfm_task_list_item_check_after: false,
@@ -223,8 +237,8 @@ impl<'a> CompileContext<'a> {
 }
 
 /// Turn events and bytes into a syntax tree.
-pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, String> {
-    let mut context = CompileContext::new(events, bytes);
+pub fn compile(events: &[Event], bytes: &[u8], options: &ParseOptions) -> Result<Node, String> {
+    let mut context = CompileContext::new(events, bytes, options);
 
     let mut index = 0;
     while index < events.len() {
@@ -272,8 +286,8 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         | Name::MathFlowChunk
         | Name::MathTextData
         | Name::MdxJsxTagAttributeValueLiteralValue => on_enter_data(context),
-        Name::CodeFencedFenceInfo
-        | Name::CodeFencedFenceMeta
+        Name::AbbreviationDefinitionValue
+        | Name::CodeFencedFenceInfoString
         | Name::DefinitionDestinationString
         | Name::DefinitionLabelString
         | Name::DefinitionTitleString
@@ -284,12 +298,23 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         | Name::ReferenceString
         | Name::ResourceDestinationString
         | Name::ResourceTitleString => on_enter_buffer(context),
+        Name::Abbreviation => on_enter_abbreviation(context),
+        Name::AbbreviationDefinition => on_enter_abbreviation_definition(context),
         Name::Autolink => on_enter_autolink(context),
         Name::BlockQuote => on_enter_block_quote(context),
         Name::CodeFenced => on_enter_code_fenced(context),
         Name::CodeIndented => on_enter_code_indented(context),
         Name::CodeText => on_enter_code_text(context),
         Name::Definition => on_enter_definition(context),
+        Name::DefinitionDescription => on_enter_definition_description(context),
+        Name::DefinitionList => on_enter_definition_list(context),
+        Name::DefinitionTerm => on_enter_definition_term(context),
+        Name::DirectiveAttributes => on_enter_directive_attributes(context),
+        Name::DirectiveContainer => on_enter_directive_container(context),
+        Name::DirectiveLabelString => on_enter_directive_label_string(context),
+        Name::DirectiveLeaf => on_enter_directive_leaf(context),
+        Name::DirectiveText => on_enter_directive_text(context),
+        Name::Emoji => on_enter_emoji(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmAutolinkLiteralEmail
@@ -305,8 +330,10 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         Name::GfmTableCell => on_enter_gfm_table_cell(context),
         Name::HardBreakEscape | Name::HardBreakTrailing => on_enter_hard_break(context),
         Name::HeadingAtx | Name::HeadingSetext => on_enter_heading(context),
+        Name::Highlight => on_enter_highlight(context),
         Name::HtmlFlow | Name::HtmlText => on_enter_html(context),
         Name::Image => on_enter_image(context),
+        Name::InlineFootnote => on_enter_inline_footnote(context),
         Name::Link => on_enter_link(context),
         Name::ListItem => on_enter_list_item(context),
         Name::ListOrdered | Name::ListUnordered => on_enter_list(context),
@@ -327,7 +354,10 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         Name::Reference => on_enter_reference(context),
         Name::Resource => on_enter_resource(context),
         Name::Strong => on_enter_strong(context),
+        Name::Subscript => on_enter_subscript(context),
+        Name::Superscript => on_enter_superscript(context),
         Name::ThematicBreak => on_enter_thematic_break(context),
+        Name::Wikilink => on_enter_wikilink(context),
         _ => {}
     }
 
@@ -337,38 +367,54 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) -> Result<(), String> {
     match context.events[context.index].name {
-        Name::Autolink
+        Name::AbbreviationDefinition
+        | Name::Autolink
         | Name::BlockQuote
         | Name::CharacterReference
         | Name::Definition
+        | Name::DefinitionDescription
+        | Name::DefinitionList
+        | Name::DefinitionTerm
+        | Name::DirectiveContainer
+        | Name::DirectiveLeaf
+        | Name::DirectiveText
         | Name::Emphasis
         | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
         | Name::GfmTableRow
         | Name::GfmTableCell
         | Name::HeadingAtx
+        | Name::Highlight
         | Name::ListItem
         | Name::ListOrdered
         | Name::ListUnordered
         | Name::Paragraph
         | Name::Strong
+        | Name::Subscript
+        | Name::Superscript
         | Name::ThematicBreak => {
             on_exit(context)?;
         }
         Name::CharacterEscapeValue
-        | Name::CodeFlowChunk
         | Name::CodeTextData
         | Name::FrontmatterChunk
         | Name::HtmlFlowData
         | Name::HtmlTextData
-        | Name::MathFlowChunk
         | Name::MathTextData
         | Name::MdxJsxTagAttributeValueLiteralValue => {
             on_exit_data(context)?;
         }
-        Name::MdxJsxTagAttributeExpression | Name::MdxJsxTagAttributeValueExpression => {
+        Name::CodeFlowChunk | Name::MathFlowChunk => on_exit_raw_flow_chunk(context)?,
+        Name::DirectiveAttributes
+        | Name::MdxJsxTagAttributeExpression
+        | Name::MdxJsxTagAttributeValueExpression => {
             on_exit_drop(context);
         }
+        Name::Abbreviation => on_exit_abbreviation(context)?,
+        Name::AbbreviationDefinitionLabelString => {
+            on_exit_abbreviation_definition_label_string(context);
+        }
+        Name::AbbreviationDefinitionValue => on_exit_abbreviation_definition_value(context),
         Name::AutolinkProtocol => on_exit_autolink_protocol(context)?,
         Name::AutolinkEmail => on_exit_autolink_email(context)?,
         Name::CharacterReferenceMarker => on_exit_character_reference_marker(context),
@@ -379,8 +425,8 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
             on_exit_character_reference_marker_hexadecimal(context);
         }
         Name::CharacterReferenceValue => on_exit_character_reference_value(context),
-        Name::CodeFencedFenceInfo => on_exit_code_fenced_fence_info(context),
-        Name::CodeFencedFenceMeta | Name::MathFlowFenceMeta => on_exit_raw_flow_fence_meta(context),
+        Name::CodeFencedFenceInfoString => on_exit_code_fenced_fence_info_string(context),
+        Name::MathFlowFenceMeta => on_exit_math_flow_fence_meta(context),
         Name::CodeFencedFence | Name::MathFlowFence => on_exit_raw_flow_fence(context),
         Name::CodeFenced | Name::MathFlow => on_exit_raw_flow(context)?,
         Name::CodeIndented => on_exit_code_indented(context)?,
@@ -391,13 +437,18 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
             on_exit_definition_id(context);
         }
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::Emoji => on_exit_emoji(context)?,
+        Name::DirectiveLabelString => on_exit_directive_label_string(context)?,
+        Name::DirectiveName => on_exit_directive_name(context),
         Name::Frontmatter => on_exit_frontmatter(context)?,
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
         | Name::GfmAutolinkLiteralProtocol
         | Name::GfmAutolinkLiteralWww
         | Name::GfmAutolinkLiteralXmpp => on_exit_gfm_autolink_literal(context)?,
-        Name::GfmFootnoteCall | Name::Image | Name::Link => on_exit_media(context)?,
+        Name::GfmFootnoteCall | Name::Image | Name::InlineFootnote | Name::Link => {
+            on_exit_media(context)?;
+        }
         Name::GfmTable => on_exit_gfm_table(context)?,
         Name::GfmTaskListItemCheck => on_exit_gfm_task_list_item_check(context),
         Name::GfmTaskListItemValueUnchecked | Name::GfmTaskListItemValueChecked => {
@@ -430,6 +481,7 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         Name::ReferenceString => on_exit_reference_string(context),
         Name::ResourceDestinationString => on_exit_resource_destination_string(context),
         Name::ResourceTitleString => on_exit_resource_title_string(context),
+        Name::Wikilink => on_exit_wikilink(context)?,
         _ => {}
     }
 
@@ -457,6 +509,23 @@ fn on_enter_data(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Abbreviation`][Name::Abbreviation].
+fn on_enter_abbreviation(context: &mut CompileContext) {
+    context.tail_push(Node::Abbreviation(Abbreviation {
+        value: String::new(),
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`AbbreviationDefinition`][Name::AbbreviationDefinition].
+fn on_enter_abbreviation_definition(context: &mut CompileContext) {
+    context.tail_push(Node::AbbreviationDefinition(AbbreviationDefinition {
+        label: String::new(),
+        title: None,
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Autolink`][Name::Autolink].
 fn on_enter_autolink(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
@@ -480,6 +549,7 @@ fn on_enter_code_fenced(context: &mut CompileContext) {
     context.tail_push(Node::Code(Code {
         lang: None,
         meta: None,
+        info: None,
         value: String::new(),
         position: None,
     }));
@@ -514,6 +584,7 @@ fn on_enter_mdx_esm(context: &mut CompileContext) {
     let result = collect(
         context.events,
         context.bytes,
+        context.tab_size,
         context.index,
         &[Name::MdxEsmData, Name::LineEnding],
         &[Name::MdxEsm],
@@ -531,6 +602,7 @@ fn on_enter_mdx_flow_expression(context: &mut CompileContext) {
     let result = collect(
         context.events,
         context.bytes,
+        context.tab_size,
         context.index,
         &[Name::MdxExpressionData, Name::LineEnding],
         &[Name::MdxFlowExpression],
@@ -548,6 +620,7 @@ fn on_enter_mdx_text_expression(context: &mut CompileContext) {
     let result = collect(
         context.events,
         context.bytes,
+        context.tab_size,
         context.index,
         &[Name::MdxExpressionData, Name::LineEnding],
         &[Name::MdxTextExpression],
@@ -571,6 +644,101 @@ fn on_enter_definition(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionDescription`][Name::DefinitionDescription].
+fn on_enter_definition_description(context: &mut CompileContext) {
+    context.tail_push(Node::DefinitionDescription(DefinitionDescription {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionList`][Name::DefinitionList].
+fn on_enter_definition_list(context: &mut CompileContext) {
+    let spread = definition_list_loose(context.events, context.index);
+
+    context.tail_push(Node::DefinitionList(DefinitionList {
+        spread,
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionTerm`][Name::DefinitionTerm].
+fn on_enter_definition_term(context: &mut CompileContext) {
+    context.tail_push(Node::DefinitionTerm(DefinitionTerm {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveAttributes`][Name::DirectiveAttributes].
+fn on_enter_directive_attributes(context: &mut CompileContext) {
+    let value = directive_attributes(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+    );
+
+    match context.tail_mut() {
+        Node::ContainerDirective(node) => node.attributes = value,
+        Node::LeafDirective(node) => node.attributes = value,
+        Node::TextDirective(node) => node.attributes = value,
+        _ => unreachable!("expected directive on stack"),
+    }
+
+    // Drop the raw attributes.
+    on_enter_buffer(context);
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_enter_directive_container(context: &mut CompileContext) {
+    context.tail_push(Node::ContainerDirective(ContainerDirective {
+        name: String::new(),
+        attributes: vec![],
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_enter_directive_label_string(context: &mut CompileContext) {
+    // The label of a container is a paragraph, as its content is flow.
+    if let Node::ContainerDirective(_) = context.tail_mut() {
+        on_enter_paragraph(context);
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLeaf`][Name::DirectiveLeaf].
+fn on_enter_directive_leaf(context: &mut CompileContext) {
+    context.tail_push(Node::LeafDirective(LeafDirective {
+        name: String::new(),
+        attributes: vec![],
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveText`][Name::DirectiveText].
+fn on_enter_directive_text(context: &mut CompileContext) {
+    context.tail_push(Node::TextDirective(TextDirective {
+        name: String::new(),
+        attributes: vec![],
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Emoji`][Name::Emoji].
+fn on_enter_emoji(context: &mut CompileContext) {
+    context.tail_push(Node::Emoji(Emoji {
+        value: String::new(),
+        position: None,
+        name: String::new(),
+        url: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     context.tail_push(Node::Emphasis(Emphasis {
@@ -694,11 +862,45 @@ fn on_enter_strong(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Highlight`][Name::Highlight].
+fn on_enter_highlight(context: &mut CompileContext) {
+    context.tail_push(Node::Highlight(Highlight {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Subscript`][Name::Subscript].
+fn on_enter_subscript(context: &mut CompileContext) {
+    context.tail_push(Node::Subscript(Subscript {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Superscript`][Name::Superscript].
+fn on_enter_superscript(context: &mut CompileContext) {
+    context.tail_push(Node::Superscript(Superscript {
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_enter_thematic_break(context: &mut CompileContext) {
     context.tail_push(Node::ThematicBreak(ThematicBreak { position: None }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Wikilink`][Name::Wikilink].
+fn on_enter_wikilink(context: &mut CompileContext) {
+    context.tail_push(Node::Wikilink(Wikilink {
+        value: String::new(),
+        position: None,
+        target: String::new(),
+        url: String::new(),
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`HeadingAtx`][Name::HeadingAtx].
 fn on_enter_heading(context: &mut CompileContext) {
     context.tail_push(Node::Heading(Heading {
@@ -728,6 +930,18 @@ fn on_enter_image(context: &mut CompileContext) {
     context.media_reference_stack.push(Reference::new());
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`InlineFootnote`][Name::InlineFootnote].
+fn on_enter_inline_footnote(context: &mut CompileContext) {
+    context.tail_push(Node::Footnote(Footnote {
+        children: vec![],
+        position: None,
+    }));
+    // Inline footnotes are never references.
+    let mut reference = Reference::new();
+    reference.reference_kind = None;
+    context.media_reference_stack.push(reference);
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Link`][Name::Link].
 fn on_enter_link(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
@@ -840,6 +1054,7 @@ fn on_enter_mdx_jsx_tag_attribute_expression(context: &mut CompileContext) -> Re
     let result = collect(
         context.events,
         context.bytes,
+        context.tab_size,
         context.index,
         &[Name::MdxExpressionData, Name::LineEnding],
         &[Name::MdxJsxTagAttributeExpression],
@@ -861,6 +1076,7 @@ fn on_enter_mdx_jsx_tag_attribute_value_expression(context: &mut CompileContext)
     let result = collect(
         context.events,
         context.bytes,
+        context.tab_size,
         context.index,
         &[Name::MdxExpressionData, Name::LineEnding],
         &[Name::MdxJsxTagAttributeValueExpression],
@@ -910,12 +1126,53 @@ fn on_exit(context: &mut CompileContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) -> Result<(), String> {
+    let slice = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
+    );
+    if let Node::Abbreviation(node) = context.tail_mut() {
+        node.value = slice.as_str().into();
+    } else {
+        unreachable!("expected abbreviation on stack");
+    }
+    on_exit(context)?;
+    Ok(())
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString].
+fn on_exit_abbreviation_definition_label_string(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
+    );
+    if let Node::AbbreviationDefinition(node) = context.tail_mut() {
+        node.label = slice.as_str().into();
+    } else {
+        unreachable!("expected abbreviation definition on stack");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue].
+fn on_exit_abbreviation_definition_value(context: &mut CompileContext) {
+    let value = context.resume().to_string();
+    if let Node::AbbreviationDefinition(node) = context.tail_mut() {
+        node.title = Some(value.trim_end().into());
+    } else {
+        unreachable!("expected abbreviation definition on stack");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkProtocol`][Name::AutolinkProtocol].
 fn on_exit_autolink_protocol(context: &mut CompileContext) -> Result<(), String> {
     on_exit_data(context)?;
     let value = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     if let Node::Link(link) = context.tail_mut() {
         link.url.push_str(value.as_str());
@@ -931,6 +1188,7 @@ fn on_exit_autolink_email(context: &mut CompileContext) -> Result<(), String> {
     let value = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     if let Node::Link(link) = context.tail_mut() {
         link.url.push_str("mailto:");
@@ -961,6 +1219,7 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let value =
         decode_character_reference(slice.as_str(), context.character_reference_marker, true)
@@ -975,26 +1234,56 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
     context.character_reference_marker = 0;
 }
 
-/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo].
-fn on_exit_code_fenced_fence_info(context: &mut CompileContext) {
-    let value = context.resume().to_string();
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString].
+fn on_exit_code_fenced_fence_info_string(context: &mut CompileContext) {
+    context.resume();
+    let mut index = context.index - 1;
+
+    while context.events[index].name != Name::CodeFencedFenceInfoString {
+        index -= 1;
+    }
+
+    let (info, language, meta) =
+        code_fenced_info(context.events, context.bytes, context.tab_size, index);
+
     if let Node::Code(node) = context.tail_mut() {
-        node.lang = Some(value);
+        node.lang = Some(language);
+        node.meta = meta;
+        node.info = Some(info);
     } else {
         unreachable!("expected code on stack");
     }
 }
 
-/// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],[`MathFlowFenceMeta`][Name::MathFlowFenceMeta]}.
-fn on_exit_raw_flow_fence_meta(context: &mut CompileContext) {
-    let value = context.resume().to_string();
-    match context.tail_mut() {
-        Node::Code(node) => node.meta = Some(value),
-        Node::Math(node) => node.meta = Some(value),
-        _ => {
-            unreachable!("expected code or math on stack");
-        }
+/// Handle [`Exit`][Kind::Exit]:[`MathFlowFenceMeta`][Name::MathFlowFenceMeta].
+fn on_exit_math_flow_fence_meta(context: &mut CompileContext) {
+    let mut value = context.resume().to_string();
+    // Trailing whitespace is not part of the info string.
+    value.truncate(value.trim_end_matches(|d| matches!(d, '\t' | ' ')).len());
+    if let Node::Math(node) = context.tail_mut() {
+        node.meta = Some(value);
+    } else {
+        unreachable!("expected math on stack");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:{[`CodeFlowChunk`][Name::CodeFlowChunk],[`MathFlowChunk`][Name::MathFlowChunk]}.
+fn on_exit_raw_flow_chunk(context: &mut CompileContext) -> Result<(), String> {
+    let value = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
+    )
+    // Must serialize to get virtual spaces, which are left when a tab is
+    // partly used as indent.
+    .serialize();
+    if let Node::Text(text) = context.tail_mut() {
+        text.value.push_str(&value);
+    } else {
+        unreachable!("expected text on stack");
     }
+    on_exit(context)?;
+    Ok(())
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFence`][Name::CodeFencedFence],[`MathFlowFence`][Name::MathFlowFence]}.
@@ -1079,6 +1368,7 @@ fn on_exit_data(context: &mut CompileContext) -> Result<(), String> {
     let value = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     if let Node::Text(text) = context.tail_mut() {
         text.value.push_str(value.as_str());
@@ -1128,6 +1418,7 @@ fn on_exit_definition_id(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let identifier = normalize_identifier(slice.as_str()).to_lowercase();
 
@@ -1159,6 +1450,31 @@ fn on_exit_drop(context: &mut CompileContext) {
     context.resume();
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_exit_directive_label_string(context: &mut CompileContext) -> Result<(), String> {
+    if let Node::Paragraph(_) = context.tail_mut() {
+        on_exit(context)?;
+    }
+    Ok(())
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveName`][Name::DirectiveName].
+fn on_exit_directive_name(context: &mut CompileContext) {
+    let value = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
+    )
+    .serialize();
+
+    match context.tail_mut() {
+        Node::ContainerDirective(node) => node.name = value,
+        Node::LeafDirective(node) => node.name = value,
+        Node::TextDirective(node) => node.name = value,
+        _ => unreachable!("expected directive on stack"),
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Frontmatter`][Name::Frontmatter].
 fn on_exit_frontmatter(context: &mut CompileContext) -> Result<(), String> {
     let value = trim_eol(context.resume().to_string(), true, true);
@@ -1180,6 +1496,7 @@ fn on_exit_gfm_autolink_literal(context: &mut CompileContext) -> Result<(), Stri
     let value = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
 
     let prefix = match &context.events[context.index].name {
@@ -1251,6 +1568,7 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
 
     if let Node::Heading(node) = context.tail_mut() {
@@ -1297,6 +1615,7 @@ fn on_exit_label_text(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let identifier = normalize_identifier(slice.as_str()).to_lowercase();
 
@@ -1310,8 +1629,9 @@ fn on_exit_label_text(context: &mut CompileContext) {
     match context.tail_mut() {
         Node::Link(node) => node.children = children,
         Node::Image(node) => node.alt = label,
+        Node::Footnote(node) => node.children = children,
         Node::FootnoteReference(_) => {}
-        _ => unreachable!("expected footnote refereence, image, or link on stack"),
+        _ => unreachable!("expected footnote, footnote refereence, image, or link on stack"),
     }
 }
 
@@ -1337,9 +1657,12 @@ fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), String> {
         context.tail_mut(),
         Node::Emphasis(_)
             | Node::Heading(_)
+            | Node::DefinitionTerm(_)
             | Node::Paragraph(_)
             | Node::Strong(_)
             | Node::Delete(_)
+            | Node::Highlight(_)
+            | Node::TextDirective(_)
     ) {
         context.index -= 1;
         on_enter_data(context);
@@ -1363,7 +1686,7 @@ fn on_exit_html(context: &mut CompileContext) -> Result<(), String> {
     Ok(())
 }
 
-/// Handle [`Exit`][Kind::Exit]:{[`GfmFootnoteCall`][Name::GfmFootnoteCall],[`Image`][Name::Image],[`Link`][Name::Link]}.
+/// Handle [`Exit`][Kind::Exit]:{[`GfmFootnoteCall`][Name::GfmFootnoteCall],[`Image`][Name::Image],[`InlineFootnote`][Name::InlineFootnote],[`Link`][Name::Link]}.
 fn on_exit_media(context: &mut CompileContext) -> Result<(), String> {
     let reference = context
         .media_reference_stack
@@ -1421,6 +1744,7 @@ fn on_exit_list_item_value(context: &mut CompileContext) {
     let start = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     )
     .as_str()
     .parse()
@@ -1510,6 +1834,7 @@ fn on_exit_mdx_jsx_tag_name_primary(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let value = slice.serialize();
     context.jsx_tag.as_mut().expect("expected tag").name = Some(value);
@@ -1520,6 +1845,7 @@ fn on_exit_mdx_jsx_tag_name_member(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let name = context
         .jsx_tag
@@ -1537,6 +1863,7 @@ fn on_exit_mdx_jsx_tag_name_local(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let name = context
         .jsx_tag
@@ -1561,6 +1888,7 @@ fn on_exit_mdx_jsx_tag_attribute_primary_name(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let value = slice.serialize();
 
@@ -1582,6 +1910,7 @@ fn on_exit_mdx_jsx_tag_attribute_name_local(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     if let Some(AttributeContent::Property(attribute)) = context
         .jsx_tag
@@ -1610,6 +1939,7 @@ fn on_exit_mdx_jsx_tag_attribute_value_literal(context: &mut CompileContext) {
     {
         node.value = Some(AttributeValue::Literal(parse_character_reference(
             &value.to_string(),
+            false,
         )));
     } else {
         unreachable!("expected property")
@@ -1627,6 +1957,7 @@ fn on_exit_reference_string(context: &mut CompileContext) {
     let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
+        context.tab_size,
     );
     let identifier = normalize_identifier(slice.as_str()).to_lowercase();
     let reference = context
@@ -1660,9 +1991,61 @@ fn on_exit_resource_title_string(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Emoji`][Name::Emoji].
+fn on_exit_emoji(context: &mut CompileContext) -> Result<(), String> {
+    let (name, value, image) = emoji(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+        &context.options.emoji_shortcodes,
+    );
+
+    if let Node::Emoji(node) = context.tail_mut() {
+        if image {
+            node.value = format!(":{}:", name);
+            node.url = Some(value);
+        } else {
+            node.value = value;
+        }
+        node.name = name;
+    } else {
+        unreachable!("expected emoji on stack");
+    }
+
+    on_exit(context)?;
+    Ok(())
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Wikilink`][Name::Wikilink].
+fn on_exit_wikilink(context: &mut CompileContext) -> Result<(), String> {
+    let (target, value) = wikilink(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+    );
+    let url = context
+        .options
+        .wikilink_url
+        .as_ref()
+        .map_or_else(|| slug(&target), |d| d(&target));
+
+    if let Node::Wikilink(node) = context.tail_mut() {
+        node.value = value;
+        node.target = target;
+        node.url = url;
+    } else {
+        unreachable!("expected wikilink on stack");
+    }
+
+    on_exit(context)?;
+    Ok(())
+}
+
 /// Create a point from an event.
 fn point_from_event_point(point: &EventPoint) -> Point {
-    Point::new(point.line, point.column, point.index)
+    point.to_unist()
 }
 
 /// Create a point from an event.
@@ -1672,7 +2055,7 @@ fn point_from_event(event: &Event) -> Point {
 
 /// Create a position from an event.
 fn position_from_event(event: &Event) -> Position {
-    let end = Point::new(event.point.line, event.point.column, event.point.index);
+    let end = point_from_event(event);
     Position {
         start: end.clone(),
         end,
@@ -0,0 +1,473 @@
+//! Turn events into a string of plain text.
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    character_reference::decode as decode_character_reference,
+    infer::{definition_list_loose, emoji, list_loose, wikilink},
+    slice::{Position, Slice},
+};
+use crate::ParseOptions;
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// List.
+#[derive(Debug)]
+struct List {
+    /// Number of the next item, in ordered lists.
+    value: Option<u32>,
+    /// Size of the prefix of the current item.
+    prefix_size: usize,
+    /// Size of the result right after the prefix of the current item.
+    prefix_end: usize,
+}
+
+/// State needed to compile events into plain text.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+struct CompileContext<'a> {
+    // Static info.
+    /// List of events.
+    events: &'a [Event],
+    /// List of bytes.
+    bytes: &'a [u8],
+    /// Configuration.
+    options: &'a ParseOptions,
+    /// Size of a tab.
+    tab_size: usize,
+    // Fields used by handlers to track the things they need to track to
+    // compile markdown.
+    /// Number of things we are in that are not shown, such as link
+    /// destinations or definitions.
+    ignore: usize,
+    /// Whether we are in a paragraph or setext heading text, where line
+    /// endings are kept.
+    text_inside: bool,
+    /// Code or math (text) that is being compiled.
+    raw_text: Option<String>,
+    /// Whether we are in code or math (flow).
+    raw_flow_inside: bool,
+    /// Number of line endings seen in code or math (flow), which are only
+    /// added when more code follows, or `None` before the line ending after
+    /// the opening fence.
+    raw_flow_line_endings: Option<usize>,
+    /// Whether we are in a table.
+    gfm_table_inside: bool,
+    /// Whether a row was seen in the current table.
+    gfm_table_row_seen: bool,
+    /// Whether a cell was seen in the current row.
+    gfm_table_cell_seen: bool,
+    /// Marker of the character reference that is being compiled.
+    character_reference_marker: u8,
+    /// Stack of lists.
+    list_stack: Vec<List>,
+    /// Whether blocks are separated by one line ending instead of two.
+    tight_stack: Vec<bool>,
+    /// Whitespace added at the start of lines, in list items.
+    indent: String,
+    /// Number of line endings to add before what comes next, after a block.
+    line_endings: usize,
+    // Fields used to influence the current compilation.
+    /// Current event index.
+    index: usize,
+    /// Result.
+    buffer: String,
+}
+
+impl<'a> CompileContext<'a> {
+    /// Create a new compile context.
+    fn new(events: &'a [Event], bytes: &'a [u8], options: &'a ParseOptions) -> CompileContext<'a> {
+        CompileContext {
+            events,
+            bytes,
+            options,
+            tab_size: options.tab_size,
+            ignore: 0,
+            text_inside: false,
+            raw_text: None,
+            raw_flow_inside: false,
+            raw_flow_line_endings: None,
+            gfm_table_inside: false,
+            gfm_table_row_seen: false,
+            gfm_table_cell_seen: false,
+            character_reference_marker: b'&',
+            list_stack: vec![],
+            tight_stack: vec![],
+            indent: String::new(),
+            line_endings: 0,
+            index: 0,
+            buffer: String::new(),
+        }
+    }
+
+    /// Get the value of the current (exit) event.
+    fn slice(&self) -> Slice<'a> {
+        Slice::from_position(
+            self.bytes,
+            &Position::from_exit_event(self.events, self.index),
+            self.tab_size,
+        )
+    }
+
+    /// Push a string.
+    ///
+    /// Line endings after a block are added first, and the indent of list
+    /// items when at the start of a line.
+    fn push(&mut self, value: &str) {
+        if let Some(raw_text) = self.raw_text.as_mut() {
+            raw_text.push_str(value);
+            return;
+        }
+
+        if value.is_empty() {
+            return;
+        }
+
+        if !self.buffer.is_empty() {
+            while self.line_endings > 0 {
+                self.buffer.push('\n');
+                self.line_endings -= 1;
+            }
+        }
+
+        self.line_endings = 0;
+
+        if matches!(self.buffer.as_bytes().last(), None | Some(b'\n')) && value != "\n" {
+            self.buffer.push_str(&self.indent);
+        }
+
+        self.buffer.push_str(value);
+    }
+
+    /// Mark the end of a block: what comes next is on its own line, and
+    /// after a blank line unless in a tight list.
+    fn block_end(&mut self) {
+        self.line_endings = if *self.tight_stack.last().unwrap_or(&false) {
+            1
+        } else {
+            2
+        };
+    }
+}
+
+/// Turn events and bytes into a string of plain text.
+pub fn compile(events: &[Event], bytes: &[u8], options: &ParseOptions) -> String {
+    let mut context = CompileContext::new(events, bytes, options);
+
+    while context.index < events.len() {
+        if events[context.index].kind == Kind::Enter {
+            enter(&mut context);
+        } else {
+            exit(&mut context);
+        }
+
+        context.index += 1;
+    }
+
+    context.buffer
+}
+
+/// Handle [`Enter`][Kind::Enter].
+fn enter(context: &mut CompileContext) {
+    match context.events[context.index].name {
+        Name::AbbreviationDefinition
+        | Name::CodeFencedFence
+        | Name::Definition
+        | Name::DirectiveAttributes
+        | Name::GfmFootnoteCall
+        | Name::GfmFootnoteDefinitionLabel
+        | Name::HtmlText
+        | Name::InlineFootnote
+        | Name::MathFlowFence
+        | Name::MdxJsxTextTag
+        | Name::MdxTextExpression
+        | Name::Reference
+        | Name::ResourceDestination
+        | Name::ResourceTitle => context.ignore += 1,
+        Name::BlockQuote | Name::DirectiveContainer | Name::GfmFootnoteDefinition => {
+            context.tight_stack.push(false);
+        }
+        Name::CodeFenced | Name::MathFlow => {
+            context.raw_flow_inside = true;
+            context.raw_flow_line_endings = None;
+        }
+        Name::CodeIndented => {
+            context.raw_flow_inside = true;
+            context.raw_flow_line_endings = Some(0);
+        }
+        Name::CodeText | Name::MathText => context.raw_text = Some(String::new()),
+        Name::GfmTable => {
+            context.gfm_table_inside = true;
+            context.gfm_table_row_seen = false;
+        }
+        Name::GfmTableRow => {
+            if context.gfm_table_row_seen {
+                context.push("\n");
+            }
+
+            context.gfm_table_row_seen = true;
+            context.gfm_table_cell_seen = false;
+        }
+        Name::GfmTableCell => {
+            if context.gfm_table_cell_seen {
+                context.push("\t");
+            }
+
+            context.gfm_table_cell_seen = true;
+        }
+        Name::ListOrdered | Name::ListUnordered => {
+            let loose = list_loose(context.events, context.index, true);
+            context.tight_stack.push(!loose);
+            context.list_stack.push(List {
+                value: None,
+                prefix_size: 0,
+                prefix_end: 0,
+            });
+        }
+        Name::DefinitionList => {
+            let loose = definition_list_loose(context.events, context.index);
+            context.tight_stack.push(!loose);
+        }
+        Name::DefinitionTerm | Name::HeadingSetextText | Name::Paragraph => {
+            context.text_inside = true;
+        }
+        _ => {}
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit].
+fn exit(context: &mut CompileContext) {
+    match context.events[context.index].name {
+        Name::AbbreviationDefinition
+        | Name::CodeFencedFence
+        | Name::Definition
+        | Name::DirectiveAttributes
+        | Name::GfmFootnoteCall
+        | Name::GfmFootnoteDefinitionLabel
+        | Name::HtmlText
+        | Name::InlineFootnote
+        | Name::MathFlowFence
+        | Name::MdxJsxTextTag
+        | Name::MdxTextExpression
+        | Name::Reference
+        | Name::ResourceDestination
+        | Name::ResourceTitle => {
+            context.ignore -= 1;
+
+            if matches!(
+                context.events[context.index].name,
+                Name::AbbreviationDefinition | Name::Definition
+            ) {
+                context.block_end();
+            }
+        }
+        Name::Abbreviation
+        | Name::AutolinkEmail
+        | Name::AutolinkProtocol
+        | Name::CharacterEscapeValue
+        | Name::CodeFlowChunk
+        | Name::CodeTextData
+        | Name::Data
+        | Name::GfmAutolinkLiteralEmail
+        | Name::GfmAutolinkLiteralMailto
+        | Name::GfmAutolinkLiteralProtocol
+        | Name::GfmAutolinkLiteralWww
+        | Name::GfmAutolinkLiteralXmpp
+        | Name::MathFlowChunk
+        | Name::MathTextData => on_exit_data(context),
+        Name::BlockQuote
+        | Name::DefinitionList
+        | Name::DirectiveContainer
+        | Name::GfmFootnoteDefinition => {
+            context.tight_stack.pop();
+            context.block_end();
+        }
+        Name::CharacterReferenceMarker => context.character_reference_marker = b'&',
+        Name::CharacterReferenceMarkerHexadecimal => context.character_reference_marker = b'x',
+        Name::CharacterReferenceMarkerNumeric => context.character_reference_marker = b'#',
+        Name::CharacterReferenceValue => on_exit_character_reference_value(context),
+        Name::CodeFenced | Name::CodeIndented | Name::MathFlow => {
+            context.raw_flow_inside = false;
+            context.block_end();
+        }
+        Name::CodeText | Name::MathText => on_exit_raw_text(context),
+        Name::DefinitionDescription
+        | Name::DirectiveContainerFence
+        | Name::DirectiveLeaf
+        | Name::Frontmatter
+        | Name::HeadingAtx
+        | Name::HeadingSetext
+        | Name::HtmlFlow
+        | Name::MdxEsm
+        | Name::MdxFlowExpression
+        | Name::MdxJsxFlowTag
+        | Name::ThematicBreak => context.block_end(),
+        Name::GfmTable => {
+            context.gfm_table_inside = false;
+            context.block_end();
+        }
+        Name::GfmTaskListItemValueChecked => context.push("[x]"),
+        Name::GfmTaskListItemValueUnchecked => context.push("[ ]"),
+        Name::HeadingSetextText => context.text_inside = false,
+        Name::LineEnding => on_exit_line_ending(context),
+        Name::ListItem => on_exit_list_item(context),
+        Name::ListItemPrefix => on_exit_list_item_prefix(context),
+        Name::ListItemValue => on_exit_list_item_value(context),
+        Name::ListOrdered | Name::ListUnordered => {
+            context.list_stack.pop();
+            context.tight_stack.pop();
+            context.block_end();
+        }
+        Name::DefinitionTerm | Name::Paragraph => {
+            context.text_inside = false;
+            context.block_end();
+        }
+        Name::Emoji => on_exit_emoji(context),
+        Name::Wikilink => on_exit_wikilink(context),
+        _ => {}
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:{[`Data`][Name::Data],[`CodeFlowChunk`][Name::CodeFlowChunk],…}.
+fn on_exit_data(context: &mut CompileContext) {
+    if context.ignore > 0 {
+        return;
+    }
+
+    if context.raw_flow_inside {
+        let mut line_endings = context.raw_flow_line_endings.replace(0).unwrap_or(0);
+
+        while line_endings > 0 {
+            context.push("\n");
+            line_endings -= 1;
+        }
+    }
+
+    let slice = context.slice();
+    context.push(slice.as_str());
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceValue`][Name::CharacterReferenceValue].
+fn on_exit_character_reference_value(context: &mut CompileContext) {
+    if context.ignore > 0 {
+        return;
+    }
+
+    let slice = context.slice();
+    let value =
+        decode_character_reference(slice.as_str(), context.character_reference_marker, true)
+            .expect("expected to parse only valid named references");
+    context.push(&value);
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`LineEnding`][Name::LineEnding].
+fn on_exit_line_ending(context: &mut CompileContext) {
+    if context.ignore > 0 {
+        // Empty.
+    } else if context.raw_text.is_some() {
+        context.push(" ");
+    } else if context.raw_flow_inside {
+        // The line ending after the opening fence is not part of the code.
+        context.raw_flow_line_endings =
+            Some(context.raw_flow_line_endings.map_or(0, |count| count + 1));
+    } else if context.text_inside {
+        context.push("\n");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Emoji`][Name::Emoji].
+fn on_exit_emoji(context: &mut CompileContext) {
+    if context.ignore > 0 {
+        return;
+    }
+
+    let (name, value, image) = emoji(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+        &context.options.emoji_shortcodes,
+    );
+
+    if image {
+        context.push(&format!(":{}:", name));
+    } else {
+        context.push(&value);
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Wikilink`][Name::Wikilink].
+fn on_exit_wikilink(context: &mut CompileContext) {
+    if context.ignore > 0 {
+        return;
+    }
+
+    let (_, value) = wikilink(
+        context.events,
+        context.bytes,
+        context.tab_size,
+        context.index,
+    );
+    context.push(&value);
+}
+
+/// Handle [`Exit`][Kind::Exit]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
+fn on_exit_raw_text(context: &mut CompileContext) {
+    let mut value = context.raw_text.take().unwrap();
+
+    // Escaped pipes in tables are pipes.
+    if context.gfm_table_inside {
+        value = value.replace("\\|", "|");
+    }
+
+    // One space on both sides is padding, unless there are only spaces.
+    if value.len() > 2
+        && value.starts_with(' ')
+        && value.ends_with(' ')
+        && value.bytes().any(|d| d != b' ')
+    {
+        value = value[1..value.len() - 1].into();
+    }
+
+    context.push(&value);
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`ListItemPrefix`][Name::ListItemPrefix].
+fn on_exit_list_item_prefix(context: &mut CompileContext) {
+    let list = context.list_stack.last_mut().unwrap();
+    let prefix = match list.value.as_mut() {
+        Some(value) => {
+            let prefix = format!("{}. ", value);
+            *value += 1;
+            prefix
+        }
+        None => "- ".into(),
+    };
+
+    list.prefix_size = prefix.len();
+    context.push(&prefix);
+    context.list_stack.last_mut().unwrap().prefix_end = context.buffer.len();
+    context.indent.push_str(&" ".repeat(prefix.len()));
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`ListItem`][Name::ListItem].
+fn on_exit_list_item(context: &mut CompileContext) {
+    let list = context.list_stack.last().unwrap();
+    context
+        .indent
+        .truncate(context.indent.len() - list.prefix_size);
+
+    // Empty items have no space after their marker.
+    if context.buffer.len() == list.prefix_end {
+        context.buffer.pop();
+    }
+
+    context.block_end();
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`ListItemValue`][Name::ListItemValue].
+fn on_exit_list_item_value(context: &mut CompileContext) {
+    let value = context.slice().as_str().parse::<u32>().ok().unwrap();
+    let list = context.list_stack.last_mut().unwrap();
+
+    if list.value.is_none() {
+        list.value = Some(value);
+    }
+}
@@ -13,7 +13,12 @@ use crate::parser::ParseState;
 use crate::resolve::{call as call_resolve, Name as ResolveName};
 use crate::state::{call, State};
 use crate::subtokenize::Subresult;
-use crate::util::{char::format_byte_opt, constant::TAB_SIZE, edit_map::EditMap};
+use crate::unist;
+use crate::util::{
+    char::format_byte_opt,
+    edit_map::EditMap,
+    warning::{Kind as WarningKind, Warning},
+};
 use alloc::{boxed::Box, string::String, vec, vec::Vec};
 
 /// Containers.
@@ -27,6 +32,10 @@ use alloc::{boxed::Box, string::String, vec, vec::Vec};
 pub enum Container {
     /// [Block quote][crate::construct::block_quote].
     BlockQuote,
+    /// [Definition list][crate::construct::definition_list] (description).
+    DefinitionDescription,
+    /// [Directive (container)][crate::construct::directive_container].
+    Directive,
     /// [List item][crate::construct::list_item].
     ListItem,
     /// [GFM: Footnote definition][crate::construct::gfm_footnote_definition].
@@ -35,7 +44,9 @@ pub enum Container {
 
 /// Info used to tokenize a container.
 ///
-/// Practically, these fields are only used for list items.
+/// Practically, these fields are only used for list items and definition
+/// descriptions, and `size` also for the opening sequence of directives
+/// (container).
 #[derive(Debug)]
 pub struct ContainerState {
     /// Kind.
@@ -89,6 +100,15 @@ pub enum LabelKind {
     ///
     /// Construct: [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote].
     GfmFootnote,
+    /// Label (inline footnote) start.
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^^
+    /// ```
+    ///
+    /// Construct: [Inline footnote][crate::construct::inline_footnote].
+    InlineFootnote,
     /// GFM: Label (footnote) link, not matching a footnote definition, so
     /// handled as a label (link) start.
     ///
@@ -221,7 +241,15 @@ pub struct TokenizeState<'a> {
     ///
     /// Used when tokenizing [text content][crate::construct::text].
     pub labels: Vec<Label>,
+    /// Warnings about undefined references, with the index of the event
+    /// that starts them.
+    ///
+    /// Given at the end of [text content][crate::construct::text], unless
+    /// they end up in a link or footnote call.
+    pub reference_warnings: Vec<(usize, WarningKind, Point)>,
 
+    /// List of defined abbreviations.
+    pub abbreviations: Vec<String>,
     /// List of defined definition identifiers.
     pub definitions: Vec<String>,
     /// List of defined GFM footnote definition identifiers.
@@ -230,6 +258,9 @@ pub struct TokenizeState<'a> {
     // Last error message provided at an EOF of an expression.
     pub mdx_last_parse_error: Option<String>,
 
+    /// Whether line endings are allowed in attributes.
+    pub attributes_eol: bool,
+
     /// Whether to connect events.
     pub connect: bool,
     /// Marker.
@@ -281,6 +312,8 @@ pub struct Tokenizer<'a> {
     consumed: bool,
     /// Stack of how to handle attempts.
     attempts: Vec<Attempt>,
+    /// Place until which bytes are fed (exclusive).
+    feed_end: (usize, usize),
     /// Current byte.
     pub current: Option<u8>,
     /// Previous byte.
@@ -330,6 +363,7 @@ impl<'a> Tokenizer<'a> {
             line_start: point.clone(),
             consumed: true,
             attempts: vec![],
+            feed_end: (0, 0),
             point,
             stack: vec![],
             events: vec![],
@@ -344,12 +378,14 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                abbreviations: vec![],
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
                 mdx_last_parse_error: None,
                 end: 0,
                 label_starts: vec![],
                 label_starts_loose: vec![],
+                reference_warnings: vec![],
                 marker: 0,
                 marker_b: 0,
                 markers: &[],
@@ -361,6 +397,7 @@ impl<'a> Tokenizer<'a> {
                 space_or_tab_eol_content: None,
                 space_or_tab_eol_connect: false,
                 space_or_tab_eol_ok: false,
+                attributes_eol: false,
                 space_or_tab_connect: false,
                 space_or_tab_content: None,
                 space_or_tab_min: 0,
@@ -407,13 +444,8 @@ impl<'a> Tokenizer<'a> {
         let info = (point.index, point.vs);
         log::debug!("position: define skip: {:?} -> ({:?})", point.line, info);
         let at = point.line - self.first_line;
-
-        if at >= self.column_start.len() {
-            self.column_start.push(info);
-        } else {
-            self.column_start[at] = info;
-        }
-
+        self.fill_column_start(at);
+        self.column_start[at] = info;
         self.account_for_potential_skip();
     }
 
@@ -422,11 +454,23 @@ impl<'a> Tokenizer<'a> {
     fn account_for_potential_skip(&mut self) {
         let at = self.point.line - self.first_line;
 
-        if self.point.column == 1 && at != self.column_start.len() {
+        if self.point.column == 1 && at < self.column_start.len() {
             self.move_to(self.column_start[at]);
         }
     }
 
+    /// Make sure there is a jump for each line up to and including `at`.
+    ///
+    /// Lines without a jump get `(0, 0)`, which is never ahead of where we
+    /// are, so they do not move.
+    /// The jump of a line must be at its index, so that we move to the right
+    /// place when a line is fed again after an attempt is reverted.
+    fn fill_column_start(&mut self, at: usize) {
+        while self.column_start.len() <= at {
+            self.column_start.push((0, 0));
+        }
+    }
+
     /// Prepare for a next byte to get consumed.
     fn expect(&mut self, byte: Option<u8>) {
         debug_assert!(self.consumed, "expected previous byte to be consumed");
@@ -449,9 +493,34 @@ impl<'a> Tokenizer<'a> {
         self.consumed = true;
     }
 
+    /// Consume the current byte, and then the bytes after it that are fed,
+    /// until a line ending, a tab, or one of `markers`.
+    ///
+    /// This is the same as consuming those bytes one by one, but a lot faster
+    /// for long runs of plain bytes, as the state machine is not involved.
+    pub fn consume_run(&mut self, markers: &[u8]) {
+        self.consume();
+
+        while self.point.vs == 0 && self.point.index < self.feed_end.0 {
+            let byte = self.parse_state.bytes[self.point.index];
+
+            if matches!(byte, b'\t' | b'\n' | b'\r') || markers.contains(&byte) {
+                break;
+            }
+
+            self.previous = Some(byte);
+            self.point.index += 1;
+            self.point.column += 1;
+        }
+    }
+
     /// Move to the next (virtual) byte.
     fn move_one(&mut self) {
-        match byte_action(self.parse_state.bytes, &self.point) {
+        match byte_action(
+            self.parse_state.bytes,
+            self.parse_state.options.tab_size,
+            &self.point,
+        ) {
             ByteAction::Ignore => {
                 self.point.index += 1;
             }
@@ -469,9 +538,7 @@ impl<'a> Tokenizer<'a> {
                     self.point.line += 1;
                     self.point.column = 1;
 
-                    if self.point.line - self.first_line + 1 > self.column_start.len() {
-                        self.column_start.push((self.point.index, self.point.vs));
-                    }
+                    self.fill_column_start(self.point.line - self.first_line);
 
                     self.line_start = self.point.clone();
 
@@ -608,6 +675,16 @@ impl<'a> Tokenizer<'a> {
         self.attempts.push(attempt);
     }
 
+    /// Give a warning to the warning sink, if there is one.
+    pub fn warn(&self, kind: WarningKind, point: &Point) {
+        if let Some(sink) = &self.parse_state.options.warning_sink {
+            sink(Warning {
+                point: unist::Point::new(point.line, point.column, point.index),
+                kind,
+            });
+        }
+    }
+
     /// Tokenize.
     pub fn push(&mut self, from: (usize, usize), to: (usize, usize), state: State) -> State {
         push_impl(self, from, to, state, false)
@@ -622,6 +699,7 @@ impl<'a> Tokenizer<'a> {
 
         let mut value = Subresult {
             done: false,
+            abbreviations: self.tokenize_state.abbreviations.split_off(0),
             gfm_footnote_definitions: self.tokenize_state.gfm_footnote_definitions.split_off(0),
             definitions: self.tokenize_state.definitions.split_off(0),
         };
@@ -629,10 +707,12 @@ impl<'a> Tokenizer<'a> {
         if resolve {
             let resolvers = self.resolvers.split_off(0);
             let mut index = 0;
+            let abbrs = &mut value.abbreviations;
             let defs = &mut value.definitions;
             let fn_defs = &mut value.gfm_footnote_definitions;
             while index < resolvers.len() {
                 if let Some(mut result) = call_resolve(self, resolvers[index])? {
+                    abbrs.append(&mut result.abbreviations);
                     fn_defs.append(&mut result.gfm_footnote_definitions);
                     defs.append(&mut result.definitions);
                 }
@@ -650,7 +730,11 @@ impl<'a> Tokenizer<'a> {
 fn move_point_back(tokenizer: &mut Tokenizer, point: &mut Point) {
     while point.index > 0 {
         point.index -= 1;
-        let action = byte_action(tokenizer.parse_state.bytes, point);
+        let action = byte_action(
+            tokenizer.parse_state.bytes,
+            tokenizer.parse_state.options.tab_size,
+            point,
+        );
         if !matches!(action, ByteAction::Ignore) {
             point.index += 1;
             break;
@@ -688,6 +772,7 @@ fn push_impl(
     );
 
     tokenizer.move_to(from);
+    tokenizer.feed_end = to;
 
     loop {
         match state {
@@ -718,7 +803,11 @@ fn push_impl(
                 let action = if tokenizer.point.index < to.0
                     || (tokenizer.point.index == to.0 && tokenizer.point.vs < to.1)
                 {
-                    Some(byte_action(tokenizer.parse_state.bytes, &tokenizer.point))
+                    Some(byte_action(
+                        tokenizer.parse_state.bytes,
+                        tokenizer.parse_state.options.tab_size,
+                        &tokenizer.point,
+                    ))
                 } else if flush {
                     None
                 } else {
@@ -762,7 +851,7 @@ fn push_impl(
 }
 
 /// Figure out how to handle a byte.
-fn byte_action(bytes: &[u8], point: &Point) -> ByteAction {
+fn byte_action(bytes: &[u8], tab_size: usize, point: &Point) -> ByteAction {
     if point.index < bytes.len() {
         let byte = bytes[point.index];
 
@@ -776,12 +865,7 @@ fn byte_action(bytes: &[u8], point: &Point) -> ByteAction {
                 ByteAction::Normal(b'\n')
             }
         } else if byte == b'\t' {
-            let remainder = point.column % TAB_SIZE;
-            let vs = if remainder == 0 {
-                0
-            } else {
-                TAB_SIZE - remainder
-            };
+            let vs = tab_size - 1 - (point.column - 1) % tab_size;
 
             // On the tab itself, first send it.
             if point.vs == 0 {
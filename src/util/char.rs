@@ -92,8 +92,9 @@ pub fn classify(char: char) -> Kind {
     if char.is_whitespace() {
         Kind::Whitespace
     }
-    // Unicode punctuation.
-    else if PUNCTUATION.contains(&char) {
+    // ASCII punctuation (including symbols such as `$` or `+`) and Unicode
+    // punctuation.
+    else if char.is_ascii_punctuation() || PUNCTUATION.contains(&char) {
         Kind::Punctuation
     }
     // Everything else.
@@ -172,6 +173,18 @@ mod tests {
             "should classify punctuation"
         );
 
+        assert_eq!(
+            classify('$'),
+            Kind::Punctuation,
+            "should classify ASCII symbols as punctuation"
+        );
+
+        assert_eq!(
+            classify('「'),
+            Kind::Punctuation,
+            "should classify CJK punctuation"
+        );
+
         assert_eq!(classify('a'), Kind::Other, "should classify other");
     }
 
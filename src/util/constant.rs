@@ -67,6 +67,17 @@ pub const CHARACTER_REFERENCE_NAMED_SIZE_MAX: usize = 31;
 /// [raw_flow]: crate::construct::raw_flow
 pub const CODE_FENCED_SEQUENCE_SIZE_MIN: usize = 3;
 
+/// The number of markers needed for [directive (container)][directive_container]
+/// to form.
+///
+/// Like the fences of [code (fenced)][raw_flow], that is `3`.
+/// More markers can be used on a container to allow containers with fewer
+/// markers inside it.
+///
+/// [directive_container]: crate::construct::directive_container
+/// [raw_flow]: crate::construct::raw_flow
+pub const DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN: usize = 3;
+
 /// The number of markers needed for [frontmatter][] to form.
 ///
 /// Like many things in markdown, the number is `3`.
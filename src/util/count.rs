@@ -0,0 +1,145 @@
+//! Count words and characters in the text of a document.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    character_reference::decode as decode_character_reference,
+    slice::{Position, Slice},
+};
+
+/// Words and characters in a document.
+///
+/// Made with [`count_words()`][crate::count_words()].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    /// Number of words.
+    ///
+    /// A word is a run of characters, between whitespace, that includes a
+    /// letter or a number, or a CJK character.
+    pub words: usize,
+    /// Number of characters, not counting whitespace.
+    pub chars: usize,
+    /// Number of CJK characters.
+    ///
+    /// These are included in `words` and `chars` too.
+    pub cjk_chars: usize,
+}
+
+/// Count words and characters in `events`.
+///
+/// Only data in text is counted, such as in paragraphs, headings, and table
+/// cells, and the data of code (text): markers, HTML, code (flow), math, and
+/// data in strings (such as the destination and title of links, or the info
+/// of code (fenced)) are not.
+/// Character escapes and character references count as the character they
+/// represent.
+pub fn count(events: &[Event], bytes: &[u8], tab_size: usize) -> Counts {
+    let mut counts = Counts::default();
+    let mut in_word = false;
+    let mut ignore = 0;
+    let mut character_reference_marker = b'&';
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match event.name {
+            Name::CodeFencedFence
+            | Name::Definition
+            | Name::GfmFootnoteCall
+            | Name::GfmFootnoteDefinitionLabel
+            | Name::MathFlowFence
+            | Name::Reference
+            | Name::ResourceDestination
+            | Name::ResourceTitle => {
+                if event.kind == Kind::Enter {
+                    ignore += 1;
+                } else {
+                    ignore -= 1;
+                }
+            }
+            Name::GfmTableCell
+            | Name::HardBreakEscape
+            | Name::HardBreakTrailing
+            | Name::LineEnding
+            | Name::SpaceOrTab => in_word = false,
+            Name::CharacterReferenceMarker => character_reference_marker = b'&',
+            Name::CharacterReferenceMarkerHexadecimal => character_reference_marker = b'x',
+            Name::CharacterReferenceMarkerNumeric => character_reference_marker = b'#',
+            Name::CharacterEscapeValue
+            | Name::CharacterReferenceValue
+            | Name::CodeTextData
+            | Name::Data
+                if event.kind == Kind::Exit && ignore == 0 =>
+            {
+                let slice = Slice::from_position(
+                    bytes,
+                    &Position::from_exit_event(events, index),
+                    tab_size,
+                );
+                let value = slice.as_str();
+
+                if event.name == Name::CharacterReferenceValue {
+                    let decoded =
+                        decode_character_reference(value, character_reference_marker, true)
+                            .expect("expected to parse only valid named references");
+                    count_chars(&mut counts, &mut in_word, &decoded);
+                } else {
+                    count_chars(&mut counts, &mut in_word, value);
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    counts
+}
+
+/// Count the words and characters in `value`.
+///
+/// `in_word` is whether a word, with a letter or a number, was counted
+/// already, which continues across events (as in `a*b*c`).
+fn count_chars(counts: &mut Counts, in_word: &mut bool, value: &str) {
+    for char in value.chars() {
+        if char.is_whitespace() {
+            *in_word = false;
+        } else if is_cjk(char) {
+            counts.chars += 1;
+            counts.cjk_chars += 1;
+            counts.words += 1;
+            *in_word = false;
+        } else {
+            counts.chars += 1;
+
+            if !*in_word && char.is_alphanumeric() {
+                counts.words += 1;
+                *in_word = true;
+            }
+        }
+    }
+}
+
+/// Check whether a `char` is a CJK character: Han, kana, bopomofo, or
+/// Hangul.
+///
+/// CJK punctuation is not included.
+fn is_cjk(char: char) -> bool {
+    matches!(char,
+        '\u{1100}'..='\u{11FF}'
+        | '\u{3041}'..='\u{309F}'
+        | '\u{30A1}'..='\u{30FA}'
+        | '\u{30FC}'..='\u{30FF}'
+        | '\u{3100}'..='\u{312F}'
+        | '\u{3130}'..='\u{318F}'
+        | '\u{31A0}'..='\u{31BF}'
+        | '\u{31F0}'..='\u{31FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{A960}'..='\u{A97F}'
+        | '\u{AC00}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FF66}'..='\u{FF9D}'
+        | '\u{20000}'..='\u{323AF}'
+    )
+}
@@ -58,7 +58,11 @@ fn shift_links(events: &mut [Event], jumps: &[(usize, usize, usize)]) {
 #[derive(Debug)]
 pub struct EditMap {
     /// Record of changes.
-    map: Vec<(usize, usize, Vec<Event>)>,
+    ///
+    /// Several changes can be at the same place: they are merged when
+    /// consuming, in the order they were made, and the last field is whether
+    /// to insert before earlier additions.
+    map: Vec<(usize, usize, Vec<Event>, bool)>,
 }
 
 impl EditMap {
@@ -76,20 +80,22 @@ impl EditMap {
     }
     /// Done, change the events.
     pub fn consume(&mut self, events: &mut Vec<Event>) {
-        self.map
-            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        // Stable, so that changes at the same place stay in order.
+        self.map.sort_by_key(|d| d.0);
 
         if self.map.is_empty() {
             return;
         }
 
+        merge(&mut self.map);
+
         // Calculate jumps: where items in the current list move to.
         let mut jumps = Vec::with_capacity(self.map.len());
         let mut index = 0;
         let mut add_acc = 0;
         let mut remove_acc = 0;
         while index < self.map.len() {
-            let (at, remove, add) = &self.map[index];
+            let (at, remove, add, _) = &self.map[index];
             remove_acc += remove;
             add_acc += add.len();
             jumps.push((*at, remove_acc, add_acc));
@@ -120,29 +126,37 @@ impl EditMap {
 }
 
 /// Create an edit.
-fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, mut add: Vec<Event>, before: bool) {
-    let mut index = 0;
-
+fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, add: Vec<Event>, before: bool) {
     if remove == 0 && add.is_empty() {
         return;
     }
 
-    while index < edit_map.map.len() {
-        if edit_map.map[index].0 == at {
-            edit_map.map[index].1 += remove;
+    edit_map.map.push((at, remove, add, before));
+}
+
+/// Merge sorted edits at the same place.
+fn merge(map: &mut Vec<(usize, usize, Vec<Event>, bool)>) {
+    let mut index = 1;
+    let mut last = 0;
+
+    while index < map.len() {
+        if map[index].0 == map[last].0 {
+            let (_, remove, mut add, before) = core::mem::take(&mut map[index]);
+            map[last].1 += remove;
 
             if before {
-                add.append(&mut edit_map.map[index].2);
-                edit_map.map[index].2 = add;
+                add.append(&mut map[last].2);
+                map[last].2 = add;
             } else {
-                edit_map.map[index].2.append(&mut add);
+                map[last].2.append(&mut add);
             }
-
-            return;
+        } else {
+            last += 1;
+            map.swap(last, index);
         }
 
         index += 1;
     }
 
-    edit_map.map.push((at, remove, add));
+    map.truncate(last + 1);
 }
@@ -0,0 +1,72 @@
+//! Fatal problems found while parsing.
+//!
+//! Markdown does not have syntax errors, so parsing markdown only fails when
+//! the options passed are invalid.
+//! MDX does have syntax errors: when MDX is turned on, how expressions, ESM,
+//! and JSX are written can also make parsing fail.
+
+use alloc::{fmt, string::String};
+
+/// Reason why markdown could not be turned into something else.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MarkdownError {
+    /// The options cannot be used.
+    ///
+    /// Some constructs cannot be turned on together, because they start
+    /// with the same markers (such as HTML (flow) and JSX (flow), which both
+    /// start with `<`), and some options only accept certain values (such as
+    /// `tab_size`, which must be at least `1`).
+    InvalidOptions(String),
+    /// The MDX is invalid.
+    ///
+    /// The message starts with the line and column where the problem was
+    /// found (such as `1:3: `).
+    ///
+    /// ```markdown
+    /// > | a {b
+    ///         ^
+    /// ```
+    Syntax(String),
+}
+
+impl fmt::Display for MarkdownError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkdownError::InvalidOptions(message) | MarkdownError::Syntax(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+impl From<MarkdownError> for String {
+    fn from(error: MarkdownError) -> Self {
+        match error {
+            MarkdownError::InvalidOptions(message) | MarkdownError::Syntax(message) => message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_error() {
+        assert_eq!(
+            format!(
+                "{}",
+                MarkdownError::Syntax("1:3: Unexpected end of file".into())
+            ),
+            "1:3: Unexpected end of file",
+            "should display a syntax error"
+        );
+
+        assert_eq!(
+            String::from(MarkdownError::InvalidOptions("a".into())),
+            "a",
+            "should turn an error into a string"
+        );
+    }
+}
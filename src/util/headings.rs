@@ -0,0 +1,87 @@
+//! Collect the headings in a document, such as for a table of contents.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    infer::text_content,
+    slice::{Position, Slice},
+    slug::{slug, unique as unique_slug, Seen},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Heading in a document.
+///
+/// Made with [`headings()`][crate::headings()].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeadingInfo {
+    /// Rank (between `1` and `6`, both including).
+    pub level: u8,
+    /// Text content, without formatting.
+    pub text: String,
+    /// Slug of `text`, made unique, which is the same as the `id` generated
+    /// with [`heading_ids`][crate::CompileOptions::heading_ids].
+    ///
+    /// Empty when `text` has nothing to make a slug from, in which case
+    /// `heading_ids` does not generate an `id` either.
+    pub id: String,
+}
+
+/// Collect the ATX and setext headings in `events`, in the order they occur.
+pub fn headings(events: &[Event], bytes: &[u8], tab_size: usize) -> Vec<HeadingInfo> {
+    let mut seen = Seen::default();
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter && matches!(event.name, Name::HeadingAtx | Name::HeadingSetext)
+        {
+            let name = event.name.clone();
+            let mut level = 0;
+            let mut text = String::new();
+
+            index += 1;
+
+            while !(events[index].kind == Kind::Exit && events[index].name == name) {
+                let event = &events[index];
+
+                if event.kind == Kind::Enter {
+                    if matches!(event.name, Name::HeadingAtxText | Name::HeadingSetextText) {
+                        text = text_content(events, bytes, tab_size, index);
+                    }
+                } else if event.name == Name::HeadingAtxSequence && level == 0 {
+                    let slice = Slice::from_position(
+                        bytes,
+                        &Position::from_exit_event(events, index),
+                        tab_size,
+                    );
+                    #[allow(clippy::cast_possible_truncation)]
+                    let rank = slice.len() as u8;
+                    level = rank;
+                } else if event.name == Name::HeadingSetextUnderlineSequence {
+                    let position = Position::from_exit_event(events, index);
+                    level = if bytes[position.start.index] == b'-' {
+                        2
+                    } else {
+                        1
+                    };
+                }
+
+                index += 1;
+            }
+
+            let id = slug(&text);
+            let id = if id.is_empty() {
+                id
+            } else {
+                unique_slug(&mut seen, &id)
+            };
+
+            result.push(HeadingInfo { level, text, id });
+        }
+
+        index += 1;
+    }
+
+    result
+}
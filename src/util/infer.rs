@@ -4,7 +4,75 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
-use alloc::{vec, vec::Vec};
+use crate::util::{
+    character_reference::parse as parse_character_reference,
+    emoji::find as find_emoji,
+    slice::{Position, Slice},
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Figure out if a definition list is spread or not.
+///
+/// Blank lines between descriptions, or between blocks in descriptions, make
+/// a list spread.
+/// Blank lines before terms do not, as a term would otherwise be a lazy line
+/// of the description before it.
+pub fn definition_list_loose(events: &[Event], mut index: usize) -> bool {
+    debug_assert!(
+        matches!(events[index].name, Name::DefinitionList),
+        "expected definition list"
+    );
+    let mut balance = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            balance += 1;
+        } else {
+            balance -= 1;
+
+            // Blank line in the list, or directly in a description.
+            if (balance == 1 || balance == 2) && event.name == Name::BlankLineEnding {
+                let mut after = index + 1;
+
+                while after < events.len()
+                    && (matches!(
+                        events[after].name,
+                        Name::BlankLineEnding
+                            | Name::BlockQuoteMarker
+                            | Name::BlockQuotePrefix
+                            | Name::LineEnding
+                            | Name::SpaceOrTab
+                    ) || (events[after].kind == Kind::Exit
+                        && events[after].name == Name::DefinitionDescription))
+                {
+                    after += 1;
+                }
+
+                if after < events.len()
+                    && events[after].name != Name::DefinitionTerm
+                    && events[after].name != Name::DefinitionList
+                {
+                    return true;
+                }
+            }
+
+            // Done.
+            if balance == 0 && event.name == Name::DefinitionList {
+                break;
+            }
+        }
+
+        index += 1;
+    }
+
+    false
+}
 
 /// Figure out if a list is spread or not.
 ///
@@ -51,6 +119,15 @@ pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bo
                 //   | * a
                 // ```
                 let mut at_empty_block_quote = false;
+                // Rest of the line of a directive (container) closing fence:
+                //
+                // ```markdown
+                //   | * :::a
+                // > |   :::␊
+                //          ^
+                //   | * b
+                // ```
+                let mut at_directive_container_end = false;
 
                 // List.
                 let mut before = index - 2;
@@ -68,10 +145,14 @@ pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bo
                         at_empty_block_quote = true;
                     } else if events[before].name == Name::ListItemPrefix {
                         at_empty_list_item = true;
+                    } else if events[before].name == Name::DirectiveContainer
+                        && events[before - 1].name == Name::DirectiveContainerFence
+                    {
+                        at_directive_container_end = true;
                     }
                 }
 
-                if !at_empty_list_item && !at_empty_block_quote {
+                if !at_empty_list_item && !at_empty_block_quote && !at_directive_container_end {
                     return true;
                 }
             }
@@ -113,6 +194,15 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
                 //   |   a
                 // ```
                 let mut at_prefix = false;
+                // Rest of the line of a directive (container) closing fence:
+                //
+                // ```markdown
+                //   | * :::a
+                // > |   :::␊
+                //          ^
+                //   |   b
+                // ```
+                let mut at_directive_container_end = false;
 
                 // List item.
                 let mut before = index - 2;
@@ -123,9 +213,14 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
 
                 if events[before].name == Name::ListItemPrefix {
                     at_prefix = true;
+                } else if events[before].name == Name::DirectiveContainer
+                    && events[before - 1].name == Name::DirectiveContainerFence
+                {
+                    at_directive_container_end = true;
                 }
 
-                if !at_prefix {
+                if !at_prefix && !at_directive_container_end && !at_end_of_last_item(events, index)
+                {
                     return true;
                 }
             }
@@ -142,6 +237,50 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
     false
 }
 
+/// Check if a blank line ending is at the end of the last item in a list.
+///
+/// Trailing blank lines in the last item do not make it spread, as they are
+/// not between two blocks:
+///
+/// ```markdown
+///   | - a
+/// > | ␊
+///     ^
+///   | b
+/// ```
+fn at_end_of_last_item(events: &[Event], mut index: usize) -> bool {
+    index += 1;
+
+    while index < events.len()
+        && matches!(
+            events[index].name,
+            Name::BlankLineEnding
+                | Name::BlockQuoteMarker
+                | Name::BlockQuotePrefix
+                | Name::SpaceOrTab
+        )
+    {
+        index += 1;
+    }
+
+    if index == events.len()
+        || events[index].kind != Kind::Exit
+        || events[index].name != Name::ListItem
+    {
+        return false;
+    }
+
+    index += 1;
+
+    while index < events.len() && events[index].name == Name::BlankLineEnding {
+        index += 1;
+    }
+
+    index == events.len()
+        || events[index].kind != Kind::Enter
+        || events[index].name != Name::ListItem
+}
+
 /// Figure out the alignment of a GFM table.
 pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
     debug_assert!(
@@ -190,3 +329,300 @@ pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
 
     align
 }
+
+/// Figure out the attributes of a directive.
+///
+/// Expects the index of [`DirectiveAttributes`][Name::DirectiveAttributes].
+/// Ids (`#a`) and classes (`.a`) are turned into `id` and `class` attributes.
+/// Character references in values are decoded.
+/// When an attribute occurs several times, it stays at the position where it
+/// was first found, and the last value wins, except for classes, which are
+/// joined with spaces.
+pub fn directive_attributes(
+    events: &[Event],
+    bytes: &[u8],
+    tab_size: usize,
+    mut index: usize,
+) -> Vec<(String, String)> {
+    debug_assert!(
+        matches!(events[index].name, Name::DirectiveAttributes),
+        "expected directive attributes"
+    );
+    let mut attributes: Vec<(String, String)> = vec![];
+    let mut key: Option<String> = None;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::DirectiveAttributeClassMarker
+                | Name::DirectiveAttributeIdMarker
+                | Name::DirectiveAttributeName => {
+                    // Attributes without value, such as `b` in `{b c=d}`, are
+                    // empty.
+                    if let Some(key) = key.take() {
+                        push_directive_attribute(&mut attributes, key, String::new());
+                    }
+
+                    key = Some(match event.name {
+                        Name::DirectiveAttributeClassMarker => "class".into(),
+                        Name::DirectiveAttributeIdMarker => "id".into(),
+                        _ => Slice::from_position(
+                            bytes,
+                            &Position::from_exit_event(events, index),
+                            tab_size,
+                        )
+                        .as_str()
+                        .to_string(),
+                    });
+                }
+                Name::DirectiveAttributeValue => {
+                    if let Some(key) = key.take() {
+                        let value = parse_character_reference(
+                            Slice::from_position(
+                                bytes,
+                                &Position::from_exit_event(events, index),
+                                tab_size,
+                            )
+                            .as_str(),
+                            true,
+                        );
+                        push_directive_attribute(&mut attributes, key, value);
+                    }
+                }
+                // Done.
+                Name::DirectiveAttributes => break,
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    if let Some(key) = key {
+        push_directive_attribute(&mut attributes, key, String::new());
+    }
+
+    attributes
+}
+
+/// Add an attribute to a list of directive attributes.
+fn push_directive_attribute(attributes: &mut Vec<(String, String)>, key: String, value: String) {
+    if let Some(existing) = attributes.iter_mut().find(|d| d.0 == key) {
+        if key == "class" && !existing.1.is_empty() {
+            existing.1.push(' ');
+            existing.1.push_str(&value);
+        } else {
+            existing.1 = value;
+        }
+    } else {
+        attributes.push((key, value));
+    }
+}
+
+/// Get the text content of what starts at the enter event at `index`.
+///
+/// Things that are not shown as text, such as images, destinations, titles,
+/// and HTML, are ignored.
+/// Emoji are kept as their shortcode (such as `:tada:`).
+pub fn text_content(events: &[Event], bytes: &[u8], tab_size: usize, index: usize) -> String {
+    let name = &events[index].name;
+    let mut index = index + 1;
+    let mut value = String::new();
+
+    while !(events[index].kind == Kind::Exit && events[index].name == *name) {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if matches!(
+                event.name,
+                Name::DirectiveAttributes
+                    | Name::GfmFootnoteCall
+                    | Name::HtmlText
+                    | Name::Image
+                    | Name::InlineFootnote
+                    | Name::MdxJsxTextTag
+                    | Name::MdxTextExpression
+                    | Name::Reference
+                    | Name::Resource
+            ) {
+                let skip = event.name.clone();
+                while !(events[index].kind == Kind::Exit && events[index].name == skip) {
+                    index += 1;
+                }
+            }
+        } else {
+            match event.name {
+                Name::Abbreviation
+                | Name::AutolinkEmail
+                | Name::AutolinkProtocol
+                | Name::CharacterEscapeValue
+                | Name::CodeTextData
+                | Name::Data
+                | Name::Emoji
+                | Name::GfmAutolinkLiteralEmail
+                | Name::GfmAutolinkLiteralMailto
+                | Name::GfmAutolinkLiteralProtocol
+                | Name::GfmAutolinkLiteralWww
+                | Name::GfmAutolinkLiteralXmpp
+                | Name::LineEnding
+                | Name::MathTextData => {
+                    value.push_str(
+                        Slice::from_position(
+                            bytes,
+                            &Position::from_exit_event(events, index),
+                            tab_size,
+                        )
+                        .as_str(),
+                    );
+                }
+                Name::Wikilink => {
+                    value.push_str(&wikilink(events, bytes, tab_size, index).1);
+                }
+                Name::CharacterReference => {
+                    value.push_str(&parse_character_reference(
+                        Slice::from_position(
+                            bytes,
+                            &Position::from_exit_event(events, index),
+                            tab_size,
+                        )
+                        .as_str(),
+                        true,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    value
+}
+
+/// Figure out the info string, language, and meta of code (fenced).
+///
+/// Expects the index of the enter of
+/// [`CodeFencedFenceInfoString`][Name::CodeFencedFenceInfoString].
+/// Character escapes and character references are decoded, and the
+/// whitespace between the language and the meta is kept as-is, so the info
+/// string starts with the language.
+/// Trailing whitespace is not part of the info string or the meta.
+pub fn code_fenced_info(
+    events: &[Event],
+    bytes: &[u8],
+    tab_size: usize,
+    mut index: usize,
+) -> (String, String, Option<String>) {
+    debug_assert!(
+        matches!(events[index].name, Name::CodeFencedFenceInfoString)
+            && events[index].kind == Kind::Enter,
+        "expected info string enter"
+    );
+    let mut info = String::new();
+    let mut language = String::new();
+    let mut meta = None;
+    let mut whitespace = String::new();
+
+    index += 1;
+
+    while events[index].name != Name::CodeFencedFenceInfoString {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if event.name == Name::CodeFencedFenceInfo {
+                language = text_content(events, bytes, tab_size, index);
+            } else if event.name == Name::CodeFencedFenceMeta {
+                let mut value = text_content(events, bytes, tab_size, index);
+                value.truncate(value.trim_end_matches(|d| matches!(d, '\t' | ' ')).len());
+                meta = Some(value);
+            }
+        } else if event.name == Name::SpaceOrTab {
+            whitespace =
+                Slice::from_position(bytes, &Position::from_exit_event(events, index), tab_size)
+                    .serialize();
+        }
+
+        index += 1;
+    }
+
+    info.push_str(&language);
+
+    if let Some(meta) = &meta {
+        info.push_str(&whitespace);
+        info.push_str(meta);
+    }
+
+    (info, language, meta)
+}
+
+/// Figure out the name and value of an emoji.
+///
+/// Expects the index of the exit of [`Emoji`][Name::Emoji].
+/// The name is without colons.
+/// The value is the emoji, or the URL of an image if it contains a slash
+/// (which the boolean is for).
+pub fn emoji(
+    events: &[Event],
+    bytes: &[u8],
+    tab_size: usize,
+    index: usize,
+    custom: &[(String, String)],
+) -> (String, String, bool) {
+    debug_assert!(
+        matches!(events[index].name, Name::Emoji) && events[index].kind == Kind::Exit,
+        "expected emoji exit"
+    );
+    let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index), tab_size);
+    let shortcode = slice.as_str();
+    let name = &shortcode[1..shortcode.len() - 1];
+    // Emoji are only formed for known names.
+    let value = find_emoji(name, custom).unwrap();
+    (name.into(), value.into(), value.contains('/'))
+}
+
+/// Figure out the target and text of a wikilink.
+///
+/// Expects the index of the exit of [`Wikilink`][Name::Wikilink].
+/// The text is the label, or the target if there is no label.
+/// Both are trimmed.
+pub fn wikilink(
+    events: &[Event],
+    bytes: &[u8],
+    tab_size: usize,
+    mut index: usize,
+) -> (String, String) {
+    debug_assert!(
+        matches!(events[index].name, Name::Wikilink) && events[index].kind == Kind::Exit,
+        "expected wikilink exit"
+    );
+    let mut target = String::new();
+    let mut label = None;
+
+    while index > 0 {
+        index -= 1;
+        let event = &events[index];
+
+        if event.kind == Kind::Exit
+            && matches!(event.name, Name::WikilinkLabel | Name::WikilinkTarget)
+        {
+            let value =
+                Slice::from_position(bytes, &Position::from_exit_event(events, index), tab_size)
+                    .as_str()
+                    .trim()
+                    .to_string();
+
+            if event.name == Name::WikilinkLabel {
+                label = Some(value);
+            } else {
+                target = value;
+            }
+        } else if event.kind == Kind::Enter && event.name == Name::Wikilink {
+            break;
+        }
+    }
+
+    let text = label.unwrap_or_else(|| target.clone());
+    (target, text)
+}
@@ -0,0 +1,248 @@
+//! Collect the links, images, and definitions in a document, such as to
+//! check for dead links.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::Point;
+use crate::util::{
+    infer::text_content,
+    normalize_identifier::normalize_identifier,
+    slice::{Position, Slice},
+};
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
+
+/// Link or image, with where it points to.
+///
+/// Made with [`link_report()`][crate::link_report()].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkRef {
+    /// URL, with character escapes and references decoded.
+    ///
+    /// The `mailto:` of email autolinks, and the `http://` of GFM autolink
+    /// literals that start with `www.`, is added, but otherwise this is as
+    /// written: relative URLs are not resolved.
+    pub destination: String,
+    /// Title, with character escapes and references decoded.
+    pub title: Option<String>,
+    /// Start of the link or image in the markdown.
+    pub point: Point,
+    /// Whether `destination` and `title` come from a definition, such as
+    /// with `[a]` or `[b][a]`, instead of being in the link or image.
+    pub resolved_from_definition: bool,
+}
+
+/// Definition.
+///
+/// Made with [`link_report()`][crate::link_report()].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinitionInfo {
+    /// Label, as written.
+    pub label: String,
+    /// Start of the definition in the markdown.
+    pub point: Point,
+    /// Whether a link or image references the definition.
+    ///
+    /// Only the first definition of a label can be referenced, so this is
+    /// always `false` for later ones.
+    pub used: bool,
+}
+
+/// Links, images, and definitions in a document.
+///
+/// Made with [`link_report()`][crate::link_report()].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LinkReport {
+    /// Links and images (including autolinks), in the order they start.
+    pub links: Vec<LinkRef>,
+    /// Definitions, in the order they occur.
+    pub definitions: Vec<DefinitionInfo>,
+}
+
+/// Definition that is being collected.
+#[derive(Debug, Default)]
+struct Definition {
+    /// Label, as written.
+    label: String,
+    /// Destination.
+    destination: String,
+    /// Title.
+    title: Option<String>,
+}
+
+/// Link or image that is being collected.
+#[derive(Debug, Default)]
+struct Media {
+    /// Normalized identifier of the label.
+    label_id: Option<String>,
+    /// Normalized identifier of the reference.
+    reference_id: Option<String>,
+    /// Whether it has a resource (`(a "b")`).
+    resource: bool,
+}
+
+/// Collect the links, images, and definitions in `events`.
+pub fn report(events: &[Event], bytes: &[u8], tab_size: usize) -> LinkReport {
+    let mut report = LinkReport::default();
+    // Normalized identifiers and values of definitions.
+    let mut definitions: Vec<(String, Definition)> = vec![];
+    let mut definition = Definition::default();
+    // References: the index of a link in `report.links`, and an identifier.
+    let mut references: Vec<(usize, String)> = vec![];
+    // Links and images we are in, with their index in `report.links`.
+    let mut media_stack: Vec<(usize, Media)> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            match event.name {
+                Name::Autolink | Name::Image | Name::Link => {
+                    if event.name != Name::Autolink {
+                        media_stack.push((report.links.len(), Media::default()));
+                    }
+
+                    report.links.push(LinkRef {
+                        destination: String::new(),
+                        title: None,
+                        point: event.point.to_unist(),
+                        resolved_from_definition: false,
+                    });
+                }
+                Name::Definition => {
+                    definition = Definition::default();
+                    report.definitions.push(DefinitionInfo {
+                        label: String::new(),
+                        point: event.point.to_unist(),
+                        used: false,
+                    });
+                }
+                Name::DefinitionDestinationString => {
+                    definition.destination = text_content(events, bytes, tab_size, index);
+                }
+                Name::DefinitionTitleString => {
+                    definition.title = Some(text_content(events, bytes, tab_size, index));
+                }
+                Name::Resource => media_stack.last_mut().unwrap().1.resource = true,
+                Name::ResourceDestinationString => {
+                    let link = media_stack.last().unwrap().0;
+                    report.links[link].destination = text_content(events, bytes, tab_size, index);
+                }
+                Name::ResourceTitleString => {
+                    let link = media_stack.last().unwrap().0;
+                    report.links[link].title = Some(text_content(events, bytes, tab_size, index));
+                }
+                _ => {}
+            }
+        } else {
+            match event.name {
+                Name::AutolinkEmail
+                | Name::AutolinkProtocol
+                | Name::GfmAutolinkLiteralEmail
+                | Name::GfmAutolinkLiteralMailto
+                | Name::GfmAutolinkLiteralProtocol
+                | Name::GfmAutolinkLiteralWww
+                | Name::GfmAutolinkLiteralXmpp => {
+                    let value = Slice::from_position(
+                        bytes,
+                        &Position::from_exit_event(events, index),
+                        tab_size,
+                    );
+                    let prefix = match event.name {
+                        Name::AutolinkEmail | Name::GfmAutolinkLiteralEmail => "mailto:",
+                        Name::GfmAutolinkLiteralWww => "http://",
+                        _ => "",
+                    };
+                    let destination = format!("{}{}", prefix, value.as_str());
+
+                    // Autolinks are entered already, GFM autolink literals
+                    // are one event.
+                    if matches!(event.name, Name::AutolinkEmail | Name::AutolinkProtocol) {
+                        report.links.last_mut().unwrap().destination = destination;
+                    } else {
+                        report.links.push(LinkRef {
+                            destination,
+                            title: None,
+                            point: events[index - 1].point.to_unist(),
+                            resolved_from_definition: false,
+                        });
+                    }
+                }
+                Name::Definition => {
+                    let id = normalize_identifier(&definition.label);
+                    report
+                        .definitions
+                        .last_mut()
+                        .unwrap()
+                        .label
+                        .clone_from(&definition.label);
+                    definitions.push((id, core::mem::take(&mut definition)));
+                }
+                Name::DefinitionLabelString => {
+                    // We don’t care about virtual spaces, so `as_str` is fine.
+                    definition.label = Slice::from_position(
+                        bytes,
+                        &Position::from_exit_event(events, index),
+                        tab_size,
+                    )
+                    .as_str()
+                    .into();
+                }
+                Name::Image | Name::Link => {
+                    let (link, media) = media_stack.pop().unwrap();
+
+                    if !media.resource {
+                        if let Some(id) = media.reference_id.or(media.label_id) {
+                            references.push((link, id));
+                        }
+                    }
+                }
+                // GFM footnote calls and inline footnotes have label text
+                // too, but are not on the stack.
+                // When in a link or image, its own label text comes later.
+                Name::LabelText | Name::ReferenceString => {
+                    if let Some((_, media)) = media_stack.last_mut() {
+                        let id = normalize_identifier(
+                            Slice::from_position(
+                                bytes,
+                                &Position::from_exit_event(events, index),
+                                tab_size,
+                            )
+                            .as_str(),
+                        );
+
+                        if event.name == Name::LabelText {
+                            media.label_id = Some(id);
+                        } else {
+                            media.reference_id = Some(id);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    // The first definition of an identifier is used.
+    let mut used = BTreeSet::new();
+
+    for (link, id) in references {
+        if let Some((_, definition)) = definitions.iter().find(|d| d.0 == id) {
+            let link = &mut report.links[link];
+            link.destination.clone_from(&definition.destination);
+            link.title.clone_from(&definition.title);
+            link.resolved_from_definition = true;
+            used.insert(id);
+        }
+    }
+
+    // Later definitions of an identifier are never used.
+    let mut seen = BTreeSet::new();
+
+    for (info, (id, _)) in report.definitions.iter_mut().zip(definitions) {
+        info.used = used.contains(&id) && seen.insert(id);
+    }
+
+    report
+}
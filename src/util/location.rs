@@ -1,9 +1,12 @@
 //! Deal with positions in a file.
 //!
 //! * Convert between byte indices and unist points.
+//! * Convert between byte indices and unist points, with columns like the
+//!   tokenizer counts them.
 //! * Convert between byte indices into a string which is built up of several
 //!   slices in a whole document, and byte indices into that whole document.
 
+use crate::construct::partial_bom::BOM;
 use crate::unist::Point;
 use alloc::{vec, vec::Vec};
 
@@ -82,6 +85,100 @@ impl Location {
         None
     }
 
+    /// Get the point for `offset`, with the column as the tokenizer counts
+    /// it.
+    ///
+    /// Like `to_point`, each byte takes up a column, but tabs expand to the
+    /// next tab stop, and the carriage return in a CRLF does not take up a
+    /// column (it is at the same column as the line feed after it).
+    /// A BOM does not take up a column either, so the column after it is `1`.
+    /// `bytes` must be the ones that `self` was made for.
+    /// A `tab_size` of `0` is treated as `1`.
+    ///
+    /// Returns `None` when given out of bounds input or an offset inside a
+    /// character.
+    #[must_use]
+    pub fn offset_to_point(&self, bytes: &[u8], tab_size: usize, offset: usize) -> Option<Point> {
+        if offset < bytes.len() && is_continuation(bytes[offset]) {
+            return None;
+        }
+
+        let point = self.to_point(offset)?;
+        let mut index = offset + 1 - point.column;
+        let mut column = 1;
+
+        if index == 0 && offset >= bom_size(bytes) {
+            index = bom_size(bytes);
+        }
+
+        while index < offset {
+            column = next_column(bytes, tab_size, index, column);
+            index += 1;
+        }
+
+        Some(Point {
+            line: point.line,
+            column,
+            offset,
+        })
+    }
+
+    /// Get the offset for `line` and `column`, with the column as the
+    /// tokenizer counts it.
+    ///
+    /// This is the opposite of `offset_to_point`.
+    /// A column in the virtual spaces of a tab gets the offset of that tab,
+    /// and the column of a CRLF gets the offset of the carriage return.
+    /// Column `1` of line `1` is after a BOM, if there is one.
+    /// A `tab_size` of `0` is treated as `1`.
+    ///
+    /// Returns `None` when given out of bounds input or a column inside a
+    /// character.
+    #[must_use]
+    pub fn point_to_offset(
+        &self,
+        bytes: &[u8],
+        tab_size: usize,
+        line: usize,
+        column: usize,
+    ) -> Option<usize> {
+        if line == 0 || line > self.indices.len() || column == 0 {
+            return None;
+        }
+
+        let mut index = if line == 1 {
+            bom_size(bytes)
+        } else {
+            self.indices[line - 2]
+        };
+        let end = self.indices[line - 1];
+        let mut current = 1;
+
+        while index < end {
+            if current == column {
+                return if index < bytes.len() && is_continuation(bytes[index]) {
+                    None
+                } else {
+                    Some(index)
+                };
+            }
+
+            if index == bytes.len() {
+                break;
+            }
+
+            current = next_column(bytes, tab_size, index, current);
+
+            if current > column {
+                return Some(index);
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+
     /// Like `to_point`, but takes a relative offset from a certain string
     /// instead of an absolute offset into the whole document.
     ///
@@ -111,6 +208,34 @@ impl Location {
     }
 }
 
+/// Get the size of the BOM at the start of `bytes`, if there is one.
+fn bom_size(bytes: &[u8]) -> usize {
+    if bytes.starts_with(&BOM) {
+        BOM.len()
+    } else {
+        0
+    }
+}
+
+/// Get the column after the byte at `index`, which is at `column`.
+///
+/// A `tab_size` of `0` is treated as `1`.
+fn next_column(bytes: &[u8], tab_size: usize, index: usize, column: usize) -> usize {
+    match bytes[index] {
+        b'\t' => {
+            let tab_size = tab_size.max(1);
+            column + tab_size - (column - 1) % tab_size
+        }
+        b'\r' if bytes.get(index + 1) == Some(&b'\n') => column,
+        _ => column + 1,
+    }
+}
+
+/// Check whether `byte` continues a UTF-8 character.
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +321,167 @@ mod tests {
             "should support some points (4)"
         );
     }
+
+    #[test]
+    fn test_location_offset_to_point() {
+        let bytes = "a\tb\r\n\tc\u{e9}d".as_bytes();
+        let location = Location::new(bytes);
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 1), // `\t`
+            Some(Point::new(1, 2, 1)),
+            "should support a tab"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 2), // `b`
+            Some(Point::new(1, 5, 2)),
+            "should expand tabs to the next tab stop"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 2, 2), // `b`
+            Some(Point::new(1, 3, 2)),
+            "should support a different tab size"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 3), // `\r`
+            Some(Point::new(1, 6, 3)),
+            "should support a carriage return"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 4), // `\n`
+            Some(Point::new(1, 6, 4)),
+            "should not count the carriage return of a CRLF"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 6), // `c`
+            Some(Point::new(2, 5, 6)),
+            "should expand a tab at the start of a line"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 8), // Inside `é`
+            None,
+            "should not support an offset inside a character"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 9), // `d`
+            Some(Point::new(2, 8, 9)),
+            "should count each byte of a character"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 10), // EOF
+            Some(Point::new(2, 9, 10)),
+            "should support the end"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 11), // Out of bounds
+            None,
+            "should not support out of bounds offsets"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 0, 2), // `b`
+            Some(Point::new(1, 3, 2)),
+            "should treat a tab size of `0` as `1`"
+        );
+
+        let bytes = "\u{feff}a\tb\r\nc".as_bytes();
+        let location = Location::new(bytes);
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 0), // BOM
+            Some(Point::new(1, 1, 0)),
+            "should support a BOM"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 1), // Inside BOM
+            None,
+            "should not support an offset inside a BOM"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 3), // `a`
+            Some(Point::new(1, 1, 3)),
+            "should not count a BOM as a column"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 5), // `b`
+            Some(Point::new(1, 5, 5)),
+            "should support a tab after a BOM"
+        );
+        assert_eq!(
+            location.offset_to_point(bytes, 4, 8), // `c`
+            Some(Point::new(2, 1, 8)),
+            "should not affect other lines after a BOM"
+        );
+    }
+
+    #[test]
+    fn test_location_point_to_offset() {
+        let bytes = "a\tb\r\n\tc\u{e9}d".as_bytes();
+        let location = Location::new(bytes);
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 5), // `b`
+            Some(2),
+            "should support a column after a tab"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 3), // `\t`
+            Some(1),
+            "should support a column in a tab"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 6), // `\r`
+            Some(3),
+            "should support the column of a CRLF"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 7), // Past the line
+            None,
+            "should not support a column past the line"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 2, 1), // `\t`
+            Some(5),
+            "should support the start of a line"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 2, 7), // Inside `é`
+            None,
+            "should not support a column inside a character"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 2, 9), // EOF
+            Some(10),
+            "should support the end"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 3, 1), // Out of bounds
+            None,
+            "should not support a line past the end"
+        );
+
+        let bytes = "\u{feff}a\tb\r\nc".as_bytes();
+        let location = Location::new(bytes);
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 1), // `a`
+            Some(3),
+            "should not count a BOM as a column"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 5), // `b`
+            Some(5),
+            "should support a tab after a BOM"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 1, 6), // `\r`
+            Some(6),
+            "should support a CRLF after a BOM"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 2, 1), // `c`
+            Some(8),
+            "should not affect other lines after a BOM"
+        );
+        assert_eq!(
+            location.point_to_offset(bytes, 4, 0, 1), // Out of bounds
+            None,
+            "should not support line `0`"
+        );
+    }
 }
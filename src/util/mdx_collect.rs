@@ -15,6 +15,7 @@ pub struct Result {
 pub fn collect(
     events: &[Event],
     bytes: &[u8],
+    tab_size: usize,
     from: usize,
     names: &[Name],
     stop: &[Name],
@@ -35,6 +36,7 @@ pub fn collect(
                         start: &events[index].point,
                         end: &events[index + 1].point,
                     },
+                    tab_size,
                 )
                 .serialize();
                 result
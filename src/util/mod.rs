@@ -3,17 +3,27 @@
 pub mod char;
 pub mod character_reference;
 pub mod constant;
+pub mod count;
 pub mod edit_map;
+pub mod emoji;
 pub mod encode;
+pub mod error;
 pub mod gfm_tagfilter;
+pub mod headings;
 pub mod identifier;
 pub mod infer;
 pub mod line_ending;
+pub mod links;
 pub mod location;
 pub mod mdx;
 pub mod mdx_collect;
 pub mod normalize_identifier;
+pub mod normalize_title;
+pub mod resolve_uri;
 pub mod sanitize_uri;
 pub mod skip;
 pub mod slice;
+pub mod slug;
+pub mod smart_punctuation;
 pub mod unicode;
+pub mod warning;
@@ -30,6 +30,7 @@ use alloc::string::String;
 ///
 /// assert_eq!(normalize_identifier(" a "), "a");
 /// assert_eq!(normalize_identifier("a\t\r\nb"), "a b");
+/// assert_eq!(normalize_identifier("a  b c"), "a b c");
 /// assert_eq!(normalize_identifier("ПРИВЕТ"), "привет");
 /// assert_eq!(normalize_identifier("Привет"), "привет");
 /// assert_eq!(normalize_identifier("привет"), "привет");
@@ -59,7 +60,7 @@ pub fn normalize_identifier(value: &str) -> String {
         }
         // First non-whitespace we see after whitespace.
         else if in_whitespace {
-            if start != 0 {
+            if !result.is_empty() {
                 result.push(' ');
             }
 
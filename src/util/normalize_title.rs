@@ -0,0 +1,62 @@
+//! Normalize titles.
+
+use alloc::{string::String, vec::Vec};
+
+/// Normalize a title, as found in [definitions][definition] and
+/// [resources][label_end], so it can be compared.
+///
+/// Titles can span several lines, which are kept in the title that is
+/// compiled (such as `title` on [`Definition`][crate::mdast::Definition]).
+/// This collapses whitespace found in markdown (`\t`, `\r`, `\n`, and ` `)
+/// into one space, and trims it (as in, dropping the first and last space).
+/// Unlike identifiers, titles are not case folded.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::normalize_title;
+///
+/// assert_eq!(normalize_title(" a "), "a");
+/// assert_eq!(normalize_title("a\t\r\n  b"), "a b");
+/// assert_eq!(normalize_title("Привет"), "Привет");
+/// ```
+///
+/// [definition]: crate::construct::definition
+/// [label_end]: crate::construct::label_end
+pub fn normalize_title(value: &str) -> String {
+    value
+        .split(|char| matches!(char, '\t' | '\n' | '\r' | ' '))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(normalize_title(""), "", "should support empty titles");
+
+        assert_eq!(
+            normalize_title(" \t\n "),
+            "",
+            "should support whitespace-only titles"
+        );
+
+        assert_eq!(
+            normalize_title("a\r\n  b\n\tc"),
+            "a b c",
+            "should collapse line endings and indents"
+        );
+
+        assert_eq!(
+            normalize_title("a\u{a0}b"),
+            "a\u{a0}b",
+            "should not collapse unicode whitespace"
+        );
+
+        assert_eq!(normalize_title("A b"), "A b", "should not change case");
+    }
+}
@@ -0,0 +1,260 @@
+//! Resolve relative URLs against a base URL.
+
+use alloc::{string::String, vec, vec::Vec};
+
+/// Parts of a URL.
+#[derive(Debug)]
+struct Parts<'a> {
+    /// Scheme (protocol), without `:`.
+    scheme: Option<&'a str>,
+    /// Authority (host, port, user info), without `//`.
+    authority: Option<&'a str>,
+    /// Path.
+    path: &'a str,
+    /// Query, without `?`.
+    query: Option<&'a str>,
+    /// Fragment, without `#`.
+    fragment: Option<&'a str>,
+}
+
+/// Resolve `value` against `base`.
+///
+/// Relative URLs (such as `a`, `./a`, `../a`, `/a`, and `?a`) are resolved.
+/// URLs with a protocol (such as `https:` or `mailto:`), protocol-relative
+/// URLs (`//example.com`), fragments (`#a`), and empty URLs are returned
+/// as-is.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::resolve_uri::resolve;
+///
+/// assert_eq!(resolve("https://a.com/b/c", "./d"), "https://a.com/b/d");
+/// assert_eq!(resolve("https://a.com/b/c", "/d"), "https://a.com/d");
+/// assert_eq!(resolve("https://a.com/b/c", "#d"), "#d");
+/// ```
+///
+/// ## References
+///
+/// *   [*§ 5.2 Relative Resolution* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2)
+pub fn resolve(base: &str, value: &str) -> String {
+    let reference = parse(value);
+
+    if reference.scheme.is_some()
+        || reference.authority.is_some()
+        || (reference.path.is_empty() && reference.query.is_none())
+    {
+        return value.into();
+    }
+
+    let base = parse(base);
+    let mut result = String::new();
+
+    if let Some(scheme) = base.scheme {
+        result.push_str(scheme);
+        result.push(':');
+    }
+
+    if let Some(authority) = base.authority {
+        result.push_str("//");
+        result.push_str(authority);
+    }
+
+    let query = if reference.path.is_empty() {
+        result.push_str(base.path);
+        reference.query.or(base.query)
+    } else {
+        if reference.path.starts_with('/') {
+            result.push_str(&remove_dot_segments(reference.path));
+        } else {
+            let mut path = String::new();
+
+            if base.authority.is_some() && base.path.is_empty() {
+                path.push('/');
+            } else if let Some(index) = base.path.rfind('/') {
+                path.push_str(&base.path[..=index]);
+            }
+
+            path.push_str(reference.path);
+            result.push_str(&remove_dot_segments(&path));
+        }
+
+        reference.query
+    };
+
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+
+    if let Some(fragment) = reference.fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+
+    result
+}
+
+/// Split a URL into parts.
+fn parse(value: &str) -> Parts<'_> {
+    let mut rest = value;
+    let mut fragment = None;
+    let mut query = None;
+    let mut scheme = None;
+    let mut authority = None;
+
+    if let Some(index) = rest.find('#') {
+        fragment = Some(&rest[index + 1..]);
+        rest = &rest[..index];
+    }
+
+    if let Some(index) = rest.find('?') {
+        query = Some(&rest[index + 1..]);
+        rest = &rest[..index];
+    }
+
+    if let Some(index) = rest.find(':') {
+        // If the first colon is after `/`, it’s not a protocol.
+        let end = rest.find('/').unwrap_or(rest.len());
+        let bytes = rest.as_bytes();
+
+        if index > 0
+            && index < end
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1..index]
+                .iter()
+                .all(|d| d.is_ascii_alphanumeric() || matches!(d, b'+' | b'-' | b'.'))
+        {
+            scheme = Some(&rest[..index]);
+            rest = &rest[index + 1..];
+        }
+    }
+
+    if let Some(after) = rest.strip_prefix("//") {
+        let index = after.find('/').unwrap_or(after.len());
+        authority = Some(&after[..index]);
+        rest = &after[index..];
+    }
+
+    Parts {
+        scheme,
+        authority,
+        path: rest,
+        query,
+        fragment,
+    }
+}
+
+/// Remove `.` and `..` segments from a path.
+fn remove_dot_segments(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let segments = path.split('/').collect::<Vec<_>>();
+    let mut result: Vec<&str> = vec![];
+    let mut index = 0;
+
+    while index < segments.len() {
+        let segment = segments[index];
+        let last = index == segments.len() - 1;
+
+        if segment == "." || segment == ".." {
+            // Keep the empty segment before the root.
+            if segment == ".." && result.len() > usize::from(absolute) {
+                result.pop();
+            }
+
+            // Keep a trailing slash.
+            if last {
+                result.push("");
+            }
+        } else {
+            result.push(segment);
+        }
+
+        index += 1;
+    }
+
+    result.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_uri() {
+        let base = "https://a.com/b/c?d#e";
+
+        assert_eq!(
+            resolve(base, "f"),
+            "https://a.com/b/f",
+            "should support a file"
+        );
+        assert_eq!(
+            resolve(base, "./f"),
+            "https://a.com/b/f",
+            "should support `./`"
+        );
+        assert_eq!(
+            resolve(base, "../f"),
+            "https://a.com/f",
+            "should support `../`"
+        );
+        assert_eq!(
+            resolve(base, "../../../f"),
+            "https://a.com/f",
+            "should not go above the root"
+        );
+        assert_eq!(
+            resolve(base, "/f/g"),
+            "https://a.com/f/g",
+            "should support `/`"
+        );
+        assert_eq!(
+            resolve(base, "f/."),
+            "https://a.com/b/f/",
+            "should keep a trailing slash"
+        );
+        assert_eq!(
+            resolve(base, "?f"),
+            "https://a.com/b/c?f",
+            "should support a query"
+        );
+        assert_eq!(
+            resolve(base, "f?g#h"),
+            "https://a.com/b/f?g#h",
+            "should support a query and a fragment"
+        );
+        assert_eq!(resolve(base, "#f"), "#f", "should not resolve a fragment");
+        assert_eq!(resolve(base, ""), "", "should not resolve an empty URL");
+        assert_eq!(
+            resolve(base, "mailto:f@g.h"),
+            "mailto:f@g.h",
+            "should not resolve a protocol"
+        );
+        assert_eq!(
+            resolve(base, "//f.com/g"),
+            "//f.com/g",
+            "should not resolve a protocol-relative URL"
+        );
+        assert_eq!(
+            resolve("https://a.com", "b"),
+            "https://a.com/b",
+            "should support a base w/o path"
+        );
+        assert_eq!(
+            resolve("/a/b/", "c"),
+            "/a/b/c",
+            "should support a base w/o protocol"
+        );
+        assert_eq!(
+            resolve("https://a.com", "b:c/d"),
+            "b:c/d",
+            "should not resolve a URL w/ a protocol"
+        );
+        assert_eq!(
+            resolve("https://a.com/", "b/c:d"),
+            "https://a.com/b/c:d",
+            "should resolve a URL w/ a colon after a slash"
+        );
+    }
+}
@@ -54,22 +54,10 @@ pub fn sanitize(value: &str) -> String {
 pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     let value = sanitize(value);
 
-    let end = value.find(|c| matches!(c, '?' | '#' | '/'));
-    let mut colon = value.find(|c| matches!(c, ':'));
-
-    // If the first colon is after `?`, `#`, or `/`, it’s not a protocol.
-    if let Some(end) = end {
-        if let Some(index) = colon {
-            if index > end {
-                colon = None;
-            }
-        }
-    }
-
     // If there is no protocol, it’s relative, and fine.
-    if let Some(colon) = colon {
+    if let Some(protocol) = protocol(&value) {
         // If it is a protocol, it should be allowed.
-        let protocol = value[0..colon].to_lowercase();
+        let protocol = protocol.to_lowercase();
         if !protocols.contains(&protocol.as_str()) {
             return "".into();
         }
@@ -78,6 +66,35 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     value
 }
 
+/// Get the protocol of a URL, if it has one.
+///
+/// Returns the part before the first colon, unless that colon comes after
+/// `?`, `#`, or `/`, or there is no colon, in which case the URL is
+/// relative.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::protocol;
+///
+/// assert_eq!(protocol("https://example.com"), Some("https"));
+/// assert_eq!(protocol("./a:b"), None);
+/// assert_eq!(protocol("#a"), None);
+/// ```
+pub fn protocol(value: &str) -> Option<&str> {
+    let end = value.find(|c| matches!(c, '?' | '#' | '/'));
+    let colon = value.find(|c| matches!(c, ':'))?;
+
+    // If the first colon is after `?`, `#`, or `/`, it’s not a protocol.
+    if let Some(end) = end {
+        if colon > end {
+            return None;
+        }
+    }
+
+    Some(&value[0..colon])
+}
+
 /// Normalize a URL (such as used in [definitions][definition],
 /// [references][label_end]).
 ///
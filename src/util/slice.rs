@@ -1,7 +1,6 @@
 //! Deal with bytes.
 
 use crate::event::{Event, Kind, Point};
-use crate::util::constant::TAB_SIZE;
 use alloc::{format, string::String, vec};
 use core::str;
 
@@ -64,7 +63,10 @@ pub struct Slice<'a> {
 
 impl<'a> Slice<'a> {
     /// Get a slice for a position.
-    pub fn from_position(bytes: &'a [u8], position: &Position) -> Slice<'a> {
+    ///
+    /// `tab_size` is needed to figure out how many virtual spaces are left
+    /// of a tab that the position starts in.
+    pub fn from_position(bytes: &'a [u8], position: &Position, tab_size: usize) -> Slice<'a> {
         let mut before = position.start.vs;
         let mut after = position.end.vs;
         let mut start = position.start.index;
@@ -73,7 +75,7 @@ impl<'a> Slice<'a> {
         // If we have virtual spaces before, it means we are past the actual
         // character at that index, and those virtual spaces.
         if before > 0 {
-            before = TAB_SIZE - before;
+            before = tab_size - (position.start.column - 1) % tab_size;
             start += 1;
         };
 
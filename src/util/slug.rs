@@ -0,0 +1,157 @@
+//! Turn text into slugs, like GitHub does for headings.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+};
+
+/// Signature of a function that turns the text content of a heading into a
+/// slug.
+///
+/// Can be passed as `heading_id_slug` in
+/// [`CompileOptions`][crate::configuration::CompileOptions] to generate
+/// different heading IDs.
+pub type Slug = dyn Fn(&str) -> String;
+
+/// Turn the text content of a heading into a slug.
+///
+/// This lowercases, turns spaces into dashes, and drops everything that is
+/// not a letter, number, dash, or underscore (such as punctuation and
+/// emoji).
+/// Non-ASCII letters and numbers are kept.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::slug::slug;
+///
+/// assert_eq!(slug("Hello, world!"), "hello-world");
+/// assert_eq!(slug("Привет мир"), "привет-мир");
+/// assert_eq!(slug("a 🎉 b"), "a--b");
+/// ```
+///
+/// ## References
+///
+/// *   [`github-slugger`](https://github.com/Flet/github-slugger)
+pub fn slug(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        if char == ' ' || char == '-' {
+            result.push('-');
+        } else if char == '_' || char.is_alphanumeric() {
+            result.extend(char.to_lowercase());
+        }
+    }
+
+    result
+}
+
+/// Slugs that were seen before, to make new slugs unique.
+#[derive(Debug, Default)]
+pub struct Seen {
+    /// Slugs that are taken.
+    slugs: BTreeSet<String>,
+    /// Next suffix to try, for each slug that was made unique.
+    counts: BTreeMap<String, usize>,
+}
+
+/// Make a slug unique in the slugs that were seen before.
+///
+/// The first occurrence of a slug is kept as-is, later occurrences get a
+/// numeric suffix (`-1`, `-2`, and so on).
+/// The result is added to `seen`.
+pub fn unique(seen: &mut Seen, value: &str) -> String {
+    let mut result = String::from(value);
+
+    if seen.slugs.contains(&result) {
+        // Suffixes below the count were taken when they were tried before,
+        // and slugs are never removed, so continue from there.
+        let count = seen.counts.entry(result.clone()).or_insert(0);
+
+        loop {
+            *count += 1;
+            result = format!("{}-{}", value, count);
+
+            if !seen.slugs.contains(&result) {
+                break;
+            }
+        }
+    }
+
+    seen.slugs.insert(result.clone());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Seen;
+    use alloc::format;
+
+    #[test]
+    fn slug() {
+        assert_eq!(super::slug("a"), "a", "should support a letter");
+        assert_eq!(
+            super::slug("Hello, World!"),
+            "hello-world",
+            "should lowercase and drop punctuation"
+        );
+        assert_eq!(
+            super::slug("a - b_c"),
+            "a---b_c",
+            "should keep dashes and underscores"
+        );
+        assert_eq!(
+            super::slug("Ελληνικά 123"),
+            "ελληνικά-123",
+            "should keep non-ASCII letters and numbers"
+        );
+        assert_eq!(super::slug("a 🎉 b"), "a--b", "should drop emoji");
+    }
+
+    #[test]
+    fn unique() {
+        let mut seen = Seen::default();
+        assert_eq!(
+            super::unique(&mut seen, "a"),
+            "a",
+            "should keep the first occurrence"
+        );
+        assert_eq!(
+            super::unique(&mut seen, "a"),
+            "a-1",
+            "should add a suffix to the second occurrence"
+        );
+        assert_eq!(
+            super::unique(&mut seen, "a"),
+            "a-2",
+            "should increment the suffix"
+        );
+        assert_eq!(
+            super::unique(&mut seen, "a-1"),
+            "a-1-1",
+            "should add a suffix to a slug that was generated before"
+        );
+
+        super::unique(&mut seen, "b-1");
+        super::unique(&mut seen, "b");
+        assert_eq!(
+            super::unique(&mut seen, "b"),
+            "b-2",
+            "should skip suffixes that are taken"
+        );
+
+        let mut seen = Seen::default();
+        let mut index = 0;
+        while index < 10_000 {
+            super::unique(&mut seen, "c");
+            index += 1;
+        }
+        assert_eq!(
+            super::unique(&mut seen, "c"),
+            format!("c-{}", index),
+            "should support many duplicates"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Turn straight quotes, dashes, and dots into typographic punctuation.
+
+use crate::util::{
+    char::{after_index, before_index, classify_opt, Kind},
+    slice::Slice,
+};
+use alloc::string::String;
+
+/// Turn the punctuation in `bytes` from `start` to `end` into typographic
+/// punctuation.
+///
+/// *   `...` turns into an ellipsis (`…`)
+/// *   `--` turns into an en dash (`–`), `---` into an em dash (`—`), and
+///     longer runs of dashes into a mix of both
+/// *   `"` and `'` turn into opening (`“`, `‘`) or closing (`”`, `’`) quotes
+///
+/// Whether a quote opens or closes is inferred from the characters around
+/// it, like whether [attention][crate::construct::attention] can open or
+/// close: a quote opens when it is left-flanking but not right-flanking, and
+/// closes otherwise, which turns apostrophes (`it's`) into right single
+/// quotes.
+/// The characters around a quote are looked up in all of `bytes`, so that a
+/// quote at the edge of the range still sees what is next to it.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::smart_punctuation::smart_punctuation;
+///
+/// let bytes = "\"It's\" -- ok...".as_bytes();
+/// assert_eq!(smart_punctuation(bytes, 0, bytes.len()), "“It’s” – ok…");
+/// ```
+///
+/// ## References
+///
+/// *   [`SmartyPants`](https://daringfireball.net/projects/smartypants/)
+/// *   [*Smart punctuation* in `commonmark.js`](https://github.com/commonmark/commonmark.js/blob/master/lib/inlines.js)
+pub fn smart_punctuation(bytes: &[u8], start: usize, end: usize) -> String {
+    let mut result = String::with_capacity(end - start);
+    let mut index = start;
+    let mut plain = start;
+
+    while index < end {
+        let replacement = match bytes[index] {
+            b'.' if index + 3 <= end && &bytes[index..index + 3] == b"..." => {
+                Some((String::from("…"), 3))
+            }
+            b'-' => {
+                let mut size = 1;
+
+                while index + size < end && bytes[index + size] == b'-' {
+                    size += 1;
+                }
+
+                if size > 1 {
+                    Some((dashes(size), size))
+                } else {
+                    None
+                }
+            }
+            b'"' | b'\'' => {
+                let before = classify_opt(before_index(bytes, index));
+                let after = classify_opt(after_index(bytes, index + 1));
+                let open = after != Kind::Whitespace
+                    && (after != Kind::Punctuation || before != Kind::Other)
+                    && !(before != Kind::Whitespace
+                        && (before != Kind::Punctuation || after != Kind::Other));
+                let quote = match (bytes[index], open) {
+                    (b'"', true) => "“",
+                    (b'"', false) => "”",
+                    (_, true) => "‘",
+                    (_, false) => "’",
+                };
+                Some((String::from(quote), 1))
+            }
+            _ => None,
+        };
+
+        if let Some((value, size)) = replacement {
+            result.push_str(Slice::from_indices(bytes, plain, index).as_str());
+            result.push_str(&value);
+            index += size;
+            plain = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    result.push_str(Slice::from_indices(bytes, plain, end).as_str());
+    result
+}
+
+/// Turn a run of `size` dashes (at least two) into em and en dashes.
+///
+/// Uses only em dashes if possible, otherwise only en dashes if possible,
+/// and otherwise as many em dashes as possible followed by one or two en
+/// dashes.
+fn dashes(size: usize) -> String {
+    let (em, en) = if size % 3 == 0 {
+        (size / 3, 0)
+    } else if size % 2 == 0 {
+        (0, size / 2)
+    } else if size % 3 == 2 {
+        ((size - 2) / 3, 1)
+    } else {
+        ((size - 4) / 3, 2)
+    };
+
+    let mut result = String::with_capacity((em + en) * 3);
+    result.push_str(&"—".repeat(em));
+    result.push_str(&"–".repeat(en));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    fn smart(value: &str) -> String {
+        super::smart_punctuation(value.as_bytes(), 0, value.len())
+    }
+
+    #[test]
+    fn smart_punctuation() {
+        assert_eq!(smart("a"), "a", "should support text w/o punctuation");
+        assert_eq!(smart("a..."), "a…", "should support an ellipsis");
+        assert_eq!(smart("a.. b"), "a.. b", "should not support two dots");
+        assert_eq!(smart("a--b"), "a–b", "should support an en dash");
+        assert_eq!(smart("a---b"), "a—b", "should support an em dash");
+        assert_eq!(smart("a-b"), "a-b", "should not support a hyphen");
+        assert_eq!(smart("a----b"), "a––b", "should support 4 dashes");
+        assert_eq!(smart("a-----b"), "a—–b", "should support 5 dashes");
+        assert_eq!(smart("a------b"), "a——b", "should support 6 dashes");
+        assert_eq!(smart("a-------b"), "a—––b", "should support 7 dashes");
+        assert_eq!(
+            smart("\"a\" 'b'"),
+            "“a” ‘b’",
+            "should support double and single quotes"
+        );
+        assert_eq!(
+            smart("it's"),
+            "it’s",
+            "should support apostrophes in contractions"
+        );
+        assert_eq!(
+            smart("(\"a\")"),
+            "(“a”)",
+            "should support quotes next to punctuation"
+        );
+        assert_eq!(
+            smart("\"a\"."),
+            "“a”.",
+            "should support a closing quote before punctuation"
+        );
+        assert_eq!(smart("'90s"), "‘90s", "should open a quote before a number");
+        assert_eq!(
+            smart("a \" b"),
+            "a ” b",
+            "should close a quote between whitespace"
+        );
+        assert_eq!(
+            smart("<i>\"a\"</i>"),
+            "<i>“a”</i>",
+            "should open a quote after a symbol"
+        );
+        assert_eq!(
+            super::smart_punctuation(b" 'a", 1, 2),
+            "‘",
+            "should look at bytes outside the range"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+//! Non-fatal problems found while parsing.
+//!
+//! Markdown does not have syntax errors: everything is valid, and things that
+//! look like a construct but are not (such as a title without its closing
+//! marker) turn into something else (typically text).
+//! Warnings can be used to explain why that happened.
+
+use crate::event::TitleKind;
+use crate::unist::Point;
+use alloc::{fmt, string::String};
+
+/// Something that looks like a mistake in the markdown.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warning {
+    /// Place where the problem starts.
+    pub point: Point,
+    /// What the problem is.
+    pub kind: Kind,
+}
+
+/// Kind of warning.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// A title was opened but not closed.
+    ///
+    /// The point of the warning is at the opening marker.
+    /// The definition or resource the title is in does not form.
+    ///
+    /// ```markdown
+    /// > | [a]: b "c
+    ///            ^
+    /// ```
+    TitleUnclosed(TitleKind),
+    /// A reference was used but there is no definition for its label.
+    ///
+    /// The point of the warning is at the start of the reference.
+    /// Holds the label, as written.
+    /// The reference does not form, and is text instead.
+    ///
+    /// ```markdown
+    /// > | [a] b
+    ///     ^
+    /// ```
+    ReferenceUndefined(String),
+    /// A GFM footnote call was used but there is no footnote definition for
+    /// its label.
+    ///
+    /// The point of the warning is at the start of the call.
+    /// Holds the label, as written, without `^`.
+    /// The call does not form, and is text instead.
+    ///
+    /// ```markdown
+    /// > | [^a] b
+    ///     ^
+    /// ```
+    FootnoteUndefined(String),
+    /// A definition was defined but never referenced.
+    ///
+    /// The point of the warning is at the start of the definition.
+    /// Holds the label, as written.
+    ///
+    /// ```markdown
+    /// > | [a]: b
+    ///     ^
+    /// ```
+    DefinitionUnused(String),
+    /// A definition was defined with the same label as an earlier one.
+    ///
+    /// The point of the warning is at the start of the later definition.
+    /// Holds the label, as written.
+    /// The first definition of a label is the one that is used, so references
+    /// never resolve to this one.
+    ///
+    /// ```markdown
+    ///   | [a]: b
+    /// > | [a]: c
+    ///     ^
+    /// ```
+    DefinitionDuplicate(String),
+    /// A list item used a different marker than the previous item.
+    ///
+    /// Only given when [`list_marker_change`][list_marker_change] is
+    /// [`ListMarkerChange::Warn`][crate::ListMarkerChange::Warn].
+    /// The point of the warning is at the start of the item.
+    /// Holds the marker of the previous item and the marker of this item.
+    /// The item starts a new list.
+    ///
+    /// ```markdown
+    ///   | - a
+    /// > | * b
+    ///     ^
+    /// ```
+    ///
+    /// [list_marker_change]: crate::ParseOptions::list_marker_change
+    ListMarkerChange(char, char),
+    /// A tab was used where only part of it counts as indentation.
+    ///
+    /// The rest of the tab is content, so how the markdown is parsed depends on
+    /// the size of tab stops.
+    /// The point of the warning is at the virtual spaces that are left.
+    ///
+    /// ```markdown
+    ///   | * a
+    ///   |
+    /// > |  ␉b
+    ///       ^
+    /// ```
+    TabSplit,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.point.line, self.point.column)?;
+
+        match &self.kind {
+            Kind::TitleUnclosed(kind) => write!(
+                f,
+                "Unexpected end of title, expected a corresponding closing `{}` for `{}`",
+                kind.closing(),
+                kind.opening()
+            ),
+            Kind::ReferenceUndefined(label) => write!(
+                f,
+                "Unexpected reference to undefined definition `{}`, expected a corresponding definition (`[{}]: url`)",
+                label, label
+            ),
+            Kind::FootnoteUndefined(label) => write!(
+                f,
+                "Unexpected call to undefined footnote `{}`, expected a corresponding footnote definition (`[^{}]: text`)",
+                label, label
+            ),
+            Kind::DefinitionUnused(label) => write!(
+                f,
+                "Unexpected unused definition `{}`, expected a corresponding reference (`[{}]`)",
+                label, label
+            ),
+            Kind::DefinitionDuplicate(label) => write!(
+                f,
+                "Unexpected duplicate definition `{}`, expected a label that is not yet defined (the first definition of a label is used)",
+                label
+            ),
+            Kind::ListMarkerChange(previous, current) => write!(
+                f,
+                "Unexpected list marker `{}`, expected `{}` to continue the list (a different marker starts a new list)",
+                current, previous
+            ),
+            Kind::TabSplit => write!(
+                f,
+                "Unexpected tab that is partially used as indentation, expected spaces"
+            ),
+        }
+    }
+}
+
+/// Signature of a function that is given warnings.
+///
+/// Can be passed as `warning_sink` in
+/// [`ParseOptions`][crate::configuration::ParseOptions] to be told about
+/// problems while parsing.
+///
+/// > 👉 **Note**: markdown is parsed with backtracking, so warnings are
+/// > given in the order they are found, which is not always document order.
+pub type Sink = dyn Fn(Warning);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_warning() {
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(1, 8, 7),
+                    kind: Kind::TitleUnclosed(TitleKind::Double)
+                }
+            ),
+            "1:8: Unexpected end of title, expected a corresponding closing `\"` for `\"`",
+            "should display an unclosed title (double)"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(2, 1, 9),
+                    kind: Kind::TitleUnclosed(TitleKind::Paren)
+                }
+            ),
+            "2:1: Unexpected end of title, expected a corresponding closing `)` for `(`",
+            "should display an unclosed title (paren)"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(1, 1, 0),
+                    kind: Kind::ReferenceUndefined("a".into())
+                }
+            ),
+            "1:1: Unexpected reference to undefined definition `a`, expected a corresponding definition (`[a]: url`)",
+            "should display an undefined reference"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(1, 1, 0),
+                    kind: Kind::FootnoteUndefined("a".into())
+                }
+            ),
+            "1:1: Unexpected call to undefined footnote `a`, expected a corresponding footnote definition (`[^a]: text`)",
+            "should display an undefined footnote call"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(1, 1, 0),
+                    kind: Kind::DefinitionUnused("a".into())
+                }
+            ),
+            "1:1: Unexpected unused definition `a`, expected a corresponding reference (`[a]`)",
+            "should display an unused definition"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(2, 1, 7),
+                    kind: Kind::DefinitionDuplicate("a".into())
+                }
+            ),
+            "2:1: Unexpected duplicate definition `a`, expected a label that is not yet defined (the first definition of a label is used)",
+            "should display a duplicate definition"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(2, 1, 4),
+                    kind: Kind::ListMarkerChange('-', '*')
+                }
+            ),
+            "2:1: Unexpected list marker `*`, expected `-` to continue the list (a different marker starts a new list)",
+            "should display a list marker change"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                Warning {
+                    point: Point::new(3, 3, 6),
+                    kind: Kind::TabSplit
+                }
+            ),
+            "3:3: Unexpected tab that is partially used as indentation, expected spaces",
+            "should display a split tab"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Abbreviation, AbbreviationDefinition, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast, to_plain,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn abbreviation() -> Result<(), String> {
+    let abbr = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("*[HTML]: Hyper Text Markup Language\n\na HTML b"),
+        "<p>*[HTML]: Hyper Text Markup Language</p>\n<p>a HTML b</p>",
+        "should not support abbreviations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: Hyper Text Markup Language\n\na HTML b", &abbr)?,
+        "<p>a <abbr title=\"Hyper Text Markup Language\">HTML</abbr> b</p>",
+        "should support abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options("a HTML b\n\n*[HTML]: Hyper Text Markup Language", &abbr)?,
+        "<p>a <abbr title=\"Hyper Text Markup Language\">HTML</abbr> b</p>\n",
+        "should support a definition after its use"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[W3C]: b\n\nHTML and W3C, HTML.", &abbr)?,
+        "<p><abbr title=\"a\">HTML</abbr> and <abbr title=\"b\">W3C</abbr>, <abbr title=\"a\">HTML</abbr>.</p>",
+        "should support several definitions and occurrences"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nhtml Html HTML", &abbr)?,
+        "<p>html Html <abbr title=\"a\">HTML</abbr></p>",
+        "should match case-sensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nHTMLs xHTML HTML_b HTML5", &abbr)?,
+        "<p>HTMLs xHTML HTML_b HTML5</p>",
+        "should match whole words only"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\n(HTML) *HTML*", &abbr)?,
+        "<p>(<abbr title=\"a\">HTML</abbr>) <em><abbr title=\"a\">HTML</abbr></em></p>",
+        "should match next to punctuation and in phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML5]: b\n\nHTML5 HTML", &abbr)?,
+        "<p><abbr title=\"b\">HTML5</abbr> <abbr title=\"a\">HTML</abbr></p>",
+        "should prefer the longest abbreviation"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTTP]: a\n*[HTML]: b\n*[++]: c\n*[HTML]: d\n\nHTTP x++ HTML",
+            &abbr
+        )?,
+        "<p><abbr title=\"a\">HTTP</abbr> x<abbr title=\"c\">++</abbr> <abbr title=\"b\">HTML</abbr></p>",
+        "should support abbreviations that start with the same character, or w/ a non-word character"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a b]: c\n\na b", &abbr)?,
+        "<p><abbr title=\"c\">a b</abbr></p>",
+        "should support whitespace in abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: a\n\n`HTML` [HTML](b) ![HTML](c) <https://HTML>",
+            &abbr
+        )?,
+        "<p><code>HTML</code> <a href=\"b\">HTML</a> <img src=\"c\" alt=\"HTML\" /> <a href=\"https://HTML\">https://HTML</a></p>",
+        "should not match in code, links, images, or autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\n    HTML\n\n```\nHTML\n```", &abbr)?,
+        "<pre><code>HTML\n</code></pre>\n<pre><code>HTML\n</code></pre>",
+        "should not match in code (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML]: b\n\nHTML", &abbr)?,
+        "<p><abbr title=\"a\">HTML</abbr></p>",
+        "should prefer the first definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:\n\nHTML", &abbr)?,
+        "<p><abbr>HTML</abbr></p>",
+        "should support a definition w/o value"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:a &amp; \"b\"  \n\nHTML", &abbr)?,
+        "<p><abbr title=\"a &amp; &quot;b&quot;\">HTML</abbr></p>",
+        "should support character references in values, and trim them"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[]: a", &abbr)?,
+        "<p>*[]: a</p>",
+        "should not support an empty label"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML] a", &abbr)?,
+        "<p>*[HTML] a</p>",
+        "should not support a missing `:`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HT\nML]: a", &abbr)?,
+        "<p>*[HT\nML]: a</p>",
+        "should not support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("   *[HTML]: a\n\n    *[HTML]: b\n\nHTML", &abbr)?,
+        "<pre><code>*[HTML]: b\n</code></pre>\n<p><abbr title=\"a\">HTML</abbr></p>",
+        "should support up to three spaces of indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("HTML\n*[HTML]: a", &abbr)?,
+        "<p>HTML\n*[HTML]: a</p>",
+        "should not interrupt a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n# HTML\n***", &abbr)?,
+        "<h1><abbr title=\"a\">HTML</abbr></h1>\n<hr />",
+        "should be followed by flow"
+    );
+
+    assert_eq!(
+        to_html_with_options("> *[HTML]: a\n\n- HTML", &abbr)?,
+        "<blockquote>\n</blockquote>\n<ul>\n<li><abbr title=\"a\">HTML</abbr></li>\n</ul>",
+        "should support definitions in containers, applying to the whole document"
+    );
+
+    assert_eq!(
+        to_plain("*[HTML]: a\n\nb HTML", &abbr.parse)?,
+        "b HTML",
+        "should support abbreviations in plain text"
+    );
+
+    assert_eq!(
+        to_mdast("*[HTML]: a\n\nb HTML", &abbr.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::AbbreviationDefinition(AbbreviationDefinition {
+                    label: "HTML".into(),
+                    title: Some("a".into()),
+                    position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![
+                        Node::Text(Text {
+                            value: "b ".into(),
+                            position: Some(Position::new(3, 1, 12, 3, 3, 14))
+                        }),
+                        Node::Abbreviation(Abbreviation {
+                            value: "HTML".into(),
+                            position: Some(Position::new(3, 3, 14, 3, 7, 18))
+                        })
+                    ],
+                    position: Some(Position::new(3, 1, 12, 3, 7, 18))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 7, 18))
+        }),
+        "should support abbreviations as `Abbreviation`s in mdast"
+    );
+
+    Ok(())
+}
@@ -820,6 +820,91 @@ fn attention() -> Result<(), String> {
         "should not end strong emphasis inside autolinks (2)"
     );
 
+    // Extra.
+    assert_eq!(
+        to_html("**(**foo**)**"),
+        "<p><strong>(<strong>foo</strong>)</strong></p>",
+        "should support strong around punctuation around strong"
+    );
+
+    assert_eq!(
+        to_html("**a+**b *$*c"),
+        "<p>**a+**b *$*c</p>",
+        "should classify ASCII symbols as punctuation"
+    );
+
+    assert_eq!(
+        to_html("これは**強調**です"),
+        "<p>これは<strong>強調</strong>です</p>",
+        "should support strong in CJK text"
+    );
+
+    assert_eq!(
+        to_html("これは**「強調」**です"),
+        "<p>これは**「強調」**です</p>",
+        "should classify CJK punctuation as punctuation"
+    );
+
+    assert_eq!(
+        to_html("これは *「強調」* です"),
+        "<p>これは <em>「強調」</em> です</p>",
+        "should support emphasis around CJK punctuation next to whitespace"
+    );
+
+    assert_eq!(
+        to_html("5_6_7 foo_bar_baz"),
+        "<p>5_6_7 foo_bar_baz</p>",
+        "should not support intraword emphasis w/ `_` between alphanumerics"
+    );
+
+    assert_eq!(
+        to_html("5*6*7 foo*bar*baz"),
+        "<p>5<em>6</em>7 foo<em>bar</em>baz</p>",
+        "should support intraword emphasis w/ `*` between alphanumerics"
+    );
+
+    assert_eq!(
+        to_html("_foo_ foo *bar* baz"),
+        "<p><em>foo</em> foo <em>bar</em> baz</p>",
+        "should support emphasis w/ `_` and `*` around words"
+    );
+
+    assert_eq!(
+        to_html("a__b__c a**b**c"),
+        "<p>a__b__c a<strong>b</strong>c</p>",
+        "should support intraword strong w/ `*`, but not w/ `_`"
+    );
+
+    assert_eq!(
+        to_html("日本_語_ 日本*語*"),
+        "<p>日本_語_ 日本<em>語</em></p>",
+        "should not support intraword emphasis w/ `_` in CJK text"
+    );
+
+    assert_eq!(
+        to_html(&"a* ".repeat(10_000)),
+        format!("<p>{}</p>", "a* ".repeat(10_000).trim_end()),
+        "should support many closing sequences w/o openers (performance)"
+    );
+
+    assert_eq!(
+        to_html(&"*a* ".repeat(10_000)),
+        format!("<p>{}</p>", "<em>a</em> ".repeat(10_000).trim_end()),
+        "should support many matching sequences (performance)"
+    );
+
+    assert_eq!(
+        to_html(&"a**b".repeat(10_001)),
+        format!("<p>a{}**b</p>", "<strong>ba</strong>ba".repeat(5_000)),
+        "should support many sequences that can open and close (performance)"
+    );
+
+    assert_eq!(
+        to_html(&format!("a{}", "*".repeat(100_000))),
+        format!("<p>a{}</p>", "*".repeat(100_000)),
+        "should support a long sequence (performance)"
+    );
+
     assert_eq!(
         to_html_with_options(
             "*a*",
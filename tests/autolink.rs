@@ -108,6 +108,12 @@ fn autolink() -> Result<(), String> {
         "should support email autolinks (3)"
     );
 
+    assert_eq!(
+        to_html("<a+b@c.d>"),
+        "<p><a href=\"mailto:a+b@c.d\">a+b@c.d</a></p>",
+        "should support email autolinks w/ a plus sign"
+    );
+
     assert_eq!(
         to_html("<foo\\+@bar.example.com>"),
         "<p>&lt;foo+@bar.example.com&gt;</p>",
@@ -132,6 +138,42 @@ fn autolink() -> Result<(), String> {
         "should not support autolinks w/ a single character for a scheme"
     );
 
+    assert_eq!(
+        to_html("<ab:c>"),
+        "<p><a href=\"\">ab:c</a></p>",
+        "should support autolinks w/ two characters for a scheme"
+    );
+
+    assert_eq!(
+        to_html("<a+b-c.d:e>"),
+        "<p><a href=\"\">a+b-c.d:e</a></p>",
+        "should support autolinks w/ `+`, `-`, and `.` in a scheme"
+    );
+
+    assert_eq!(
+        to_html("<1a:b>"),
+        "<p>&lt;1a:b&gt;</p>",
+        "should not support autolinks w/ a digit at the start of a scheme"
+    );
+
+    assert_eq!(
+        to_html("<abcdefghijabcdefghijabcdefghij12:c>"),
+        "<p><a href=\"\">abcdefghijabcdefghijabcdefghij12:c</a></p>",
+        "should support autolinks w/ 32 characters for a scheme"
+    );
+
+    assert_eq!(
+        to_html("<abcdefghijabcdefghijabcdefghij123:c>"),
+        "<p>&lt;abcdefghijabcdefghijabcdefghij123:c&gt;</p>",
+        "should not support autolinks w/ 33 characters for a scheme"
+    );
+
+    assert_eq!(
+        to_html("<a:b\u{7}c>"),
+        "<p>&lt;a:b\u{7}c&gt;</p>",
+        "should not support protocol autolinks w/ control characters"
+    );
+
     assert_eq!(
         to_html("<foo.bar.baz>"),
         "<p>&lt;foo.bar.baz&gt;</p>",
@@ -270,6 +312,21 @@ fn autolink() -> Result<(), String> {
         "should support turning off autolinks"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "<https://a> <b@c.d>",
+            &Options {
+                compile: CompileOptions {
+                    link_external_rel: Some("nofollow".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://a\" rel=\"nofollow\">https://a</a> <a href=\"mailto:b@c.d\">b@c.d</a></p>",
+        "should support `link_external_rel`"
+    );
+
     assert_eq!(
         to_mdast(
             "a <https://alpha.com> b <bravo@charlie.com> c.",
@@ -195,6 +195,42 @@ fn block_quote() -> Result<(), String> {
         "should not support interrupting many block quotes w/ paragraphs (2)"
     );
 
+    assert_eq!(
+        to_html("> > a\n# b"),
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>\n<h1>b</h1>",
+        "should support interrupting many block quotes w/ headings (atx)"
+    );
+
+    assert_eq!(
+        to_html("> > a\n***"),
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>\n<hr />",
+        "should support interrupting many block quotes w/ thematic breaks"
+    );
+
+    assert_eq!(
+        to_html("> > a\n```\nb"),
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>\n<pre><code>b\n</code></pre>\n",
+        "should support interrupting many block quotes w/ fenced code"
+    );
+
+    assert_eq!(
+        to_html("> > a\n> b\n==="),
+        "<blockquote>\n<blockquote>\n<p>a\nb\n===</p>\n</blockquote>\n</blockquote>",
+        "should not support lazy setext heading underlines in many block quotes"
+    );
+
+    assert_eq!(
+        to_html("> - a\nb"),
+        "<blockquote>\n<ul>\n<li>a\nb</li>\n</ul>\n</blockquote>",
+        "should support lazy lines in lists in block quotes"
+    );
+
+    assert_eq!(
+        to_html("> - a\n***"),
+        "<blockquote>\n<ul>\n<li>a</li>\n</ul>\n</blockquote>\n<hr />",
+        "should support interrupting lists in block quotes w/ thematic breaks"
+    );
+
     assert_eq!(
         to_html(">     a\n\n>    b"),
         "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<blockquote>\n<p>b</p>\n</blockquote>",
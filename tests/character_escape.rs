@@ -104,6 +104,54 @@ fn character_escape() -> Result<(), String> {
         "should support turning off character escapes"
     );
 
+    let custom = Options {
+        parse: ParseOptions {
+            character_escape_characters: Some(vec!['*', 'a', '1', ' ', '\n', 'é']),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("\\~a~"),
+        "<p>~a~</p>",
+        "should support escaping tildes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\* \\a \\1", &custom)?,
+        "<p>* a 1</p>",
+        "should support `character_escape_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\_a\\_ \\~", &custom)?,
+        "<p>\\<em>a\\</em> \\~</p>",
+        "should not support escaping other characters w/ `character_escape_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\ a\\\nb \\é", &custom)?,
+        "<p>\\ a<br />\nb \\é</p>",
+        "should not support escaping whitespace, line endings, or non-ASCII w/ `character_escape_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "\\~a~ ~b~",
+            &Options {
+                parse: ParseOptions {
+                    character_escape_characters: Some(vec!['~']),
+                    constructs: Constructs::gfm(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>~a~ <del>b</del></p>",
+        "should support `character_escape_characters` w/ GFM strikethrough"
+    );
+
     assert_eq!(
         to_mdast("a \\* b", &Default::default())?,
         Node::Root(Root {
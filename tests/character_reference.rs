@@ -131,6 +131,30 @@ fn character_reference() -> Result<(), String> {
         "should support the longest possible named character reference"
     );
 
+    assert_eq!(
+        to_html("&nbsp; &copy; &hearts; &Aacute; &zwnj; &lang; &fjlig;"),
+        "<p>\u{a0} © ♥ Á \u{200c} ⟨ fj</p>",
+        "should support named character references across the table"
+    );
+
+    assert_eq!(
+        to_html("&nGg; &NotEqualTilde; &ThickSpace;"),
+        "<p>⋙\u{338} ≂\u{338} \u{205f}\u{200a}</p>",
+        "should support named character references w/ multiple code points"
+    );
+
+    assert_eq!(
+        to_html("&#xD800; &#xDFFF; &#x110000;"),
+        "<p>� � �</p>",
+        "should replace surrogates and too large code points w/ a replacement character"
+    );
+
+    assert_eq!(
+        to_html("&AMP; &Amp;"),
+        "<p>&amp; &amp;Amp;</p>",
+        "should support named character references case-sensitively"
+    );
+
     assert_eq!(
         to_html("&#xff9999;"),
         "<p>�</p>",
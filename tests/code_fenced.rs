@@ -3,7 +3,7 @@ use markdown::{
     mdast::{Code, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -51,6 +51,18 @@ fn code_fenced() -> Result<(), String> {
         "should support a closing sequence equal to, but not shorter than, the opening"
     );
 
+    assert_eq!(
+        to_html("~~~~\naaa\n~~~\n```\n~~~~~~"),
+        "<pre><code>aaa\n~~~\n```\n</code></pre>",
+        "should support a longer tilde closing sequence after shorter and other sequences"
+    );
+
+    assert_eq!(
+        to_html("````\naaa\n~~~~\n```\n`````"),
+        "<pre><code>aaa\n~~~~\n```\n</code></pre>",
+        "should support a longer grave accent closing sequence after shorter and other sequences"
+    );
+
     assert_eq!(
         to_html("```"),
         "<pre><code></code></pre>\n",
@@ -99,6 +111,36 @@ fn code_fenced() -> Result<(), String> {
       "should remove up to three space from the content if the opening sequence is indented w/ 3 spaces"
     );
 
+    assert_eq!(
+        to_html("  ```\n    aaa\n  ```"),
+        "<pre><code>  aaa\n</code></pre>",
+        "should remove exactly the indent of the opening sequence from content"
+    );
+
+    assert_eq!(
+        to_html(" ```\n\taaa\n ```"),
+        "<pre><code>   aaa\n</code></pre>",
+        "should remove part of a tab from content if the opening sequence is indented"
+    );
+
+    assert_eq!(
+        to_html("  ```\n \taaa\n\t  bbb\n  ```"),
+        "<pre><code>  aaa\n    bbb\n</code></pre>",
+        "should remove indent from content as columns, w/ tab stops"
+    );
+
+    assert_eq!(
+        to_html("   ```\n\t\taaa\n   ```"),
+        "<pre><code> \taaa\n</code></pre>",
+        "should not remove more than the indent of the opening sequence from tabs"
+    );
+
+    assert_eq!(
+        to_html("> ```\n>\t\taaa\n> ```"),
+        "<blockquote>\n<pre><code>  \taaa\n</code></pre>\n</blockquote>",
+        "should keep what is left of a tab after a container prefix"
+    );
+
     assert_eq!(
         to_html("    ```\n    aaa\n    ```"),
         "<pre><code>```\naaa\n```\n</code></pre>",
@@ -177,6 +219,24 @@ fn code_fenced() -> Result<(), String> {
         "should support grave accents and tildes in the meta string of tilde fenced code"
     );
 
+    assert_eq!(
+        to_html("~~~ ```js\na\n~~~"),
+        "<pre><code class=\"language-```js\">a\n</code></pre>",
+        "should support grave accents in the info string of tilde fenced code"
+    );
+
+    assert_eq!(
+        to_html("``` ~~~js\na\n```"),
+        "<pre><code class=\"language-~~~js\">a\n</code></pre>",
+        "should support tildes in the info string of grave accent fenced code"
+    );
+
+    assert_eq!(
+        to_html("```js`\na\n```"),
+        "<p>```js`\na</p>\n<pre><code></code></pre>\n",
+        "should not support grave accents in the info string of grave accent fenced code"
+    );
+
     assert_eq!(
         to_html("```\n``` aaa\n```"),
         "<pre><code>``` aaa\n</code></pre>",
@@ -232,6 +292,12 @@ fn code_fenced() -> Result<(), String> {
         "should encode dangerous characters in languages"
     );
 
+    assert_eq!(
+        to_html("```  a&amp;b c\n```"),
+        "<pre><code class=\"language-a&amp;b\"></code></pre>",
+        "should support character references and leading whitespace in info strings"
+    );
+
     assert_eq!(
       to_html("   ```\naaa\n    ```"),
       "<pre><code>aaa\n ```\n</code></pre>\n",
@@ -280,6 +346,119 @@ fn code_fenced() -> Result<(), String> {
         "should support turning off code (fenced)"
     );
 
+    let highlight = Options {
+        compile: CompileOptions {
+            highlight: Some(Box::new(|language, meta, code| {
+                format!(
+                    "<b data-language=\"{}\" data-meta=\"{}\">{}</b>",
+                    language, meta, code
+                )
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```js x\na<b\n```", &highlight)?,
+        "<pre><code class=\"language-js\"><b data-language=\"js\" data-meta=\"x\">a<b\n</b></code></pre>",
+        "should support `highlight`"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~~a\\_&lt;b\n~~~", &highlight)?,
+        "<pre><code class=\"language-a_&lt;b\"><b data-language=\"a_<b\" data-meta=\"\"></b></code></pre>",
+        "should support `highlight` w/ a decoded language"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```\n> a\n\nb", &highlight)?,
+        "<blockquote>\n<pre><code><b data-language=\"\" data-meta=\"\">a\n</b></code></pre>\n</blockquote>\n<p>b</p>",
+        "should support `highlight` w/o info and w/o closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("```  rust,ignore  {1,3-5}  x&amp;y \t\na\n```", &highlight)?,
+        "<pre><code class=\"language-rust,ignore\"><b data-language=\"rust,ignore\" data-meta=\"{1,3-5}  x&y\">a\n</b></code></pre>",
+        "should support `highlight` w/ the rest of the info string as meta"
+    );
+
+    let lang = Options {
+        compile: CompileOptions {
+            code_language_prefix: Some("lang-".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```  rust  x\na\n```", &lang)?,
+        "<pre><code class=\"lang-rust\">a\n</code></pre>",
+        "should support `code_language_prefix`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```rust\na\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_language_prefix: Some(String::new()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre><code class=\"rust\">a\n</code></pre>",
+        "should support an empty `code_language_prefix`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```\n\n    b", &lang)?,
+        "<pre><code>a\n</code></pre>\n<pre><code>b\n</code></pre>",
+        "should not use `code_language_prefix` w/o language"
+    );
+
+    let pre = Options {
+        compile: CompileOptions {
+            pre_without_code: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```txt\na\n```", &pre)?,
+        "<pre class=\"language-txt\">a\n</pre>",
+        "should support `pre_without_code`, moving the class to `pre`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```\n> a\n\n    b", &pre)?,
+        "<blockquote>\n<pre>a\n</pre>\n</blockquote>\n<pre>b\n</pre>",
+        "should support `pre_without_code` w/o info and in code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```\na\n```\n\n$$\nb\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    pre_without_code: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<pre>a\n</pre>\n<pre><code class=\"language-math math-display\">b\n</code></pre>",
+        "should not support `pre_without_code` in math (flow)"
+    );
+
     assert_eq!(
         to_mdast(
             "```js extra\nconsole.log(1)\nconsole.log(2)\n```",
@@ -289,6 +468,7 @@ fn code_fenced() -> Result<(), String> {
             children: vec![Node::Code(Code {
                 lang: Some("js".into()),
                 meta: Some("extra".into()),
+                info: Some("js extra".into()),
                 value: "console.log(1)\nconsole.log(2)".into(),
                 position: Some(Position::new(1, 1, 0, 4, 4, 45))
             })],
@@ -297,12 +477,73 @@ fn code_fenced() -> Result<(), String> {
         "should support code (fenced) as `Code`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("~~~ \t a&amp;b \t c\\*d&#x20;\t\n~~~", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                lang: Some("a&b".into()),
+                meta: Some("c*d".into()),
+                info: Some("a&b \t c*d".into()),
+                value: String::new(),
+                position: Some(Position::new(1, 1, 0, 2, 4, 31))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 31))
+        }),
+        "should support a decoded info string in mdast, w/o leading and trailing whitespace"
+    );
+
+    assert_eq!(
+        to_mdast("```a b \t\nc\n```", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                lang: Some("a".into()),
+                meta: Some("b".into()),
+                info: Some("a b".into()),
+                value: "c".into(),
+                position: Some(Position::new(1, 1, 0, 3, 4, 14))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 14))
+        }),
+        "should support a meta w/ trailing whitespace in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("```js \t\n```", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                lang: Some("js".into()),
+                meta: None,
+                info: Some("js".into()),
+                value: String::new(),
+                position: Some(Position::new(1, 1, 0, 2, 4, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 11))
+        }),
+        "should support an info string w/o meta in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("  ```\n\taaa\n    bbb\n  ```", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                lang: None,
+                meta: None,
+                info: None,
+                value: "  aaa\n  bbb".into(),
+                position: Some(Position::new(1, 3, 2, 4, 6, 24))
+            })],
+            position: Some(Position::new(1, 1, 0, 4, 6, 24))
+        }),
+        "should keep what is left of indent, including of tabs, in mdast"
+    );
+
     assert_eq!(
         to_mdast("```\nasd", &Default::default())?,
         Node::Root(Root {
             children: vec![Node::Code(Code {
                 lang: None,
                 meta: None,
+                info: None,
                 value: "asd".into(),
                 position: Some(Position::new(1, 1, 0, 2, 4, 7))
             })],
@@ -317,6 +558,7 @@ fn code_fenced() -> Result<(), String> {
             children: vec![Node::Code(Code {
                 lang: None,
                 meta: None,
+                info: None,
                 value: "asd".into(),
                 position: Some(Position::new(1, 1, 0, 3, 4, 11))
             })],
@@ -331,6 +573,7 @@ fn code_fenced() -> Result<(), String> {
             children: vec![Node::Code(Code {
                 lang: None,
                 meta: None,
+                info: None,
                 value: "asd".into(),
                 position: Some(Position::new(1, 1, 0, 3, 4, 13))
             })],
@@ -203,6 +203,103 @@ fn code_indented() -> Result<(), String> {
         "should support turning off code (indented, 9)"
     );
 
+    assert_eq!(
+        to_html_with_options("a\n        b\n\n        c", &off)?,
+        "<p>a\nb</p>\n<p>c</p>",
+        "should support turning off code (indented, 10)"
+    );
+
+    assert_eq!(
+        to_html_with_options("-     a\n\n      b", &off)?,
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should support turning off code (indented, 11)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    ```\n    a\n    ```\n\n    > b", &off)?,
+        "<pre><code>a\n</code></pre>\n<blockquote>\n<p>b</p>\n</blockquote>",
+        "should support turning off code (indented, 12)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "    a<b\n\n    c",
+            &Options {
+                compile: CompileOptions {
+                    highlight: Some(Box::new(|language, meta, code| {
+                        format!(
+                            "<b data-language=\"{}\" data-meta=\"{}\">{}</b>",
+                            language, meta, code
+                        )
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre><code><b data-language=\"\" data-meta=\"\">a<b\n\nc\n</b></code></pre>",
+        "should support `highlight`"
+    );
+
+    assert_eq!(
+        to_html(">     a\n\n    b"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>b\n</code></pre>",
+        "should not continue code in a block quote after a blank line"
+    );
+
+    assert_eq!(
+        to_html("-     a\n\n      "),
+        "<ul>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ul>",
+        "should not include a trailing blank line in code in a list item"
+    );
+
+    assert!(
+        to_mdast(">     +\n\n    ", &Default::default()).is_ok(),
+        "should not crash on code in a block quote followed by an indented blank line"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n  - b\n\n          c\n        d"),
+        "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>\n<p>b</p>\n<pre><code>  c\nd\n</code></pre>\n</li>\n</ul>\n</li>\n</ul>",
+        "should keep relative indentation in code in a nested list item"
+    );
+
+    assert_eq!(
+        to_html("1. a\n\n   1. b\n\n          c\n\n\n          d"),
+        "<ol>\n<li>\n<p>a</p>\n<ol>\n<li>\n<p>b</p>\n<pre><code>c\n\n\nd\n</code></pre>\n</li>\n</ol>\n</li>\n</ol>",
+        "should support blank lines in code in a nested list item"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n\n        c\n          \n        d"),
+        "<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<pre><code>c\n  \nd\n</code></pre>\n</li>\n</ul>\n</li>\n</ul>",
+        "should keep whitespace past the indent on blank lines in code in a nested list item"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n\n\t\t  c"),
+        "<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<pre><code>  c\n</code></pre>\n</li>\n</ul>\n</li>\n</ul>",
+        "should support tabs as indent of code in a nested list item"
+    );
+
+    assert_eq!(
+        to_html("1. a\n   1. b\n\n\t\t\tc"),
+        "<ol>\n<li>a\n<ol>\n<li>\n<p>b</p>\n<pre><code>  c\n</code></pre>\n</li>\n</ol>\n</li>\n</ol>",
+        "should support tabs split by the list item prefix and the code indent"
+    );
+
+    assert_eq!(
+        to_html("1. a\n   1. b\n\n\t\tc"),
+        "<ol>\n<li>a\n<ol>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ol>\n</li>\n</ol>",
+        "should not form code from what is left of a tab split by a list item prefix"
+    );
+
+    assert_eq!(
+        to_html("1. a\n\n\t b"),
+        "<ol>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ol>",
+        "should trim what is left of a tab split by a list item prefix in a paragraph"
+    );
+
     assert_eq!(
         to_mdast(
             "\tconsole.log(1)\n    console.log(2)\n",
@@ -212,6 +309,7 @@ fn code_indented() -> Result<(), String> {
             children: vec![Node::Code(Code {
                 lang: None,
                 meta: None,
+                info: None,
                 value: "console.log(1)\nconsole.log(2)".into(),
                 position: Some(Position::new(1, 1, 0, 2, 19, 34))
             })],
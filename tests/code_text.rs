@@ -163,6 +163,30 @@ fn code_text() -> Result<(), String> {
         "should support an escaped initial grave accent"
     );
 
+    assert_eq!(
+        to_html("` a\n `"),
+        "<p><code>a </code></p>",
+        "should strip one space on both sides, after turning line endings into spaces"
+    );
+
+    assert_eq!(
+        to_html("`a\n `"),
+        "<p><code>a  </code></p>",
+        "should not strip spaces on one side w/ a line ending on the other side"
+    );
+
+    assert_eq!(
+        to_html("`\n`"),
+        "<p><code> </code></p>",
+        "should not strip a line ending turned into the only space"
+    );
+
+    assert_eq!(
+        to_html("``a```b``"),
+        "<p><code>a```b</code></p>",
+        "should support longer sequences inside code"
+    );
+
     assert_eq!(
         to_html_with_options(
             "`a`",
@@ -1,11 +1,12 @@
 extern crate markdown;
 use markdown::{
     mdast::{Definition, Node, Root},
-    to_html, to_html_with_options, to_mdast,
+    normalize_title, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions, Warning,
 };
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn definition() -> Result<(), String> {
@@ -84,6 +85,18 @@ fn definition() -> Result<(), String> {
         "should support definitions w/ explicit empty destinations"
     );
 
+    assert_eq!(
+        to_html("[foo]: <a\\>b c>\n\n[foo]"),
+        "<p><a href=\"a%3Eb%20c\">foo</a></p>",
+        "should support definitions w/ escaped `>` and spaces in enclosed destinations"
+    );
+
+    assert_eq!(
+        to_html("[foo]: <a\nb>\n\n[foo]"),
+        "<p>[foo]: &lt;a\nb&gt;</p>\n<p>[foo]</p>",
+        "should not support definitions w/ line endings in enclosed destinations"
+    );
+
     assert_eq!(
         to_html_with_options("[foo]: <bar>(baz)\n\n[foo]", &danger)?,
         "<p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>",
@@ -108,6 +121,12 @@ fn definition() -> Result<(), String> {
         "should match w/ the first definition"
     );
 
+    assert_eq!(
+        to_html("[x]: first\n[x]: second\n[x]: third\n\n[x] [x][] ![x]"),
+        "<p><a href=\"first\">x</a> <a href=\"first\">x</a> <img src=\"first\" alt=\"x\" /></p>",
+        "should match w/ the first of three definitions"
+    );
+
     assert_eq!(
         to_html("[FOO]: /url\n\n[Foo]"),
         "<p><a href=\"/url\">Foo</a></p>",
@@ -223,6 +242,36 @@ fn definition() -> Result<(), String> {
         "should not support setext heading underlines after definitions (2)"
     );
 
+    assert_eq!(
+        to_html("[a]: b\n---\nc"),
+        "<hr />\n<p>c</p>",
+        "should support thematic breaks instead of setext heading underlines after definitions"
+    );
+
+    assert_eq!(
+        to_html("[a]: b\n--\nc"),
+        "<p>--\nc</p>",
+        "should not support too short thematic breaks after definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a]: b\n---",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        thematic_break: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>---</p>",
+        "should not support thematic breaks after definitions if they are turned off"
+    );
+
     assert_eq!(
         to_html(
             "[foo]: /foo-url \"foo\"\n[bar]: /bar-url\n  \"bar\"\n[baz]: /baz-url\n\n[foo],\n[bar],\n[baz]"),
@@ -490,6 +539,237 @@ fn definition() -> Result<(), String> {
         "should prefer code (fenced) over definition titles"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "[a]: b (c (d) e)\n\n[a]",
+            &Options {
+                parse: ParseOptions {
+                    title_balance_parens: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"b\" title=\"c (d) e\">a</a></p>",
+        "should support balanced parens in titles w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a]: b “c\nd”\n\n[a]",
+            &Options {
+                parse: ParseOptions {
+                    title_smart_quotes: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"b\" title=\"c\nd\">a</a></p>",
+        "should support titles in curly quotes w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a]: b \"c\u{1b}\"\n\n[a]",
+            &Options {
+                parse: ParseOptions {
+                    title_validate: Some(Box::new(|char| !char.is_control())),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>[a]: b &quot;c\u{1b}&quot;</p>\n<p>[a]</p>",
+        "should not support definitions w/ titles rejected by `title_validate`"
+    );
+
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"c\"\n[d]: e (f)\n\n[a] [d]", &warn)?,
+        "<p><a href=\"b\" title=\"c\">a</a> <a href=\"e\" title=\"f\">d</a></p>",
+        "should support closed titles w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        Vec::<String>::new(),
+        "should not warn about closed titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"c\nd", &warn)?,
+        "<p>[a]: b &quot;c\nd</p>",
+        "should not support unclosed titles w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "1:8: Unexpected end of title, expected a corresponding closing `\"` for `\"`",
+            "1:1: Unexpected reference to undefined definition `a`, expected a corresponding definition (`[a]: url`)"
+        ],
+        "should warn about unclosed titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n  (c\n\n[a]", &warn)?,
+        "<p>(c</p>\n<p><a href=\"b\">a</a></p>",
+        "should support a definition w/o an unclosed title on the next line w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec!["2:3: Unexpected end of title, expected a corresponding closing `)` for `(`"],
+        "should warn about unclosed titles on the next line"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n[c]: d\n[e]: f\n\n[A] ![x][e] [e](g)", &warn)?,
+        "<p><a href=\"b\">A</a> <img src=\"f\" alt=\"x\" /> <a href=\"g\">e</a></p>",
+        "should support unused definitions w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec!["2:1: Unexpected unused definition `c`, expected a corresponding reference (`[c]`)"],
+        "should warn about unused definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x]: a\n[X]: b\n[ x ]: c\n\n[x]", &warn)?,
+        "<p><a href=\"a\">x</a></p>",
+        "should use the first of several duplicate definitions w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "2:1: Unexpected duplicate definition `X`, expected a label that is not yet defined (the first definition of a label is used)",
+            "3:1: Unexpected duplicate definition ` x `, expected a label that is not yet defined (the first definition of a label is used)"
+        ],
+        "should warn about duplicate definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x]: a\n[x]: b", &warn)?,
+        "",
+        "should support unused duplicate definitions w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "1:1: Unexpected unused definition `x`, expected a corresponding reference (`[x]`)",
+            "2:1: Unexpected duplicate definition `x`, expected a label that is not yet defined (the first definition of a label is used)"
+        ],
+        "should warn about duplicate definitions instead of unused ones"
+    );
+
+    let title_size_max = Options {
+        parse: ParseOptions {
+            title_size_max: Some(3),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"cde\"\n\n[a]", &title_size_max)?,
+        "<p><a href=\"b\" title=\"cde\">a</a></p>",
+        "should support titles up to `title_size_max`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"cdef\"\n\n[a]", &title_size_max)?,
+        "<p>[a]: b &quot;cdef&quot;</p>\n<p>[a]</p>",
+        "should not support titles larger than `title_size_max`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"c\\\"d\"\n\n[a]", &title_size_max)?,
+        "<p>[a]: b &quot;c&quot;d&quot;</p>\n<p>[a]</p>",
+        "should count both bytes of escapes in titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"c\n d\n  e\"\n\n[a]", &title_size_max)?,
+        "<p><a href=\"b\" title=\"c\nd\ne\">a</a></p>",
+        "should not count line endings and indents in titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"c\n d\n  ef\"\n\n[a]", &title_size_max)?,
+        "<p>[a]: b &quot;c\nd\nef&quot;</p>\n<p>[a]</p>",
+        "should not support titles spanning lines larger than `title_size_max`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b \"abcd\n\n[a]: c \"e\"\n\n[a]", &title_size_max)?,
+        "<p><a href=\"c\" title=\"e\">a</a>: b &quot;abcd</p>\n<p><a href=\"c\" title=\"e\">a</a></p>",
+        "should reset the size after a failed title"
+    );
+
+    let definitions = Rc::new(RefCell::new(vec![]));
+    let sink = definitions.clone();
+    let collect = Options {
+        compile: CompileOptions {
+            definition_sink: Some(Box::new(move |label, destination, title| {
+                sink.borrow_mut()
+                    .push(format!("{}|{}|{:?}", label, destination, title));
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[Ab]: c 'd'\n[E]: <f g>\n\n[aB][]", &collect)?,
+        "<p><a href=\"c\" title=\"d\">aB</a></p>",
+        "should support `definition_sink`"
+    );
+
+    assert_eq!(
+        definitions.take(),
+        vec!["ab|c|Some(\"d\")", "e|f g|None"],
+        "should call `definition_sink` w/ normalized labels, destinations, and titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: <b\\>&amp;> \"c&quot;\\\"\n d\"", &collect)?,
+        "",
+        "should support `definition_sink` w/ escapes and references"
+    );
+
+    assert_eq!(
+        definitions.take(),
+        vec!["a|b>&|Some(\"c\\\"\\\"\\nd\")"],
+        "should call `definition_sink` w/ decoded destinations and titles"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [a]: b\n\n- [c]: d\n\n[e]", &collect)?,
+        "<blockquote>\n</blockquote>\n<ul>\n<li></li>\n</ul>\n<p>[e]</p>",
+        "should support `definition_sink` w/ definitions in containers"
+    );
+
+    assert_eq!(
+        definitions.take(),
+        vec!["a|b|None", "c|d|None"],
+        "should call `definition_sink` for definitions in containers"
+    );
+
     assert_eq!(
         to_html_with_options(
             "[foo]: /url \"title\"",
@@ -523,5 +803,28 @@ fn definition() -> Result<(), String> {
         "should support definitions as `Definition`s in mdast"
     );
 
+    let tree = to_mdast("[a]: b \"c\n  d  e\"", &Default::default())?;
+    let title = if let Node::Root(Root { children, .. }) = &tree {
+        if let Some(Node::Definition(Definition { title, .. })) = children.first() {
+            title.clone()
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    assert_eq!(
+        title.as_deref(),
+        Some("c\nd  e"),
+        "should keep line endings in titles in mdast"
+    );
+
+    assert_eq!(
+        title.as_deref().map(normalize_title),
+        Some("c d e".into()),
+        "should support normalizing titles with `normalize_title`"
+    );
+
     Ok(())
 }
@@ -0,0 +1,238 @@
+extern crate markdown;
+use markdown::{
+    mdast::{
+        Definition, DefinitionDescription, DefinitionList, DefinitionTerm, Node, Paragraph, Root,
+        Text,
+    },
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn definition_list() -> Result<(), String> {
+    let dl = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                definition_list: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a\n: b"),
+        "<p>a\n: b</p>",
+        "should not support definition lists by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support a definition list"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n: c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dd>c</dd>\n</dl>",
+        "should support several descriptions for a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\nc\n: d", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dt>c</dt>\n<dd>d</dd>\n</dl>",
+        "should support several terms in a list (blank lines before terms do not make it loose)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n: c", &dl)?,
+        "<dl>\n<dt>a\nb</dt>\n<dd>c</dd>\n</dl>",
+        "should support a term spanning several lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*\n: `b`", &dl)?,
+        "<dl>\n<dt><em>a</em></dt>\n<dd><code>b</code></dd>\n</dl>",
+        "should support phrasing in terms and descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:b", &dl)?,
+        "<p>a\n:b</p>",
+        "should not support a marker w/o whitespace after it"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:\tb", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support a tab after the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:\n  b", &dl)?,
+        "<p>a\n:\nb</p>",
+        "should not support a description starting with a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n   : b", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support up to three spaces before the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n    : b", &dl)?,
+        "<p>a\n: b</p>",
+        "should not support four spaces before the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options(": a", &dl)?,
+        "<p>: a</p>",
+        "should not support a description w/o term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n: b", &dl)?,
+        "<p>a</p>\n<p>: b</p>",
+        "should not support a blank line between a term and its description"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n: b", &dl)?,
+        "<h1>a</h1>\n<p>: b</p>",
+        "should not support a heading as a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n: c", &dl)?,
+        "<p>: c</p>",
+        "should not support a definition as a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\nc", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b\nc</dd>\n</dl>",
+        "should support lazy continuation lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n  c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b\nc</dd>\n</dl>",
+        "should support indented continuation lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n***", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n<hr />",
+        "should support interrupting a description"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n: c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n</dd>\n<dd>\n<p>c</p>\n</dd>\n</dl>",
+        "should support a blank line between descriptions (loose)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n  c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n<p>c</p>\n</dd>\n</dl>",
+        "should support several blocks in a description (loose)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n      c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<p>b</p>\n<pre><code>c\n</code></pre>\n</dd>\n</dl>",
+        "should support indented code in a description"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: - b\n  - c", &dl)?,
+        "<dl>\n<dt>a</dt>\n<dd>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</dd>\n</dl>",
+        "should support containers in a description"
+    );
+
+    assert_eq!(
+        to_html_with_options("x\n: ```\n: y", &dl)?,
+        "<dl>\n<dt>x</dt>\n<dd>\n<pre><code>\n</code></pre>\n</dd>\n<dd>y</dd>\n</dl>",
+        "should support a description after an unclosed fenced code"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> : b", &dl)?,
+        "<blockquote>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</blockquote>",
+        "should support a definition list in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  : b", &dl)?,
+        "<ul>\n<li>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</li>\n</ul>",
+        "should support a definition list in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n[b]: c\n: d", &dl)?,
+        "<p>a</p>\n<p>: d</p>",
+        "should not use a paragraph before a definition as a term"
+    );
+
+    assert_eq!(
+        to_mdast("a\n: b", &dl.parse)?,
+        Node::Root(Root {
+            children: vec![Node::DefinitionList(DefinitionList {
+                children: vec![
+                    Node::DefinitionTerm(DefinitionTerm {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::DefinitionDescription(DefinitionDescription {
+                        children: vec![Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "b".into(),
+                                position: Some(Position::new(2, 3, 4, 2, 4, 5))
+                            })],
+                            position: Some(Position::new(2, 3, 4, 2, 4, 5))
+                        })],
+                        position: Some(Position::new(2, 1, 2, 2, 4, 5))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 4, 5)),
+                spread: false
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 5))
+        }),
+        "should support definition lists as `DefinitionList`s in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("[a]: b\n: c", &dl.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::Definition(Definition {
+                    url: "b".into(),
+                    identifier: "a".into(),
+                    label: Some("a".into()),
+                    title: None,
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: ": c".into(),
+                        position: Some(Position::new(2, 1, 7, 2, 4, 10))
+                    })],
+                    position: Some(Position::new(2, 1, 7, 2, 4, 10))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 2, 4, 10))
+        }),
+        "should support descriptions w/o term as paragraphs in mdast"
+    );
+
+    Ok(())
+}
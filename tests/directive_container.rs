@@ -0,0 +1,167 @@
+extern crate markdown;
+use markdown::{
+    mdast::{ContainerDirective, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn directive_container() -> Result<(), String> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_container: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(":::a\nb\n:::"),
+        "<p>:::a\nb\n:::</p>",
+        "should not support directives (container) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb\n:::", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>",
+        "should support a directive (container)"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a[b *c*]{.d}\ne\n:::", &directive)?,
+        "<div data-directive=\"a\" class=\"d\">\n<p>b <em>c</em></p>\n<p>e</p>\n</div>",
+        "should support a label and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a[]\nb\n:::", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>",
+        "should not add a paragraph for an empty label"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a\nb\n::", &directive)?,
+        "<p>::a\nb\n::</p>",
+        "should not support less than three markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a b", &directive)?,
+        "<p>:::a b</p>",
+        "should not support content after the opening fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a", &directive)?,
+        "<div data-directive=\"a\">\n</div>",
+        "should support a directive (container) w/o closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb\n::::", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>",
+        "should support a closing fence with more markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb\n::: c", &directive)?,
+        "<div data-directive=\"a\">\n<p>b\n::: c</p>\n</div>",
+        "should not support content after the closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb\n:::\nc", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>\n<p>c</p>",
+        "should support content after a directive (container)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:::b\nc\n:::", &directive)?,
+        "<p>a</p>\n<div data-directive=\"b\">\n<p>c</p>\n</div>",
+        "should interrupt a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("  :::a\n  b\n  :::", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>",
+        "should support an indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("::::a\n:::b\nc\n:::\n::::", &directive)?,
+        "<div data-directive=\"a\">\n<div data-directive=\"b\">\n<p>c</p>\n</div>\n</div>",
+        "should support nesting w/ more markers on the outer directive"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\n:::b\nc\n:::\n:::", &directive)?,
+        "<div data-directive=\"a\">\n<div data-directive=\"b\">\n<p>c</p>\n</div>\n</div>\n<p>:::</p>",
+        "should close inner and outer directives at once w/ the same markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\n```\nb\n:::\nc", &directive)?,
+        "<div data-directive=\"a\">\n<pre><code>b\n</code></pre>\n</div>\n<p>c</p>",
+        "should close unclosed flow at the closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\n- b\n- c\n:::\n- d", &directive)?,
+        "<div data-directive=\"a\">\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</div>\n<ul>\n<li>d</li>\n</ul>",
+        "should close containers at the closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("> :::a\n> b\n\nc", &directive)?,
+        "<blockquote>\n<div data-directive=\"a\">\n<p>b</p>\n</div>\n</blockquote>\n<p>c</p>",
+        "should close when the parent container closes"
+    );
+
+    assert_eq!(
+        to_html_with_options("- :::a\n  b\n  :::\n- c", &directive)?,
+        "<ul>\n<li>\n<div data-directive=\"a\">\n<p>b</p>\n</div>\n</li>\n<li>c</li>\n</ul>",
+        "should not make a list loose after a closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("- :::a\n  b\n  :::\n  c", &directive)?,
+        "<ul>\n<li>\n<div data-directive=\"a\">\n<p>b</p>\n</div>\nc</li>\n</ul>",
+        "should support a tight paragraph after a closing fence"
+    );
+
+    assert_eq!(
+        to_mdast(":::a[b]{c}\nd\n:::", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::ContainerDirective(ContainerDirective {
+                name: "a".into(),
+                attributes: vec![("c".into(), "".into())],
+                children: vec![
+                    Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                        }),],
+                        position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                    }),
+                    Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "d".into(),
+                            position: Some(Position::new(2, 1, 11, 2, 2, 12))
+                        }),],
+                        position: Some(Position::new(2, 1, 11, 2, 2, 12))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 4, 16))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 16))
+        }),
+        "should support directives (container) as `ContainerDirective`s in mdast"
+    );
+
+    Ok(())
+}
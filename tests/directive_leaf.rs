@@ -0,0 +1,137 @@
+extern crate markdown;
+use markdown::{
+    mdast::{LeafDirective, Node, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn directive_leaf() -> Result<(), String> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_leaf: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("::a[b]"),
+        "<p>::a[b]</p>",
+        "should not support directives (leaf) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a", &directive)?,
+        "<div data-directive=\"a\"></div>",
+        "should support a name"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a[b *c*]{#d .e f=g}", &directive)?,
+        "<div data-directive=\"a\" id=\"d\" class=\"e\" f=\"g\">b <em>c</em></div>",
+        "should support a name, a label, and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("::", &directive)?,
+        "<p>::</p>",
+        "should not support a missing name"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a b", &directive)?,
+        "<p>::a b</p>",
+        "should not support content after a directive"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a \t", &directive)?,
+        "<div data-directive=\"a\"></div>",
+        "should support whitespace after a directive"
+    );
+
+    assert_eq!(
+        to_html_with_options("   ::a", &directive)?,
+        "<div data-directive=\"a\"></div>",
+        "should support an indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("    ::a", &directive)?,
+        "<pre><code>::a\n</code></pre>",
+        "should not support a directive indented too much"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a[b\nc]", &directive)?,
+        "<p>::a[b\nc]</p>",
+        "should not support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a{b\nc}", &directive)?,
+        "<p>::a{b\nc}</p>",
+        "should not support a line ending in attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a{b=\"c}", &directive)?,
+        "<p>::a{b=&quot;c}</p>",
+        "should not support an unclosed quoted value"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n::b\nc", &directive)?,
+        "<p>a</p>\n<div data-directive=\"b\"></div>\n<p>c</p>",
+        "should interrupt a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ::a\n- ::b", &directive)?,
+        "<blockquote>\n<div data-directive=\"a\"></div>\n</blockquote>\n<ul>\n<li>\n<div data-directive=\"b\"></div>\n</li>\n</ul>",
+        "should support directives (leaf) in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n> :",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        directive_leaf: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>\n<blockquote>\n<p>:</p>\n</blockquote>",
+        "should not continue a table in a new container if something that looks like a directive is at the end"
+    );
+
+    assert_eq!(
+        to_mdast("::a[b]{c=d}", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::LeafDirective(LeafDirective {
+                name: "a".into(),
+                attributes: vec![("c".into(), "d".into())],
+                children: vec![Node::Text(Text {
+                    value: "b".into(),
+                    position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support directives (leaf) as `LeafDirective`s in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text, TextDirective},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn directive_text() -> Result<(), String> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_text: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(":a[b]"),
+        "<p>:a[b]</p>",
+        "should not support directives (text) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a", &directive)?,
+        "<p><span data-directive=\"a\"></span></p>",
+        "should support a name"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b]", &directive)?,
+        "<p><span data-directive=\"a\">b</span></p>",
+        "should support a name and a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b]{c}", &directive)?,
+        "<p><span data-directive=\"a\" c=\"\">b</span></p>",
+        "should support a name, a label, and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b c", &directive)?,
+        "<p>a <span data-directive=\"b\"></span> c</p>",
+        "should support a directive between text"
+    );
+
+    assert_eq!(
+        to_html_with_options(":1", &directive)?,
+        "<p>:1</p>",
+        "should not support a name starting with a digit"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a_b-c", &directive)?,
+        "<p><span data-directive=\"a_b-c\"></span></p>",
+        "should support dashes and underscores in a name"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a-", &directive)?,
+        "<p>:a-</p>",
+        "should not support a name ending in a dash"
+    );
+
+    assert_eq!(
+        to_html_with_options("a::b", &directive)?,
+        "<p>a::b</p>",
+        "should not support a colon right before a directive"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\::b", &directive)?,
+        "<p>a:<span data-directive=\"b\"></span></p>",
+        "should support an escaped colon right before a directive"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\:b", &directive)?,
+        "<p>a:b</p>",
+        "should not support an escaped colon"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b [c] d]", &directive)?,
+        "<p><span data-directive=\"a\">b [c] d</span></p>",
+        "should support balanced brackets in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b", &directive)?,
+        "<p><span data-directive=\"a\"></span>[b</p>",
+        "should not support an unclosed label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[*b* `c`]", &directive)?,
+        "<p><span data-directive=\"a\"><em>b</em> <code>c</code></span></p>",
+        "should support text in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b\nc]", &directive)?,
+        "<p><span data-directive=\"a\">b\nc</span></p>",
+        "should support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b \n]", &directive)?,
+        "<p><span data-directive=\"a\">b\n</span></p>",
+        "should support whitespace before a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b  \n  c]", &directive)?,
+        "<p><span data-directive=\"a\">b<br />\nc</span></p>",
+        "should support a hard break (trailing) in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b\n\nc]", &directive)?,
+        "<p><span data-directive=\"a\"></span>[b</p>\n<p>c]</p>",
+        "should not support a blank line in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{#b.c.d}", &directive)?,
+        "<p><span data-directive=\"a\" id=\"b\" class=\"c d\"></span></p>",
+        "should support id and class shortcuts"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{#b #c .d class=e}", &directive)?,
+        "<p><span data-directive=\"a\" id=\"c\" class=\"d e\"></span></p>",
+        "should use the last id and join classes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=c b=d}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"d\"></span></p>",
+        "should use the last value of other attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=\"c&amp;d\" e='f\"g'}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"c&amp;d\" e=\"f&quot;g\"></span></p>",
+        "should support quoted values"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=\"c}", &directive)?,
+        "<p><span data-directive=\"a\"></span>{b=&quot;c}</p>",
+        "should not support an unclosed quoted value"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b='c}", &directive)?,
+        "<p><span data-directive=\"a\"></span>{b='c}</p>",
+        "should not support an unclosed single quoted value"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=\"\" c=''}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"\" c=\"\"></span></p>",
+        "should support empty quoted values"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=c b=\"\"}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"\"></span></p>",
+        "should use the last value of an attribute, even if empty"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{#b .c d=\"e f\" g=h .i}", &directive)?,
+        "<p><span data-directive=\"a\" id=\"b\" class=\"c i\" d=\"e f\" g=\"h\"></span></p>",
+        "should support shortcuts mixed with other attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=\"c&quot;d\"}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"c&quot;d\"></span></p>",
+        "should support character references in quoted values"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{b=\"c\\\"d\"}", &directive)?,
+        "<p><span data-directive=\"a\"></span>{b=&quot;c&quot;d&quot;}</p>",
+        "should not support character escapes in quoted values"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{\nb=c\n}", &directive)?,
+        "<p><span data-directive=\"a\" b=\"c\"></span></p>",
+        "should support line endings in attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b](c)", &directive)?,
+        "<p><span data-directive=\"a\">b</span>(c)</p>",
+        "should not support a resource after a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("![:a[b]](c)", &directive)?,
+        "<p><img src=\"c\" alt=\"b\" /></p>",
+        "should support a directive in image text"
+    );
+
+    let directive_gfm = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                directive_text: true,
+                emoji: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(":a[b\nc@d.e]", &directive_gfm)?,
+        "<p><span data-directive=\"a\">b\n<a href=\"mailto:c@d.e\">c@d.e</a></span></p>",
+        "should support email autolink literals after a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[\nb@c.d]", &directive_gfm)?,
+        "<p><span data-directive=\"a\">\n<a href=\"mailto:b@c.d\">b@c.d</a></span></p>",
+        "should support email autolink literals after an initial line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b :+1:\nc]", &directive_gfm)?,
+        "<p><span data-directive=\"a\">b 👍\nc</span></p>",
+        "should support emoji before a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a[b HTML\nc]\n\n*[HTML]: d", &directive_gfm)?,
+        "<p><span data-directive=\"a\">b <abbr title=\"d\">HTML</abbr>\nc</span></p>\n",
+        "should support abbreviations before a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options(":a{onclick=b title=c}", &directive)?,
+        "<p><span data-directive=\"a\" title=\"c\"></span></p>",
+        "should drop event handler attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":a{onclick=b title=c}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        directive_text: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><span data-directive=\"a\" onclick=\"b\" title=\"c\"></span></p>",
+        "should keep event handler attributes if dangerous html is allowed"
+    );
+
+    assert_eq!(
+        to_mdast("a :b[c]{d=e} f.", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::TextDirective(TextDirective {
+                        name: "b".into(),
+                        attributes: vec![("d".into(), "e".into())],
+                        children: vec![Node::Text(Text {
+                            value: "c".into(),
+                            position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 13, 12))
+                    }),
+                    Node::Text(Text {
+                        value: " f.".into(),
+                        position: Some(Position::new(1, 13, 12, 1, 16, 15))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 16, 15))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 16, 15))
+        }),
+        "should support directives (text) as `TextDirective`s in mdast"
+    );
+
+    Ok(())
+}
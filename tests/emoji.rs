@@ -0,0 +1,244 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Emoji, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast, to_plain,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn emoji() -> Result<(), String> {
+    let emoji = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                emoji: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    let custom = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                emoji: true,
+                ..Constructs::default()
+            },
+            emoji_shortcodes: vec![
+                ("ferris".into(), "/emoji/ferris.png".into()),
+                ("tada".into(), "!".into()),
+                ("rust".into(), "🦀".into()),
+                ("rust".into(), "x".into()),
+            ],
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html(":tada:"),
+        "<p>:tada:</p>",
+        "should not support emoji by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(":tada:", &emoji)?,
+        "<p>🎉</p>",
+        "should support emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options(":+1: :-1: :100: :white_check_mark: :fr:", &emoji)?,
+        "<p>👍 👎 💯 ✅ 🇫🇷</p>",
+        "should support `+`, `-`, digits, and `_` in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("a:tada:b", &emoji)?,
+        "<p>a🎉b</p>",
+        "should support emoji next to other characters"
+    );
+
+    assert_eq!(
+        to_html_with_options(":tada::smile:", &emoji)?,
+        "<p>🎉😄</p>",
+        "should support emoji next to each other"
+    );
+
+    assert_eq!(
+        to_html_with_options(":nope: :Tada:", &emoji)?,
+        "<p>:nope: :Tada:</p>",
+        "should not support unknown shortcodes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":nope:tada:", &emoji)?,
+        "<p>:nope🎉</p>",
+        "should support the closing colon of an unknown shortcode as an opening"
+    );
+
+    assert_eq!(
+        to_html_with_options(":not a shortcode: :tada :: a:b", &emoji)?,
+        "<p>:not a shortcode: :tada :: a:b</p>",
+        "should not support other things between colons"
+    );
+
+    assert_eq!(
+        to_html_with_options("`:tada:`", &emoji)?,
+        "<p><code>:tada:</code></p>",
+        "should not support emoji in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com/:tada:>", &emoji)?,
+        "<p><a href=\"https://example.com/:tada:\">https://example.com/:tada:</a></p>",
+        "should not support emoji in autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "https://example.com/:tada:b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        emoji: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p><a href=\"https://example.com/:tada:b\">https://example.com/:tada:b</a></p>",
+        "should not support emoji in GFM autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\:tada: :tada\\:", &emoji)?,
+        "<p>:tada: :tada:</p>",
+        "should not support escaped colons"
+    );
+
+    assert_eq!(
+        to_html_with_options("*:tada:* [:tada:](a)", &emoji)?,
+        "<p><em>🎉</em> <a href=\"a\">🎉</a></p>",
+        "should support emoji in other phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("![:tada:](a)", &emoji)?,
+        "<p><img src=\"a\" alt=\"🎉\" /></p>",
+        "should support emoji in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":tada: :tada",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        directive_text: true,
+                        emoji: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>🎉 <span data-directive=\"tada\"></span></p>",
+        "should prefer emoji over text directives"
+    );
+
+    assert_eq!(
+        to_html_with_options(":ferris: :tada: :rust: :smile:", &custom)?,
+        "<p><img src=\"/emoji/ferris.png\" alt=\":ferris:\" /> ! 🦀 😄</p>",
+        "should support `emoji_shortcodes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a :ferris:](b)", &custom)?,
+        "<p><img src=\"b\" alt=\"a :ferris:\" /></p>",
+        "should use the shortcode of custom images in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":ferris:",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        emoji: true,
+                        ..Constructs::default()
+                    },
+                    emoji_shortcodes: vec![("ferris".into(), "javascript:alert(1)//".into())],
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><img src=\"\" alt=\":ferris:\" /></p>",
+        "should sanitize the URLs of custom images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":ferris:",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        emoji: true,
+                        ..Constructs::default()
+                    },
+                    emoji_shortcodes: vec![("ferris".into(), "ferris.png/".into())],
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    base_url: Some("https://example.com/emoji/".into()),
+                    ..CompileOptions::default()
+                },
+            }
+        )?,
+        "<p><img src=\"https://example.com/emoji/ferris.png/\" alt=\":ferris:\" /></p>",
+        "should resolve the URLs of custom images against `base_url`"
+    );
+
+    assert_eq!(
+        to_plain(":tada: :ferris:", &custom.parse)?,
+        "! :ferris:",
+        "should support emoji in plain text"
+    );
+
+    assert_eq!(
+        to_mdast(":ferris: a :tada:", &custom.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Emoji(Emoji {
+                        value: ":ferris:".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+                        name: "ferris".into(),
+                        url: Some("/emoji/ferris.png".into()),
+                    }),
+                    Node::Text(Text {
+                        value: " a ".into(),
+                        position: Some(Position::new(1, 9, 8, 1, 12, 11))
+                    }),
+                    Node::Emoji(Emoji {
+                        value: "!".into(),
+                        position: Some(Position::new(1, 12, 11, 1, 18, 17)),
+                        name: "tada".into(),
+                        url: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 18, 17))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 18, 17))
+        }),
+        "should support emoji as `Emoji`s in mdast"
+    );
+
+    Ok(())
+}
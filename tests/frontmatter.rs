@@ -104,6 +104,24 @@ fn frontmatter() -> Result<(), String> {
         "should not support frontmatter after content"
     );
 
+    assert_eq!(
+        to_html_with_options("\n---\na: b\n---", &frontmatter)?,
+        "<hr />\n<h2>a: b</h2>",
+        "should not support frontmatter after a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options(" ---\na: b\n---", &frontmatter)?,
+        "<hr />\n<h2>a: b</h2>",
+        "should not support an indented opening fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\n---\n---\n---", &frontmatter)?,
+        "<hr />\n<hr />",
+        "should support one frontmatter block at most"
+    );
+
     assert_eq!(
         to_html_with_options("> ---\n> ---\n> ## Neptune", &frontmatter)?,
         "<blockquote>\n<hr />\n<hr />\n<h2>Neptune</h2>\n</blockquote>",
@@ -128,6 +146,100 @@ fn frontmatter() -> Result<(), String> {
         "should support blank lines in frontmatter"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "+++\na = \"b\"\n+++",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        frontmatter: true,
+                        ..Default::default()
+                    },
+                    frontmatter_toml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>+++\na = &quot;b&quot;\n+++</p>",
+        "should not support toml if `frontmatter_toml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "---\na: b\n---",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        frontmatter: true,
+                        ..Default::default()
+                    },
+                    frontmatter_toml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "",
+        "should support yaml if `frontmatter_toml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "---\na: b\n---",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        frontmatter: true,
+                        ..Default::default()
+                    },
+                    frontmatter_yaml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr />\n<h2>a: b</h2>",
+        "should not support yaml if `frontmatter_yaml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "+++\na = \"b\"\n+++",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        frontmatter: true,
+                        ..Default::default()
+                    },
+                    frontmatter_yaml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "",
+        "should support toml if `frontmatter_yaml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na: b\n+++", &frontmatter)?,
+        "<hr />\n<p>a: b\n+++</p>",
+        "should not support a closing fence w/ different markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\n\n", &frontmatter)?,
+        "<hr />\n",
+        "should not support an opening fence w/o closing fence, followed by a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\r\n\n\r\n", &frontmatter)?,
+        "<hr />\r\n",
+        "should not support an opening fence w/o closing fence, followed by blank lines (CRLF)"
+    );
+
     assert_eq!(
         to_mdast("---\na: b\n---", &frontmatter.parse)?,
         Node::Root(Root {
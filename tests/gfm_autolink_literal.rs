@@ -183,6 +183,30 @@ fn gfm_autolink_literal() -> Result<(), String> {
         "should support two closing parens in a path"
     );
 
+    assert_eq!(
+        to_html_with_options("https://a.bc/d_(e).", &Options::gfm())?,
+        "<p><a href=\"https://a.bc/d_(e)\">https://a.bc/d_(e)</a>.</p>",
+        "should include a balanced closing paren in a path"
+    );
+
+    assert_eq!(
+        to_html_with_options("https://a.bc/d((e))f)", &Options::gfm())?,
+        "<p><a href=\"https://a.bc/d((e))f\">https://a.bc/d((e))f</a>)</p>",
+        "should exclude an unbalanced closing paren at the end of a path"
+    );
+
+    assert_eq!(
+        to_html_with_options("(www.a.bc)", &Options::gfm())?,
+        "<p>(<a href=\"http://www.a.bc\">www.a.bc</a>)</p>",
+        "should exclude a closing paren around a www url"
+    );
+
+    assert_eq!(
+        to_html_with_options("a@b.co.", &Options::gfm())?,
+        "<p><a href=\"mailto:a@b.co\">a@b.co</a>.</p>",
+        "should exclude a trailing period after an email"
+    );
+
     assert_eq!(
         to_html_with_options("ftp://a/b/c.txt", &Options::gfm())?,
         "<p>ftp://a/b/c.txt</p>",
@@ -3,9 +3,10 @@ use markdown::{
     mdast::{FootnoteDefinition, FootnoteReference, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options, ParseOptions,
+    CompileOptions, Options, ParseOptions, Warning,
 };
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn gfm_footnote() -> Result<(), String> {
@@ -162,6 +163,38 @@ fn gfm_footnote() -> Result<(), String> {
         "should supports multiple calls to the same definition"
     );
 
+    assert_eq!(
+        to_html_with_options("a[^c] b[^d]\n\n[^d]: e\n[^c]: f", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> b<sup><a href=\"#user-content-fn-d\" id=\"user-content-fnref-d\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-c\">
+<p>f <a href=\"#user-content-fnref-c\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-d\">
+<p>e <a href=\"#user-content-fnref-d\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should number and order footnotes by their first call, not by their definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c\n\n    d", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>c</p>
+<p>d <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support indented paragraphs in footnote definitions"
+    );
+
     assert_eq!(
         to_html_with_options("![^a](b)", &Options::gfm())?,
         "<p>!<a href=\"b\">^a</a></p>",
@@ -1662,5 +1695,43 @@ multi-paragraph list items. <a href="#user-content-fnref-longnote" data-footnote
         "should support GFM footnotes as `FootnoteDefinition`, `FootnoteReference`s in mdast"
     );
 
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("[^1]\n\n[^1]: x", &warn)?,
+        to_html_with_options("[^1]\n\n[^1]: x", &Options::gfm())?,
+        "should support footnotes w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        Vec::<String>::new(),
+        "should not warn about footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[^a] [^b]\n\n[^b]: c", &warn)?,
+        to_html_with_options("[^a] [^b]\n\n[^b]: c", &Options::gfm())?,
+        "should support undefined footnote calls w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "1:1: Unexpected call to undefined footnote `a`, expected a corresponding footnote definition (`[^a]: text`)"
+        ],
+        "should warn about undefined footnote calls"
+    );
+
     Ok(())
 }
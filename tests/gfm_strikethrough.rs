@@ -63,6 +63,36 @@ fn gfm_strikethrough() -> Result<(), String> {
         "should close if preceded and followed by punctuation"
     );
 
+    assert_eq!(
+        to_html_with_options("**~~a~~**", &Options::gfm())?,
+        "<p><strong><del>a</del></strong></p>",
+        "should support strikethrough in strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~**a**~~", &Options::gfm())?,
+        "<p><del><strong>a</strong></del></p>",
+        "should support strong in strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a~", &Options::gfm())?,
+        "<p>~~a~</p>",
+        "should not match sequences of different sizes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~~ b ~~ c", &Options::gfm())?,
+        "<p>a ~~ b ~~ c</p>",
+        "should not support sequences surrounded by whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("1~~2~~3~~4", &Options::gfm())?,
+        "<p>1<del>2</del>3~~4</p>",
+        "should pair intraword sequences from left to right"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"
@@ -268,6 +268,22 @@ fn gfm_table() -> Result<(), String> {
         "should be interrupted by HTML (flow)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "> a|\n\n<pre>b",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    allow_dangerous_protocol: true,
+                    ..CompileOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<blockquote>\n<p>a|</p>\n</blockquote>\n<pre>b",
+        "should support HTML (flow) after a lazy line that could be a delimiter row"
+    );
+
     assert_eq!(
         to_html_with_options("| a |\n| - |\n\tcode?", &Options {
                 compile: CompileOptions {
@@ -376,6 +392,36 @@ fn gfm_table() -> Result<(), String> {
         "should prefer GFM tables over definitions"
     );
 
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c \\| d |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c | d</td>\n<td></td>\n</tr>\n</tbody>\n</table>",
+        "should support escaped pipes in cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b | c |\n| - | - | - |\n| d |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n<th>c</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>d</td>\n<td></td>\n<td></td>\n</tr>\n</tbody>\n</table>",
+        "should add empty cells to body rows w/ fewer cells than the head row"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b | c |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table>",
+        "should drop cells from body rows w/ more cells than the head row"
+    );
+
+    assert_eq!(
+        to_html_with_options("a | b |\n| - | -\nc | d", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c</td>\n<td>d</td>\n</tr>\n</tbody>\n</table>",
+        "should support rows w/ and w/o leading and trailing pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b | c | d |\n| --- | :-- | :-: | --: |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th align=\"left\">b</th>\n<th align=\"center\">c</th>\n<th align=\"right\">d</th>\n</tr>\n</thead>\n</table>",
+        "should support no, left, center, and right alignment"
+    );
+
     assert_eq!(
         to_html_with_options("    | a |\n\t| - |\n    | b |", &Options {
                 parse: ParseOptions {
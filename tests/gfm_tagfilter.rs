@@ -50,6 +50,38 @@ fn gfm_tagfilter() -> Result<(), String> {
         "should filter"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n<iframe",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    gfm_tagfilter: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div>\n&lt;iframe",
+        "should filter tag names at the end of a line"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div><b>a</b></div>\n\nb <i>c</i> <iframe>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    gfm_tagfilter: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div><b>a</b></div>\n<p>b <i>c</i> &lt;iframe></p>",
+        "should not filter other tags"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"
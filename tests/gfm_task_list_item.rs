@@ -39,6 +39,36 @@ fn gfm_task_list_item() -> Result<(), String> {
         "should not support laziness (2)"
     );
 
+    assert_eq!(
+        to_html_with_options("* [X] y.", &Options::gfm())?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> y.</li>\n</ul>",
+        "should support uppercase task list item checks"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. [x] y.", &Options::gfm())?,
+        "<ol>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> y.</li>\n</ol>",
+        "should support task list item checks in ordered lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  [x] b", &Options::gfm())?,
+        "<ul>\n<li>a\n[x] b</li>\n</ul>",
+        "should not support task list item checks after other content in the paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("* > [x] a", &Options::gfm())?,
+        "<ul>\n<li>\n<blockquote>\n<p>[x] a</p>\n</blockquote>\n</li>\n</ul>",
+        "should not support task list item checks in other containers in list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [x] b", &Options::gfm())?,
+        "<p>a [x] b</p>",
+        "should not support task list item checks outside of list items"
+    );
+
     assert_eq!(
         to_html_with_options(
             &r###"
@@ -3,7 +3,7 @@ use markdown::{
     mdast::{Break, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -45,6 +45,60 @@ fn hard_break_escape() -> Result<(), String> {
         "should not support escape hard breaks at the end of a heading"
     );
 
+    assert_eq!(
+        to_html("a\\\n"),
+        "<p>a\\</p>\n",
+        "should not support escape hard breaks before the last line ending"
+    );
+
+    assert_eq!(
+        to_html("a\\\n\nb"),
+        "<p>a\\</p>\n<p>b</p>",
+        "should not support escape hard breaks before a blank line"
+    );
+
+    assert_eq!(
+        to_html("> a\\\n> b\n\n> c\\"),
+        "<blockquote>\n<p>a<br />\nb</p>\n</blockquote>\n<blockquote>\n<p>c\\</p>\n</blockquote>",
+        "should support escape hard breaks in containers, but not at the end"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\nb"),
+        "<p>a\\\nb</p>",
+        "should not support an escaped backslash as an escape hard break"
+    );
+
+    assert_eq!(
+        to_html("a\\ \nb"),
+        "<p>a\\\nb</p>",
+        "should not support a backslash followed by whitespace as an escape hard break"
+    );
+
+    assert_eq!(
+        to_html("a\\\r\nb\\\rc"),
+        "<p>a<br />\r\nb<br />\rc</p>",
+        "should support escape hard breaks before a carriage return (+ line feed)"
+    );
+
+    assert_eq!(
+        to_html("a\\\nb\n==="),
+        "<h1>a<br />\nb</h1>",
+        "should support escape hard breaks in a setext heading"
+    );
+
+    assert_eq!(
+        to_html("a\\\n==="),
+        "<h1>a\\</h1>",
+        "should not support escape hard breaks at the end of a setext heading"
+    );
+
+    assert_eq!(
+        to_html("# a\\\nb"),
+        "<h1>a\\</h1>\n<p>b</p>",
+        "should not support escape hard breaks in an ATX heading, which is one line"
+    );
+
     assert_eq!(
         to_html_with_options(
             "a\\\nb",
@@ -63,6 +117,21 @@ fn hard_break_escape() -> Result<(), String> {
         "should support turning off hard break (escape)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a\\\nb",
+            &Options {
+                compile: CompileOptions {
+                    hard_break_html: Some("<br>".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a<br>\nb</p>",
+        "should support `hard_break_html` for hard breaks (escape)"
+    );
+
     assert_eq!(
         to_mdast("a\\\nb.", &Default::default())?,
         Node::Root(Root {
@@ -3,7 +3,7 @@ use markdown::{
     mdast::{Break, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -129,6 +129,21 @@ fn hard_break_trailing() -> Result<(), String> {
         "should support turning off hard break (trailing)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a  \nb",
+            &Options {
+                compile: CompileOptions {
+                    hard_break_html: Some("<br>".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a<br>\nb</p>",
+        "should support `hard_break_html` for hard breaks (trailing)"
+    );
+
     assert_eq!(
         to_mdast("a  \nb.", &Default::default())?,
         Node::Root(Root {
@@ -3,7 +3,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, HeadingAnchor, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -225,6 +225,208 @@ fn heading_atx() -> Result<(), String> {
         "should support turning off heading (atx)"
     );
 
+    let literal = Options {
+        parse: ParseOptions {
+            heading_atx_closing_sequence: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("## a ##\n# b #  \n### c ### d ###", &literal)?,
+        "<h2>a ##</h2>\n<h1>b #</h1>\n<h3>c ### d ###</h3>",
+        "should support closing sequences as text w/ `heading_atx_closing_sequence: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# #\n##", &literal)?,
+        "<h1>#</h1>\n<h2></h2>",
+        "should support a heading w/ only a sequence as text w/ `heading_atx_closing_sequence: false`"
+    );
+
+    assert_eq!(
+        to_html("## a #b"),
+        "<h2>a #b</h2>",
+        "should not support a closing sequence w/o whitespace before it"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a #b", &literal)?,
+        "<h2>a #b</h2>",
+        "should not support a closing sequence w/o whitespace before it w/ `heading_atx_closing_sequence: false`"
+    );
+
+    let ids = Options {
+        compile: CompileOptions {
+            heading_ids: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# Hello, *World*!", &ids)?,
+        "<h1 id=\"hello-world\">Hello, <em>World</em>!</h1>",
+        "should support `heading_ids`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n## a\n### a-1", &ids)?,
+        "<h1 id=\"a\">a</h1>\n<h2 id=\"a-1\">a</h2>\n<h3 id=\"a-1-1\">a-1</h3>",
+        "should support `heading_ids` w/ duplicates"
+    );
+
+    assert!(
+        to_html_with_options(&"# a\n".repeat(10_000), &ids)?
+            .ends_with("<h1 id=\"a-9998\">a</h1>\n<h1 id=\"a-9999\">a</h1>\n"),
+        "should support `heading_ids` w/ many duplicates"
+    );
+
+    assert_eq!(
+        to_html_with_options("# [a](b \"c\") ![d](e) `f` &amp; <g>", &ids)?,
+        "<h1 id=\"a--f--\"><a href=\"b\" title=\"c\">a</a> <img src=\"e\" alt=\"d\" /> <code>f</code> &amp; &lt;g&gt;</h1>",
+        "should support `heading_ids` w/ text content, ignoring images, resources, and HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Ünïcödé 🎉", &ids)?,
+        "<h1 id=\"ünïcödé-\">Ünïcödé 🎉</h1>",
+        "should support `heading_ids` w/ non-ASCII"
+    );
+
+    assert_eq!(
+        to_html_with_options("#", &ids)?,
+        "<h1></h1>",
+        "should not add an empty `id`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a\n# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_ids: true,
+                    heading_id_slug: Some(Box::new(|value| format!("x-{}", value))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"x-a\">a</h1>\n<h1 id=\"x-a-1\">a</h1>",
+        "should support `heading_id_slug`"
+    );
+
+    let before = Options {
+        compile: CompileOptions {
+            heading_anchor: HeadingAnchor::Before,
+            heading_ids: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# a *b*", &before)?,
+        "<h1 id=\"a-b\"><a href=\"#a-b\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a <em>b</em></h1>",
+        "should support `heading_anchor: HeadingAnchor::Before`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a *b* #",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::After,
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a-b\">a <em>b</em><a href=\"#a-b\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a></h1>",
+        "should support `heading_anchor: HeadingAnchor::After`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n# a", &before)?,
+        "<h1 id=\"a\"><a href=\"#a\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a</h1>\n<h1 id=\"a-1\"><a href=\"#a-1\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a</h1>",
+        "should link anchors to unique ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("#\n# !", &before)?,
+        "<h1></h1>\n<h1>!</h1>",
+        "should not add anchors to headings w/o `id`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::Before,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a</h1>",
+        "should not add anchors w/o `heading_ids`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# Ünï",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::Before,
+                    heading_ids: true,
+                    heading_id_slug: Some(Box::new(|value| format!("x\"{}", value))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"x&quot;Ünï\"><a href=\"#x%22%C3%9Cn%C3%AF\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>Ünï</h1>",
+        "should support `heading_anchor` w/ `heading_id_slug`, encoding the URL"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::Before,
+                    heading_anchor_class: Some("x\"y".into()),
+                    heading_anchor_content: Some("<svg></svg>".into()),
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a\"><a href=\"#a\" class=\"x&quot;y\" aria-hidden=\"true\" tabindex=\"-1\"><svg></svg></a>a</h1>",
+        "should support `heading_anchor_class` and `heading_anchor_content`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::Before,
+                    heading_anchor_class: Some(String::new()),
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a\"><a href=\"#a\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a</h1>",
+        "should support an empty `heading_anchor_class`"
+    );
+
     assert_eq!(
         to_mdast("## alpha #", &Default::default())?,
         Node::Root(Root {
@@ -1,9 +1,9 @@
 extern crate markdown;
 use markdown::{
     mdast::{Heading, Node, Root, Text},
-    to_html, to_html_with_options, to_mdast,
+    parse, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, EventKind, EventName, HeadingAnchor, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -99,6 +99,36 @@ fn heading_setext() -> Result<(), String> {
         "should not support too much whitespace before underline (2)"
     );
 
+    assert_eq!(
+        to_html("Foo\n  \t="),
+        "<p>Foo\n=</p>",
+        "should not support too much whitespace before underline (3)"
+    );
+
+    assert_eq!(
+        to_html("Foo\n    ===\n\n    ==="),
+        "<p>Foo\n===</p>\n<pre><code>===\n</code></pre>",
+        "should not support too much whitespace before underline (4)"
+    );
+
+    assert_eq!(
+        to_html("Foo\n===   "),
+        "<h1>Foo</h1>",
+        "should support trailing spaces after the underline"
+    );
+
+    assert_eq!(
+        to_html("Foo\n=== \t\nBar\n---\t\t\nBaz"),
+        "<h1>Foo</h1>\n<h2>Bar</h2>\n<p>Baz</p>",
+        "should support trailing tabs after the underline"
+    );
+
+    assert_eq!(
+        to_html("> Foo\n>  ==\t\n- Bar\n     --  "),
+        "<blockquote>\n<h1>Foo</h1>\n</blockquote>\n<ul>\n<li>\n<h2>Bar</h2>\n</li>\n</ul>",
+        "should support whitespace around the underline in containers"
+    );
+
     assert_eq!(
         to_html("Foo\n= ="),
         "<p>Foo\n= =</p>",
@@ -250,6 +280,54 @@ fn heading_setext() -> Result<(), String> {
         "should prefer a setext heading over an interrupting list"
     );
 
+    assert_eq!(
+        to_html("[d]: e\n\na [b](c)\n[d][]\n==="),
+        "<h1>a <a href=\"c\">b</a>\n<a href=\"e\">d</a></h1>",
+        "should support links in a heading spanning lines"
+    );
+
+    assert_eq!(
+        to_html("a\n\n---"),
+        "<p>a</p>\n<hr />",
+        "should prefer a thematic break over an underline after a blank line"
+    );
+
+    assert_eq!(
+        to_html("a\n---\n---"),
+        "<h2>a</h2>\n<hr />",
+        "should support a thematic break after an underline"
+    );
+
+    assert_eq!(
+        parse("a\n---\n\n---\n[b]: c\n---", &Default::default())?
+            .iter()
+            .filter(|d| d.kind == EventKind::Enter
+                && matches!(
+                    d.name,
+                    EventName::HeadingSetextUnderline | EventName::ThematicBreak
+                ))
+            .map(|d| d.name.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            EventName::HeadingSetextUnderline,
+            EventName::ThematicBreak,
+            EventName::ThematicBreak
+        ],
+        "should expose whether an underline or a thematic break formed in events"
+    );
+
+    assert_eq!(
+        to_html("a\n---\nb\n==="),
+        "<h2>a</h2>\n<h1>b</h1>",
+        "should support a heading after a heading"
+    );
+
+    assert_eq!(
+        to_html("[a]: b\nc\n===\n\n[a]"),
+        "<h1>c</h1>\n<p><a href=\"b\">a</a></p>",
+        "should support a heading after a definition"
+    );
+
     assert_eq!(
         to_html("> ===\na"),
         "<blockquote>\n<p>===\na</p>\n</blockquote>",
@@ -292,6 +370,53 @@ fn heading_setext() -> Result<(), String> {
         "should support turning off setext underlines"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "# a\n\na\n-",
+            &Options {
+                compile: CompileOptions {
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a\">a</h1>\n<h2 id=\"a-1\">a</h2>",
+        "should support `heading_ids`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a *b*\n=\n\na\n-",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::Before,
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"a-b\"><a href=\"#a-b\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a <em>b</em></h1>\n<h2 id=\"a\"><a href=\"#a\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a>a</h2>",
+        "should support `heading_anchor: HeadingAnchor::Before`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\nb\n=",
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::After,
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 id=\"ab\">a\nb<a href=\"#ab\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a></h1>",
+        "should support `heading_anchor: HeadingAnchor::After`"
+    );
+
     assert_eq!(
         to_mdast("alpha\nbravo\n==", &Default::default())?,
         Node::Root(Root {
@@ -305,7 +430,7 @@ fn heading_setext() -> Result<(), String> {
             })],
             position: Some(Position::new(1, 1, 0, 3, 3, 14))
         }),
-        "should support heading (atx) as `Heading`s in mdast"
+        "should support heading (setext) as `Heading`s in mdast"
     );
 
     Ok(())
@@ -0,0 +1,114 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Highlight, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn highlight() -> Result<(), String> {
+    let highlight = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                highlight: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a ==b== c"),
+        "<p>a ==b== c</p>",
+        "should not support highlight by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b== c", &highlight)?,
+        "<p>a <mark>b</mark> c</p>",
+        "should support highlight"
+    );
+
+    assert_eq!(
+        to_html_with_options("a==b==c", &highlight)?,
+        "<p>a<mark>b</mark>c</p>",
+        "should support highlight inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b c== d", &highlight)?,
+        "<p>a <mark>b c</mark> d</p>",
+        "should support whitespace inside highlight"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n==b\nc== d", &highlight)?,
+        "<p>a\n<mark>b\nc</mark> d</p>",
+        "should support line endings inside highlight"
+    );
+
+    assert_eq!(
+        to_html_with_options("a == b == c", &highlight)?,
+        "<p>a == b == c</p>",
+        "should not support sequences surrounded by whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b ==", &highlight)?,
+        "<p>a ==b ==</p>",
+        "should not support whitespace before a closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("=a= ===b=== ====", &highlight)?,
+        "<p>=a= ===b=== ====</p>",
+        "should not support sequences of other sizes"
+    );
+
+    assert_eq!(
+        to_html_with_options("*==a==* ==*a*== **==a**==", &highlight)?,
+        "<p><em><mark>a</mark></em> <mark><em>a</em></mark> <strong>==a</strong>==</p>",
+        "should nest with emphasis, but not overlap"
+    );
+
+    assert_eq!(
+        to_html_with_options("==a [b==](c) `==d==`", &highlight)?,
+        "<p>==a <a href=\"c\">b==</a> <code>==d==</code></p>",
+        "should not match across links or in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n==", &highlight)?,
+        "<h1>a</h1>",
+        "should prefer heading (setext) underlines"
+    );
+
+    assert_eq!(
+        to_html_with_options("![==a==](b)", &highlight)?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should support highlight in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("==a==", &highlight.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Highlight(Highlight {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should support highlight as `Highlight`s in mdast"
+    );
+
+    Ok(())
+}
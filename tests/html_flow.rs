@@ -341,6 +341,81 @@ fn html_flow_2_comment() -> Result<(), String> {
         "should not support lazyness (2)"
     );
 
+    let strip = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            strip_comments: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\n\n<!-- b\n\nc -->\n\nd", &strip)?,
+        "<p>a</p>\n<p>d</p>",
+        "should support `strip_comments` to drop comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!--[if IE]><p>a</p><![endif]-->\nb", &strip)?,
+        "<p>b</p>",
+        "should support `strip_comments` to drop conditional comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<!-- b", &strip)?,
+        "<p>a</p>",
+        "should support `strip_comments` to drop unclosed comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a\n\nb", &strip)?,
+        "",
+        "should support `strip_comments` to drop unclosed comments to the end of the HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("> <!-- a\n\nb", &strip)?,
+        "<blockquote>\n</blockquote>\n<p>b</p>",
+        "should support `strip_comments` to drop unclosed comments to the end of the HTML in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a --> <b>c</b>", &strip)?,
+        " <b>c</b>",
+        "should support `strip_comments` to keep other HTML after comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n<!-- a -->\n</div>", &strip)?,
+        "<div>\n</div>",
+        "should support `strip_comments` to drop comments in other HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n<!-- a -->", &strip)?,
+        "<div>",
+        "should support `strip_comments` to drop comments at the end of other HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div><!-- a\nb -->\nc</div>", &strip)?,
+        "<div>\nc</div>",
+        "should support `strip_comments` to drop comments over several lines in other HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n<!--> a <!---> b\n</div>", &strip)?,
+        "<div>\n a  b\n</div>",
+        "should support `strip_comments` to drop empty comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("x\n\n<!-- a -->", &strip)?,
+        "<p>x</p>",
+        "should support `strip_comments` to drop the line ending before HTML that ends up empty"
+    );
+
     Ok(())
 }
 
@@ -386,6 +461,12 @@ fn html_flow_3_instruction() -> Result<(), String> {
         "should support blank lines in instructions"
     );
 
+    assert_eq!(
+        to_html_with_options("<?a > b\nc?>d\ne", &danger)?,
+        "<?a > b\nc?>d\n<p>e</p>",
+        "should end instructions at `?>`, not `>`"
+    );
+
     assert_eq!(
         to_html_with_options("> <?\na", &danger)?,
         "<blockquote>\n<?\n</blockquote>\n<p>a</p>",
@@ -451,6 +532,12 @@ fn html_flow_4_declaration() -> Result<(), String> {
         "should support blank lines in declarations"
     );
 
+    assert_eq!(
+        to_html_with_options("<!a ?\nb>c\nd", &danger)?,
+        "<!a ?\nb>c\n<p>d</p>",
+        "should end declarations at `>`"
+    );
+
     assert_eq!(
         to_html_with_options("> <!a\nb", &danger)?,
         "<blockquote>\n<!a\n</blockquote>\n<p>b</p>",
@@ -525,6 +612,12 @@ fn html_flow_5_cdata() -> Result<(), String> {
         "should support blank lines in cdata"
     );
 
+    assert_eq!(
+        to_html_with_options("<![CDATA[a]]b]>\nc]]>d\ne", &danger)?,
+        "<![CDATA[a]]b]>\nc]]>d\n<p>e</p>",
+        "should end cdata at `]]>`, not `]]` or `]>`"
+    );
+
     assert_eq!(
         to_html_with_options("> <![CDATA[\na", &danger)?,
         "<blockquote>\n<![CDATA[\n</blockquote>\n<p>a</p>",
@@ -892,6 +985,24 @@ fn html_flow_7_complete() -> Result<(), String> {
         "should not support interrupting paragraphs w/ complete tags"
     );
 
+    assert_eq!(
+        to_html_with_options("a\n</b>\n\nc\n<d-e f>", &danger)?,
+        "<p>a\n</b></p>\n<p>c\n<d-e f></p>",
+        "should not support interrupting paragraphs w/ complete closing tags, custom elements"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> <b>\n\n- c\n  <d>", &danger)?,
+        "<blockquote>\n<p>a\n<b></p>\n</blockquote>\n<ul>\n<li>c\n<d></li>\n</ul>",
+        "should not support interrupting paragraphs in containers w/ complete tags"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<div>\n\nb\n<!-- c -->", &danger)?,
+        "<p>a</p>\n<div>\n<p>b</p>\n<!-- c -->",
+        "should support interrupting paragraphs w/ basic tags and comments, unlike complete tags"
+    );
+
     assert_eq!(
         to_html_with_options("<x", &danger)?,
         "<p>&lt;x</p>",
@@ -214,6 +214,38 @@ fn html_text() -> Result<(), String> {
         "should not support eof in a comment (4)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a <!-- b --> <i>c</i>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    strip_comments: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a  <i>c</i></p>",
+        "should support `strip_comments` to drop comments"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <!--b",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    strip_comments: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a &lt;!--b</p>",
+        "should not drop unclosed comments (as text) w/ `strip_comments`"
+    );
+
     // Note: cmjs parses this differently.
     // See: <https://github.com/commonmark/commonmark.js/issues/193>
     assert_eq!(
@@ -38,6 +38,24 @@ fn image() -> Result<(), String> {
         "should “support” links in images"
     );
 
+    assert_eq!(
+        to_html("![a [b](c) d](e)"),
+        "<p><img src=\"e\" alt=\"a b d\" /></p>",
+        "should use the text of links in images as alt"
+    );
+
+    assert_eq!(
+        to_html("[b]: x\n\n![a [b][] *c*](d)"),
+        "<p><img src=\"d\" alt=\"a b c\" /></p>",
+        "should use the text of references and emphasis in images as alt"
+    );
+
+    assert_eq!(
+        to_html("[a ![b [c](d)](e)](f)"),
+        "<p>[a <img src=\"e\" alt=\"b c\" />](f)</p>",
+        "should not support links around images w/ links"
+    );
+
     assert_eq!(
         to_html("[foo *bar*]: train.jpg \"train & tracks\"\n\n![foo *bar*][]"),
         "<p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>",
@@ -134,6 +152,30 @@ fn image() -> Result<(), String> {
         "should support case-insensitive label matching"
     );
 
+    assert_eq!(
+        to_html("[a]: b \"c\"\n\n![a][a] ![a][] ![a]"),
+        "<p><img src=\"b\" alt=\"a\" title=\"c\" /> <img src=\"b\" alt=\"a\" title=\"c\" /> <img src=\"b\" alt=\"a\" title=\"c\" /></p>",
+        "should support full, collapsed, and shortcut references to the same definition"
+    );
+
+    assert_eq!(
+        to_html("![a][b] ![a][] ![a]"),
+        "<p>![a][b] ![a][] ![a]</p>",
+        "should not support full, collapsed, or shortcut references to undefined definitions"
+    );
+
+    assert_eq!(
+        to_html("[a]: b\n\n![a][c]"),
+        "<p>![a][c]</p>",
+        "should not support a full reference to an undefined definition, even if its text is defined"
+    );
+
+    assert_eq!(
+        to_html("[c]: d\n\n![a][b][c]"),
+        "<p>![a]<a href=\"d\">b</a></p>",
+        "should not support an image w/ an undefined full reference, but a link after it"
+    );
+
     assert_eq!(
         to_html("[foo]: /url \"title\"\n\n!\\[foo]"),
         "<p>![foo]</p>",
@@ -215,6 +257,35 @@ fn image() -> Result<(), String> {
         "should support turning off label start (image)"
     );
 
+    let attributes = Options {
+        compile: CompileOptions {
+            image_attributes: vec![
+                ("loading".into(), "lazy".into()),
+                ("data-a".into(), "b\"c".into()),
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("![a *b*](c \"d\")", &attributes)?,
+        "<p><img src=\"c\" alt=\"a b\" loading=\"lazy\" data-a=\"b&quot;c\" title=\"d\" /></p>",
+        "should support `image_attributes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ![b](c)](d)\n\n![e][]\n\n[e]: f \"g\"", &attributes)?,
+        "<p><img src=\"d\" alt=\"a b\" loading=\"lazy\" data-a=\"b&quot;c\" /></p>\n<p><img src=\"f\" alt=\"e\" loading=\"lazy\" data-a=\"b&quot;c\" title=\"g\" /></p>\n",
+        "should support `image_attributes` w/ images in images and definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b)", &attributes)?,
+        "<p><a href=\"b\">a</a></p>",
+        "should not add `image_attributes` to links"
+    );
+
     assert_eq!(
         to_html("![](javascript:alert(1))"),
         "<p><img src=\"\" alt=\"\" /></p>",
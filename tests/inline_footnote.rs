@@ -0,0 +1,283 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Footnote, FootnoteDefinition, FootnoteReference, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn inline_footnote() -> Result<(), String> {
+    let inline = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                inline_footnote: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("a^[b]"),
+        "<p>a^[b]</p>",
+        "should not support inline footnotes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b *c*] d", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> d</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <em>c</em> <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b [c](d) e] f", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> f</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"d\">c</a> e <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support links in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b [c] d]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b [c] d <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support balanced brackets in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b\\]c]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b]c <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support character escapes in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b\nc]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b
+c <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support line endings in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b] c^[d]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> c<sup><a href=\"#user-content-fn-2\" id=\"user-content-fnref-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-2\">
+<p>d <a href=\"#user-content-fnref-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should number inline footnotes in order"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b] c^[d] e[^f]\n\n[^b]: g\n[^f]: h", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> c<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> e<sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>g <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-1\">
+<p>d <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-f\">
+<p>h <a href=\"#user-content-fnref-f\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should number inline footnotes together with footnote calls"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b]\n\n[^1]: c\n[^2]: d", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-3\" id=\"user-content-fnref-3\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-3\">
+<p>b <a href=\"#user-content-fnref-3\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not use the identifier of a footnote definition"
+    );
+
+    let result = to_html_with_options(&("^[a] ".repeat(4_000) + "\n\n[^2]: b"), &inline)?;
+    assert!(
+        result.contains("<li id=\"user-content-fn-4001\">")
+            && !result.contains("<li id=\"user-content-fn-4002\">"),
+        "should support many inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b [^c]]\n\n[^c]: d", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-c\">
+<p>d <a href=\"#user-content-fnref-c\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-1\">
+<p>b <sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support footnote calls in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b](c)", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup>(c)</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not support a resource after an inline footnote"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a>
+</li>
+</ol>
+</section>
+",
+        "should support an empty inline footnote"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^[b", &inline)?,
+        "<p>a ^[b</p>",
+        "should not support an unclosed inline footnote"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^ [b]", &inline)?,
+        "<p>a ^ [b]</p>",
+        "should not support whitespace between `^` and `[`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\^[b]", &inline)?,
+        "<p>a^[b]</p>",
+        "should not support an escaped `^`"
+    );
+
+    assert_eq!(
+        to_mdast("a^[b] c", &inline.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::Footnote(Footnote {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        })],
+                        position: Some(Position::new(1, 2, 1, 1, 6, 5))
+                    }),
+                    Node::Text(Text {
+                        value: " c".into(),
+                        position: Some(Position::new(1, 6, 5, 1, 8, 7))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support inline footnotes as `Footnote`s in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("^[[^a]]\n\n[^a]: b", &inline.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Footnote(Footnote {
+                        children: vec![Node::FootnoteReference(FootnoteReference {
+                            identifier: "a".into(),
+                            label: Some("a".into()),
+                            position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                }),
+                Node::FootnoteDefinition(FootnoteDefinition {
+                    identifier: "a".into(),
+                    label: Some("a".into()),
+                    children: vec![Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(3, 7, 15, 3, 8, 16))
+                        })],
+                        position: Some(Position::new(3, 7, 15, 3, 8, 16))
+                    })],
+                    position: Some(Position::new(3, 1, 9, 3, 8, 16))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 8, 16))
+        }),
+        "should support a footnote call at the start of an inline footnote in mdast"
+    );
+
+    Ok(())
+}
@@ -3,9 +3,10 @@ use markdown::{
     mdast::{Definition, LinkReference, Node, Paragraph, ReferenceKind, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions, Warning,
 };
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn link_reference() -> Result<(), String> {
@@ -384,12 +385,106 @@ fn link_reference() -> Result<(), String> {
         "should not support 1000 characters in a reference"
     );
 
+    assert_eq!(
+        to_html(format!("[a]: b\n\n[a{}] [a{}][]", " ".repeat(999), " ".repeat(999)).as_str()),
+        format!("<p>[a{}] [a{}][]</p>", " ".repeat(999), " ".repeat(999)),
+        "should not support 1000 characters in a shortcut or collapsed reference, even if they normalize to a defined label"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}{}", "[".repeat(5000), "]".repeat(5000))),
+        format!("<p>{}{}</p>", "[".repeat(5000), "]".repeat(5000)),
+        "should support deeply nested brackets (performance)"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}{}", "![".repeat(5000), "](".repeat(5000))),
+        format!("<p>{}{}</p>", "![".repeat(5000), "](".repeat(5000)),
+        "should support deeply nested brackets followed by unclosed resources (performance)"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}{}", "[".repeat(5000), "[a](b)".repeat(5000))),
+        format!(
+            "<p>{}{}</p>",
+            "[".repeat(5000),
+            "<a href=\"b\">a</a>".repeat(5000)
+        ),
+        "should support many links after many unclosed brackets (performance)"
+    );
+
     assert_eq!(
         to_html("[x] missing-colon\n\nWill it link? [x]"),
         "<p>[x] missing-colon</p>\n<p>Will it link? [x]</p>",
         "should not fail on a missing colon in a definition"
     );
 
+    assert_eq!(
+        to_html("[ab]: x\n\n[a b] [a b][] [c][a b]"),
+        "<p>[a b] [a b][] [c][a b]</p>",
+        "should not match labels that only differ in whitespace between words"
+    );
+
+    assert_eq!(
+        to_html("[Foo  Bar\tbaz]: x\n\n[FOO BAR BAZ] [foo\nbar  baz][] [a][ foo bar baz ]"),
+        "<p><a href=\"x\">FOO BAR BAZ</a> <a href=\"x\">foo\nbar  baz</a> <a href=\"x\">a</a></p>",
+        "should match multi-word labels w/ collapsed whitespace in shortcut, collapsed, and full references"
+    );
+
+    assert_eq!(
+        to_html("[ẞ]: x\n\n[SS] [ß][] [a][ss]"),
+        "<p><a href=\"x\">SS</a> <a href=\"x\">ß</a> <a href=\"x\">a</a></p>",
+        "should match labels w/ case folding (`ẞ`, `ß`, `SS`)"
+    );
+
+    assert_eq!(
+        to_html("[Ǆ Ωμέγα]: x\n\n[ǆ ΩΜΈΓΑ]"),
+        "<p><a href=\"x\">ǆ ΩΜΈΓΑ</a></p>",
+        "should match labels w/ mixed-case Unicode"
+    );
+
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n\n[a] [c] [d][] [e][a] [a][f] [g](h) [ ]", &warn)?,
+        "<p><a href=\"b\">a</a> [c] [d][] <a href=\"b\">e</a> [a][f] <a href=\"h\">g</a> [ ]</p>",
+        "should support undefined references w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "3:5: Unexpected reference to undefined definition `c`, expected a corresponding definition (`[c]: url`)",
+            "3:9: Unexpected reference to undefined definition `d`, expected a corresponding definition (`[d]: url`)",
+            "3:25: Unexpected reference to undefined definition `f`, expected a corresponding definition (`[f]: url`)"
+        ],
+        "should warn about undefined references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]](b) [[c]][d] ![[e]](f)\n\n[d]: g", &warn)?,
+        "<p><a href=\"b\">[a]</a> <a href=\"g\">[c]</a> <img src=\"f\" alt=\"[e]\" /></p>\n",
+        "should support undefined references in links w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "1:21: Unexpected reference to undefined definition `e`, expected a corresponding definition (`[e]: url`)"
+        ],
+        "should not warn about undefined references in links (but do in images)"
+    );
+
     assert_eq!(
         to_html_with_options(
             "[x]()",
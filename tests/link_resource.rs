@@ -1,11 +1,12 @@
 extern crate markdown;
 use markdown::{
-    mdast::{Link, Node, Paragraph, Root, Text},
+    mdast::{Image, Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options,
+    CompileOptions, Options, ParseOptions, Warning,
 };
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn link_resource() -> Result<(), String> {
@@ -362,6 +363,36 @@ fn link_resource() -> Result<(), String> {
         "should not support enclosed link destination starting w/ an incorrect escape"
     );
 
+    assert_eq!(
+        to_html("[a](<b\\>c d>)"),
+        "<p><a href=\"b%3Ec%20d\">a</a></p>",
+        "should support escaped `>` and spaces in enclosed destinations"
+    );
+
+    assert_eq!(
+        to_html("[a](<b\\\\>)"),
+        "<p><a href=\"b%5C\">a</a></p>",
+        "should support an escaped backslash at the end of enclosed destinations"
+    );
+
+    assert_eq!(
+        to_html("[a](<b<c>)"),
+        "<p>[a](&lt;b&lt;c&gt;)</p>",
+        "should not support unescaped `<` in enclosed destinations"
+    );
+
+    assert_eq!(
+        to_html("[a](<b)c>)"),
+        "<p><a href=\"b)c\">a</a></p>",
+        "should support unbalanced parens in enclosed destinations"
+    );
+
+    assert_eq!(
+        to_html("[a](b\u{1}c)"),
+        "<p>[a](b\u{1}c)</p>",
+        "should not support control characters in unenclosed destinations"
+    );
+
     assert_eq!(
         to_html("[](a \""),
         "<p>[](a &quot;</p>",
@@ -466,6 +497,338 @@ fn link_resource() -> Result<(), String> {
         "should support a single NUL character as a link resource"
     );
 
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a [b](c 'd\ne) f", &warn)?,
+        "<p>a [b](c 'd\ne) f</p>",
+        "should not support unclosed titles w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec!["1:9: Unexpected end of title, expected a corresponding closing `'` for `'`"],
+        "should warn about unclosed titles"
+    );
+
+    let title_validate = Options {
+        parse: ParseOptions {
+            title_validate: Some(Box::new(|char| !char.is_control() || char == '\t')),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c\td é\")", &title_validate)?,
+        "<p><a href=\"b\" title=\"c\td é\">a</a></p>",
+        "should support titles accepted by `title_validate`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c\u{0}d\")", &title_validate)?,
+        "<p>[a](b &quot;c\u{fffd}d&quot;)</p>",
+        "should not support titles rejected by `title_validate`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b 'c\u{7f}')", &title_validate)?,
+        "<p>[a](b 'c\u{7f}')</p>",
+        "should not support titles rejected by `title_validate` (DEL)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c\nd))", &title_validate)?,
+        "<p><a href=\"b\" title=\"c\nd\">a</a></p>",
+        "should not pass line endings to `title_validate`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b \"c\\\"d\")",
+            &Options {
+                parse: ParseOptions {
+                    title_validate: Some(Box::new(|char| char != '"')),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>[a](b &quot;c&quot;d&quot;)</p>",
+        "should pass escaped characters to `title_validate`"
+    );
+
+    assert_eq!(
+        to_html("[a](b 'c\"d')"),
+        "<p><a href=\"b\" title=\"c&quot;d\">a</a></p>",
+        "should encode a double quote in a title"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"<c>&d\")"),
+        "<p><a href=\"b\" title=\"&lt;c&gt;&amp;d\">a</a></p>",
+        "should encode `<`, `>`, and `&` in a title"
+    );
+
+    assert_eq!(
+        to_html("[a](<b \"c>)"),
+        "<p><a href=\"b%20%22c\">a</a></p>",
+        "should percent-encode a space and a double quote in a destination"
+    );
+
+    assert_eq!(
+        to_html("[a](b\\<c>d&e)"),
+        "<p><a href=\"b%3Cc%3Ed&amp;e\">a</a></p>",
+        "should percent-encode `<` and `>`, and encode `&`, in a destination"
+    );
+
+    assert_eq!(
+        to_html("![a](<b \"c> 'd\"e')"),
+        "<p><img src=\"b%20%22c\" alt=\"a\" title=\"d&quot;e\" /></p>",
+        "should encode destinations and titles of images"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n[a]: <b \"c> 'd\"e'"),
+        "<p><a href=\"b%20%22c\" title=\"d&quot;e\">a</a></p>\n",
+        "should encode destinations and titles of definitions"
+    );
+
+    assert_eq!(
+        to_html("<https://a.b/c\"d&e>"),
+        "<p><a href=\"https://a.b/c%22d&amp;e\">https://a.b/c&quot;d&amp;e</a></p>",
+        "should encode autolinks in the same way"
+    );
+
+    let title_smart_quotes = Options {
+        parse: ParseOptions {
+            title_smart_quotes: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b “c”)"),
+        "<p>[a](b “c”)</p>",
+        "should not support curly quotes around titles by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “c”)", &title_smart_quotes)?,
+        "<p><a href=\"b\" title=\"c\">a</a></p>",
+        "should support curly double quotes around titles w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b ‘c’)", &title_smart_quotes)?,
+        "<p><a href=\"b\" title=\"c\">a</a></p>",
+        "should support curly single quotes around titles w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “”)", &title_smart_quotes)?,
+        "<p><a href=\"b\">a</a></p>",
+        "should support empty titles in curly quotes w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “c\"d’e”)", &title_smart_quotes)?,
+        "<p><a href=\"b\" title=\"c&quot;d’e\">a</a></p>",
+        "should support other quotes in titles in curly quotes w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “c\")", &title_smart_quotes)?,
+        "<p>[a](b “c&quot;)</p>",
+        "should not close a curly double quote with a straight quote w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “c’)", &title_smart_quotes)?,
+        "<p>[a](b “c’)</p>",
+        "should not close a curly double quote with a curly single quote w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c”\")", &title_smart_quotes)?,
+        "<p><a href=\"b\" title=\"c”\">a</a></p>",
+        "should not close a straight quote with a curly quote w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b “c…”)", &title_smart_quotes)?,
+        "<p><a href=\"b\" title=\"c…\">a</a></p>",
+        "should support other multibyte characters in titles w/ `title_smart_quotes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b …)", &title_smart_quotes)?,
+        "<p>[a](b …)</p>",
+        "should not support other multibyte characters as markers w/ `title_smart_quotes`"
+    );
+
+    let title_balance_parens = Options {
+        parse: ParseOptions {
+            title_balance_parens: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b (c (d) e))"),
+        "<p>[a](b (c (d) e))</p>",
+        "should not support nested parens in titles by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c (d) e))", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c (d) e\">a</a></p>",
+        "should support balanced parens in titles w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c((d)) e)) f", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c((d)) e\">a</a> f</p>",
+        "should support deeply nested parens in titles w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c (d\ne) f))", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c (d\ne) f\">a</a></p>",
+        "should support balanced parens across lines w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c (d))", &title_balance_parens)?,
+        "<p>[a](b (c (d))</p>",
+        "should not support unbalanced parens in titles w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c\\(d))", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c(d\">a</a></p>",
+        "should not count escaped opening parens w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (c (d\\)) e))", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c (d)) e\">a</a></p>",
+        "should not count escaped closing parens w/ `title_balance_parens`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c(d\")", &title_balance_parens)?,
+        "<p><a href=\"b\" title=\"c(d\">a</a></p>",
+        "should not balance parens in quoted titles w/ `title_balance_parens`"
+    );
+
+    let title_size_max = Options {
+        parse: ParseOptions {
+            title_size_max: Some(2),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](b 'cd')", &title_size_max)?,
+        "<p><a href=\"b\" title=\"cd\">a</a></p>",
+        "should support titles up to `title_size_max`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b (cde))", &title_size_max)?,
+        "<p>[a](b (cde))</p>",
+        "should not support titles larger than `title_size_max`"
+    );
+
+    let external = Options {
+        compile: CompileOptions {
+            link_external_rel: Some("nofollow noopener".into()),
+            link_external_target: Some("_blank".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://b \"c\") [d](HTTP://e) [f](//g)", &external)?,
+        "<p><a href=\"https://b\" title=\"c\" rel=\"nofollow noopener\" target=\"_blank\">a</a> <a href=\"HTTP://e\" rel=\"nofollow noopener\" target=\"_blank\">d</a> <a href=\"//g\" rel=\"nofollow noopener\" target=\"_blank\">f</a></p>",
+        "should support `link_external_rel`, `link_external_target`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](./b) [c](/d) [e](#f) [g](mailto:h) [i]()", &external)?,
+        "<p><a href=\"./b\">a</a> <a href=\"/d\">c</a> <a href=\"#f\">e</a> <a href=\"mailto:h\">g</a> <a href=\"\">i</a></p>",
+        "should not add `link_external_rel`, `link_external_target` to other links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://b)", &external)?,
+        "<p><img src=\"https://b\" alt=\"a\" /></p>",
+        "should not add `link_external_rel`, `link_external_target` to images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][]\n\n[a]: https://b", &external)?,
+        "<p><a href=\"https://b\" rel=\"nofollow noopener\" target=\"_blank\">a</a></p>\n",
+        "should support `link_external_rel`, `link_external_target` w/ references"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b) [c](https://d)",
+            &Options {
+                compile: CompileOptions {
+                    link_external: Some(Box::new(|url| url != "https://b")),
+                    link_external_rel: Some("nofollow".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://b\">a</a> <a href=\"https://d\" rel=\"nofollow\">c</a></p>",
+        "should support `link_external`"
+    );
+
+    let base = Options {
+        compile: CompileOptions {
+            base_url: Some("https://example.com/a/b".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](c) [d](../e?f#g) [h](/i)", &base)?,
+        "<p><a href=\"https://example.com/a/c\">a</a> <a href=\"https://example.com/e?f#g\">d</a> <a href=\"https://example.com/i\">h</a></p>",
+        "should support `base_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#b) [c](//d) [e](mailto:f) [g](https://h)", &base)?,
+        "<p><a href=\"#b\">a</a> <a href=\"//d\">c</a> <a href=\"mailto:f\">e</a> <a href=\"https://h\">g</a></p>",
+        "should not resolve fragments, protocol-relative URLs, protocols w/ `base_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][] ![b][a]\n\n[a]: ./c", &base)?,
+        "<p><a href=\"https://example.com/a/c\">a</a> <img src=\"https://example.com/a/c\" alt=\"b\" /></p>\n",
+        "should support `base_url` w/ definitions"
+    );
+
     assert_eq!(
         to_mdast(
             "a [alpha]() b [bravo](charlie 'delta') c.",
@@ -512,5 +875,27 @@ fn link_resource() -> Result<(), String> {
         "should support link (resource) as `Link`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[![a](b)](c)", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    url: "c".into(),
+                    title: None,
+                    children: vec![Node::Image(Image {
+                        alt: "a".into(),
+                        url: "b".into(),
+                        title: None,
+                        position: Some(Position::new(1, 2, 1, 1, 9, 8))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+        }),
+        "should support an image at the start of a link in mdast"
+    );
+
     Ok(())
 }
@@ -3,9 +3,10 @@ use markdown::{
     mdast::{List, ListItem, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, ListMarkerChange, Options, ParseOptions, Warning,
 };
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn list() -> Result<(), String> {
@@ -109,6 +110,18 @@ fn list() -> Result<(), String> {
         "should support ordered item values starting w/ `0`s"
     );
 
+    assert_eq!(
+        to_html("000000001. ok"),
+        "<ol>\n<li>ok</li>\n</ol>",
+        "should not support start for ordered item values of `1` starting w/ `0`s"
+    );
+
+    assert_eq!(
+        to_html("3. a\n7. b"),
+        "<ol start=\"3\">\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should use the value of the first item as start, not later items"
+    );
+
     assert_eq!(
         to_html("-1. not ok"),
         "<p>-1. not ok</p>",
@@ -276,6 +289,36 @@ fn list() -> Result<(), String> {
         "should support a definition, followed by a lazy paragraph"
     );
 
+    assert_eq!(
+        to_html("- a\n  - b\nc"),
+        "<ul>\n<li>a\n<ul>\n<li>b\nc</li>\n</ul>\n</li>\n</ul>",
+        "should support lazy lines in sublists"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n# c"),
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>\n<h1>c</h1>",
+        "should not support lazy headings (atx) in sublists"
+    );
+
+    assert_eq!(
+        to_html("- a\n```\nb\n```"),
+        "<ul>\n<li>a</li>\n</ul>\n<pre><code>b\n</code></pre>",
+        "should not support lazy fenced code in lists"
+    );
+
+    assert_eq!(
+        to_html("- a\n==="),
+        "<ul>\n<li>a\n===</li>\n</ul>",
+        "should not support lazy setext heading underlines in lists"
+    );
+
+    assert_eq!(
+        to_html("- > a\nb\n- c"),
+        "<ul>\n<li>\n<blockquote>\n<p>a\nb</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>",
+        "should support lazy lines in block quotes in lists"
+    );
+
     assert_eq!(
         to_html("- foo\n  - bar\n    - baz\n      - boo"),
         "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>",
@@ -486,6 +529,36 @@ fn list() -> Result<(), String> {
         "should support loose lists w/ tight sublists (2)"
     );
 
+    assert_eq!(
+        to_html("- a\n- b\n\n\nc"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n<p>c</p>",
+        "should support tight lists w/ trailing blank lines"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n\n- b"),
+        "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ul>",
+        "should support loose lists w/ blank lines between items"
+    );
+
+    assert_eq!(
+        to_html("> - a\n>\n>\n> b"),
+        "<blockquote>\n<ul>\n<li>a</li>\n</ul>\n<p>b</p>\n</blockquote>",
+        "should support tight lists w/ trailing blank lines in block quotes"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n\n\n- c"),
+        "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n</ul>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>",
+        "should support loose lists w/ tight sublists (3)"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n\n    c\n- d"),
+        "<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>",
+        "should support tight lists w/ loose sublists"
+    );
+
     // Extra.
     assert_eq!(
         to_html("* a\n*\n\n  \n\t\n* b"),
@@ -572,6 +645,12 @@ fn list() -> Result<(), String> {
         "should support the common list breaking comment method"
     );
 
+    assert_eq!(
+        to_html("- a\n\nb\n2. c"),
+        "<ul>\n<li>a</li>\n</ul>\n<p>b\n2. c</p>",
+        "should not support a list starting at not 1 interrupting a paragraph after a list is closed"
+    );
+
     assert_eq!(
         to_html_with_options(
             "- one\n\n two",
@@ -590,6 +669,89 @@ fn list() -> Result<(), String> {
         "should support turning off lists"
     );
 
+    let join = Options {
+        parse: ParseOptions {
+            list_marker_change: ListMarkerChange::Join,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("- a\n* b"),
+        "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>",
+        "should start a new list when the marker changes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n* b", &join)?,
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should support `list_marker_change: ListMarkerChange::Join`"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. a\n2) b\n\n3. c", &join)?,
+        "<ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>",
+        "should join ordered items w/ different delimiters w/ `ListMarkerChange::Join`"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n1. b\n- c", &join)?,
+        "<ul>\n<li>a</li>\n</ul>\n<ol>\n<li>b</li>\n</ol>\n<ul>\n<li>c</li>\n</ul>",
+        "should not join ordered and unordered items w/ `ListMarkerChange::Join`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> - a\n>   + b\n>   * c\n> * d", &join)?,
+        "<blockquote>\n<ul>\n<li>a\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>d</li>\n</ul>\n</blockquote>",
+        "should join nested items in containers w/ `ListMarkerChange::Join`"
+    );
+
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            list_marker_change: ListMarkerChange::Warn,
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("- a\n* b\n\n1. c\n2) d\n- e", &warn)?,
+        "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>\n<ol>\n<li>c</li>\n</ol>\n<ol start=\"2\">\n<li>d</li>\n</ol>\n<ul>\n<li>e</li>\n</ul>",
+        "should start a new list w/ `ListMarkerChange::Warn`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "2:1: Unexpected list marker `*`, expected `-` to continue the list (a different marker starts a new list)",
+            "5:1: Unexpected list marker `)`, expected `.` to continue the list (a different marker starts a new list)"
+        ],
+        "should warn about changed markers w/ `ListMarkerChange::Warn`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "- a\n* b",
+            &Options {
+                parse: ParseOptions {
+                    warning_sink: Some(Box::new(move |warning: Warning| {
+                        panic!("unexpected warning: {}", warning);
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ul>\n<li>a</li>\n</ul>\n<ul>\n<li>b</li>\n</ul>",
+        "should not warn about changed markers by default"
+    );
+
     assert_eq!(
         to_mdast("* a", &Default::default())?,
         Node::Root(Root {
@@ -269,5 +269,18 @@ fn math_flow() -> Result<(), String> {
         "should support math (flow) as `Math`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("$$ a \t\nb\n$$", &math.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Math(Math {
+                meta: Some("a".into()),
+                value: "b".into(),
+                position: Some(Position::new(1, 1, 0, 3, 3, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 3, 11))
+        }),
+        "should support a meta w/ trailing whitespace in mdast"
+    );
+
     Ok(())
 }
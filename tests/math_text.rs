@@ -208,6 +208,43 @@ fn math_text() -> Result<(), String> {
         "should support an escaped initial dollar"
     );
 
+    assert_eq!(
+        to_html_with_options("`$a$`", &math)?,
+        "<p><code>$a$</code></p>",
+        "should not support math (text) in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$`a`$", &math)?,
+        "<p><code class=\"language-math math-inline\">`a`</code></p>",
+        "should not support code (text) in math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$5 and $6", &math)?,
+        "<p><code class=\"language-math math-inline\">5 and </code>6</p>",
+        "should support math (text) between “normal” dollars"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$5 and $6",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_text: true,
+                        ..Default::default()
+                    },
+                    math_text_single_dollar: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>$5 and $6</p>",
+        "should not support math (text) between “normal” dollars, w/ `math_text_single_dollar: false`"
+    );
+
     assert_eq!(
         to_mdast("a $alpha$ b.", &math.parse)?,
         Node::Root(Root {
@@ -4,7 +4,7 @@ use markdown::{
     mdast::{MdxjsEsm, Node, Root},
     to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    Constructs, MdxSignal, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 use test_utils::swc::{parse_esm, parse_expression};
@@ -94,13 +94,38 @@ fn mdx_esm() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("import a", &swc).err().unwrap(),
+        to_html_with_options(
+            "import a",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::mdx(),
+                    mdx_esm_parse: Some(Box::new(|_value| MdxSignal::Error("b".into(), 100))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )
+        .err()
+        .unwrap()
+        .to_string(),
+        "1:9: b",
+        "should crash at the current point if an error is outside the esm"
+    );
+
+    assert_eq!(
+        to_html_with_options("import a", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:9: Could not parse esm with swc: Expected ',', got '<eof>'",
         "should crash on invalid import/exports (1)"
     );
 
     assert_eq!(
-        to_html_with_options("import 1/1", &swc).err().unwrap(),
+        to_html_with_options("import 1/1", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:8: Could not parse esm with swc: Expected 'from', got 'numeric literal (1, 1)'",
         "should crash on invalid import/exports (2)"
     );
@@ -120,7 +145,8 @@ fn mdx_esm() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("import a from 'b'\n*md*?", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:6: Could not parse esm with swc: Unexpected token `?`. Expected this, import, async, function, [ for array literal, { for object literal, @ for decorator, function, class, null, true, false, number, bigint, string, regexp, ` for template literal, (, or an identifier",
         "should crash on markdown after import/export w/o blank line"
     );
@@ -134,7 +160,8 @@ fn mdx_esm() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("export var a = 1\nvar b\n\nc", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected statement in code: only import/exports are supported",
         "should crash on other statements in “blocks”"
     );
@@ -142,7 +169,8 @@ fn mdx_esm() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("import ('a')\n\nb", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:1: Unexpected statement in code: only import/exports are supported",
         "should crash on import-as-a-function with a space `import (x)`"
     );
@@ -260,3 +288,62 @@ fn mdx_esm() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn mdx_esm_agnostic() -> Result<(), String> {
+    let mdx = Options {
+        parse: ParseOptions::mdx(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("import a from 'b'\n\nc", &mdx)?,
+        "<p>c</p>",
+        "should support an import"
+    );
+
+    assert_eq!(
+        to_html_with_options("export default a\n\nb", &mdx)?,
+        "<p>b</p>",
+        "should support an export"
+    );
+
+    assert_eq!(
+        to_html_with_options("import a\n# b\n\nc", &mdx)?,
+        "<p>c</p>",
+        "should support markdown-like lines up to a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("import a\nexport {b\n\n}", &mdx)?,
+        "<p>}</p>",
+        "should not check the ESM (w/o blank lines)"
+    );
+
+    assert_eq!(
+        to_html_with_options(" import a", &mdx)?,
+        "<p>import a</p>",
+        "should not support an indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nimport b", &mdx)?,
+        "<p>a\nimport b</p>",
+        "should not support import/exports when interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_mdast("import a from 'b'\nexport {a}", &mdx.parse)?,
+        Node::Root(Root {
+            children: vec![Node::MdxjsEsm(MdxjsEsm {
+                value: "import a from 'b'\nexport {a}".into(),
+                position: Some(Position::new(1, 1, 0, 2, 11, 28)),
+                stops: vec![(0, 0), (17, 17), (18, 18)]
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 11, 28))
+        }),
+        "should support mdx esm as `MdxjsEsm`s in mdast"
+    );
+
+    Ok(())
+}
@@ -66,13 +66,16 @@ fn mdx_expression_flow_agnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("{a", &mdx).err().unwrap(),
+        to_html_with_options("{a", &mdx).err().unwrap().to_string(),
         "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (1)"
     );
 
     assert_eq!(
-        to_html_with_options("{b { c }", &mdx).err().unwrap(),
+        to_html_with_options("{b { c }", &mdx)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:9: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (2)"
     );
@@ -104,7 +107,8 @@ fn mdx_expression_flow_agnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> {a\nb}", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazyness (1)"
     );
@@ -124,7 +128,8 @@ fn mdx_expression_flow_agnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> {\n> a\nb}", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "3:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazyness (4)"
     );
@@ -170,13 +175,16 @@ fn mdx_expression_flow_gnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("{a", &swc).err().unwrap(),
+        to_html_with_options("{a", &swc).err().unwrap().to_string(),
         "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (1)"
     );
 
     assert_eq!(
-        to_html_with_options("{b { c }", &swc).err().unwrap(),
+        to_html_with_options("{b { c }", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:4: Could not parse expression with swc: Unexpected content after expression",
         "should crash if no closing brace is found (2)"
     );
@@ -239,37 +247,52 @@ fn mdx_expression_spread() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("<a {b} />", &swc).err().unwrap(),
+        to_html_with_options("<a {b} />", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:5: Expected a single spread value, such as `...x`",
         "should crash if not a spread"
     );
 
     assert_eq!(
-        to_html_with_options("<a {...?} />", &swc).err().unwrap(),
+        to_html_with_options("<a {...?} />", &swc).err().unwrap().to_string(),
         "1:13: Could not parse expression with swc: Unexpected token `?`. Expected this, import, async, function, [ for array literal, { for object literal, @ for decorator, function, class, null, true, false, number, bigint, string, regexp, ` for template literal, (, or an identifier",
         "should crash on an incorrect spread"
     );
 
     assert_eq!(
-        to_html_with_options("<a {...b,c} d>", &swc).err().unwrap(),
+        to_html_with_options("<a {...b,c} d>", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:5: Expected a single spread value, such as `...x`",
         "should crash if a spread and other things"
     );
 
     assert_eq!(
-        to_html_with_options("<a {} />", &swc).err().unwrap(),
+        to_html_with_options("<a {} />", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:5: Expected a single spread value, such as `...x`",
         "should crash on an empty spread"
     );
 
     assert_eq!(
-        to_html_with_options("<a {a=b} />", &swc).err().unwrap(),
+        to_html_with_options("<a {a=b} />", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:12: Could not parse expression with swc: assignment property is invalid syntax",
         "should crash if not an identifier"
     );
 
     assert_eq!(
-        to_html_with_options("<a {/* b */} />", &swc).err().unwrap(),
+        to_html_with_options("<a {/* b */} />", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:5: Expected a single spread value, such as `...x`",
         "should crash on a comment spread"
     );
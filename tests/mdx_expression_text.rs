@@ -64,13 +64,19 @@ fn mdx_expression_text_gnostic_core() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a {//} b", &swc).err().unwrap(),
+        to_html_with_options("a {//} b", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:4: Could not parse expression with swc: Unexpected eof",
         "should crash on an incorrect line comment (1)"
     );
 
     assert_eq!(
-        to_html_with_options("a { // b } c", &swc).err().unwrap(),
+        to_html_with_options("a { // b } c", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:4: Could not parse expression with swc: Unexpected eof",
         "should crash on an incorrect line comment (2)"
     );
@@ -118,7 +124,7 @@ fn mdx_expression_text_gnostic_core() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a {var b = \"c\"} d", &swc).err().unwrap(),
+        to_html_with_options("a {var b = \"c\"} d", &swc).err().unwrap().to_string(),
         "1:4: Could not parse expression with swc: Unexpected token `var`. Expected this, import, async, function, [ for array literal, { for object literal, @ for decorator, function, class, null, true, false, number, bigint, string, regexp, ` for template literal, (, or an identifier",
         "should crash on non-expressions"
     );
@@ -130,7 +136,10 @@ fn mdx_expression_text_gnostic_core() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("> a {\n> b<} c", &swc).err().unwrap(),
+        to_html_with_options("> a {\n> b<} c", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "2:8: Could not parse expression with swc: Unexpected eof",
         "should crash on incorrect expressions in containers (1)"
     );
@@ -138,7 +147,8 @@ fn mdx_expression_text_gnostic_core() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> a {\n> b\n> c} d", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "3:3: Could not parse expression with swc: Unexpected content after expression",
         "should crash on incorrect expressions in containers (2)"
     );
@@ -166,7 +176,10 @@ fn mdx_expression_text_agnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a {b c", &mdx).err().unwrap(),
+        to_html_with_options("a {b c", &mdx)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (1)"
     );
@@ -174,7 +187,8 @@ fn mdx_expression_text_agnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a {b { c } d", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (2)"
     );
@@ -245,7 +259,10 @@ fn mdx_expression_text_gnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a {??} b", &swc).err().unwrap(),
+        to_html_with_options("a {??} b", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:9: Could not parse expression with swc: Unexpected eof",
         "should crash on an incorrect expression"
     );
@@ -257,13 +274,19 @@ fn mdx_expression_text_gnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a {b c", &swc).err().unwrap(),
+        to_html_with_options("a {b c", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash if no closing brace is found (1)"
     );
 
     assert_eq!(
-        to_html_with_options("a {b { c } d", &swc).err().unwrap(),
+        to_html_with_options("a {b { c } d", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:6: Could not parse expression with swc: Unexpected content after expression",
         "should crash if no closing brace is found (2)"
     );
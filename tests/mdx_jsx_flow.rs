@@ -1,9 +1,12 @@
 extern crate markdown;
 use markdown::{
-    mdast::{List, ListItem, MdxJsxFlowElement, Node, Paragraph, Root, Text},
+    mdast::{
+        AttributeContent, AttributeValue, List, ListItem, MdxJsxAttribute, MdxJsxFlowElement, Node,
+        Paragraph, Root, Text,
+    },
     to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    Constructs, MarkdownError, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -59,6 +62,56 @@ fn mdx_jsx_flow_agnostic() -> Result<(), String> {
         "should support indented jsx if indented code is enabled"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "<a />",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        mdx_jsx_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        ),
+        Err(MarkdownError::InvalidOptions(
+            "Unexpected `html_flow` and `mdx_jsx_flow`, expected only one of them to be turned on, as both start with `<`".into()
+        )),
+        "should crash if html (flow) and jsx (flow) are both enabled"
+    );
+
+    assert_eq!(
+        to_html_with_options("< a />", &mdx)?,
+        "<p>&lt; a /&gt;</p>",
+        "should not support whitespace after `<`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "< a />",
+            &Options {
+                parse: ParseOptions {
+                    mdx_jsx_strict: true,
+                    ..ParseOptions::mdx()
+                },
+                ..Default::default()
+            }
+        )
+        .err()
+        .unwrap()
+        .to_string(),
+        "1:2: Unexpected character U+0020 after `<`, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a less than sign in MDX, use `&lt;` or `{'<'}`)",
+        "should crash on whitespace after `<` w/ `mdx_jsx_strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a", &mdx).err().unwrap().to_string(),
+        "1:3: Unexpected end of file after name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
+        "should crash on an unclosed tag at the end of the file"
+    );
+
     assert_eq!(
         to_html_with_options("<a></a>", &mdx)?,
         "",
@@ -129,13 +182,14 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("* <!a>\n1. b", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`)",
         "should handle crash in containers gracefully"
     );
 
     assert_eq!(
-        to_html_with_options("> <X\n/>", &mdx).err().unwrap(),
+        to_html_with_options("> <X\n/>", &mdx).err().unwrap().to_string(),
         "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (1)"
     );
@@ -143,7 +197,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> a\n> <X\n/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "3:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (2)"
     );
@@ -151,7 +206,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> <a b='\nc'/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (3)"
     );
@@ -159,7 +215,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> <a b='c\n'/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (4)"
     );
@@ -167,7 +224,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> <a b='c\nd'/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (5)"
     );
@@ -175,7 +233,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> <a b={c\nd}/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (6)"
     );
@@ -183,7 +242,8 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("> <a {b\nc}/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc",
         "should not support lazy flow (7)"
     );
@@ -225,5 +285,28 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
         "should support mdx jsx (flow) as `MdxJsxFlowElement`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("<a b=\"*c*\" d={*e*} />", &mdx.parse)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("a".into()),
+                attributes: vec![
+                    AttributeContent::Property(MdxJsxAttribute {
+                        name: "b".into(),
+                        value: Some(AttributeValue::Literal("*c*".into())),
+                    }),
+                    AttributeContent::Property(MdxJsxAttribute {
+                        name: "d".into(),
+                        value: Some(AttributeValue::Expression("*e*".into(), vec![(0, 14)])),
+                    }),
+                ],
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 1, 22, 21))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 22, 21))
+        }),
+        "should not support markdown in tags, w/o a parser for expressions"
+    );
+
     Ok(())
 }
@@ -416,7 +416,8 @@ fn mdx_jsx_text_core() -> Result<(), String> {
     assert_eq!(
         to_mdast("a </b> c", &mdx.parse)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:4: Unexpected closing slash `/` in tag, expected an open tag first (mdx-jsx:unexpected-closing-slash)",
         "should crash when building the ast on a closing tag if none is open"
     );
@@ -424,7 +425,8 @@ fn mdx_jsx_text_core() -> Result<(), String> {
     assert_eq!(
         to_mdast("a <b> c </b/> d", &mdx.parse)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:12: Unexpected self-closing slash `/` in closing tag, expected the end of the tag (mdx-jsx:unexpected-self-closing-slash)",
         "should crash when building the ast on a closing tag with a self-closing slash"
     );
@@ -432,7 +434,8 @@ fn mdx_jsx_text_core() -> Result<(), String> {
     assert_eq!(
         to_mdast("a <b> c </b d> e", &mdx.parse)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:13: Unexpected attribute in closing tag, expected the end of the tag (mdx-jsx:unexpected-attribute)",
         "should crash when building the ast on a closing tag with an attribute"
     );
@@ -440,7 +443,8 @@ fn mdx_jsx_text_core() -> Result<(), String> {
     assert_eq!(
         to_mdast("a <>b</c> d", &mdx.parse)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected closing tag `</c>`, expected corresponding closing tag for `<>` (1:3) (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched tags (1)"
     );
@@ -448,32 +452,33 @@ fn mdx_jsx_text_core() -> Result<(), String> {
     assert_eq!(
         to_mdast("a <b>c</> d", &mdx.parse)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected closing tag `</>`, expected corresponding closing tag for `<b>` (1:3) (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched tags (2)"
     );
 
     assert_eq!(
-        to_mdast("*a <b>c* d</b>.", &mdx.parse).err().unwrap(),
+        to_mdast("*a <b>c* d</b>.", &mdx.parse).err().unwrap().to_string(),
         "1:9: Expected a closing tag for `<b>` (1:4) before the end of `Emphasis` (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched interleaving (1)"
     );
 
     assert_eq!(
-        to_mdast("<a>b *c</a> d*.", &mdx.parse).err().unwrap(),
+        to_mdast("<a>b *c</a> d*.", &mdx.parse).err().unwrap().to_string(),
         "1:8: Expected the closing tag `</a>` either before the start of `Emphasis` (1:6), or another opening tag after that start (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched interleaving (2)"
     );
 
     assert_eq!(
-        to_mdast("a <b>.", &mdx.parse).err().unwrap(),
+        to_mdast("a <b>.", &mdx.parse).err().unwrap().to_string(),
         "1:7: Expected a closing tag for `<b>` (1:3) before the end of `Paragraph` (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched interleaving (3)"
     );
 
     // Note: this is flow, not text.
     assert_eq!(
-        to_mdast("<a>", &mdx.parse).err().unwrap(),
+        to_mdast("<a>", &mdx.parse).err().unwrap().to_string(),
         "1:4: Expected a closing tag for `<a>` (1:1) (mdx-jsx:end-tag-mismatch)",
         "should crash when building the ast on mismatched interleaving (4)"
     );
@@ -518,6 +523,47 @@ fn mdx_jsx_text_agnosic() -> Result<(), String> {
         "should support an attribute value expression"
     );
 
+    assert_eq!(
+        to_html_with_options("a <b", &mdx)
+            .err()
+            .unwrap()
+            .to_string(),
+        "1:5: Unexpected end of file after name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
+        "should crash on an unclosed tag at the end of the file"
+    );
+
+    let strict = Options {
+        parse: ParseOptions {
+            mdx_jsx_strict: true,
+            ..ParseOptions::mdx()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a < b", &strict)
+            .err()
+            .unwrap()
+            .to_string(),
+        "1:4: Unexpected character U+0020 after `<`, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a less than sign in MDX, use `&lt;` or `{'<'}`)",
+        "should crash on whitespace after `<` w/ `mdx_jsx_strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <", &strict)
+            .err()
+            .unwrap()
+            .to_string(),
+        "1:4: Unexpected end of file after `<`, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a less than sign in MDX, use `&lt;` or `{'<'}`)",
+        "should crash on the end of the file after `<` w/ `mdx_jsx_strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a &lt; b {'<'} c", &strict)?,
+        "<p>a &lt; b  c</p>",
+        "should support character references and expressions for `<` w/ `mdx_jsx_strict`"
+    );
+
     Ok(())
 }
 
@@ -576,7 +622,10 @@ fn mdx_jsx_text_gnostic() -> Result<(), String> {
     );
 
     assert_eq!(
-        to_html_with_options("a <b c={} /> d", &swc).err().unwrap(),
+        to_html_with_options("a <b c={} /> d", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
         "1:15: Could not parse expression with swc: Unexpected eof",
         "should crash on an empty attribute value expression"
     );
@@ -584,7 +633,8 @@ fn mdx_jsx_text_gnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b {1 + 1} /> c", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:18: Could not parse expression with swc: Expected ',', got '}'",
         "should crash on a non-spread attribute expression"
     );
@@ -592,7 +642,8 @@ fn mdx_jsx_text_gnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b c={?} /> d", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:16: Could not parse expression with swc: Unexpected token `?`. Expected this, import, async, function, [ for array literal, { for object literal, @ for decorator, function, class, null, true, false, number, bigint, string, regexp, ` for template literal, (, or an identifier",
         "should crash on invalid JS in an attribute value expression"
     );
@@ -600,7 +651,8 @@ fn mdx_jsx_text_gnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b {?} /> c", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:14: Could not parse expression with swc: Unexpected token `?`. Expected identifier, string literal, numeric literal or [ for the computed key",
         "should crash on invalid JS in an attribute expression"
     );
@@ -608,7 +660,8 @@ fn mdx_jsx_text_gnostic() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b{c=d}={}/> f", &swc)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Expected a single spread value, such as `...x`",
         "should crash on invalid JS in an attribute expression (2)"
     );
@@ -656,7 +709,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <!> b", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`)",
         "should crash on a nonconforming start identifier"
     );
@@ -664,7 +718,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a </(> b.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character `(` (U+0028) before name, expected a character that can start a name, such as a letter, `$`, or `_`",
         "should crash on a nonconforming start identifier in a closing tag"
     );
@@ -678,7 +733,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <© /> b.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:4: Unexpected character U+00A9 before name, expected a character that can start a name, such as a letter, `$`, or `_`",
         "should crash on non-conforming non-ascii identifier start characters"
     );
@@ -686,7 +742,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <!--b-->", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`)",
         "should crash nicely on what might be a comment"
     );
@@ -694,7 +751,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <// b\nc/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character `/` (U+002F) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: JS comments in JSX tags are not supported in MDX)",
         "should crash nicely on JS line comments inside tags (1)"
     );
@@ -702,7 +760,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b// c\nd/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `/` (U+002F) after self-closing slash, expected `>` to end the tag (note: JS comments in JSX tags are not supported in MDX)",
         "should crash nicely JS line comments inside tags (2)"
     );
@@ -710,7 +769,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a </*b*/c>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character `*` (U+002A) before name, expected a character that can start a name, such as a letter, `$`, or `_`",
         "should crash nicely JS multiline comments inside tags (1)"
     );
@@ -718,7 +778,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b/*c*/>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `*` (U+002A) after self-closing slash, expected `>` to end the tag",
         "should crash nicely JS multiline comments inside tags (2)"
     );
@@ -732,7 +793,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a¬ /> b.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character U+00AC in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on non-conforming non-ascii identifier continuation characters"
     );
@@ -740,7 +802,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b@c.d>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character `@` (U+0040) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`)",
         "should crash nicely on what might be an email link"
     );
@@ -754,7 +817,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a?> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:5: Unexpected character `?` (U+003F) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on nonconforming identifier continuation characters"
     );
@@ -768,7 +832,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b.c@d.e>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected character `@` (U+0040) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`)",
         "should crash nicely on what might be an email link in member names"
     );
@@ -782,7 +847,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a:+> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `+` (U+002B) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`)",
         "should crash on a nonconforming character to start a local name"
     );
@@ -790,7 +856,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <http://example.com>", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:9: Unexpected character `/` (U+002F) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`)",
         "should crash nicely on what might be a protocol in local names"
     );
@@ -798,7 +865,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <http: >", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:10: Unexpected character `>` (U+003E) before local name, expected a character that can start a name, such as a letter, `$`, or `_`",
         "should crash nicely on what might be a protocol in local names"
     );
@@ -806,7 +874,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a:b|> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected character `|` (U+007C) in local name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character in a local name"
     );
@@ -814,7 +883,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a..> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `.` (U+002E) before member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character to start a member name"
     );
@@ -822,7 +892,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a.b,> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected character `,` (U+002C) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character in a member name"
     );
@@ -830,7 +901,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a:b .> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `.` (U+002E) after local name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character after a local name"
     );
@@ -838,7 +910,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a.b :> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `:` (U+003A) after member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character after a member name"
     );
@@ -846,7 +919,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a => c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `=` (U+003D) after name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character after name"
     );
@@ -908,7 +982,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b {...p}~>c</b>.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:12: Unexpected character `~` (U+007E) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character before an attribute name"
     );
@@ -916,7 +991,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <b {...", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:10: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash on a missing closing brace in attribute expression"
     );
@@ -924,7 +1000,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b@> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:7: Unexpected character `@` (U+0040) in attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character in attribute name"
     );
@@ -944,7 +1021,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b 1> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `1` (U+0031) after attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag",
         "should crash on a nonconforming character after an attribute name"
     );
@@ -952,7 +1030,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b:#> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `#` (U+0023) before local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag",
         "should crash on a nonconforming character to start a local attribute name"
     );
@@ -960,7 +1039,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b:c%> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:9: Unexpected character `%` (U+0025) in local attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character in a local attribute name"
     );
@@ -968,7 +1048,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b:c ^> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:10: Unexpected character `^` (U+005E) after local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag",
         "should crash on a nonconforming character after a local attribute name"
     );
@@ -988,7 +1069,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b=``> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `` ` `` (U+0060) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{`",
         "should crash on a nonconforming character before an attribute value"
     );
@@ -996,7 +1078,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b=<c />> d.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:8: Unexpected character `<` (U+003C) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{` (note: to use an element or fragment as a prop value in MDX, use `{<element />}`)",
         "should crash nicely on what might be a fragment, element as prop value"
     );
@@ -1004,7 +1087,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b=\"> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022)",
         "should crash on a missing closing quote in double quoted attribute value"
     );
@@ -1012,7 +1096,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b=\"> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022)",
         "should crash on a missing closing quote in single quoted attribute value"
     );
@@ -1020,7 +1105,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b={> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{`",
         "should crash on a missing closing brace in an attribute value expression"
     );
@@ -1028,7 +1114,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a b=\"\"*> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:10: Unexpected character `*` (U+002A) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag",
         "should crash on a nonconforming character after an attribute value"
     );
@@ -1048,7 +1135,8 @@ fn mdx_jsx_text_complete() -> Result<(), String> {
     assert_eq!(
         to_html_with_options("a <a/b> c.", &mdx)
             .err()
-            .unwrap(),
+            .unwrap()
+            .to_string(),
         "1:6: Unexpected character `b` (U+0062) after self-closing slash, expected `>` to end the tag",
         "should crash on a nonconforming character after a self-closing slash"
     );
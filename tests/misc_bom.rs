@@ -1,9 +1,14 @@
 extern crate markdown;
-use markdown::to_html;
+use markdown::{
+    mdast::{Heading, Node, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Options, ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn bom() {
+fn bom() -> Result<(), String> {
     assert_eq!(to_html("\u{FEFF}"), "", "should ignore just a bom");
 
     assert_eq!(
@@ -11,4 +16,85 @@ fn bom() {
         "<h1>hea\u{FEFF}ding</h1>",
         "should ignore a bom"
     );
+
+    assert_eq!(
+        to_html("\u{FEFF}> a\n> b"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should support a container after a bom"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}\ta"),
+        "<pre><code>a\n</code></pre>",
+        "should not count a bom for tab stops (1)"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}>\t\ta"),
+        "<blockquote>\n<pre><code>  a\n</code></pre>\n</blockquote>",
+        "should not count a bom for tab stops (2)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "\u{FEFF}import a from 'b'\n\n# c",
+            &Options {
+                parse: ParseOptions::mdx(),
+                ..Default::default()
+            }
+        )?,
+        "<h1>c</h1>",
+        "should support ESM after a bom"
+    );
+
+    assert_eq!(
+        to_mdast("\u{FEFF}# a", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 1,
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 3, 5, 1, 4, 6))
+                })],
+                position: Some(Position::new(1, 1, 3, 1, 4, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 4, 6))
+        }),
+        "should start at column 1 after a bom (mdast)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bom_unicode_whitespace() {
+    assert_eq!(
+        to_html("\u{A0}a"),
+        "<p>\u{A0}a</p>",
+        "should keep a no-break space at the start of a paragraph"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}\u{A0}a"),
+        "<p>\u{A0}a</p>",
+        "should keep a no-break space after a bom"
+    );
+
+    assert_eq!(
+        to_html("\u{A0}# a"),
+        "<p>\u{A0}# a</p>",
+        "should not count a no-break space as indentation (1)"
+    );
+
+    assert_eq!(
+        to_html("\u{A0}\u{A0}\u{A0}\u{A0}a"),
+        "<p>\u{A0}\u{A0}\u{A0}\u{A0}a</p>",
+        "should not count a no-break space as indentation (2)"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}*\u{A0}a*"),
+        "<p>*\u{A0}a*</p>",
+        "should count a no-break space as whitespace for emphasis"
+    );
 }
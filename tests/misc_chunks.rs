@@ -0,0 +1,99 @@
+extern crate markdown;
+use markdown::{parse, parse_chunks, Event, EventContent, EventKind, EventName, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Get the content type and joined bytes of each chain of chunks in `value`.
+fn chains(value: &str) -> Result<Vec<(EventContent, String)>, String> {
+    let events = parse_chunks(value, &ParseOptions::default())?;
+    let mut result = vec![];
+
+    for (index, event) in events.iter().enumerate() {
+        if let Some(link) = &event.link {
+            if link.previous.is_none() {
+                let mut joined = String::new();
+                let mut current = Some(index);
+
+                while let Some(index) = current {
+                    joined.push_str(chunk(value, &events, index));
+                    current = events[index].link.as_ref().unwrap().next;
+                }
+
+                result.push((link.content.clone(), joined));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Get the bytes of the chunk that enters at `index`.
+fn chunk<'a>(value: &'a str, events: &[Event], index: usize) -> &'a str {
+    &value[events[index].point.index..events[index + 1].point.index]
+}
+
+#[test]
+fn chunks() -> Result<(), String> {
+    assert_eq!(
+        chains("*a*\nb")?,
+        vec![(EventContent::Text, "*a*\nb".into())],
+        "should link the lines of a paragraph"
+    );
+
+    assert_eq!(
+        chains("> a\n> b")?,
+        vec![(EventContent::Text, "a\nb".into())],
+        "should skip container prefixes between chunks"
+    );
+
+    assert_eq!(
+        chains("- a\n  b")?,
+        vec![(EventContent::Text, "a\nb".into())],
+        "should skip indent between chunks"
+    );
+
+    assert_eq!(
+        chains("[a]: b \"c\nd\"")?,
+        vec![
+            (EventContent::String, "a".into()),
+            (EventContent::String, "b".into()),
+            (EventContent::String, "c\nd".into())
+        ],
+        "should link the parts of a definition, with line endings as chunks"
+    );
+
+    assert_eq!(
+        chains("# a *b*\n\n```js c\n```")?,
+        vec![
+            (EventContent::Text, "a *b*".into()),
+            (EventContent::String, "js".into()),
+            (EventContent::String, "c".into())
+        ],
+        "should link headings and the info and meta of fenced code"
+    );
+
+    let events = parse_chunks("a\n\n[a]: b", &ParseOptions::default())?;
+
+    assert!(
+        events
+            .iter()
+            .any(|d| d.kind == EventKind::Enter && d.name == EventName::Definition),
+        "should parse flow and content"
+    );
+
+    assert!(
+        events.iter().enumerate().all(|(index, d)| d.link.is_none()
+            || (d.kind == EventKind::Enter
+                && events[index + 1].kind == EventKind::Exit
+                && events[index + 1].name == d.name)),
+        "should only link void enter events"
+    );
+
+    assert!(
+        parse("*a*\nb", &ParseOptions::default())?
+            .iter()
+            .all(|d| d.link.is_none()),
+        "should not have links in `parse`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+extern crate markdown;
+use markdown::{count_words, Counts, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn count() -> Result<(), String> {
+    let default = ParseOptions::default();
+
+    assert_eq!(
+        count_words("", &default)?,
+        Counts::default(),
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        count_words("a b\nc", &default)?,
+        Counts {
+            words: 3,
+            chars: 3,
+            cjk_chars: 0
+        },
+        "should count words between whitespace and line endings"
+    );
+
+    assert_eq!(
+        count_words("# a *b* **c** d", &default)?,
+        Counts {
+            words: 4,
+            chars: 4,
+            cjk_chars: 0
+        },
+        "should not count markers"
+    );
+
+    assert_eq!(
+        count_words("a*b*c", &default)?,
+        Counts {
+            words: 1,
+            chars: 3,
+            cjk_chars: 0
+        },
+        "should count a word across markers"
+    );
+
+    assert_eq!(
+        count_words("it’s a - b!", &default)?,
+        Counts {
+            words: 3,
+            chars: 8,
+            cjk_chars: 0
+        },
+        "should not count punctuation by itself as a word"
+    );
+
+    assert_eq!(
+        count_words(
+            "[a](https://b.c \"d\") ![e](f) [g][h] <https://i.j>\n\n[h]: k",
+            &default
+        )?,
+        Counts {
+            words: 3,
+            chars: 3,
+            cjk_chars: 0
+        },
+        "should not count destinations, titles, references, autolinks, or definitions"
+    );
+
+    assert_eq!(
+        count_words("a `b c` d\n\n```e f\ng\n```\n\n    h", &default)?,
+        Counts {
+            words: 4,
+            chars: 4,
+            cjk_chars: 0
+        },
+        "should count code (text), but not code (flow)"
+    );
+
+    assert_eq!(
+        count_words("a <b c=\"d\">e</b>\n\n<div>\nf\n</div>", &default)?,
+        Counts {
+            words: 2,
+            chars: 2,
+            cjk_chars: 0
+        },
+        "should not count HTML"
+    );
+
+    assert_eq!(
+        count_words("a&amp;b \\* &#x41;", &default)?,
+        Counts {
+            words: 2,
+            chars: 5,
+            cjk_chars: 0
+        },
+        "should count character escapes and references as what they represent"
+    );
+
+    assert_eq!(
+        count_words("中文 and 日本語、かな", &default)?,
+        Counts {
+            words: 8,
+            chars: 11,
+            cjk_chars: 7
+        },
+        "should count CJK characters as words"
+    );
+
+    assert_eq!(
+        count_words("| a | b |\n| - | - |\n|c|d|", &ParseOptions::gfm())?,
+        Counts {
+            words: 4,
+            chars: 4,
+            cjk_chars: 0
+        },
+        "should count table cells as separate words (gfm)"
+    );
+
+    assert_eq!(
+        count_words("a[^b] www.c.com\n\n[^b]: d", &ParseOptions::gfm())?,
+        Counts {
+            words: 2,
+            chars: 2,
+            cjk_chars: 0
+        },
+        "should not count footnote labels or autolink literals (gfm)"
+    );
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 extern crate markdown;
-use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use markdown::{
+    to_html, to_html_with_options, unist::Position, CompileOptions, Constructs, Options,
+    ParseOptions,
+};
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn dangerous_html() -> Result<(), String> {
@@ -31,5 +35,154 @@ fn dangerous_html() -> Result<(), String> {
         "should be unsafe w/ `allowDangerousHtml`"
     );
 
+    let positions = Rc::new(RefCell::new(vec![]));
+    let sink = positions.clone();
+    let report = Options {
+        compile: CompileOptions {
+            dangerous_html_sink: Some(Box::new(move |position: Position| {
+                sink.borrow_mut().push(position);
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>
+a
+</div>
+
+b <i>c</i>",
+            &report
+        )?,
+        "&lt;div&gt;
+a
+&lt;/div&gt;
+<p>b &lt;i&gt;c&lt;/i&gt;</p>",
+        "should support `dangerous_html_sink`"
+    );
+
+    assert_eq!(
+        positions.take(),
+        vec![
+            Position::new(1, 1, 0, 3, 7, 14),
+            Position::new(5, 3, 18, 5, 6, 21),
+            Position::new(5, 7, 22, 5, 11, 26)
+        ],
+        "should call `dangerous_html_sink` w/ the positions of HTML (flow, text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a `<b>` c", &report)?,
+        "<p>a <code>&lt;b&gt;</code> c</p>",
+        "should support `dangerous_html_sink` w/o HTML"
+    );
+
+    assert_eq!(
+        positions.take(),
+        vec![],
+        "should not call `dangerous_html_sink` w/o HTML"
+    );
+
+    let sink = positions.clone();
+
+    assert_eq!(
+        to_html_with_options(
+            "<!-- a -->\n\n<div>\n<!-- b -->\n</div>\n\nc <!-- d --> <i>",
+            &Options {
+                compile: CompileOptions {
+                    dangerous_html_sink: Some(Box::new(move |position: Position| {
+                        sink.borrow_mut().push(position);
+                    })),
+                    strip_comments: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "&lt;div&gt;\n&lt;/div&gt;\n<p>c  &lt;i&gt;</p>",
+        "should support `dangerous_html_sink` w/ `strip_comments`"
+    );
+
+    assert_eq!(
+        positions.take(),
+        vec![
+            Position::new(3, 1, 12, 5, 7, 35),
+            Position::new(7, 14, 50, 7, 17, 53)
+        ],
+        "should not call `dangerous_html_sink` w/ comments that are dropped"
+    );
+
+    let sink = positions.clone();
+
+    assert_eq!(
+        to_html_with_options(
+            "<x>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    dangerous_html_sink: Some(Box::new(move |position: Position| {
+                        sink.borrow_mut().push(position);
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<x>",
+        "should support `dangerous_html_sink` w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        positions.take(),
+        vec![],
+        "should not call `dangerous_html_sink` w/ `allow_dangerous_html`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn no_html() -> Result<(), String> {
+    let no_html = &Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                html_flow: false,
+                html_text: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("<div>\n*a*\n\n</div>", no_html)?,
+        "<p>&lt;div&gt;\n<em>a</em></p>\n<p>&lt;/div&gt;</p>",
+        "should treat HTML (flow) as text w/o HTML constructs, even w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<div>", no_html)?,
+        "<p>a\n&lt;div&gt;</p>",
+        "should not interrupt paragraphs w/o HTML constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <b c=\"d\">e</b> &amp; <!-- f -->", no_html)?,
+        "<p>a &lt;b c=&quot;d&quot;&gt;e&lt;/b&gt; &amp; &lt;!-- f --&gt;</p>",
+        "should escape HTML (text) w/o HTML constructs, even w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b> <c@d.e>", no_html)?,
+        "<p><a href=\"https://a.b\">https://a.b</a> <a href=\"mailto:c@d.e\">c@d.e</a></p>",
+        "should support autolinks w/o HTML constructs"
+    );
+
     Ok(())
 }
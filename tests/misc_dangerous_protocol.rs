@@ -1,5 +1,5 @@
 extern crate markdown;
-use markdown::to_html;
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -196,3 +196,68 @@ fn dangerous_protocol_link() {
         "should allow a colon in a path"
     );
 }
+
+#[test]
+fn dangerous_protocol_allowed_schemes() -> Result<(), String> {
+    let allowed = Options {
+        compile: CompileOptions {
+            allowed_schemes: Some(vec!["https".into(), "DATA".into()]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://b) [c](HTTPS://d)", &allowed)?,
+        "<p><a href=\"https://b\">a</a> <a href=\"HTTPS://d\">c</a></p>",
+        "should allow schemes in `allowed_schemes`, case-insensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](http://b) [c](javascript:alert(1))", &allowed)?,
+        "<p><a href=\"#\">a</a> <a href=\"#\">c</a></p>",
+        "should use `#` for links w/ other schemes"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](data:b) ![c](http://d)", &allowed)?,
+        "<p><img src=\"data:b\" alt=\"a\" /> <img src=\"\" alt=\"c\" /></p>",
+        "should support `allowed_schemes` for images, also w/ dangerous schemes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: vbscript:b\n\n[a]", &allowed)?,
+        "<p><a href=\"#\">a</a></p>",
+        "should support `allowed_schemes` for references"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a> <javascript:b> <c@d.e>", &allowed)?,
+        "<p><a href=\"https://a\">https://a</a> javascript:b c@d.e</p>",
+        "should show autolinks w/ other schemes as text"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](./b) [c](/d:e) [f](#g) [h](?i)", &allowed)?,
+        "<p><a href=\"./b\">a</a> <a href=\"/d:e\">c</a> <a href=\"#g\">f</a> <a href=\"?i\">h</a></p>",
+        "should always allow URLs w/o scheme"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](javascript:b) www.c.d",
+            &Options {
+                parse: markdown::ParseOptions::gfm(),
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    allowed_schemes: Some(vec!["https".into()]),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<p><a href=\"#\">a</a> www.c.d</p>",
+        "should prefer `allowed_schemes` over `allow_dangerous_protocol`"
+    );
+
+    Ok(())
+}
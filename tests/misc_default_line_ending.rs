@@ -62,3 +62,63 @@ fn default_line_ending() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn normalize_line_endings() -> Result<(), String> {
+    let lf = &Options {
+        compile: CompileOptions {
+            normalize_line_endings: Some(LineEnding::LineFeed),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let crlf = &Options {
+        compile: CompileOptions {
+            normalize_line_endings: Some(LineEnding::CarriageReturnLineFeed),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\r\nb\n\n> c\r\n\r\n- d\r- e"),
+        "<p>a\r\nb</p>\n<blockquote>\r\n<p>c</p>\r\n</blockquote>\r\n<ul>\r\n<li>d</li>\r<li>e</li>\r\n</ul>",
+        "should keep mixed line endings by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r\nb\n\n> c\r\n\r\n- d\r- e", lf)?,
+        "<p>a\nb</p>\n<blockquote>\n<p>c</p>\n</blockquote>\n<ul>\n<li>d</li>\n<li>e</li>\n</ul>",
+        "should support normalizing mixed line endings to `\\n`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n\n> c\n", crlf)?,
+        "<p>a\r\nb</p>\r\n<blockquote>\r\n<p>c</p>\r\n</blockquote>\r\n",
+        "should support normalizing line endings to `\\r\\n`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\r\na\rb\n```\r\n", lf)?,
+        "<pre><code>a\nb\n</code></pre>\n",
+        "should normalize line endings in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> a",
+            &Options {
+                compile: CompileOptions {
+                    default_line_ending: LineEnding::CarriageReturn,
+                    normalize_line_endings: Some(LineEnding::LineFeed),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should prefer `normalize_line_endings` over `default_line_ending`"
+    );
+
+    Ok(())
+}
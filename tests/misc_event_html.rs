@@ -0,0 +1,263 @@
+extern crate markdown;
+use markdown::{to_html_with_options, CompileOptions, EventKind, EventName, EventOutput, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn event_html() -> Result<(), String> {
+    let italic = Options {
+        compile: CompileOptions {
+            event_html: Some(Box::new(|kind, name, _value, _buffer, _html| {
+                match (kind, name) {
+                    (EventKind::Enter, EventName::Emphasis) => Some(EventOutput::Raw("<i>".into())),
+                    (EventKind::Exit, EventName::Emphasis) => Some(EventOutput::Raw("</i>".into())),
+                    _ => None,
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("*a* **b**", &italic)?,
+        "<p><i>a</i> <strong>b</strong></p>",
+        "should support `event_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![*a*](b)", &italic)?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should not generate HTML in image alt text"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a *b* c",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|_kind, _name, _value, _buffer, _html| None)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <em>b</em> c</p>",
+        "should use the default when `None` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a `b<c` d",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, value, _buffer, _html| {
+                        match (kind, name) {
+                            (EventKind::Enter, EventName::CodeText) => Some(EventOutput::Raw(
+                                format!("<kbd>{}", value.trim_matches('`').replace('<', "&lt;")),
+                            )),
+                            (EventKind::Exit, EventName::CodeText) => {
+                                Some(EventOutput::Raw("</kbd>".into()))
+                            }
+                            (_, EventName::CodeTextData | EventName::CodeTextSequence) => {
+                                Some(EventOutput::Raw(String::new()))
+                            }
+                            _ => None,
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <kbd>b&lt;c</kbd> d</p>",
+        "should pass the markdown of the thing"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a*",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, _value, _buffer, _html| {
+                        match (kind, name) {
+                            (EventKind::Enter, EventName::Emphasis) => {
+                                Some(EventOutput::Escaped("<".into()))
+                            }
+                            (EventKind::Exit, EventName::Emphasis) => {
+                                Some(EventOutput::Escaped(">".into()))
+                            }
+                            _ => None,
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;a&gt;</p>",
+        "should encode `EventOutput::Escaped`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a *b*",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, _value, buffer, _html| {
+                        if *kind == EventKind::Enter && *name == EventName::Emphasis {
+                            Some(EventOutput::Escaped(format!("[{}]", buffer)))
+                        } else {
+                            None
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a [&lt;p&gt;a ]b</em></p>",
+        "should pass the current buffer"
+    );
+
+    let link = Options {
+        compile: CompileOptions {
+            event_html: Some(Box::new(|kind, name, _value, _buffer, html| {
+                match (kind, name) {
+                    (EventKind::Exit, EventName::Link) => Some(EventOutput::Raw(format!(
+                        "<span>{}</span>",
+                        html.replacen("<a ", "<a class=\"x\" ", 1)
+                    ))),
+                    _ => None,
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a *b*](c)", &link)?,
+        "<p><span><a class=\"x\" href=\"c\">a <em>b</em></a></span></p>",
+        "should pass the default HTML (links)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a *b*](c)",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, _value, _buffer, html| {
+                        match (kind, name) {
+                            (EventKind::Exit, EventName::Image) => {
+                                Some(EventOutput::Raw(format!("<figure>{}</figure>", html)))
+                            }
+                            (_, EventName::Emphasis) => Some(EventOutput::Raw("!".into())),
+                            _ => None,
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><figure><img src=\"c\" alt=\"a b\" /></figure></p>",
+        "should pass the default HTML (images)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a\n\nb\n=\n\n## c",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, _value, _buffer, html| {
+                        match (kind, name) {
+                            (
+                                EventKind::Exit,
+                                EventName::HeadingAtx
+                                | EventName::HeadingAtxSequence
+                                | EventName::HeadingSetextUnderlineSequence,
+                            ) => Some(EventOutput::Raw(html.replace("h1>", "h2>"))),
+                            _ => None,
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h2>a</h2>\n<h2>b</h2>\n<h2>c</h2>",
+        "should pass the default HTML (headings)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```js\nb\n```",
+            &Options {
+                compile: CompileOptions {
+                    event_html: Some(Box::new(|kind, name, _value, _buffer, html| {
+                        match (kind, name) {
+                            (EventKind::Enter, EventName::CodeFenced) => {
+                                Some(EventOutput::Raw(html.replace("<pre>", "<pre class=\"x\">")))
+                            }
+                            _ => None,
+                        }
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre class=\"x\"><code class=\"language-js\">b\n</code></pre>",
+        "should pass the default HTML (fenced code)"
+    );
+
+    let list = Options {
+        compile: CompileOptions {
+            event_html: Some(Box::new(|kind, name, _value, _buffer, html| {
+                match (kind, name) {
+                    (EventKind::Enter, EventName::ListUnordered) => {
+                        Some(EventOutput::Raw(format!("<div>{}", html)))
+                    }
+                    (EventKind::Exit, EventName::ListUnordered) => {
+                        Some(EventOutput::Raw(format!("{}</div>", html)))
+                    }
+                    (EventKind::Enter, EventName::ListItemMarker) => {
+                        Some(EventOutput::Raw(html.replace("<li>", "<li class=\"x\">")))
+                    }
+                    _ => None,
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("- a\n- b", &list)?,
+        "<div><ul>\n<li class=\"x\">a</li>\n<li class=\"x\">b</li>\n</ul></div>",
+        "should pass the default HTML (lists)"
+    );
+
+    let list_end = Options {
+        compile: CompileOptions {
+            event_html: Some(Box::new(|kind, name, _value, _buffer, _html| {
+                match (kind, name) {
+                    (EventKind::Exit, EventName::ListUnordered) => {
+                        Some(EventOutput::Raw("\n</menu>".into()))
+                    }
+                    _ => None,
+                }
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("- a\n\n  - b\n  - c\n\n  d", &list_end)?,
+        "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</menu>\n<p>d</p>\n</li>\n</menu>",
+        "should keep whether lists are tight when their events are replaced"
+    );
+
+    Ok(())
+}
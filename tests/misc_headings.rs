@@ -0,0 +1,127 @@
+extern crate markdown;
+use markdown::{
+    headings, to_html_with_options, CompileOptions, HeadingAnchor, HeadingInfo, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Make a heading.
+fn heading(level: u8, text: &str, id: &str) -> HeadingInfo {
+    HeadingInfo {
+        level,
+        text: text.into(),
+        id: id.into(),
+    }
+}
+
+#[test]
+fn headings_toc() -> Result<(), String> {
+    let default = ParseOptions::default();
+
+    assert_eq!(
+        headings("a\n\n> b", &default)?,
+        vec![],
+        "should support a document w/o headings"
+    );
+
+    assert_eq!(
+        headings("# a\n\n###### b\n\nc\n=\n\nd\n-", &default)?,
+        vec![
+            heading(1, "a", "a"),
+            heading(6, "b", "b"),
+            heading(1, "c", "c"),
+            heading(2, "d", "d")
+        ],
+        "should support ATX and setext headings, in order"
+    );
+
+    assert_eq!(
+        headings("## a *b* `c` [d](e) ![f](g) <h> &amp; ##", &default)?,
+        vec![heading(2, "a b c d   &", "a-b-c-d---")],
+        "should flatten formatting in the text"
+    );
+
+    assert_eq!(
+        headings("# a\n# a\n# A", &default)?,
+        vec![
+            heading(1, "a", "a"),
+            heading(1, "a", "a-1"),
+            heading(1, "A", "a-2")
+        ],
+        "should make ids unique"
+    );
+
+    assert_eq!(
+        headings(&"# a\n".repeat(10_000), &default)?
+            .last()
+            .map(|d| d.id.clone()),
+        Some("a-9999".into()),
+        "should make ids unique w/ many duplicates"
+    );
+
+    assert_eq!(
+        headings("#\n\n# !", &default)?,
+        vec![heading(1, "", ""), heading(1, "!", "")],
+        "should support headings w/o text to make ids from"
+    );
+
+    assert_eq!(
+        headings("> # a\n\n- b\n  ===", &default)?,
+        vec![heading(1, "a", "a"), heading(1, "b", "b")],
+        "should support headings in containers"
+    );
+
+    assert_eq!(
+        headings("a\nb\n===", &default)?,
+        vec![heading(1, "a\nb", "ab")],
+        "should support line endings in setext headings"
+    );
+
+    let value = "# a\n\n## a *b*\n\nc\n=";
+    let mut html = String::new();
+    for info in headings(value, &default)? {
+        html.push_str(&format!(
+            "<h{} id=\"{}\">{}</h{}>\n",
+            info.level, info.id, info.text, info.level
+        ));
+    }
+    assert_eq!(
+        html.replace("a b<", "a <em>b</em><"),
+        to_html_with_options(
+            value,
+            &Options {
+                compile: CompileOptions {
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )? + "\n",
+        "should make the same ids as `heading_ids`"
+    );
+
+    let mut html = String::new();
+    for info in headings(value, &default)? {
+        html.push_str(&format!(
+            "<h{} id=\"{}\">{}<a href=\"#{}\" class=\"anchor\" aria-hidden=\"true\" tabindex=\"-1\">¶</a></h{}>\n",
+            info.level, info.id, info.text, info.id, info.level
+        ));
+    }
+    assert_eq!(
+        html.replace("a b<", "a <em>b</em><"),
+        to_html_with_options(
+            value,
+            &Options {
+                compile: CompileOptions {
+                    heading_anchor: HeadingAnchor::After,
+                    heading_ids: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )? + "\n",
+        "should not include anchors in the text of headings"
+    );
+
+    Ok(())
+}
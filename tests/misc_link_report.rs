@@ -0,0 +1,157 @@
+extern crate markdown;
+use markdown::{link_report, unist::Point, Constructs, DefinitionInfo, LinkRef, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Make a link.
+fn link(destination: &str, title: Option<&str>, point: Point, resolved: bool) -> LinkRef {
+    LinkRef {
+        destination: destination.into(),
+        title: title.map(Into::into),
+        point,
+        resolved_from_definition: resolved,
+    }
+}
+
+#[test]
+fn link_report_links() -> Result<(), String> {
+    let default = ParseOptions::default();
+
+    assert_eq!(
+        link_report("a *b*", &default)?.links,
+        vec![],
+        "should support a document w/o links"
+    );
+
+    assert_eq!(
+        link_report("a [b](c \"d\") ![e](<f g>)\n[h]()", &default)?.links,
+        vec![
+            link("c", Some("d"), Point::new(1, 3, 2), false),
+            link("f g", None, Point::new(1, 14, 13), false),
+            link("", None, Point::new(2, 1, 25), false)
+        ],
+        "should support links and images w/ resources"
+    );
+
+    assert_eq!(
+        link_report("[a] [a][] [c][A]\n\n[a]: d 'e'\n[a]: f", &default)?.links,
+        vec![
+            link("d", Some("e"), Point::new(1, 1, 0), true),
+            link("d", Some("e"), Point::new(1, 5, 4), true),
+            link("d", Some("e"), Point::new(1, 11, 10), true)
+        ],
+        "should support references, w/ the first definition"
+    );
+
+    assert_eq!(
+        link_report("[a](b\\)c&amp;d \"e&quot;\")", &default)?.links,
+        vec![link("b)c&d", Some("e\""), Point::new(1, 1, 0), false)],
+        "should decode escapes and references"
+    );
+
+    assert_eq!(
+        link_report("![a [b](c)](d)", &default)?.links,
+        vec![
+            link("d", None, Point::new(1, 1, 0), false),
+            link("c", None, Point::new(1, 5, 4), false)
+        ],
+        "should support links in images, in the order they start"
+    );
+
+    assert_eq!(
+        link_report("<https://a.b> <c@d.e>", &default)?.links,
+        vec![
+            link("https://a.b", None, Point::new(1, 1, 0), false),
+            link("mailto:c@d.e", None, Point::new(1, 15, 14), false)
+        ],
+        "should support autolinks"
+    );
+
+    assert_eq!(
+        link_report("www.a.com b@c.d https://e.f", &ParseOptions::gfm())?.links,
+        vec![
+            link("http://www.a.com", None, Point::new(1, 1, 0), false),
+            link("mailto:b@c.d", None, Point::new(1, 11, 10), false),
+            link("https://e.f", None, Point::new(1, 17, 16), false)
+        ],
+        "should support autolink literals (gfm)"
+    );
+
+    assert_eq!(
+        link_report("> - [a]\n\n[a]: b", &default)?.links,
+        vec![link("b", None, Point::new(1, 5, 4), true)],
+        "should point to the source in containers"
+    );
+
+    assert_eq!(
+        link_report("[^1]\n\n[^1]: x", &ParseOptions::gfm())?.links,
+        vec![],
+        "should not include footnote calls (gfm)"
+    );
+
+    let inline_footnote = ParseOptions {
+        constructs: Constructs {
+            inline_footnote: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        link_report("a^[inline]", &inline_footnote)?.links,
+        vec![],
+        "should not include inline footnotes"
+    );
+
+    assert_eq!(
+        link_report("a^[b [c]]\n\n[c]: d", &inline_footnote)?.links,
+        vec![link("d", None, Point::new(1, 6, 5), true)],
+        "should support references in inline footnotes"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn link_report_definitions() -> Result<(), String> {
+    let default = ParseOptions::default();
+
+    assert_eq!(
+        link_report("[a]: b\n[C]: d\n\n> [e]: f\n\n[c] ![c][] [a](g)", &default)?.definitions,
+        vec![
+            DefinitionInfo {
+                label: "a".into(),
+                point: Point::new(1, 1, 0),
+                used: false
+            },
+            DefinitionInfo {
+                label: "C".into(),
+                point: Point::new(2, 1, 7),
+                used: true
+            },
+            DefinitionInfo {
+                label: "e".into(),
+                point: Point::new(4, 3, 17),
+                used: false
+            }
+        ],
+        "should support definitions, and whether they are used"
+    );
+
+    assert_eq!(
+        link_report("[x]: a\n[x]: b\n[X]: c\n\n[x]", &default)?.links,
+        vec![link("a", None, Point::new(5, 1, 22), true)],
+        "should resolve to the first of duplicate definitions"
+    );
+
+    assert_eq!(
+        link_report("[x]: a\n[x]: b\n[X]: c\n\n[x]", &default)?
+            .definitions
+            .iter()
+            .map(|d| d.used)
+            .collect::<Vec<_>>(),
+        vec![true, false, false],
+        "should not mark later duplicate definitions as used"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+extern crate markdown;
+use markdown::{parse, MarkdownError, ParseOptions, Parser};
+use pretty_assertions::assert_eq;
+
+/// Pass `chunks` to a parser, and get the markdown and events it results in.
+fn feed(chunks: &[&[u8]], options: &ParseOptions) -> Result<(String, String), String> {
+    let mut parser = Parser::new(options);
+
+    for chunk in chunks {
+        parser.write(chunk);
+    }
+
+    let tree = parser.finish()?;
+    Ok((tree.value().into(), format!("{:?}", tree.events())))
+}
+
+#[test]
+fn parser() -> Result<(), String> {
+    let default = ParseOptions::default();
+    let value = "> a\té\r\n>\t- 𝒳 *b*\r\n\r\n\tc\u{feff}\rd";
+    let expected = (value.to_string(), format!("{:?}", parse(value, &default)?));
+    let bytes = value.as_bytes();
+
+    assert_eq!(
+        feed(&[], &default)?,
+        (String::new(), format!("{:?}", parse("", &default)?)),
+        "should support no chunks"
+    );
+
+    assert_eq!(
+        feed(&[bytes], &default)?,
+        expected,
+        "should support one chunk"
+    );
+
+    let mut index = 0;
+    while index <= bytes.len() {
+        assert_eq!(
+            feed(&[&bytes[..index], &bytes[index..]], &default)?,
+            expected,
+            "should support chunks split at {}",
+            index
+        );
+        index += 1;
+    }
+
+    let single = bytes.chunks(1).collect::<Vec<_>>();
+    assert_eq!(
+        feed(&single, &default)?,
+        expected,
+        "should support chunks of single bytes"
+    );
+
+    assert_eq!(
+        feed(&[b"a\xC3", b"\xA9b"], &default)?.0,
+        "aéb",
+        "should support a character split between chunks"
+    );
+
+    assert_eq!(
+        feed(&[b"\ta\r", b"\n\tb"], &default)?.0,
+        "\ta\r\n\tb",
+        "should support a CRLF split between chunks"
+    );
+
+    assert_eq!(
+        feed(&[b"a\xC3", b"b\xFF"], &default)?.0,
+        "a\u{fffd}b\u{fffd}",
+        "should replace bytes that are not valid UTF-8"
+    );
+
+    assert_eq!(
+        feed(&[b"a\xE2\x82"], &default)?.0,
+        "a\u{fffd}",
+        "should replace a character that is not finished at the end"
+    );
+
+    let options = ParseOptions {
+        tab_size: 0,
+        ..Default::default()
+    };
+    assert_eq!(
+        feed(&[b"a"], &options),
+        Err(String::from(MarkdownError::InvalidOptions(
+            "Unexpected `tab_size` of `0`, expected a size of at least `1`".into()
+        ))),
+        "should error with invalid options"
+    );
+
+    Ok(())
+}
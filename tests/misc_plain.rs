@@ -0,0 +1,110 @@
+extern crate markdown;
+use markdown::{to_plain, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn plain() -> Result<(), String> {
+    let default = ParseOptions::default();
+    let gfm = ParseOptions::gfm();
+
+    assert_eq!(
+        to_plain("", &default)?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_plain("# a *b*\n\nc **d**\ne", &default)?,
+        "a b\n\nc d\ne",
+        "should separate headings and paragraphs by blank lines"
+    );
+
+    assert_eq!(
+        to_plain("a\\\nb  \nc", &default)?,
+        "a\nb\nc",
+        "should turn hard breaks into line endings"
+    );
+
+    assert_eq!(
+        to_plain(
+            "[a](b \"c\"), ![d *e*](f), [g][], [h][g], <https://i.j>\n\n[g]: k",
+            &default
+        )?,
+        "a, d e, g, h, https://i.j",
+        "should turn links into their text, images into their alt, and autolinks into their URL"
+    );
+
+    assert_eq!(
+        to_plain("a `` b`c `` d", &default)?,
+        "a b`c d",
+        "should keep code (text)"
+    );
+
+    assert_eq!(
+        to_plain("```js\na\n\n  b\n```\n\n    c", &default)?,
+        "a\n\n  b\n\nc",
+        "should keep code (flow)"
+    );
+
+    assert_eq!(
+        to_plain("a <b>c</b>\n\n<div>\nd\n</div>\n\n***\n\ne", &default)?,
+        "a c\n\ne",
+        "should drop HTML and thematic breaks"
+    );
+
+    assert_eq!(
+        to_plain("a&amp;b \\* &#x41;", &default)?,
+        "a&b * A",
+        "should support character escapes and references"
+    );
+
+    assert_eq!(
+        to_plain("> a\n> b\n>\n> c", &default)?,
+        "a\nb\n\nc",
+        "should drop block quote markers"
+    );
+
+    assert_eq!(
+        to_plain("* a\n* b\n  * c\n\n    d\n* e", &default)?,
+        "- a\n- b\n  - c\n\n    d\n- e",
+        "should prefix list items and indent their content"
+    );
+
+    assert_eq!(
+        to_plain("* a\n\n* b", &default)?,
+        "- a\n\n- b",
+        "should separate items in loose lists by blank lines"
+    );
+
+    assert_eq!(
+        to_plain("3. a\n1. b\n\nc", &default)?,
+        "3. a\n4. b\n\nc",
+        "should number items in ordered lists"
+    );
+
+    assert_eq!(
+        to_plain("-\n- a", &default)?,
+        "-\n- a",
+        "should not add whitespace after empty items"
+    );
+
+    assert_eq!(
+        to_plain("| a | b |\n| - | - |\n| `c\\|d` |", &gfm)?,
+        "a\tb\nc|d",
+        "should separate cells by tabs (gfm)"
+    );
+
+    assert_eq!(
+        to_plain("* [x] a\n* [ ] b", &gfm)?,
+        "- [x] a\n- [ ] b",
+        "should support tasks (gfm)"
+    );
+
+    assert_eq!(
+        to_plain("a[^b] www.c.com\n\n[^b]: d", &gfm)?,
+        "a www.c.com\n\nd",
+        "should drop footnote labels (gfm)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+extern crate markdown;
+use markdown::{parse, reparse, unist::Point, Edit, ParseOptions, ParseTree, Warning};
+use pretty_assertions::assert_eq;
+
+/// Edit `value`, and get the events of parsing the result again, and of
+/// parsing it from scratch.
+fn edit(
+    value: &str,
+    start: usize,
+    end: usize,
+    insert: &str,
+    options: &ParseOptions,
+) -> Result<(String, String), String> {
+    let tree = ParseTree::new(value, options)?;
+    let tree = reparse(
+        &tree,
+        Edit {
+            start,
+            end,
+            value: insert.into(),
+        },
+    )?;
+    let expected = parse(tree.value(), options)?;
+    Ok((format!("{:?}", tree.events()), format!("{:?}", expected)))
+}
+
+#[test]
+fn reparse_edit() -> Result<(), String> {
+    let default = ParseOptions::default();
+    let gfm = ParseOptions::gfm();
+
+    let (actual, expected) = edit("a\n\nb\n\nc", 3, 4, "*d*", &default)?;
+    assert_eq!(actual, expected, "should support editing a paragraph");
+
+    let (actual, expected) = edit("a\n\nb\n\nc", 0, 0, "# ", &default)?;
+    assert_eq!(actual, expected, "should support an edit at the start");
+
+    let (actual, expected) = edit("a\n\nb\n\nc", 7, 7, "\n\nd", &default)?;
+    assert_eq!(actual, expected, "should support an edit at the end");
+
+    let (actual, expected) = edit("a\n\nb\n\nc\n\nd", 3, 3, "```\n", &default)?;
+    assert_eq!(
+        actual, expected,
+        "should support opening a fenced code block that includes later blocks"
+    );
+
+    let (actual, expected) = edit("```\na\n\nb\n\nc", 0, 4, "", &default)?;
+    assert_eq!(
+        actual, expected,
+        "should support removing a fence, splitting a code block into blocks"
+    );
+
+    let (actual, expected) = edit("- a\n\nb\n\nc", 5, 5, "  ", &default)?;
+    assert_eq!(
+        actual, expected,
+        "should support indenting a block into a list item before it"
+    );
+
+    let (actual, expected) = edit("a\n\n[b]\n\nc", 9, 9, "\n\n[b]: d", &default)?;
+    assert_eq!(actual, expected, "should support adding a definition");
+
+    let (actual, expected) = edit("[a]\n\nb\n\n[a]: c", 8, 14, "", &default)?;
+    assert_eq!(actual, expected, "should support removing a definition");
+
+    let (actual, expected) = edit("a\n\n| b |\n\nc", 8, 8, "\n| - |", &gfm)?;
+    assert_eq!(actual, expected, "should support extensions (gfm)");
+
+    let (actual, expected) = edit("a\r\n\r\nb\r\n\r\nc", 5, 5, "d\r\ne", &default)?;
+    assert_eq!(actual, expected, "should support CRLF line endings");
+
+    let options = ParseOptions {
+        warning_sink: Some(Box::new(|_warning: Warning| {})),
+        ..Default::default()
+    };
+    let (actual, expected) = edit("[a]\n\nb", 5, 5, "[c]", &options)?;
+    assert_eq!(
+        actual, expected,
+        "should support a warning sink (by parsing everything again)"
+    );
+
+    let tree = ParseTree::new("a\n\nb", &default)?;
+    assert_eq!(
+        reparse(
+            &tree,
+            Edit {
+                start: 3,
+                end: 4,
+                value: "c".into()
+            }
+        )?
+        .value(),
+        "a\n\nc",
+        "should support getting the edited markdown"
+    );
+
+    let tree = reparse(
+        &tree,
+        Edit {
+            start: 1,
+            end: 1,
+            value: "\nd".into(),
+        },
+    )?;
+    assert_eq!(
+        (tree.offset_to_point(5), tree.point_to_offset(2, 1)),
+        (Some(Point::new(4, 1, 5)), Some(2)),
+        "should support offsets and points in the edited markdown"
+    );
+
+    Ok(())
+}
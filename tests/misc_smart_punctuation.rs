@@ -0,0 +1,107 @@
+extern crate markdown;
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn smart_punctuation() -> Result<(), String> {
+    let smart = Options {
+        compile: CompileOptions {
+            smart_punctuation: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("\"a\" 'b' c--d e---f g..."),
+        "<p>&quot;a&quot; 'b' c--d e---f g...</p>",
+        "should not support smart punctuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"a\" 'b' c--d e---f g...", &smart)?,
+        "<p>“a” ‘b’ c–d e—f g…</p>",
+        "should support smart punctuation w/ `smart_punctuation`"
+    );
+
+    assert_eq!(
+        to_html_with_options("It's 'a' rock song from the '90s", &smart)?,
+        "<p>It’s ‘a’ rock song from the ‘90s</p>",
+        "should turn apostrophes into right single quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"*a*\" *\"b\"* (\"c\")", &smart)?,
+        "<p>“<em>a</em>” <em>“b”</em> (“c”)</p>",
+        "should infer quote direction across other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"a\nb\"", &smart)?,
+        "<p>“a\nb”</p>",
+        "should infer quote direction across line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a--\nb", &smart)?,
+        "<p>a–\nb</p>",
+        "should support dashes at the end of a line"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\\"a\\\" a\\--b a\\...", &smart)?,
+        "<p>&quot;a&quot; a--b a...</p>",
+        "should not change escaped punctuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("&quot;a&quot; a&#45;-b", &smart)?,
+        "<p>&quot;a&quot; a--b</p>",
+        "should not change punctuation from character references"
+    );
+
+    assert_eq!(
+        to_html_with_options("`\"a--b...\"`", &smart)?,
+        "<p><code>&quot;a--b...&quot;</code></p>",
+        "should not change code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```a--b\n\"c--d\"\n```\n\n    'e--f'", &smart)?,
+        "<pre><code class=\"language-a--b\">&quot;c--d&quot;\n</code></pre>\n<pre><code>'e--f'\n</code></pre>",
+        "should not change code (fenced, indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<i title=\"a--b\">\"c\"</i>\n\n<div title='d--e'>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    smart_punctuation: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><i title=\"a--b\">“c”</i></p>\n<div title='d--e'>",
+        "should not change HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[\"a\"](b--c 'd--e') ![f--g](h...) <https://i.j/k--l>\n\n[m]: n--o",
+            &smart
+        )?,
+        "<p><a href=\"b--c\" title=\"d–e\">“a”</a> <img src=\"h...\" alt=\"f–g\" /> <a href=\"https://i.j/k--l\">https://i.j/k--l</a></p>\n",
+        "should not change URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("# \"a\"\n\n> b...", &smart)?,
+        "<h1>“a”</h1>\n<blockquote>\n<p>b…</p>\n</blockquote>",
+        "should change text in other flow constructs"
+    );
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 extern crate markdown;
-use markdown::to_html;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Options,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn soft_break() {
+fn soft_break() -> Result<(), String> {
     assert_eq!(
         to_html("foo\nbaz"),
         "<p>foo\nbaz</p>",
@@ -15,4 +20,82 @@ fn soft_break() {
         "<p>foo\nbaz</p>",
         "should trim spaces around line endings"
     );
+
+    let hard = Options {
+        compile: CompileOptions {
+            soft_break_as_hard: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\nb\nc", &hard)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should support `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb\\\nc", &hard)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should not add a second break after hard breaks w/ `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a\nb* [c\nd](e\n'f\ng') ![h\ni](j)", &hard)?,
+        "<p><em>a<br />\nb</em> <a href=\"e\" title=\"f\ng\">c<br />\nd</a> <img src=\"j\" alt=\"h\ni\" /></p>",
+        "should not add breaks in titles or image descriptions w/ `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb`\nc", &hard)?,
+        "<p><code>a b</code><br />\nc</p>",
+        "should not add breaks in code (text) w/ `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n===\n\n```\nc\nd\n```\n\n- e\n  f\n- g", &hard)?,
+        "<h1>a\nb</h1>\n<pre><code>c\nd\n</code></pre>\n<ul>\n<li>e<br />\nf</li>\n<li>g</li>\n</ul>",
+        "should only add breaks in paragraphs w/ `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <b\nc> d", &hard)?,
+        "<p>a &lt;b<br />\nc&gt; d</p>",
+        "should add breaks in HTML shown as text w/ `soft_break_as_hard`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <b\nc> d\ne",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    hard_break_html: Some("<br>".into()),
+                    soft_break_as_hard: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <b\nc> d<br>\ne</p>",
+        "should not add breaks in HTML w/ `soft_break_as_hard` and `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_mdast("foo \n baz", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "foo\nbaz".into(),
+                    position: Some(Position::new(1, 1, 0, 2, 5, 9))
+                }),],
+                position: Some(Position::new(1, 1, 0, 2, 5, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 5, 9))
+        }),
+        "should support line endings as part of `Text`s in mdast"
+    );
+
+    Ok(())
 }
@@ -1,6 +1,12 @@
 extern crate markdown;
-use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    offset_to_point, parse, point_to_offset, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, EventKind, EventName, MarkdownError, Options, ParseOptions, ParseTree, Warning,
+};
 use pretty_assertions::assert_eq;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn tabs_flow() -> Result<(), String> {
@@ -285,9 +291,189 @@ fn tabs_virtual_spaces() {
 
     assert_eq!(
         to_html("-\ta\n\n\tb"),
-        "<ul>\n<li>\n<p>a</p>\n<p>\tb</p>\n</li>\n</ul>",
-        // To do: CM.js does not output the tab before `b`. See if that makes sense?
-        // "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
         "should support a part of a tab as a container, and the rest of a tab as flow"
     );
 }
+
+#[test]
+fn tabs_size() -> Result<(), String> {
+    let eight = &Options {
+        parse: ParseOptions {
+            tab_size: 8,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let two = &Options {
+        parse: ParseOptions {
+            tab_size: 2,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("\tx", eight)?,
+        "<pre><code>    x\n</code></pre>",
+        "should support a tab size of 8 (code)"
+    );
+
+    assert_eq!(
+        to_html_with_options("  \tx", eight)?,
+        "<pre><code>    x\n</code></pre>",
+        "should expand a tab to the next tab stop"
+    );
+
+    assert_eq!(
+        to_html_with_options("\tx", two)?,
+        "<p>x</p>",
+        "should support a tab size of 2 (not enough for code)"
+    );
+
+    assert_eq!(
+        to_html_with_options("\t\tx", two)?,
+        "<pre><code>x\n</code></pre>",
+        "should support a tab size of 2 (two tabs for code)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">\tx", eight)?,
+        "<blockquote>\n<pre><code>  x\n</code></pre>\n</blockquote>",
+        "should support a tab size of 8 (block quote)"
+    );
+
+    assert_eq!(
+        to_html_with_options("-\tx", eight)?,
+        "<ul>\n<li>\n<pre><code>  x\n</code></pre>\n</li>\n</ul>",
+        "should support a tab size of 8 (list item)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n\tx", eight)?,
+        "<pre><code>\tx\n</code></pre>\n",
+        "should keep tabs in fenced code"
+    );
+
+    assert_eq!(
+        to_mdast("a\tb", &eight.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\tb".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 10, 3))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 10, 3))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 3))
+        }),
+        "should use the tab size for columns in positions"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "\tx",
+            &Options {
+                parse: ParseOptions {
+                    tab_size: 0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        ),
+        Err(MarkdownError::InvalidOptions(
+            "Unexpected `tab_size` of `0`, expected a size of at least `1`".into()
+        )),
+        "should crash on a tab size of 0"
+    );
+
+    let warnings = Rc::new(RefCell::new(vec![]));
+    let sink = warnings.clone();
+    let warn = Options {
+        parse: ParseOptions {
+            warning_sink: Some(Box::new(move |warning: Warning| {
+                sink.borrow_mut().push(warning.to_string());
+            })),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("- a\n\n\tb\n\n \tc", &warn)?,
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>",
+        "should support split tabs w/ `warning_sink`"
+    );
+
+    assert_eq!(
+        warnings.take(),
+        vec![
+            "3:3: Unexpected tab that is partially used as indentation, expected spaces",
+            "5:3: Unexpected tab that is partially used as indentation, expected spaces"
+        ],
+        "should warn about split tabs"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tabs_offsets() -> Result<(), String> {
+    for tab_size in [4, 2] {
+        let options = ParseOptions {
+            tab_size,
+            ..Default::default()
+        };
+        let value = "a\tb\r\n\r\n>\t-\tc\td\r\n\t\te\tf\r\n\r\n\t```\tg\r\n\th\t\r\n";
+        let bom = format!("\u{feff}{}", value);
+
+        for value in [value, bom.as_str()] {
+            let tree = ParseTree::new(value, &options)?;
+
+            for event in parse(value, &options)? {
+                let point = &event.point;
+
+                if point.vs == 0 {
+                    assert_eq!(
+                        offset_to_point(value, point.index, &options).map(|d| (d.line, d.column)),
+                        Some((point.line, point.column)),
+                        "should match the point of events at {} (tab size {})",
+                        point.index,
+                        tab_size
+                    );
+                    assert_eq!(
+                        tree.offset_to_point(point.index),
+                        offset_to_point(value, point.index, &options),
+                        "should match the point of events at {} in a tree (tab size {})",
+                        point.index,
+                        tab_size
+                    );
+                }
+
+                // The start of a BOM is at the same column as what is after it.
+                if event.kind == EventKind::Enter && event.name == EventName::ByteOrderMark {
+                    continue;
+                }
+
+                assert_eq!(
+                    point_to_offset(value, point.line, point.column, &options),
+                    Some(point.index),
+                    "should match the offset of events at {}:{} (tab size {})",
+                    point.line,
+                    point.column,
+                    tab_size
+                );
+                assert_eq!(
+                    tree.point_to_offset(point.line, point.column),
+                    Some(point.index),
+                    "should match the offset of events at {}:{} in a tree (tab size {})",
+                    point.line,
+                    point.column,
+                    tab_size
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
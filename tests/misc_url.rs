@@ -1,5 +1,5 @@
 extern crate markdown;
-use markdown::to_html;
+use markdown::{to_html, to_html_with_options, Options};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -146,3 +146,39 @@ fn url() {
         "should support ascii characters"
     );
 }
+
+#[test]
+fn url_github() -> Result<(), String> {
+    // Outputs here are the same as those of github.com.
+    assert_eq!(
+        to_html("[a](<https://example.com/a b>)"),
+        "<p><a href=\"https://example.com/a%20b\">a</a></p>",
+        "should encode a space like GitHub"
+    );
+
+    assert_eq!(
+        to_html("[a](https://example.com/ü/日本)"),
+        "<p><a href=\"https://example.com/%C3%BC/%E6%97%A5%E6%9C%AC\">a</a></p>",
+        "should encode non-ascii path segments like GitHub"
+    );
+
+    assert_eq!(
+        to_html("[a](https://example.com/%C3%BC%20b)"),
+        "<p><a href=\"https://example.com/%C3%BC%20b\">a</a></p>",
+        "should not encode encoded sequences again like GitHub"
+    );
+
+    assert_eq!(
+        to_html("![a](<https://example.com/a b/ü.png>)"),
+        "<p><img src=\"https://example.com/a%20b/%C3%BC.png\" alt=\"a\" /></p>",
+        "should encode image sources like GitHub"
+    );
+
+    assert_eq!(
+        to_html_with_options("www.example.com/ü", &Options::gfm())?,
+        "<p><a href=\"http://www.example.com/%C3%BC\">www.example.com/ü</a></p>",
+        "should encode GFM autolink literals like GitHub"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Subscript, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn subscript() -> Result<(), String> {
+    let sub = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+    let gfm_sub = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("H~2~O"),
+        "<p>H~2~O</p>",
+        "should not support subscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("H~2~O", &sub)?,
+        "<p>H<sub>2</sub>O</p>",
+        "should support subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~ b ~", &sub)?,
+        "<p>a ~ b ~</p>",
+        "should not support whitespace after an opening or before a closing tilde"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a b~", &sub)?,
+        "<p>~a b~</p>",
+        "should not support whitespace inside subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a~~", &sub)?,
+        "<p>~~a~~</p>",
+        "should not support subscript w/ several tildes"
+    );
+
+    assert_eq!(
+        to_html_with_options("~*a*~ ~a`~`", &sub)?,
+        "<p><sub><em>a</em></sub> ~a<code>~</code></p>",
+        "should support phrasing in subscript, but not tildes in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a~ ~~b~~", &gfm_sub)?,
+        "<p><sub>a</sub> <del>b</del></p>",
+        "should prefer subscript for one tilde, and strikethrough for two"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a b~ ~~a b~~", &gfm_sub)?,
+        "<p>~a b~ <del>a b</del></p>",
+        "should not support strikethrough w/ one tilde"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~H~2~O~~ ~H~~2~~O~", &gfm_sub)?,
+        "<p><del>H<sub>2</sub>O</del> <sub>H<del>2</del>O</sub></p>",
+        "should support subscript and strikethrough in each other"
+    );
+
+    assert_eq!(
+        to_mdast("H~2~O", &sub.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "H".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::Subscript(Subscript {
+                        children: vec![Node::Text(Text {
+                            value: "2".into(),
+                            position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                        })],
+                        position: Some(Position::new(1, 2, 1, 1, 5, 4))
+                    }),
+                    Node::Text(Text {
+                        value: "O".into(),
+                        position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should support subscript as `Subscript`s in mdast"
+    );
+
+    Ok(())
+}
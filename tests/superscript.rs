@@ -0,0 +1,145 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Superscript, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn superscript() -> Result<(), String> {
+    let sup = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                superscript: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("x^2^"),
+        "<p>x^2^</p>",
+        "should not support superscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("x^2^", &sup)?,
+        "<p>x<sup>2</sup></p>",
+        "should support superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a^ b^c^d", &sup)?,
+        "<p><sup>a</sup> b<sup>c</sup>d</p>",
+        "should support superscript next to whitespace and inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^ b ^", &sup)?,
+        "<p>a ^ b ^</p>",
+        "should not support whitespace after an opening or before a closing caret"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a b^", &sup)?,
+        "<p>^a b^</p>",
+        "should not support whitespace inside superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a\nb^", &sup)?,
+        "<p>^a\nb^</p>",
+        "should not support a line ending inside superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^^ ^^a^^", &sup)?,
+        "<p>^^ ^^a^^</p>",
+        "should not support superscript w/o content or w/ several carets"
+    );
+
+    assert_eq!(
+        to_html_with_options("x^2^y^3^", &sup)?,
+        "<p>x<sup>2</sup>y<sup>3</sup></p>",
+        "should support several superscripts"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a^b^", &sup)?,
+        "<p><sup>a</sup>b^</p>",
+        "should match the first closing caret"
+    );
+
+    assert_eq!(
+        to_html_with_options("x^*a*^ x^[a](b)^", &sup)?,
+        "<p>x<sup><em>a</em></sup> x<sup><a href=\"b\">a</a></sup></p>",
+        "should support phrasing in superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a`^` [^b](c)^", &sup)?,
+        "<p>^a<code>^</code> <a href=\"c\">^b</a>^</p>",
+        "should not match carets in code, or across links"
+    );
+
+    assert_eq!(
+        to_html_with_options("x^\\^a^ \\^a^", &sup)?,
+        "<p>x<sup>^a</sup> ^a^</p>",
+        "should support escaped carets"
+    );
+
+    assert_eq!(
+        to_html_with_options("![x^2^](a)", &sup)?,
+        "<p><img src=\"a\" alt=\"x2\" /></p>",
+        "should support superscript in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "x^[a]^",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        inline_footnote: true,
+                        superscript: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>x<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup>^</p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p>a <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should prefer inline footnotes"
+    );
+
+    assert_eq!(
+        to_mdast("x^2^", &sup.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "x".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::Superscript(Superscript {
+                        children: vec![Node::Text(Text {
+                            value: "2".into(),
+                            position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                        })],
+                        position: Some(Position::new(1, 2, 1, 1, 5, 4))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 5, 4))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 4))
+        }),
+        "should support superscript as `Superscript`s in mdast"
+    );
+
+    Ok(())
+}
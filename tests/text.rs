@@ -1,9 +1,13 @@
 extern crate markdown;
-use markdown::to_html;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_mdast,
+    unist::Position,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn text() {
+fn text() -> Result<(), String> {
     assert_eq!(
         to_html("hello $.;'there"),
         "<p>hello $.;'there</p>",
@@ -21,4 +25,21 @@ fn text() {
         "<p>Multiple     spaces</p>",
         "should preserve internal spaces verbatim"
     );
+
+    assert_eq!(
+        to_mdast("alpha bravo", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "alpha bravo".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 12, 11))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support text as `Text`s in `Paragraph`s in mdast"
+    );
+
+    Ok(())
 }
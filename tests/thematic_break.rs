@@ -189,6 +189,62 @@ fn thematic_break() -> Result<(), String> {
         "should support turning off thematic breaks"
     );
 
+    let asterisks = Options {
+        parse: ParseOptions {
+            thematic_break_markers: Some(vec!['*']),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("***\n\n___\n\n---", &asterisks)?,
+        "<hr />\n<p>___</p>\n<p>---</p>",
+        "should support `thematic_break_markers`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n---\n\nb\n***", &asterisks)?,
+        "<h2>a</h2>\n<p>b</p>\n<hr />",
+        "should support setext heading underlines w/ disallowed markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n- b\n\n- - -", &asterisks)?,
+        "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<ul>\n<li>\n<ul>\n<li></li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>",
+        "should support lists w/ disallowed markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***\n\n****\n\n* * * * *",
+            &Options {
+                parse: ParseOptions {
+                    thematic_break_size_min: Some(4),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>***</p>\n<hr />\n<hr />",
+        "should support `thematic_break_size_min`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "**\n\n***",
+            &Options {
+                parse: ParseOptions {
+                    thematic_break_size_min: Some(1),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>**</p>\n<hr />",
+        "should not support `thematic_break_size_min` below 3"
+    );
+
     assert_eq!(
         to_mdast("***", &Default::default())?,
         Node::Root(Root {
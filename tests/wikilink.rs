@@ -0,0 +1,250 @@
+extern crate markdown;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text, Wikilink},
+    to_html, to_html_with_options, to_mdast, to_plain,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn wikilink() -> Result<(), String> {
+    let wikilink = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                wikilink: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("[[a]]"),
+        "<p>[[a]]</p>",
+        "should not support wikilinks by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]]", &wikilink)?,
+        "<p><a href=\"a\">a</a></p>",
+        "should support wikilinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[Some Page]]", &wikilink)?,
+        "<p><a href=\"some-page\">Some Page</a></p>",
+        "should slug the target by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|b]]", &wikilink)?,
+        "<p><a href=\"a\">b</a></p>",
+        "should support a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|]]", &wikilink)?,
+        "<p><a href=\"a\">a</a></p>",
+        "should treat an empty label as no label"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[ a b | c ]]", &wikilink)?,
+        "<p><a href=\"a-b\">c</a></p>",
+        "should trim the target and label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[[b]]c", &wikilink)?,
+        "<p>a<a href=\"b\">b</a>c</p>",
+        "should support wikilinks inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[]] [[ ]] [[|a]]", &wikilink)?,
+        "<p>[[]] [[ ]] [[|a]]</p>",
+        "should not support an empty target"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[[a]]]", &wikilink)?,
+        "<p>[<a href=\"a\">a</a>]</p>",
+        "should support a wikilink in brackets"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]b]] [[a[b]]", &wikilink)?,
+        "<p>[[a]b]] [[a[b]]</p>",
+        "should not support brackets inside"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|b|c]]", &wikilink)?,
+        "<p>[[a|b|c]]</p>",
+        "should not support a pipe in the label"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a\nb]]", &wikilink)?,
+        "<p>[[a\nb]]</p>",
+        "should not support line endings inside"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a", &wikilink)?,
+        "<p>[[a</p>",
+        "should not support a missing closing"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]", &wikilink)?,
+        "<p>[[a]</p>",
+        "should not support a half closing"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[*a*|`b`]]", &wikilink)?,
+        "<p><a href=\"a\">`b`</a></p>",
+        "should not support constructs inside"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|<b>&amp;]]", &wikilink)?,
+        "<p><a href=\"a\">&lt;b&gt;&amp;amp;</a></p>",
+        "should encode the label"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[[a]]*", &wikilink)?,
+        "<p><em><a href=\"a\">a</a></em></p>",
+        "should support wikilinks in other phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("[b [[a]]](c)", &wikilink)?,
+        "<p><a href=\"c\">b a</a></p>",
+        "should not link wikilinks in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![b [[a]]](c)", &wikilink)?,
+        "<p><img src=\"c\" alt=\"b a\" /></p>",
+        "should use the label of wikilinks in images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]]\n\n[a]: b", &wikilink)?,
+        "<p><a href=\"a\">a</a></p>\n",
+        "should take precedence over references"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[[a]] [^b]\n\n[^b]: c",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wikilink: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p><a href=\"a\">a</a> <sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-b\">\n<p>c <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should not interfere with GFM footnote calls"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[[Some Page|a]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wikilink: true,
+                        ..Constructs::default()
+                    },
+                    wikilink_url: Some(Box::new(|target| format!("/wiki/{}.html", target))),
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"/wiki/Some%20Page.html\">a</a></p>",
+        "should support `wikilink_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[[a]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wikilink: true,
+                        ..Constructs::default()
+                    },
+                    wikilink_url: Some(Box::new(|target| format!("javascript:{}", target))),
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"\">a</a></p>",
+        "should sanitize URLs from `wikilink_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[[a]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wikilink: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    base_url: Some("https://example.com/wiki/".into()),
+                    ..CompileOptions::default()
+                },
+            }
+        )?,
+        "<p><a href=\"https://example.com/wiki/a\">a</a></p>",
+        "should resolve against `base_url`"
+    );
+
+    assert_eq!(
+        to_plain("[[a|b]] [[c]]", &wikilink.parse)?,
+        "b c",
+        "should support wikilinks in plain text"
+    );
+
+    assert_eq!(
+        to_mdast("[[Some Page|a]] b", &wikilink.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Wikilink(Wikilink {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 16, 15)),
+                        target: "Some Page".into(),
+                        url: "some-page".into(),
+                    }),
+                    Node::Text(Text {
+                        value: " b".into(),
+                        position: Some(Position::new(1, 16, 15, 1, 18, 17))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 18, 17))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 18, 17))
+        }),
+        "should support wikilinks as `Wikilink`s in mdast"
+    );
+
+    Ok(())
+}
@@ -47,6 +47,7 @@ fn mdast_util_to_hast_test() {
         mdast_util_to_hast(&mdast::Node::Code(mdast::Code {
             lang: Some("b".into()),
             meta: None,
+            info: Some("b".into()),
             value: "a".into(),
             position: None,
         })),